@@ -60,13 +60,10 @@ fn setup_scene_system(
 
     commands.spawn((
         RatatuiCamera::default(),
-        RatatuiCameraStrategy::HalfBlocks(HalfBlocksConfig {
-            colors: ColorsConfig {
-                support: ColorSupport::ANSI256,
-                ..default()
-            },
-            ..default()
-        }),
+        RatatuiCameraStrategy::HalfBlocks(
+            HalfBlocksConfig::default()
+                .with_colors(ColorsConfig::default().with_support(ColorSupport::ANSI256)),
+        ),
         Camera3d::default(),
         Transform::from_xyz(2.5, 2.5, 2.5).looking_at(Vec3::ZERO, Vec3::Z),
     ));
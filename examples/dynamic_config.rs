@@ -186,5 +186,9 @@ fn toggle_ratatui_camera_strategy(
         }
         RatatuiCameraStrategy::None => RatatuiCameraStrategy::None,
         RatatuiCameraStrategy::Depth(_) => RatatuiCameraStrategy::None,
+        RatatuiCameraStrategy::LuminanceDepth(_) => RatatuiCameraStrategy::None,
+        RatatuiCameraStrategy::Glyphs(_) => RatatuiCameraStrategy::None,
+        RatatuiCameraStrategy::Threshold(_) => RatatuiCameraStrategy::None,
+        RatatuiCameraStrategy::Normals(_) => RatatuiCameraStrategy::None,
     });
 }
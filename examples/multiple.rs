@@ -14,14 +14,12 @@ use bevy_ratatui_camera::ColorChoice;
 use bevy_ratatui_camera::ColorsConfig;
 use bevy_ratatui_camera::LuminanceConfig;
 use bevy_ratatui_camera::RatatuiCamera;
+use bevy_ratatui_camera::RatatuiCameraLayout;
+use bevy_ratatui_camera::RatatuiCameraOrder;
 use bevy_ratatui_camera::RatatuiCameraPlugin;
 use bevy_ratatui_camera::RatatuiCameraStrategy;
-use bevy_ratatui_camera::RatatuiCameraWidget;
+use bevy_ratatui_camera::RatatuiCameraWidgets;
 use log::LevelFilter;
-use ratatui::layout::Constraint;
-use ratatui::layout::Direction;
-use ratatui::layout::Layout;
-use ratatui::widgets::Widget;
 
 mod shared;
 
@@ -61,28 +59,28 @@ fn setup_scene_system(
 
     commands.spawn((
         RatatuiCamera::default(),
+        RatatuiCameraOrder(0),
         RatatuiCameraStrategy::luminance_with_characters(&[' ', '-', '+', '=', '#']),
         Camera3d::default(),
         Transform::from_xyz(0., 3., 0.).looking_at(Vec3::ZERO, Vec3::Z),
     ));
     commands.spawn((
         RatatuiCamera::default(),
-        RatatuiCameraStrategy::Luminance(LuminanceConfig {
-            characters: CharactersConfig {
-                list: RatatuiCameraStrategy::CHARACTERS_BRAILLE.into(),
-                scale: LuminanceConfig::SCALE_DEFAULT,
-            },
-            colors: ColorsConfig {
-                background: Some(ColorChoice::Scale(0.3)),
-                ..default()
-            },
-            ..default()
-        }),
+        RatatuiCameraOrder(1),
+        RatatuiCameraStrategy::Luminance(
+            LuminanceConfig::default()
+                .with_characters(CharactersConfig::new(
+                    RatatuiCameraStrategy::CHARACTERS_BRAILLE,
+                    LuminanceConfig::SCALE_DEFAULT,
+                ))
+                .with_colors(ColorsConfig::default().with_background(ColorChoice::Scale(0.3))),
+        ),
         Camera3d::default(),
         Transform::from_xyz(0., 0., 3.).looking_at(Vec3::ZERO, Vec3::Z),
     ));
     commands.spawn((
         RatatuiCamera::default(),
+        RatatuiCameraOrder(2),
         RatatuiCameraStrategy::luminance_with_characters(&[' ', '.', 'o', 'O', '0']),
         Camera3d::default(),
         Transform::from_xyz(2., 2., 2.).looking_at(Vec3::ZERO, Vec3::Z),
@@ -91,7 +89,7 @@ fn setup_scene_system(
 
 fn draw_scene_system(
     mut ratatui: ResMut<RatatuiContext>,
-    mut camera_widgets: Query<&mut RatatuiCameraWidget>,
+    mut camera_widgets: RatatuiCameraWidgets,
     flags: Res<shared::Flags>,
     diagnostics: Res<DiagnosticsStore>,
     kitty_enabled: Option<Res<KittyEnabled>>,
@@ -99,17 +97,8 @@ fn draw_scene_system(
     ratatui.draw(|frame| {
         let area = shared::debug_frame(frame, &flags, &diagnostics, kitty_enabled.as_deref());
 
-        let widgets = camera_widgets.iter_mut().enumerate().collect::<Vec<_>>();
-
-        let layout = Layout::new(
-            Direction::Horizontal,
-            vec![Constraint::Fill(1); widgets.len()],
-        )
-        .split(area);
-
-        for (i, mut widget) in widgets {
-            widget.render(layout[i], frame.buffer_mut());
-        }
+        let widgets = camera_widgets.iter_ordered().map(|(_, widget)| widget);
+        RatatuiCameraLayout::horizontal().render(area, frame.buffer_mut(), widgets);
     })?;
 
     Ok(())
@@ -61,11 +61,9 @@ fn setup_scene_system(
         Foreground,
         RatatuiCamera::default(),
         RatatuiCameraStrategy::luminance_braille(),
-        RatatuiCameraEdgeDetection {
-            edge_color: Some(ratatui::style::Color::Magenta),
-            edge_characters: EdgeCharacters::Single('#'),
-            ..Default::default()
-        },
+        RatatuiCameraEdgeDetection::default()
+            .with_edge_color(ratatui::style::Color::Magenta)
+            .with_edge_characters(EdgeCharacters::Single('#')),
         Camera3d::default(),
         Camera {
             // by setting this camera's clear_color transparent, background pixels will be given an
@@ -79,11 +77,9 @@ fn setup_scene_system(
         Background,
         RatatuiCamera::default(),
         RatatuiCameraStrategy::luminance_misc(),
-        RatatuiCameraEdgeDetection {
-            edge_color: Some(ratatui::style::Color::Cyan),
-            edge_characters: EdgeCharacters::Single('#'),
-            ..Default::default()
-        },
+        RatatuiCameraEdgeDetection::default()
+            .with_edge_color(ratatui::style::Color::Cyan)
+            .with_edge_characters(EdgeCharacters::Single('#')),
         Camera3d::default(),
         Transform::from_xyz(3., 0., 1.).looking_at(Vec3::ZERO, Vec3::Z),
     ));
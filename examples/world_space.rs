@@ -14,10 +14,12 @@ use bevy_ratatui::event::MouseMessage;
 use bevy_ratatui::kitty::KittyEnabled;
 use bevy_ratatui_camera::RatatuiCamera;
 use bevy_ratatui_camera::RatatuiCameraDepthBuffer;
+use bevy_ratatui_camera::RatatuiCameraDepthBuffers;
 use bevy_ratatui_camera::RatatuiCameraDepthDetection;
 use bevy_ratatui_camera::RatatuiCameraLastArea;
 use bevy_ratatui_camera::RatatuiCameraPlugin;
 use bevy_ratatui_camera::RatatuiCameraWidget;
+use bevy_ratatui_camera::RatatuiOverlayOrder;
 use crossterm::event::MouseEventKind;
 use log::LevelFilter;
 use ratatui::buffer::Buffer;
@@ -177,17 +179,10 @@ fn mouse_follow_system(
 
     let (camera, camera_transform, widget, last_area) = *ratatui_camera;
 
-    let ndc = widget.cell_to_ndc(**last_area, mouse_position);
-
-    let world_position = camera.ndc_to_world(camera_transform, ndc).unwrap();
-
-    let viewport_position = camera
-        .world_to_viewport(camera_transform, world_position)
-        .unwrap();
-
-    let ray = camera
-        .viewport_to_world(camera_transform, viewport_position)
-        .unwrap();
+    let Some(ray) = widget.cell_to_world_ray(**last_area, mouse_position, camera, camera_transform)
+    else {
+        return;
+    };
 
     let Some(intersect_d) = ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y)) else {
         return;
@@ -205,18 +200,19 @@ fn draw_scene_system(
     flags: Res<shared::Flags>,
     diagnostics: Res<DiagnosticsStore>,
     kitty_enabled: Option<Res<KittyEnabled>>,
+    mut depth_buffers: ResMut<RatatuiCameraDepthBuffers>,
 ) -> Result {
     let (camera, camera_transform, ref mut widget) = *ratatui_camera_single;
 
     ratatui.draw(|frame| {
         let area = shared::debug_frame(frame, &flags, &diagnostics, kitty_enabled.as_deref());
 
-        let depth_buffer = &mut widget.new_depth_buffer(area);
+        let depth_buffer = depth_buffers.for_widget(widget, area);
 
         widget.render(area, frame.buffer_mut(), depth_buffer);
 
         // generate a widget for each label by converting its NDC coordinates to a buffer cell.
-        let mut label_widgets = labels
+        let label_widgets = labels
             .iter()
             .filter_map(|(label, label_transform)| {
                 let ndc = camera.world_to_ndc(camera_transform, label_transform.translation())?;
@@ -237,11 +233,16 @@ fn draw_scene_system(
             })
             .collect::<Vec<_>>();
 
-        // use `render_overlay_with_depth` to make sure area is corrected for aspect ratio, widget
-        // is skipped during resize frames, and draws are occluded based on the depth buffer.
-        while let Some(label_widget) = label_widgets.pop() {
-            widget.render_overlay_with_depth(area, frame.buffer_mut(), &label_widget, depth_buffer);
-        }
+        // use `render_overlays_with_depth` to make sure area is corrected for aspect ratio,
+        // widgets are skipped during resize frames, labels are drawn furthest first, and draws are
+        // occluded based on the depth buffer.
+        let label_widgets = label_widgets.iter().map(|label_widget| {
+            (
+                RatatuiOverlayOrder::new(0, label_widget.depth),
+                label_widget,
+            )
+        });
+        widget.render_overlays_with_depth(area, frame.buffer_mut(), label_widgets, depth_buffer);
     })?;
 
     Ok(())
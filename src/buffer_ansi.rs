@@ -0,0 +1,110 @@
+use std::{fmt::Write, ops::Range};
+
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+
+/// Convert the contents of a ratatui buffer into text with ANSI escape sequences for colors, one
+/// line per buffer row, suitable for printing directly to a terminal or saving as a `.ans` file.
+pub fn buffer_to_ansi(buffer: &Buffer) -> String {
+    let mut ansi = String::new();
+
+    for y in buffer.area.top()..buffer.area.bottom() {
+        write_row_ansi(
+            &mut ansi,
+            buffer,
+            y,
+            buffer.area.left()..buffer.area.right(),
+        );
+        ansi.push_str("\r\n");
+    }
+
+    ansi
+}
+
+/// Convert the cells of a buffer within the area into ANSI escape sequences that move the cursor
+/// to the start of each row before drawing it, redrawing that area of a terminal in place.
+pub(crate) fn buffer_area_to_ansi(buffer: &Buffer, area: Rect) -> String {
+    let mut ansi = String::new();
+
+    for y in area.top()..area.bottom() {
+        let _ = write!(ansi, "\x1b[{};{}H", y + 1, area.left() + 1);
+        write_row_ansi(&mut ansi, buffer, y, area.left()..area.right());
+    }
+
+    ansi
+}
+
+/// Append the cells of one buffer row within the columns, with the colors of each run of cells,
+/// and reset the colors afterwards.
+fn write_row_ansi(ansi: &mut String, buffer: &Buffer, y: u16, columns: Range<u16>) {
+    let mut last_colors = None;
+
+    for x in columns {
+        let Some(cell) = buffer.cell((x, y)) else {
+            continue;
+        };
+
+        if last_colors != Some((cell.fg, cell.bg)) {
+            let _ = write!(
+                ansi,
+                "\x1b[{};{}m",
+                sgr_for_color(cell.fg, false),
+                sgr_for_color(cell.bg, true)
+            );
+            last_colors = Some((cell.fg, cell.bg));
+        }
+
+        ansi.push_str(cell.symbol());
+    }
+
+    ansi.push_str("\x1b[0m");
+}
+
+/// Select Graphic Rendition parameters for a ratatui color, as a foreground or background color.
+fn sgr_for_color(color: Color, background: bool) -> String {
+    let offset = if background { 10 } else { 0 };
+
+    match color {
+        Color::Reset => format!("{}", 39 + offset),
+        Color::Black => format!("{}", 30 + offset),
+        Color::Red => format!("{}", 31 + offset),
+        Color::Green => format!("{}", 32 + offset),
+        Color::Yellow => format!("{}", 33 + offset),
+        Color::Blue => format!("{}", 34 + offset),
+        Color::Magenta => format!("{}", 35 + offset),
+        Color::Cyan => format!("{}", 36 + offset),
+        Color::Gray => format!("{}", 37 + offset),
+        Color::DarkGray => format!("{}", 90 + offset),
+        Color::LightRed => format!("{}", 91 + offset),
+        Color::LightGreen => format!("{}", 92 + offset),
+        Color::LightYellow => format!("{}", 93 + offset),
+        Color::LightBlue => format!("{}", 94 + offset),
+        Color::LightMagenta => format!("{}", 95 + offset),
+        Color::LightCyan => format!("{}", 96 + offset),
+        Color::White => format!("{}", 97 + offset),
+        Color::Indexed(index) => format!("{};5;{}", 38 + offset, index),
+        Color::Rgb(r, g, b) => format!("{};2;{};{};{}", 38 + offset, r, g, b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{buffer::Buffer, style::Color};
+
+    use super::buffer_to_ansi;
+
+    #[test]
+    fn colors_are_written_once_per_run_of_cells() {
+        let mut buffer = Buffer::with_lines(["abc", "de"]);
+        for x in 0..2 {
+            buffer[(x, 0)].set_fg(Color::Red);
+        }
+        buffer[(2, 0)].set_fg(Color::Rgb(1, 2, 3));
+        buffer[(0, 1)].set_bg(Color::Indexed(42));
+
+        assert_eq!(
+            buffer_to_ansi(&buffer),
+            "\x1b[31;49mab\x1b[38;2;1;2;3;49mc\x1b[0m\r\n\
+             \x1b[39;48;5;42md\x1b[39;49me \x1b[0m\r\n",
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt::Write;
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Modifier},
+};
+
+use crate::{RatatuiCameraWidget, color_support::rgb_for_color};
+
+/// Convert the contents of a ratatui buffer into an HTML `<pre>` block, with runs of identically
+/// styled cells wrapped in `<span>` elements carrying their colors. Default (`Color::Reset`)
+/// colors are left unset, so that they inherit from the page.
+pub fn buffer_to_html(buffer: &Buffer) -> String {
+    let mut html =
+        String::from("<pre style=\"font-family: monospace; line-height: 1; letter-spacing: 0;\">");
+
+    for y in buffer.area.top()..buffer.area.bottom() {
+        let mut run_style = None;
+
+        for x in buffer.area.left()..buffer.area.right() {
+            let Some(cell) = buffer.cell((x, y)) else {
+                continue;
+            };
+
+            let style = css_for_cell(cell);
+            if run_style.as_ref() != Some(&style) {
+                if run_style.is_some() {
+                    html.push_str("</span>");
+                }
+                let _ = write!(html, "<span style=\"{style}\">");
+                run_style = Some(style);
+            }
+
+            push_escaped(&mut html, cell.symbol());
+        }
+
+        if run_style.is_some() {
+            html.push_str("</span>");
+        }
+        html.push('\n');
+    }
+
+    html.push_str("</pre>");
+    html
+}
+
+/// Inline CSS declarations matching a cell's colors and modifiers.
+fn css_for_cell(cell: &Cell) -> String {
+    let (fg, bg) = if cell.modifier.contains(Modifier::REVERSED) {
+        (cell.bg, cell.fg)
+    } else {
+        (cell.fg, cell.bg)
+    };
+
+    let mut css = String::new();
+    push_color(&mut css, "color", fg);
+    push_color(&mut css, "background-color", bg);
+
+    if cell.modifier.contains(Modifier::BOLD) {
+        css.push_str("font-weight: bold;");
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        css.push_str("opacity: 0.5;");
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        css.push_str("font-style: italic;");
+    }
+    if cell.modifier.contains(Modifier::UNDERLINED) {
+        css.push_str("text-decoration: underline;");
+    }
+
+    css
+}
+
+fn push_color(css: &mut String, property: &str, color: Color) {
+    if let Some([r, g, b]) = rgb_for_color(color) {
+        let _ = write!(css, "{property}: #{r:02x}{g:02x}{b:02x};");
+    }
+}
+
+fn push_escaped(html: &mut String, symbol: &str) {
+    for character in symbol.chars() {
+        match character {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            _ => html.push(character),
+        }
+    }
+}
+
+impl RatatuiCameraWidget {
+    /// Draw the widget within the provided area (see [RatatuiCameraWidget::render_to_buffer]) and
+    /// convert the result into an HTML `<pre>` block with styled spans matching the cells'
+    /// characters and colors, for embedding terminal renders in web pages or bug reports.
+    pub fn to_html(&self, area: Rect) -> String {
+        buffer_to_html(&self.render_to_buffer(area))
+    }
+}
@@ -0,0 +1,125 @@
+use image::{Rgba, RgbaImage};
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Modifier},
+};
+
+use crate::{
+    RatatuiCameraWidget,
+    color_support::rgb_for_color,
+    widget_glyph_masks::{GLYPH_MASK_HEIGHT, GLYPH_MASK_WIDTH, GlyphMask, glyph_mask, mask_covers},
+};
+
+/// Colors used in place of `Color::Reset`, as rasterized images have no terminal theme.
+const DEFAULT_FOREGROUND: [u8; 3] = [229, 229, 229];
+const DEFAULT_BACKGROUND: [u8; 3] = [0, 0, 0];
+
+/// Character drawn in place of characters missing from the embedded bitmap font.
+const FALLBACK_CHARACTER: char = '#';
+
+/// Rasterize the contents of a ratatui buffer into an image, drawing each cell's character with
+/// the embedded bitmap font (each font pixel covering `scale` by `scale` image pixels, so cells
+/// are `4 * scale` pixels wide and `8 * scale` pixels high). `Color::Reset` is drawn as light
+/// gray text on a black background.
+pub(crate) fn buffer_to_image(buffer: &Buffer, scale: u32) -> RgbaImage {
+    let scale = scale.max(1);
+    let (cell_width, cell_height) = (GLYPH_MASK_WIDTH * scale, GLYPH_MASK_HEIGHT * scale);
+
+    let mut image = RgbaImage::new(
+        buffer.area.width as u32 * cell_width,
+        buffer.area.height as u32 * cell_height,
+    );
+
+    for (index, cell) in buffer.content.iter().enumerate() {
+        let column = index as u32 % buffer.area.width as u32;
+        let row = index as u32 / buffer.area.width as u32;
+
+        let (fg, bg) = colors_for_cell(cell);
+        let mask = mask_for_symbol(cell.symbol());
+
+        for y in 0..cell_height {
+            for x in 0..cell_width {
+                let color = if mask_covers(mask, x / scale, y / scale) {
+                    fg
+                } else {
+                    bg
+                };
+
+                image.put_pixel(
+                    column * cell_width + x,
+                    row * cell_height + y,
+                    Rgba([color[0], color[1], color[2], 255]),
+                );
+            }
+        }
+    }
+
+    image
+}
+
+/// Foreground and background rgb colors of a cell, swapped if the cell is reversed.
+fn colors_for_cell(cell: &Cell) -> ([u8; 3], [u8; 3]) {
+    let (fg, bg) = if cell.modifier.contains(Modifier::REVERSED) {
+        (cell.bg, cell.fg)
+    } else {
+        (cell.fg, cell.bg)
+    };
+
+    let rgb = |color: Color, default: [u8; 3]| rgb_for_color(color).unwrap_or(default);
+
+    (rgb(fg, DEFAULT_FOREGROUND), rgb(bg, DEFAULT_BACKGROUND))
+}
+
+/// Coverage mask of the first character of a cell's symbol. Braille characters are built from
+/// their dots, and other characters missing from the embedded font fall back to a placeholder.
+fn mask_for_symbol(symbol: &str) -> GlyphMask {
+    let Some(character) = symbol.chars().next().filter(|c| !c.is_whitespace()) else {
+        return 0;
+    };
+
+    glyph_mask(character)
+        .or_else(|| braille_mask(character))
+        .or_else(|| glyph_mask(FALLBACK_CHARACTER))
+        .unwrap_or_default()
+}
+
+/// Coverage mask of a braille character, with each of its dots (two columns of four) drawn as a
+/// single pixel.
+fn braille_mask(character: char) -> Option<GlyphMask> {
+    // bit order of the eight dots within the braille block, as (column, row).
+    const DOTS: [(u32, u32); 8] = [
+        (0, 0),
+        (0, 1),
+        (0, 2),
+        (1, 0),
+        (1, 1),
+        (1, 2),
+        (0, 3),
+        (1, 3),
+    ];
+
+    let dots = (character as u32)
+        .checked_sub(0x2800)
+        .filter(|dots| *dots <= 0xFF)?;
+
+    let mask = DOTS
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| dots & (1 << bit) != 0)
+        .fold(0, |mask, (_, (column, row))| {
+            mask | 1 << (row * 2 * GLYPH_MASK_WIDTH + column * 2 + 1)
+        });
+
+    Some(mask)
+}
+
+impl RatatuiCameraWidget {
+    /// Draw the widget within the provided area (see [RatatuiCameraWidget::render_to_buffer]) and
+    /// rasterize the result into an image, drawing each cell's character with an embedded bitmap
+    /// font where each font pixel covers `scale` by `scale` image pixels. Useful for sharing
+    /// previews of terminal renders where a terminal is not available.
+    pub fn to_image(&self, area: Rect, scale: u32) -> RgbaImage {
+        buffer_to_image(&self.render_to_buffer(area), scale)
+    }
+}
@@ -0,0 +1,137 @@
+use bevy::{asset::RenderAssetUsages, image::ImageSampler, prelude::*};
+use image::DynamicImage;
+use ratatui::{buffer::Buffer, layout::Rect};
+
+use crate::buffer_image::buffer_to_image;
+
+pub struct RatatuiBufferTexturePlugin;
+
+impl Plugin for RatatuiBufferTexturePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, rasterize_buffer_texture_system);
+    }
+}
+
+/// Holds a ratatui buffer that is rasterized into a bevy image whenever it changes, with each
+/// cell's character drawn using an embedded bitmap font (as by
+/// [RatatuiCameraWidget::to_image](crate::RatatuiCameraWidget::to_image)). Use the image as a
+/// material's texture to place "terminal screens" inside your scene, and draw any ratatui widgets
+/// (including camera widgets) into the buffer to show them on the screen.
+///
+/// The buffer is rasterized in the `Last` schedule, so draw into it during `PostUpdate` or
+/// earlier. Characters missing from the bitmap font are drawn as `#`, and `Color::Reset` is drawn
+/// as light gray text on a black background.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiBufferTexture, RatatuiCameraWidget};
+/// # use ratatui::widgets::{Block, Widget};
+/// #
+/// fn setup_screen_system(
+///     mut commands: Commands,
+///     mut images: ResMut<Assets<Image>>,
+///     mut meshes: ResMut<Assets<Mesh>>,
+///     mut materials: ResMut<Assets<StandardMaterial>>,
+/// ) {
+///     let texture = RatatuiBufferTexture::new(40, 12, &mut images);
+///
+///     commands.spawn((
+///         Mesh3d(meshes.add(Plane3d::new(Vec3::Z, Vec2::new(2., 1.2)))),
+///         MeshMaterial3d(materials.add(StandardMaterial {
+///             base_color_texture: Some(texture.image().clone()),
+///             unlit: true,
+///             ..default()
+///         })),
+///         texture,
+///     ));
+/// }
+///
+/// fn draw_screen_system(
+///     mut screen: Single<&mut RatatuiBufferTexture>,
+///     mut camera_widget: Single<&mut RatatuiCameraWidget>,
+/// ) {
+///     let area = screen.area();
+///     let block = Block::bordered().title("security camera");
+///     let inner = block.inner(area);
+///
+///     block.render(area, &mut screen.buffer);
+///     camera_widget.render(inner, &mut screen.buffer);
+/// }
+/// ```
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiBufferTexture {
+    /// The buffer rasterized into the image. Draw widgets into it as you would into a terminal
+    /// frame's buffer.
+    pub buffer: Buffer,
+
+    /// The number of image pixels each pixel of the bitmap font covers horizontally and vertically
+    /// (cells are `4 * scale` by `8 * scale` pixels).
+    pub scale: u32,
+
+    image: Handle<Image>,
+}
+
+impl RatatuiBufferTexture {
+    /// Create an empty buffer of the provided size in cells, along with the image it is rasterized
+    /// into.
+    pub fn new(width: u16, height: u16, images: &mut Assets<Image>) -> Self {
+        let buffer = Buffer::empty(Rect::new(0, 0, width, height));
+        let scale = 2;
+        let image = images.add(rasterize_buffer(&buffer, scale));
+
+        Self {
+            buffer,
+            scale,
+            image,
+        }
+    }
+
+    /// Rasterize each font pixel into `scale` by `scale` image pixels (see
+    /// [RatatuiBufferTexture::scale]).
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// The image the buffer is rasterized into.
+    pub fn image(&self) -> &Handle<Image> {
+        &self.image
+    }
+
+    /// The area covered by the buffer, for drawing widgets into it.
+    pub fn area(&self) -> Rect {
+        self.buffer.area
+    }
+
+    /// Resize the buffer to the provided size in cells, clearing its contents. The image is
+    /// resized to match when it is next rasterized.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    }
+}
+
+/// Rasterize the buffer into a bevy image, sampled without filtering to keep characters crisp.
+fn rasterize_buffer(buffer: &Buffer, scale: u32) -> Image {
+    let mut image = Image::from_dynamic(
+        DynamicImage::ImageRgba8(buffer_to_image(buffer, scale)),
+        true,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+
+    image
+}
+
+fn rasterize_buffer_texture_system(
+    buffer_textures: Query<&RatatuiBufferTexture, Changed<RatatuiBufferTexture>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for buffer_texture in &buffer_textures {
+        if let Some(image) = images.get_mut(&buffer_texture.image) {
+            *image = rasterize_buffer(&buffer_texture.buffer, buffer_texture.scale);
+        }
+    }
+}
@@ -1,10 +1,14 @@
-use bevy::prelude::*;
+use std::time::Duration;
+
+use bevy::{prelude::*, render::extract_component::ExtractComponent};
 use ratatui::layout::Rect;
 
-use crate::camera_strategy::RatatuiCameraStrategy;
+use crate::font_metrics::RatatuiCameraFontMetrics;
 
 /// Spawn this component with your bevy camera in order to send each frame's rendered image to
-/// a RatatuiCameraWidget that will be inserted into the same camera entity.
+/// a RatatuiCameraWidget that will be inserted into the same camera entity. A
+/// [RatatuiCameraStrategy](crate::RatatuiCameraStrategy) is inserted alongside it if not already
+/// present, taken from the [RatatuiCameraDefaults](crate::RatatuiCameraDefaults) resource.
 ///
 /// Example:
 ///
@@ -20,8 +24,9 @@ use crate::camera_strategy::RatatuiCameraStrategy;
 /// # };
 /// ```
 ///
-#[derive(Component, Clone, Debug)]
-#[require(RatatuiCameraStrategy, RatatuiCameraLastArea)]
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(RatatuiCameraLastArea)]
 pub struct RatatuiCamera {
     /// Whether to automatically resize the render texture based on the previous area the
     /// associated widget was rendered to.
@@ -29,6 +34,29 @@ pub struct RatatuiCamera {
 
     /// Dimensions (width, height) of the image the camera will render to.
     pub dimensions: UVec2,
+
+    /// If present, the smallest dimensions autoresize will choose. Dimensions below this are
+    /// scaled up (preserving their aspect ratio) until both are at least this large.
+    pub min_dimensions: Option<UVec2>,
+
+    /// If present, the largest dimensions autoresize will choose, e.g. to keep a maximized
+    /// terminal on a large monitor from rendering thousands of pixels across. Dimensions above
+    /// this are scaled down (preserving their aspect ratio) until both fit within it. Takes
+    /// precedence over `min_dimensions`.
+    pub max_dimensions: Option<UVec2>,
+
+    /// If present, the aspect ratio (width divided by height) autoresize will keep, regardless of
+    /// the shape of the area the widget is drawn within, e.g. `Some(16. / 9.)` for games whose
+    /// field of view should not depend on the terminal's shape. Dimensions are shrunk along one
+    /// axis to fit the aspect ratio, and the rest of the area is letterboxed when drawn.
+    pub fixed_aspect: Option<f32>,
+
+    /// Number of frames that can be in flight between the GPU and the widget at once (the number
+    /// of staging buffers used for each readback). With `1`, each frame's rendered image is waited
+    /// on before the next frame starts, which is the lowest latency but stalls rendering when
+    /// readback is slow. Higher values never wait, at the cost of up to that many frames of
+    /// latency, and frames are skipped rather than stalling if every buffer is still in use.
+    pub max_frame_latency: u32,
 }
 
 impl Default for RatatuiCamera {
@@ -36,18 +64,76 @@ impl Default for RatatuiCamera {
         Self {
             autoresize: true,
             dimensions: UVec2::new(1, 1),
+            min_dimensions: None,
+            max_dimensions: None,
+            fixed_aspect: None,
+            max_frame_latency: Self::MAX_FRAME_LATENCY_DEFAULT,
         }
     }
 }
 
 impl RatatuiCamera {
+    /// Default value of `max_frame_latency`.
+    pub const MAX_FRAME_LATENCY_DEFAULT: u32 = 2;
+
     /// Creates a new RatatuiCamera that renders to an image of the provided dimensions.
     pub fn new(width: u32, height: u32) -> Self {
         Self {
             autoresize: false,
             dimensions: UVec2::new(width, height),
+            ..default()
         }
     }
+
+    /// Shrink dimensions chosen by autoresize to `fixed_aspect`, then scale them (preserving
+    /// their aspect ratio) to respect `min_dimensions` and `max_dimensions`.
+    pub(crate) fn clamp_dimensions(&self, dimensions: UVec2) -> UVec2 {
+        let mut scaled = dimensions.as_vec2();
+
+        if let Some(aspect) = self
+            .fixed_aspect
+            .filter(|aspect| aspect.is_finite() && *aspect > 0.)
+        {
+            scaled = if scaled.x > scaled.y * aspect {
+                Vec2::new(scaled.y * aspect, scaled.y)
+            } else {
+                Vec2::new(scaled.x, scaled.x / aspect)
+            };
+        }
+
+        if let Some(min_dimensions) = self.min_dimensions {
+            let scale = (min_dimensions.as_vec2() / scaled).max_element();
+            if scale > 1. {
+                scaled *= scale;
+            }
+        }
+
+        if let Some(max_dimensions) = self.max_dimensions {
+            let scale = (max_dimensions.as_vec2() / scaled).min_element();
+            if scale < 1. {
+                scaled *= scale;
+            }
+        }
+
+        scaled.round().as_uvec2().max(UVec2::ONE)
+    }
+
+    /// Size (in terminal cells) of the area the camera's image is drawn within, when its widget is
+    /// rendered in the provided area (excluding the gutters needed to preserve the aspect ratio,
+    /// see [RatatuiCameraWidget::calculate_render_area](crate::RatatuiCameraWidget::calculate_render_area)).
+    pub(crate) fn render_area_size(
+        &self,
+        area: Rect,
+        font_metrics: &RatatuiCameraFontMetrics,
+    ) -> UVec2 {
+        let dimensions = self.dimensions.as_vec2();
+        let aspect_ratio = dimensions.x * font_metrics.cell_aspect_ratio() / dimensions.y;
+
+        let width = (area.width as f32).min(area.height as f32 * aspect_ratio);
+        let height = (area.height as f32).min(area.width as f32 / aspect_ratio);
+
+        UVec2::new(width.round() as u32, height.round() as u32)
+    }
 }
 
 /// When within a camera entity alongside a RatatuiCamera, the depth prepass texture will copied
@@ -57,6 +143,75 @@ impl RatatuiCamera {
 #[derive(Component, Clone, Debug, Default)]
 pub struct RatatuiCameraDepthDetection;
 
+/// When within a camera entity alongside a RatatuiCamera, the depth texture is downsampled on the
+/// GPU to two pixels per terminal cell (keeping the nearest depth within each block of pixels)
+/// before being copied back, rather than copying back the depth of every pixel. This greatly
+/// reduces readback bandwidth for large render textures, as depth is only ever used per cell.
+///
+/// Inserts [RatatuiCameraDepthDetection]. Currently just works with 3d cameras.
+#[derive(Component, ExtractComponent, Clone, Debug, Default)]
+#[require(RatatuiCameraDepthDetection)]
+pub struct RatatuiCameraDepthDownsampling;
+
+/// When within a camera entity alongside a RatatuiCamera, the normal prepass texture will be
+/// copied back from the GPU each frame and made available to the associated RatatuiCameraWidget.
+/// Required by strategies that shade based on surface normals, like
+/// [RatatuiCameraStrategy::Normals](crate::RatatuiCameraStrategy::Normals).
+#[derive(Component, Clone, Debug, Default)]
+pub struct RatatuiCameraNormalDetection;
+
+/// When within a camera entity alongside a RatatuiCamera and a RatatuiCameraEdgeDetection, meshes
+/// with a [RatatuiCameraEdgeColor](crate::RatatuiCameraEdgeColor) component are drawn in an extra
+/// pass that records their edge colors, which is copied back from the GPU each frame and used to
+/// color the edges detected around those meshes. Currently just works with 3d cameras.
+#[derive(Component, Clone, Debug, Default)]
+pub struct RatatuiCameraEdgeColorDetection;
+
+/// When within a camera entity alongside a RatatuiCamera, meshes with a
+/// [RatatuiCameraMask](crate::RatatuiCameraMask) component are drawn in an extra pass that records
+/// their mask values, which is copied back from the GPU each frame and made available to the
+/// associated RatatuiCameraWidget (see
+/// [RatatuiCameraWidget::mask_at_cell](crate::RatatuiCameraWidget::mask_at_cell)). Useful for
+/// branching on what kind of object covers each cell, e.g. in color callbacks or overlay widgets.
+/// Currently just works with 3d cameras.
+#[derive(Component, Clone, Debug, Default)]
+pub struct RatatuiCameraMaskDetection;
+
+/// When within a camera entity alongside a RatatuiCamera, the widget keeps drawing the previous
+/// frame (scaled to the new area) while a resize is in progress, rather than skipping the frame
+/// the area changed in and then drawing blank cells until an image of the new size is read back.
+/// Makes resizing the terminal (or the widget's layout) look smooth, at the cost of briefly
+/// showing the old image letterboxed or stretched within the new area.
+#[derive(Component, Clone, Debug, Default)]
+pub struct RatatuiCameraSmoothResize;
+
+/// When within a camera entity alongside a RatatuiCamera, sets the position of the camera's widget
+/// (lowest first) when iterated with
+/// [RatatuiCameraWidgets::iter_ordered](crate::RatatuiCameraWidgets::iter_ordered), so that
+/// layouts can map cameras to panes deterministically. Cameras without one are ordered as `0`, and
+/// ties are broken by entity. Unrelated to `Camera::order`, which sets the order cameras render in.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraOrder};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraOrder(1),
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(
+    Component, Deref, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect,
+)]
+#[reflect(Component)]
+pub struct RatatuiCameraOrder(pub i32);
+
 /// Component representing the area that the camera entity's widget was rendered within last frame.
 /// Used internally for triggering resizes, and translating buffer coordinates to bevy coordinates.
 #[derive(Component, Deref, Clone, Debug, Default)]
@@ -69,11 +224,24 @@ pub struct RatatuiCameraLastArea(pub Rect);
 /// indicated by the relation. The composite render from both cameras will then be converted to
 /// unicode as one image.
 ///
+/// Subcameras render after the main camera, in ascending `order`, with ties broken by the order
+/// they were related to the main camera in. Note that this takes control of the `order` field of
+/// each subcamera's `Camera` component, which is kept just above the main camera's.
+///
+/// Subcameras are also set not to clear the shared render texture, so they draw over the main
+/// camera's output rather than erasing it (see
+/// [RatatuiSubcameraManualClear](crate::RatatuiSubcameraManualClear) to opt out).
+///
+/// Like other relations, `RatatuiSubcamera` cannot be mutated in place: re-insert it with a
+/// different target to point the subcamera at another RatatuiCamera at runtime. If the relation
+/// is removed or the main camera is despawned, the subcamera is deactivated (see
+/// [RatatuiSubcameraTargetChanged](crate::RatatuiSubcameraTargetChanged)).
+///
 /// Example:
 ///
 /// ```no_run
 /// # use bevy::prelude::*;
-/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiSubcameras};
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiSubcamera, RatatuiSubcameras};
 /// #
 /// # #[derive(Component)]
 /// # pub struct POVCamera;
@@ -81,20 +249,48 @@ pub struct RatatuiCameraLastArea(pub Rect);
 /// # pub struct FXCamera;
 /// #
 /// # fn setup_scene_system(mut commands: Commands) {
+/// let main_camera = commands
+///     .spawn((
+///         RatatuiCamera::default(),
+///         Camera3d::default(),
+///         related!(RatatuiSubcameras[
+///             (Camera3d::default(), POVCamera),
+///             (Camera3d::default(), FXCamera),
+///         ]),
+///     ))
+///     .id();
+///
+/// // composited over both of the subcameras above.
 /// commands.spawn((
-///     RatatuiCamera::default(),
 ///     Camera3d::default(),
-///     related!(RatatuiSubcameras[
-///         (Camera3d::default(), POVCamera),
-///         (Camera3d::default(), FXCamera),
-///     ]),
+///     RatatuiSubcamera::new(main_camera).with_order(1),
 /// ));
 /// # };
 /// ```
 ///
-#[derive(Component, Debug)]
+#[derive(Component, Clone, Copy, Debug)]
 #[relationship(relationship_target = RatatuiSubcameras)]
-pub struct RatatuiSubcamera(pub Entity);
+pub struct RatatuiSubcamera {
+    /// The RatatuiCamera entity whose render texture this subcamera renders to.
+    #[relationship]
+    pub target: Entity,
+
+    /// Subcameras with a higher order render later, drawing over subcameras with a lower order.
+    pub order: isize,
+}
+
+impl RatatuiSubcamera {
+    /// Render to the provided RatatuiCamera entity's render texture, with an order of `0`.
+    pub fn new(target: Entity) -> Self {
+        Self { target, order: 0 }
+    }
+
+    /// Subcameras with a higher order render later, drawing over subcameras with a lower order.
+    pub fn with_order(mut self, order: isize) -> Self {
+        self.order = order;
+        self
+    }
+}
 
 /// Bevy relation target for subcameras that will render to this camera entity's render target.
 #[derive(Component, Debug)]
@@ -112,3 +308,158 @@ pub struct RatatuiSubcameras(Vec<Entity>);
 /// mechanisms for performing that retrieval up-to-date (e.g. after resizes).
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RatatuiCameraSet;
+
+/// System set that runs in the [PostUpdate] schedule instead of [RatatuiCameraSet] when
+/// [RatatuiCameraLatency::Low] is selected, for the systems that retrieve rendered images from the
+/// GPU and create the RatatuiCameraWidget components. Order your draw systems after this set.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RatatuiCameraLowLatencySet;
+
+/// Resource selecting when in each frame rendered images are retrieved from the GPU and turned
+/// into RatatuiCameraWidget components.
+///
+/// By default, this happens at the start of the frame in [RatatuiCameraSet], so widgets hold an
+/// image rendered at least a frame before they are drawn. For games where a frame of latency
+/// matters (e.g. rhythm or action games), select [RatatuiCameraLatency::Low] and order your draw
+/// system after [RatatuiCameraLowLatencySet], so that the images are retrieved immediately before
+/// drawing, picking up renders that finished during the frame's update.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCameraLatency, RatatuiCameraLowLatencySet};
+/// #
+/// # fn draw_scene_system() {}
+/// #
+/// # fn build(app: &mut App) {
+/// app.insert_resource(RatatuiCameraLatency::Low {
+///     wait: Some(Duration::from_millis(4)),
+/// })
+/// .add_systems(PostUpdate, draw_scene_system.after(RatatuiCameraLowLatencySet));
+/// # }
+/// ```
+///
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub enum RatatuiCameraLatency {
+    /// Retrieve rendered images at the start of the frame, never waiting on the GPU.
+    #[default]
+    Standard,
+
+    /// Retrieve rendered images in [RatatuiCameraLowLatencySet], in [PostUpdate].
+    Low {
+        /// If present, when no new image has arrived yet, block for up to this long waiting for
+        /// the render in progress to finish. This reduces latency further when bevy's pipelined
+        /// rendering is enabled, but stalls the frame while waiting (once per camera, and once
+        /// per depth, edge detection, normal, or emissive readback).
+        wait: Option<Duration>,
+    },
+}
+
+impl RatatuiCameraLatency {
+    /// How long to block for new images from a camera when receiving them. Inactive cameras are
+    /// never waited on, as they are not rendering any new images.
+    pub(crate) fn wait(&self, camera: &Camera) -> Option<Duration> {
+        match self {
+            Self::Standard => None,
+            Self::Low { wait } => wait.filter(|_| camera.is_active),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::UVec2;
+
+    use super::RatatuiCamera;
+
+    fn camera_with(
+        fixed_aspect: Option<f32>,
+        min_dimensions: Option<UVec2>,
+        max_dimensions: Option<UVec2>,
+    ) -> RatatuiCamera {
+        RatatuiCamera {
+            fixed_aspect,
+            min_dimensions,
+            max_dimensions,
+            ..RatatuiCamera::default()
+        }
+    }
+
+    #[test]
+    fn fixed_aspect_shrinks_the_longer_axis() {
+        let camera = camera_with(Some(2.), None, None);
+
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(100, 100)),
+            UVec2::new(100, 50)
+        );
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(200, 50)),
+            UVec2::new(100, 50)
+        );
+    }
+
+    #[test]
+    fn invalid_fixed_aspects_are_ignored() {
+        for aspect in [0., -1., f32::NAN, f32::INFINITY] {
+            let camera = camera_with(Some(aspect), None, None);
+
+            assert_eq!(
+                camera.clamp_dimensions(UVec2::new(30, 20)),
+                UVec2::new(30, 20)
+            );
+        }
+    }
+
+    #[test]
+    fn min_dimensions_scale_up_preserving_the_aspect_ratio() {
+        let camera = camera_with(None, Some(UVec2::new(40, 40)), None);
+
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(20, 10)),
+            UVec2::new(80, 40)
+        );
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(60, 50)),
+            UVec2::new(60, 50)
+        );
+    }
+
+    #[test]
+    fn max_dimensions_scale_down_preserving_the_aspect_ratio() {
+        let camera = camera_with(None, None, Some(UVec2::new(50, 50)));
+
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(200, 100)),
+            UVec2::new(50, 25)
+        );
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(30, 20)),
+            UVec2::new(30, 20)
+        );
+    }
+
+    #[test]
+    fn max_dimensions_take_precedence_over_min_dimensions() {
+        let camera = camera_with(None, Some(UVec2::new(100, 100)), Some(UVec2::new(50, 50)));
+
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(10, 10)),
+            UVec2::new(50, 50)
+        );
+    }
+
+    #[test]
+    fn dimensions_are_never_zero() {
+        let camera = camera_with(Some(1000.), None, None);
+
+        assert_eq!(camera.clamp_dimensions(UVec2::ZERO), UVec2::ONE);
+        assert_eq!(
+            camera.clamp_dimensions(UVec2::new(10, 1)),
+            UVec2::new(10, 1)
+        );
+        assert_eq!(camera.clamp_dimensions(UVec2::new(1, 10)), UVec2::ONE);
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::Color,
+};
+use serde::{Deserialize, Serialize};
+
+/// When spawned with a RatatuiCamera, the widget keeps the cells it drew last frame, and cells
+/// that have not visibly changed since then are written exactly as they were last frame. As
+/// ratatui only sends cells that differ from the previous frame to the terminal, this means only
+/// the parts of the image that actually moved are repainted, which helps terminals with slow
+/// escape sequence throughput (e.g. over ssh), where small color fluctuations across the whole
+/// image would otherwise repaint every cell.
+///
+/// The cells that changed are recorded in the widget's
+/// [changes](crate::RatatuiCameraWidget::changes) each time it is rendered.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraCellDiffing};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraCellDiffing { color_tolerance: 8 },
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RatatuiCameraCellDiffing {
+    /// Largest difference in any rgb channel between a cell's colors this frame and last frame
+    /// for the cell to still count as unchanged (as long as its character is the same). `0` only
+    /// skips cells that are exactly the same. Colors that are not rgb must match exactly.
+    pub color_tolerance: u8,
+}
+
+/// Which cells of the area a camera widget was drawn within changed since the previous frame,
+/// recorded each time a widget with [RatatuiCameraCellDiffing] is rendered.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RatatuiCameraCellChanges {
+    /// The area the widget's image was drawn within.
+    pub area: Rect,
+
+    /// Whether each cell in the area changed, row by row.
+    pub mask: Vec<bool>,
+}
+
+impl RatatuiCameraCellChanges {
+    /// Whether the cell at the provided buffer position changed, or `false` for positions outside
+    /// of the area.
+    pub fn is_changed(&self, x: u16, y: u16) -> bool {
+        if !self.area.contains((x, y).into()) {
+            return false;
+        }
+
+        let index =
+            (y - self.area.y) as usize * self.area.width as usize + (x - self.area.x) as usize;
+        self.mask.get(index).copied().unwrap_or(false)
+    }
+
+    /// Number of cells that changed.
+    pub fn changed_count(&self) -> usize {
+        self.mask.iter().filter(|changed| **changed).count()
+    }
+}
+
+/// The cells a widget drew within its render area, kept for comparison with the next frame.
+#[derive(Debug)]
+pub(crate) struct DrawnCells {
+    area: Rect,
+    cells: Vec<Cell>,
+}
+
+impl RatatuiCameraCellDiffing {
+    /// Restore the cells in the area that are unchanged since the previously drawn cells, and
+    /// record which cells changed. Returns the cells now drawn along with the changes.
+    pub(crate) fn diff(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        previous: Option<&DrawnCells>,
+    ) -> (Arc<DrawnCells>, RatatuiCameraCellChanges) {
+        let previous = previous.filter(|previous| previous.area == area);
+
+        let mut cells = Vec::with_capacity(area.area() as usize);
+        let mut mask = Vec::with_capacity(area.area() as usize);
+
+        for (index, position) in area.positions().enumerate() {
+            let Some(cell) = buf.cell_mut(position) else {
+                cells.push(Cell::default());
+                mask.push(true);
+                continue;
+            };
+
+            match previous.map(|previous| &previous.cells[index]) {
+                Some(previous_cell) if self.unchanged(previous_cell, cell) => {
+                    *cell = previous_cell.clone();
+                    mask.push(false);
+                }
+                _ => mask.push(true),
+            }
+
+            cells.push(cell.clone());
+        }
+
+        (
+            Arc::new(DrawnCells { area, cells }),
+            RatatuiCameraCellChanges { area, mask },
+        )
+    }
+
+    fn unchanged(&self, previous: &Cell, cell: &Cell) -> bool {
+        previous.symbol() == cell.symbol()
+            && previous.modifier == cell.modifier
+            && self.similar_colors(previous.fg, cell.fg)
+            && self.similar_colors(previous.bg, cell.bg)
+    }
+
+    fn similar_colors(&self, a: Color, b: Color) -> bool {
+        match (a, b) {
+            (Color::Rgb(a_r, a_g, a_b), Color::Rgb(b_r, b_g, b_b)) => {
+                [a_r.abs_diff(b_r), a_g.abs_diff(b_g), a_b.abs_diff(b_b)]
+                    .into_iter()
+                    .all(|difference| difference <= self.color_tolerance)
+            }
+            _ => a == b,
+        }
+    }
+}
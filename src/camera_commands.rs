@@ -0,0 +1,64 @@
+use bevy::{core_pipeline::tonemapping::Tonemapping, prelude::*};
+
+use crate::{RatatuiCamera, RatatuiCameraStrategy};
+
+/// Extension trait for [Commands], for spawning a 3d camera set up to render to the terminal in
+/// one call.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCameraCommands, RatatuiCameraStrategy};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn_ratatui_camera(
+///     Transform::from_xyz(2.5, 2.5, 2.5).looking_at(Vec3::ZERO, Vec3::Z),
+///     RatatuiCameraStrategy::luminance_braille(),
+/// );
+/// # };
+/// ```
+///
+pub trait RatatuiCameraCommands {
+    /// Spawn a `Camera3d` with the provided transform and strategy, along with an autoresizing
+    /// RatatuiCamera (see [RatatuiCameraEntityCommands::insert_ratatui_camera]). Returns the
+    /// camera's [EntityCommands], for inserting further components.
+    fn spawn_ratatui_camera(
+        &mut self,
+        transform: Transform,
+        strategy: RatatuiCameraStrategy,
+    ) -> EntityCommands<'_>;
+}
+
+impl RatatuiCameraCommands for Commands<'_, '_> {
+    fn spawn_ratatui_camera(
+        &mut self,
+        transform: Transform,
+        strategy: RatatuiCameraStrategy,
+    ) -> EntityCommands<'_> {
+        let mut entity = self.spawn((Camera3d::default(), transform));
+        entity.insert_ratatui_camera(strategy);
+        entity
+    }
+}
+
+/// Extension trait for [EntityCommands], for setting up an existing camera entity to render to
+/// the terminal.
+pub trait RatatuiCameraEntityCommands {
+    /// Insert an autoresizing RatatuiCamera with the provided strategy, along with `Msaa::Off`
+    /// (which edge detection and depth detection require, and which makes little difference at
+    /// terminal resolutions) and `Tonemapping::None` (so that the colors printed to the terminal
+    /// match the scene's colors, without depending on bevy's `tonemapping_luts` feature).
+    fn insert_ratatui_camera(&mut self, strategy: RatatuiCameraStrategy) -> &mut Self;
+}
+
+impl RatatuiCameraEntityCommands for EntityCommands<'_> {
+    fn insert_ratatui_camera(&mut self, strategy: RatatuiCameraStrategy) -> &mut Self {
+        self.insert((
+            RatatuiCamera::default(),
+            strategy,
+            Msaa::Off,
+            Tonemapping::None,
+        ))
+    }
+}
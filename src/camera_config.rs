@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{RatatuiCameraEdgeDetection, RatatuiCameraSet, RatatuiCameraStrategy};
+
+pub struct RatatuiCameraConfigPlugin;
+
+impl Plugin for RatatuiCameraConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<RatatuiCameraConfig>()
+            .init_asset_loader::<RatatuiCameraConfigLoader>()
+            .add_systems(
+                First,
+                apply_ratatui_camera_configs_system.before(RatatuiCameraSet),
+            );
+    }
+}
+
+/// A camera's conversion settings, loaded from a `.ratatui.ron` asset file. Insert
+/// a [RatatuiCameraConfigHandle] alongside your `RatatuiCamera` to apply the loaded settings
+/// (and any later changes to the file, if bevy's `file_watcher` feature is enabled).
+///
+/// Color callbacks ([ColorChoice::Callback](crate::ColorChoice::Callback)) and character ramp
+/// handles ([CharactersConfig::ramp](crate::CharactersConfig::ramp)) cannot be described in data
+/// files.
+///
+/// # Example:
+///
+/// ```ron
+/// (
+///     strategy: Luminance((
+///         characters: (list: [' ', '.', '+', '#'], scale: 8.0),
+///         colors: (support: ANSI256),
+///     )),
+///     edge_detection: Some((edge_color: Some(Cyan))),
+/// )
+/// ```
+///
+#[derive(Asset, TypePath, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RatatuiCameraConfig {
+    /// Strategy used to convert the rendered image to unicode.
+    pub strategy: RatatuiCameraStrategy,
+
+    /// Edge detection settings. If `None`, any existing edge detection is removed from the
+    /// camera.
+    pub edge_detection: Option<RatatuiCameraEdgeDetection>,
+}
+
+/// Insert alongside a `RatatuiCamera` to apply the settings from a [RatatuiCameraConfig] asset
+/// once it has loaded, and again whenever it is modified.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraConfigHandle};
+/// #
+/// # fn setup_scene_system(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraConfigHandle(asset_server.load("main.ratatui.ron")),
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Deref, Clone, Debug)]
+pub struct RatatuiCameraConfigHandle(pub Handle<RatatuiCameraConfig>);
+
+/// Asset loader for [RatatuiCameraConfig] assets, stored in RON files with the `.ratatui.ron`
+/// extension.
+#[derive(Default, Debug, TypePath)]
+pub struct RatatuiCameraConfigLoader;
+
+/// Errors that can occur while loading a [RatatuiCameraConfig].
+#[derive(Debug)]
+pub enum RatatuiCameraConfigLoaderError {
+    /// The asset file could not be read.
+    Io(std::io::Error),
+
+    /// The asset file is not a valid RON camera config.
+    Ron(ron::error::SpannedError),
+}
+
+impl Display for RatatuiCameraConfigLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read camera config: {error}"),
+            Self::Ron(error) => write!(f, "could not parse camera config: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for RatatuiCameraConfigLoaderError {}
+
+impl From<std::io::Error> for RatatuiCameraConfigLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for RatatuiCameraConfigLoaderError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+impl AssetLoader for RatatuiCameraConfigLoader {
+    type Asset = RatatuiCameraConfig;
+    type Settings = ();
+    type Error = RatatuiCameraConfigLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        Ok(ron::de::from_bytes::<RatatuiCameraConfig>(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ratatui.ron"]
+    }
+}
+
+fn apply_ratatui_camera_configs_system(
+    mut commands: Commands,
+    mut config_messages: MessageReader<AssetEvent<RatatuiCameraConfig>>,
+    configs: Res<Assets<RatatuiCameraConfig>>,
+    ratatui_cameras: Query<(Entity, Ref<RatatuiCameraConfigHandle>)>,
+) {
+    let updated_ids = config_messages
+        .read()
+        .filter_map(|message| match message {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for (entity, config_handle) in &ratatui_cameras {
+        if !config_handle.is_changed() && !updated_ids.contains(&config_handle.id()) {
+            continue;
+        }
+
+        let Some(config) = configs.get(&config_handle.0) else {
+            continue;
+        };
+
+        let mut entity = commands.entity(entity);
+        entity.insert(config.strategy.clone());
+
+        match config.edge_detection {
+            Some(edge_detection) => entity.insert(edge_detection),
+            None => entity.remove::<RatatuiCameraEdgeDetection>(),
+        };
+    }
+}
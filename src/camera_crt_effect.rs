@@ -0,0 +1,138 @@
+use std::path::Path;
+
+use bevy::{
+    asset::{AssetPath, embedded_asset, io::AssetSourceId},
+    prelude::*,
+    render::{extract_component::ExtractComponent, render_resource::ShaderType},
+    shader::ShaderRef,
+};
+
+use crate::{
+    RatatuiCamera, RatatuiCameraFontMetrics, RatatuiCameraPostProcess,
+    RatatuiCameraPostProcessPlugin, camera::RatatuiCameraLastArea,
+};
+
+pub struct RatatuiCameraCrtEffectPlugin;
+
+impl Plugin for RatatuiCameraCrtEffectPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "src/", "shaders/crt.wgsl");
+
+        app.add_plugins(RatatuiCameraPostProcessPlugin::<RatatuiCameraCrtSettings>::default())
+            .add_observer(ratatui_crt_effect_removal_observer)
+            .add_systems(PostUpdate, update_crt_settings_system);
+    }
+}
+
+/// When spawned with a RatatuiCamera, the camera's image is stylized like an old CRT monitor
+/// before it is converted into unicode characters: every terminal row gets a darkened scanline,
+/// the image bulges outwards with a slight barrel distortion, and colors are tinted like a
+/// monochrome phosphor screen. Runs on the GPU as a [RatatuiCameraPostProcess], so it works with
+/// every strategy, though scanlines are only visible as stripes with the half blocks strategy
+/// (the lower half of each cell is darkened, which other strategies average into a dimmer cell).
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraCrtEffect};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraCrtEffect {
+///         tint: Color::srgb(1.0, 0.75, 0.4),
+///         ..default()
+///     },
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RatatuiCameraCrtEffect {
+    /// How much the scanlines darken the image, from `0.0` (no scanlines) to `1.0` (black).
+    pub scanline_intensity: f32,
+
+    /// Strength of the barrel distortion, where `0.0` leaves the image flat. Small values (below
+    /// `0.1`) are usually enough.
+    pub curvature: f32,
+
+    /// Color the image is multiplied by, e.g. green or amber for a monochrome phosphor look.
+    /// White leaves colors unchanged.
+    pub tint: Color,
+}
+
+impl Default for RatatuiCameraCrtEffect {
+    fn default() -> Self {
+        Self {
+            scanline_intensity: 0.3,
+            curvature: 0.05,
+            tint: Color::srgb(0.85, 1.0, 0.85),
+        }
+    }
+}
+
+/// Settings for the CRT shader, as stored in a uniform buffer in the render world, resolved from
+/// the camera's [RatatuiCameraCrtEffect] and the area it is drawn within.
+#[derive(Component, ExtractComponent, ShaderType, Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraCrtSettings {
+    tint: LinearRgba,
+    scanline_intensity: f32,
+    scanline_count: f32,
+    curvature: f32,
+}
+
+impl RatatuiCameraPostProcess for RatatuiCameraCrtSettings {
+    fn fragment_shader() -> ShaderRef {
+        let path = Path::new("bevy_ratatui_camera").join("shaders/crt.wgsl");
+        let source = AssetSourceId::from("embedded");
+        AssetPath::from_path(&path)
+            .with_source(source)
+            .into_owned()
+            .into()
+    }
+}
+
+fn update_crt_settings_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<(
+        Entity,
+        &RatatuiCamera,
+        &RatatuiCameraLastArea,
+        &RatatuiCameraCrtEffect,
+        Option<&RatatuiCameraCrtSettings>,
+    )>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    for (entity, ratatui_camera, last_area, crt_effect, settings) in &ratatui_cameras {
+        // one scanline per terminal row, assuming two pixels per row until the camera is drawn.
+        let rows = match ratatui_camera
+            .render_area_size(**last_area, &font_metrics)
+            .y
+        {
+            0 => ratatui_camera.dimensions.y / 2,
+            rows => rows,
+        };
+
+        let settings_new = RatatuiCameraCrtSettings {
+            tint: crt_effect.tint.to_linear(),
+            scanline_intensity: crt_effect.scanline_intensity.clamp(0., 1.),
+            scanline_count: rows.max(1) as f32,
+            curvature: crt_effect.curvature.max(0.),
+        };
+
+        if settings != Some(&settings_new) {
+            commands.entity(entity).insert(settings_new);
+        }
+    }
+}
+
+fn ratatui_crt_effect_removal_observer(
+    remove: On<Remove, RatatuiCameraCrtEffect>,
+    mut commands: Commands,
+) {
+    commands
+        .entity(remove.entity)
+        .remove::<RatatuiCameraCrtSettings>();
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+
+use crate::{ColorsConfig, RatatuiCamera, RatatuiCameraStrategy};
+
+/// Configuration shared by every RatatuiCamera, so that apps with many cameras do not have to
+/// repeat it on each spawn. Provide it with
+/// [RatatuiCameraPlugin::with_defaults](crate::RatatuiCameraPlugin::with_defaults), or insert or
+/// modify it as a resource at any time; it is read whenever a RatatuiCamera is spawned.
+///
+/// Components inserted on a camera entity always take precedence: the strategy is only used for
+/// cameras spawned without a RatatuiCameraStrategy, and the autoresize settings are only applied
+/// to cameras spawned with `RatatuiCamera::default()`.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{
+/// #     ColorSupport, ColorsConfig, RatatuiCameraDefaults, RatatuiCameraPlugin,
+/// #     RatatuiCameraStrategy,
+/// # };
+/// #
+/// # fn build_app(app: &mut App) {
+/// app.add_plugins(RatatuiCameraPlugin::with_defaults(
+///     RatatuiCameraDefaults::default()
+///         .with_strategy(RatatuiCameraStrategy::luminance_braille())
+///         .with_colors(ColorsConfig::default().with_support(ColorSupport::ANSI256))
+///         .with_max_dimensions(UVec2::new(640, 360)),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Resource, Clone, Debug)]
+#[non_exhaustive]
+pub struct RatatuiCameraDefaults {
+    /// Strategy used by cameras spawned without a RatatuiCameraStrategy.
+    pub strategy: RatatuiCameraStrategy,
+
+    /// If present, replaces the color configuration of `strategy` (for strategies that have one),
+    /// e.g. to limit every camera to ANSI colors without choosing each camera's strategy.
+    pub colors: Option<ColorsConfig>,
+
+    /// Whether cameras spawned with `RatatuiCamera::default()` automatically resize their render
+    /// texture, see [RatatuiCamera::autoresize].
+    pub autoresize: bool,
+
+    /// Smallest dimensions autoresize will choose for cameras spawned with
+    /// `RatatuiCamera::default()`, see [RatatuiCamera::min_dimensions].
+    pub min_dimensions: Option<UVec2>,
+
+    /// Largest dimensions autoresize will choose for cameras spawned with
+    /// `RatatuiCamera::default()`, see [RatatuiCamera::max_dimensions].
+    pub max_dimensions: Option<UVec2>,
+
+    /// Aspect ratio autoresize will keep for cameras spawned with `RatatuiCamera::default()`, see
+    /// [RatatuiCamera::fixed_aspect].
+    pub fixed_aspect: Option<f32>,
+}
+
+impl Default for RatatuiCameraDefaults {
+    fn default() -> Self {
+        let camera = RatatuiCamera::default();
+
+        Self {
+            strategy: RatatuiCameraStrategy::default(),
+            colors: None,
+            autoresize: camera.autoresize,
+            min_dimensions: camera.min_dimensions,
+            max_dimensions: camera.max_dimensions,
+            fixed_aspect: camera.fixed_aspect,
+        }
+    }
+}
+
+impl RatatuiCameraDefaults {
+    /// The strategy inserted into cameras spawned without one, with `colors` applied.
+    pub fn default_strategy(&self) -> RatatuiCameraStrategy {
+        let mut strategy = self.strategy.clone();
+
+        if let (Some(colors), Some(strategy_colors)) = (&self.colors, strategy.colors_mut()) {
+            *strategy_colors = colors.clone();
+        }
+
+        strategy
+    }
+
+    /// Set [RatatuiCameraDefaults::strategy].
+    pub fn with_strategy(mut self, strategy: RatatuiCameraStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set [RatatuiCameraDefaults::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = Some(colors);
+        self
+    }
+
+    /// Set [RatatuiCameraDefaults::autoresize].
+    pub fn with_autoresize(mut self, autoresize: bool) -> Self {
+        self.autoresize = autoresize;
+        self
+    }
+
+    /// Set [RatatuiCameraDefaults::min_dimensions].
+    pub fn with_min_dimensions(mut self, min_dimensions: UVec2) -> Self {
+        self.min_dimensions = Some(min_dimensions);
+        self
+    }
+
+    /// Set [RatatuiCameraDefaults::max_dimensions].
+    pub fn with_max_dimensions(mut self, max_dimensions: UVec2) -> Self {
+        self.max_dimensions = Some(max_dimensions);
+        self
+    }
+
+    /// Set [RatatuiCameraDefaults::fixed_aspect].
+    pub fn with_fixed_aspect(mut self, fixed_aspect: f32) -> Self {
+        self.fixed_aspect = Some(fixed_aspect);
+        self
+    }
+}
+
+/// Inserts a RatatuiCameraStrategy on RatatuiCameras spawned without one, and applies the
+/// autoresize settings, both taken from the [RatatuiCameraDefaults] resource at spawn time.
+pub struct RatatuiCameraDefaultsPlugin;
+
+impl Plugin for RatatuiCameraDefaultsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RatatuiCameraDefaults>()
+            .add_observer(apply_camera_defaults_observer);
+    }
+}
+
+fn apply_camera_defaults_observer(
+    add: On<Add, RatatuiCamera>,
+    mut commands: Commands,
+    mut ratatui_cameras: Query<(&mut RatatuiCamera, Has<RatatuiCameraStrategy>)>,
+    defaults: Res<RatatuiCameraDefaults>,
+) -> Result {
+    let (mut ratatui_camera, has_strategy) = ratatui_cameras.get_mut(add.entity)?;
+
+    if !has_strategy {
+        commands
+            .entity(add.entity)
+            .insert(defaults.default_strategy());
+    }
+
+    if *ratatui_camera == RatatuiCamera::default() {
+        ratatui_camera.autoresize = defaults.autoresize;
+        ratatui_camera.min_dimensions = defaults.min_dimensions;
+        ratatui_camera.max_dimensions = defaults.max_dimensions;
+        ratatui_camera.fixed_aspect = defaults.fixed_aspect;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use ratatui::symbols::line;
+use serde::{Deserialize, Serialize};
+
+use crate::RatatuiCameraDepthDetection;
+
+/// When spawned with a RatatuiCamera, large jumps in depth between neighboring cells are detected
+/// while converting the rendered image, and the nearer cells along each jump are drawn with
+/// box-drawing characters joined up with each other. This outlines objects against whatever lies
+/// behind them without a GPU edge detection pass (see
+/// [RatatuiCameraEdgeDetection](crate::RatatuiCameraEdgeDetection)).
+///
+/// Requires (and inserts) [RatatuiCameraDepthDetection], as it relies on the depth texture.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{DepthJointStyle, RatatuiCamera, RatatuiCameraDepthJoints};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraDepthJoints {
+///         style: DepthJointStyle::Rounded,
+///         ..default()
+///     },
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
+#[require(RatatuiCameraDepthDetection)]
+#[serde(default)]
+pub struct RatatuiCameraDepthJoints {
+    /// Jump in distance from the camera between neighboring cells, relative to the distance of the
+    /// nearer cell, above which the cells are considered separate objects. For example, `0.25`
+    /// detects a jump when the farther cell is more than 25% further away than the nearer one.
+    pub threshold: f32,
+
+    /// The set of box-drawing characters used for drawing joints.
+    pub style: DepthJointStyle,
+
+    /// An override color for joint characters. When `None`, the color already drawn in the cell
+    /// is kept.
+    pub color: Option<ratatui::style::Color>,
+}
+
+impl Default for RatatuiCameraDepthJoints {
+    fn default() -> Self {
+        Self {
+            threshold: 0.25,
+            style: DepthJointStyle::default(),
+            color: None,
+        }
+    }
+}
+
+/// The set of box-drawing characters used by [RatatuiCameraDepthJoints].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DepthJointStyle {
+    /// Single thin lines, e.g. '─', '│', and '┌'.
+    #[default]
+    Plain,
+
+    /// Single thin lines with rounded corners, e.g. '─', '│', and '╭'.
+    Rounded,
+
+    /// Double lines, e.g. '═', '║', and '╔'.
+    Double,
+
+    /// Single thick lines, e.g. '━', '┃', and '┏'.
+    Thick,
+}
+
+impl DepthJointStyle {
+    /// The ratatui line set corresponding to this style.
+    pub fn line_set(&self) -> line::Set {
+        match self {
+            Self::Plain => line::NORMAL,
+            Self::Rounded => line::ROUNDED,
+            Self::Double => line::DOUBLE,
+            Self::Thick => line::THICK,
+        }
+    }
+
+    /// Select the character joining up with the neighboring joints in each direction.
+    pub(crate) fn symbol(&self, up: bool, right: bool, down: bool, left: bool) -> &'static str {
+        let set = self.line_set();
+
+        match (up, right, down, left) {
+            (true, true, true, true) => set.cross,
+            (true, true, true, false) => set.vertical_right,
+            (true, false, true, true) => set.vertical_left,
+            (false, true, true, true) => set.horizontal_down,
+            (true, true, false, true) => set.horizontal_up,
+            (false, true, true, false) => set.top_left,
+            (false, false, true, true) => set.top_right,
+            (true, true, false, false) => set.bottom_left,
+            (true, false, false, true) => set.bottom_right,
+            (true, false, _, false) | (false, false, true, false) => set.vertical,
+            _ => set.horizontal,
+        }
+    }
+}
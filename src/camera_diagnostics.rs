@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore},
+    platform::time::Instant,
+    prelude::*,
+};
+
+use crate::{
+    RatatuiCameraWidget,
+    camera_image_pipe::ImageReceiver,
+    camera_readback::{
+        RatatuiCameraReceiver, RatatuiDepthReceiver, RatatuiEdgeColorReceiver,
+        RatatuiEmissiveReceiver, RatatuiMaskReceiver, RatatuiNormalReceiver, RatatuiSobelReceiver,
+    },
+};
+
+#[cfg(feature = "gpu_cells")]
+use crate::camera_gpu_cells::RatatuiCellsReceiver;
+
+/// Add this plugin alongside [RatatuiCameraPlugin](crate::RatatuiCameraPlugin) to record
+/// diagnostics for each RatatuiCamera, which show up in bevy's diagnostics store (and so in e.g.
+/// `LogDiagnosticsPlugin` output or an FPS overlay), to help choose dimensions and strategies:
+///
+/// - `ratatui_camera/<camera>/readback_time`: milliseconds spent reading the camera's most recent
+///   frame back from the GPU (the slowest of its textures).
+/// - `ratatui_camera/<camera>/readback_bytes`: bytes read back from the GPU for the camera's most
+///   recent frame, across all of its textures.
+/// - `ratatui_camera/<camera>/conversion_time`: milliseconds spent converting the camera's images
+///   into terminal cells the last time its widget was rendered.
+///
+/// `<camera>` is the camera entity's `Name` if it has one (with any `/` replaced by `_`), and
+/// otherwise the entity itself. Readback measurements are only recorded for frames that arrived,
+/// and conversion measurements only for widgets rendered other than through `detached()`.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy::diagnostic::LogDiagnosticsPlugin;
+/// # use bevy_ratatui_camera::{RatatuiCameraDiagnosticsPlugin, RatatuiCameraPlugin};
+/// #
+/// App::new().add_plugins((
+///     RatatuiCameraPlugin,
+///     RatatuiCameraDiagnosticsPlugin,
+///     LogDiagnosticsPlugin::default(),
+/// ));
+/// ```
+///
+#[derive(Debug)]
+pub struct RatatuiCameraDiagnosticsPlugin;
+
+impl Plugin for RatatuiCameraDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .add_systems(Last, record_ratatui_camera_diagnostics_system);
+    }
+}
+
+fn record_ratatui_camera_diagnostics_system(
+    mut store: ResMut<DiagnosticsStore>,
+    ratatui_cameras: Query<(
+        Entity,
+        Option<&Name>,
+        Option<&RatatuiCameraWidget>,
+        &RatatuiCameraReceiver,
+        (
+            Option<&RatatuiDepthReceiver>,
+            Option<&RatatuiSobelReceiver>,
+            Option<&RatatuiNormalReceiver>,
+            Option<&RatatuiEmissiveReceiver>,
+            Option<&RatatuiEdgeColorReceiver>,
+            Option<&RatatuiMaskReceiver>,
+        ),
+    )>,
+    #[cfg(feature = "gpu_cells")] cells_receivers: Query<&RatatuiCellsReceiver>,
+) {
+    let now = Instant::now();
+
+    for (
+        entity,
+        name,
+        widget,
+        camera_receiver,
+        (
+            depth_receiver,
+            sobel_receiver,
+            normal_receiver,
+            emissive_receiver,
+            edge_color_receiver,
+            mask_receiver,
+        ),
+    ) in &ratatui_cameras
+    {
+        let label = match name.filter(|name| !name.is_empty()) {
+            Some(name) => name.as_str().replace('/', "_"),
+            None => entity.to_string(),
+        };
+
+        let receivers = [
+            Some(&**camera_receiver),
+            depth_receiver.map(|receiver| &**receiver),
+            sobel_receiver.map(|receiver| &**receiver),
+            normal_receiver.map(|receiver| &**receiver),
+            emissive_receiver.map(|receiver| &**receiver),
+            edge_color_receiver.map(|receiver| &**receiver),
+            mask_receiver.map(|receiver| &**receiver),
+            #[cfg(feature = "gpu_cells")]
+            cells_receivers.get(entity).ok().map(|receiver| &**receiver),
+        ];
+
+        let measurements = receivers
+            .into_iter()
+            .flatten()
+            .filter_map(|receiver: &ImageReceiver| receiver.measurement);
+
+        let mut readback: Option<(Duration, usize)> = None;
+        for measurement in measurements {
+            let (time, bytes) = readback.get_or_insert_default();
+            *time = measurement.time.max(*time);
+            *bytes += measurement.bytes;
+        }
+
+        let mut record = |measurement: &str, suffix: &'static str, value: f64| {
+            record_measurement(&mut store, &label, measurement, suffix, now, value);
+        };
+
+        if let Some((time, bytes)) = readback {
+            record("readback_time", "ms", time.as_secs_f64() * 1000.);
+            record("readback_bytes", "B", bytes as f64);
+        }
+
+        if let Some(conversion_time) =
+            widget.and_then(|widget| widget.draw_state.lock().conversion_time)
+        {
+            record(
+                "conversion_time",
+                "ms",
+                conversion_time.as_secs_f64() * 1000.,
+            );
+        }
+    }
+}
+
+/// Add a measurement to a camera's diagnostic, registering the diagnostic if it is new.
+fn record_measurement(
+    store: &mut DiagnosticsStore,
+    label: &str,
+    measurement: &str,
+    suffix: &'static str,
+    time: Instant,
+    value: f64,
+) {
+    let path = DiagnosticPath::new(format!("ratatui_camera/{label}/{measurement}"));
+
+    if store.get(&path).is_none() {
+        store.add(Diagnostic::new(path.clone()).with_suffix(suffix));
+    }
+
+    if let Some(diagnostic) = store
+        .get_mut(&path)
+        .filter(|diagnostic| diagnostic.is_enabled)
+    {
+        diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+    }
+}
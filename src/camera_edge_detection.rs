@@ -1,19 +1,29 @@
 use bevy::{prelude::*, render::extract_component::ExtractComponent};
+use serde::{Deserialize, Serialize};
 
 /// When spawned with a RatatuiCamera, an edge detection step will run in the render pipeline, and
 /// detected edges will be handled differently by each image to unicode character conversion
-/// strategy. The edge detection is performed via a sobel filter convolved over the depth, normal,
-/// and color textures generated during rendering, resulting in a new texture of detected edges
-/// and their directions (horizontal, vertical, both diagonals). Where edges are detected, special
-/// characters and optionally an override color can be used.
+/// strategy. The edge detection is performed via a filter (sobel by default, see [EdgeKernel])
+/// convolved over the depth, normal, and color textures generated during rendering, resulting in
+/// a new texture of detected edges and their directions (horizontal, vertical, both diagonals).
+/// Where edges are detected, special characters and optionally an override color can be used.
 ///
 /// Currently just works with `RatatuiCameraStrategy::Luminance` and 3d cameras.
 ///
-#[derive(Component, ExtractComponent, Clone, Copy, Debug)]
+#[derive(Component, ExtractComponent, Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[reflect(Component)]
+#[serde(default)]
 pub struct RatatuiCameraEdgeDetection {
     /// Width of the range used for detecting edges. Higher thickness value means a wider edge.
     pub thickness: f32,
 
+    /// Unit that `thickness` is measured in.
+    pub thickness_unit: EdgeThicknessUnit,
+
+    /// The convolution kernel used for detecting edges.
+    pub kernel: EdgeKernel,
+
     /// Enable using the color texture to detect edges.
     pub color_enabled: bool,
     /// Threshold for edge severity required for an edge to be detected in the color texture.
@@ -29,16 +39,111 @@ pub struct RatatuiCameraEdgeDetection {
     /// Threshold for edge severity required for an edge to be detected in the normal texture.
     pub normal_threshold: f32,
 
+    /// Range above each threshold over which edge intensity ramps smoothly from none to full.
+    /// When `0.0`, edges are either detected or not. When greater, the edge color is blended with
+    /// the underlying color in proportion to the edge's intensity, and edge characters are only
+    /// used past the halfway point, which reduces flickering edges in animated scenes.
+    pub softness: f32,
+
+    /// Fraction of the previous frame's detected edges blended into the current frame's, from
+    /// `0.0` (no smoothing) towards `1.0`. Higher values steady shimmering edges in animated
+    /// scenes, at the cost of edges trailing behind moving objects.
+    pub temporal_smoothing: f32,
+
     /// The unicode characters used for rendering edges in the terminal buffer.
     pub edge_characters: EdgeCharacters,
     /// An override color that replaces the rendered color when an edge is detected.
+    #[reflect(ignore)]
     pub edge_color: Option<ratatui::style::Color>,
 }
 
+impl RatatuiCameraEdgeDetection {
+    /// Set [RatatuiCameraEdgeDetection::thickness].
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::thickness_unit].
+    pub fn with_thickness_unit(mut self, thickness_unit: EdgeThicknessUnit) -> Self {
+        self.thickness_unit = thickness_unit;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::kernel].
+    pub fn with_kernel(mut self, kernel: EdgeKernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::color_enabled].
+    pub fn with_color_enabled(mut self, color_enabled: bool) -> Self {
+        self.color_enabled = color_enabled;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::color_threshold].
+    pub fn with_color_threshold(mut self, color_threshold: f32) -> Self {
+        self.color_threshold = color_threshold;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::depth_enabled].
+    pub fn with_depth_enabled(mut self, depth_enabled: bool) -> Self {
+        self.depth_enabled = depth_enabled;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::depth_threshold].
+    pub fn with_depth_threshold(mut self, depth_threshold: f32) -> Self {
+        self.depth_threshold = depth_threshold;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::normal_enabled].
+    pub fn with_normal_enabled(mut self, normal_enabled: bool) -> Self {
+        self.normal_enabled = normal_enabled;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::normal_threshold].
+    pub fn with_normal_threshold(mut self, normal_threshold: f32) -> Self {
+        self.normal_threshold = normal_threshold;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::softness].
+    pub fn with_softness(mut self, softness: f32) -> Self {
+        self.softness = softness;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::temporal_smoothing].
+    pub fn with_temporal_smoothing(mut self, temporal_smoothing: f32) -> Self {
+        self.temporal_smoothing = temporal_smoothing;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::edge_characters].
+    pub fn with_edge_characters(mut self, edge_characters: EdgeCharacters) -> Self {
+        self.edge_characters = edge_characters;
+        self
+    }
+
+    /// Set [RatatuiCameraEdgeDetection::edge_color].
+    pub fn with_edge_color(mut self, edge_color: ratatui::style::Color) -> Self {
+        self.edge_color = Some(edge_color);
+        self
+    }
+}
+
 impl Default for RatatuiCameraEdgeDetection {
     fn default() -> Self {
         Self {
             thickness: 2.0,
+            thickness_unit: EdgeThicknessUnit::default(),
+
+            kernel: EdgeKernel::default(),
 
             color_enabled: true,
             color_threshold: 0.4,
@@ -49,15 +154,140 @@ impl Default for RatatuiCameraEdgeDetection {
             normal_enabled: true,
             normal_threshold: 2.5,
 
+            softness: 0.0,
+            temporal_smoothing: 0.0,
+
             edge_characters: EdgeCharacters::default(),
             edge_color: None,
         }
     }
 }
 
+/// When within a camera entity alongside a RatatuiCamera, detected edges are downsampled on the
+/// GPU to two pixels per terminal cell (keeping the strongest edge in each direction within each
+/// block of pixels) before being copied back, rather than copying back the edges of every pixel.
+/// Edges are only ever sampled per cell, so this greatly reduces the edge detection's readback
+/// bandwidth for large render textures, without losing thin edges.
+///
+/// Inserts [RatatuiCameraEdgeDetection]. Currently just works with 3d cameras.
+#[derive(Component, Clone, Debug, Default)]
+#[require(RatatuiCameraEdgeDetection)]
+pub struct RatatuiCameraEdgeDownsampling;
+
+/// When inserted into a mesh entity, edges detected around the mesh by cameras with
+/// [RatatuiCameraEdgeColorDetection](crate::RatatuiCameraEdgeColorDetection) are drawn in this
+/// color, instead of the edge detection's `edge_color`. Useful for outlining entities by type,
+/// e.g. enemies in red and pickups in yellow.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiCameraEdgeColor;
+/// #
+/// # fn setup_scene_system(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+/// commands.spawn((
+///     Mesh3d(meshes.add(Cuboid::default())),
+///     RatatuiCameraEdgeColor(Color::srgb(1.0, 0.0, 0.0)),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RatatuiCameraEdgeColor(pub Color);
+
+/// Unit that [RatatuiCameraEdgeDetection::thickness] is measured in.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub enum EdgeThicknessUnit {
+    /// Pixels of the camera's render texture. The apparent line weight changes when the render
+    /// texture is resized (e.g. by autoresize).
+    #[default]
+    Pixels,
+
+    /// Terminal cells. The thickness in pixels is recalculated whenever the camera's dimensions
+    /// or the area it is drawn within change, so that the line weight stays consistent.
+    Cells,
+}
+
+/// The 3x3 convolution kernel used for edge detection. Each kernel describes the horizontal
+/// gradient (rows from top to bottom), and the vertical and diagonal kernels are derived by
+/// rotating its outer ring of weights in 45 degree steps.
+///
+/// Kernels are normalized to the same total absolute weight as the sobel kernel, so that edge
+/// thresholds behave similarly regardless of the kernel chosen.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize, Reflect)]
+pub enum EdgeKernel {
+    /// Sobel operator, a good general purpose default.
+    #[default]
+    Sobel,
+
+    /// Scharr operator, which has better rotational symmetry than sobel and so detects diagonal
+    /// edges more consistently.
+    Scharr,
+
+    /// Prewitt operator, which weights all neighbors equally and responds more to noise.
+    Prewitt,
+
+    /// Custom 3x3 weights for detecting horizontal gradients, rows from top to bottom.
+    Custom([[f32; 3]; 3]),
+}
+
+impl EdgeKernel {
+    /// Total absolute weight that kernels are normalized to (that of the sobel kernel).
+    #[cfg(feature = "sobel")]
+    const NORMALIZED_WEIGHT: f32 = 8.0;
+
+    /// Positions of the kernel's outer ring (row-major indices), clockwise from the top left.
+    #[cfg(feature = "sobel")]
+    const RING: [usize; 8] = [0, 1, 2, 5, 8, 7, 6, 3];
+
+    /// The weights for detecting horizontal gradients, rows from top to bottom, before
+    /// normalization.
+    pub fn weights(&self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Sobel => [[1.0, 0.0, -1.0], [2.0, 0.0, -2.0], [1.0, 0.0, -1.0]],
+            Self::Scharr => [[3.0, 0.0, -3.0], [10.0, 0.0, -10.0], [3.0, 0.0, -3.0]],
+            Self::Prewitt => [[1.0, 0.0, -1.0], [1.0, 0.0, -1.0], [1.0, 0.0, -1.0]],
+            Self::Custom(weights) => *weights,
+        }
+    }
+
+    /// Normalized weights for each of the nine kernel positions (row-major), with the
+    /// horizontal, vertical, forward diagonal, and backward diagonal kernels packed into the
+    /// x, y, z, and w components respectively.
+    #[cfg(feature = "sobel")]
+    pub(crate) fn directional_weights(&self) -> [Vec4; 9] {
+        let horizontal = self.weights().concat();
+
+        let total = horizontal.iter().map(|weight| weight.abs()).sum::<f32>();
+        let normalization = if total > 0.0 {
+            Self::NORMALIZED_WEIGHT / total
+        } else {
+            1.0
+        };
+
+        let rotated = |steps: usize| {
+            let mut kernel = [0.0; 9];
+            kernel[4] = horizontal[4];
+            for (ring_index, &position) in Self::RING.iter().enumerate() {
+                kernel[position] = horizontal[Self::RING[(ring_index + 8 - steps) % 8]];
+            }
+            kernel
+        };
+
+        let (vertical, forward, backward) = (rotated(2), rotated(3), rotated(1));
+
+        std::array::from_fn(|i| {
+            Vec4::new(horizontal[i], vertical[i], forward[i], backward[i]) * normalization
+        })
+    }
+}
+
 /// Specify how to handle rendering detected edges as unicode characters.
 ///
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
 pub enum EdgeCharacters {
     /// Each character in a detected edge will be shown as a specified character.
     Single(char),
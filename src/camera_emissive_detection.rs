@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use ratatui::style::Modifier;
+
+/// When spawned with a RatatuiCamera, the brightness of the camera's HDR main texture (before
+/// tonemapping, and after bloom if enabled) is recorded in the render pipeline and copied back
+/// from the GPU. Cells covering pixels brighter than `threshold` are emphasized when drawn, so
+/// that emissive materials visibly "glow" in the terminal.
+///
+/// Inserts bevy's `Hdr` component into the camera, as brightness values above 1.0 only exist in
+/// HDR rendering. Currently just works with 3d cameras.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraEmissiveDetection};
+/// # use ratatui::style::Modifier;
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraEmissiveDetection {
+///         modifier: Modifier::BOLD | Modifier::SLOW_BLINK,
+///         ..default()
+///     },
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RatatuiCameraEmissiveDetection {
+    /// Brightness (of the brightest color channel) above which a pixel is considered emissive.
+    /// Values are recorded up to [RatatuiCameraEmissiveDetection::MAX_BRIGHTNESS].
+    pub threshold: f32,
+
+    /// Cell modifiers added to emissive cells.
+    pub modifier: Modifier,
+
+    /// For strategies that select characters from a list, the number of steps further along the
+    /// list (towards more opaque characters) that emissive cells are shifted.
+    pub character_boost: usize,
+}
+
+impl RatatuiCameraEmissiveDetection {
+    /// The highest brightness value that can be recorded, brighter pixels are clamped to this.
+    pub const MAX_BRIGHTNESS: f32 = 4.0;
+}
+
+impl Default for RatatuiCameraEmissiveDetection {
+    fn default() -> Self {
+        Self {
+            threshold: 1.0,
+            modifier: Modifier::BOLD,
+            character_boost: 2,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+/// Message written when a RatatuiCamera runs into a recoverable problem, which is also logged as a
+/// warning. Rather than panicking (which would leave the terminal in raw mode), the camera carries
+/// on, e.g. by keeping its previous image. Read these with a `MessageReader` to react to them.
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub enum RatatuiCameraError {
+    /// An image read back from the GPU for the camera could not be decoded, so the camera's
+    /// previous image was kept.
+    Readback {
+        /// The RatatuiCamera entity.
+        entity: Entity,
+
+        /// Why the image could not be decoded.
+        reason: String,
+    },
+
+    /// The textures that the camera's images are read back through could not be created, so the
+    /// camera kept its previous readback (if any).
+    ImagePipe {
+        /// The RatatuiCamera entity.
+        entity: Entity,
+
+        /// Why the textures could not be created.
+        reason: String,
+    },
+
+    /// The camera's render target could not be updated because the entity was despawned (or is no
+    /// longer a camera) by the time the update was applied.
+    StaleTarget {
+        /// The despawned entity.
+        entity: Entity,
+    },
+
+    /// The camera's strategy or edge detection relies on the depth texture, but this platform
+    /// cannot sample depth textures (e.g. WebGL), so depth strategies fall back to a luminance
+    /// strategy and edge detection ignores depth.
+    DepthSamplingUnsupported {
+        /// The RatatuiCamera entity.
+        entity: Entity,
+    },
+
+    /// Drawing to the terminal failed while drawing with
+    /// [RatatuiCameraDraw](crate::RatatuiCameraDraw), so the frame was skipped.
+    Draw {
+        /// Why drawing failed.
+        reason: String,
+    },
+}
+
+impl fmt::Display for RatatuiCameraError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Readback { entity, reason } => {
+                write!(f, "failed to read back image for camera {entity}: {reason}")
+            }
+            Self::ImagePipe { entity, reason } => {
+                write!(
+                    f,
+                    "failed to create image pipe for camera {entity}: {reason}"
+                )
+            }
+            Self::StaleTarget { entity } => {
+                write!(
+                    f,
+                    "failed to update render target of despawned camera {entity}"
+                )
+            }
+            Self::DepthSamplingUnsupported { entity } => write!(
+                f,
+                "depth texture sampling is unsupported on this platform, so camera {entity} \
+                falls back to luminance instead of using depth"
+            ),
+            Self::Draw { reason } => write!(f, "failed to draw camera widgets: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RatatuiCameraError {}
+
+impl RatatuiCameraError {
+    /// Log the error as a warning and write it as a message.
+    pub(crate) fn report(self, errors: &mut MessageWriter<RatatuiCameraError>) {
+        log::warn!("{self}");
+        errors.write(self);
+    }
+
+    /// Log the error as a warning and write it as a message once the commands are applied, for
+    /// code that only has access to `Commands`.
+    pub(crate) fn report_deferred(self, commands: &mut Commands) {
+        log::warn!("{self}");
+        commands.write_message(self);
+    }
+}
@@ -0,0 +1,215 @@
+use bevy::prelude::*;
+use bevy_ratatui::event::MouseMessage;
+use crossterm::event::MouseEventKind;
+use ratatui::layout::Position;
+
+use crate::{RatatuiCameraLastArea, RatatuiCameraOrder, RatatuiCameraWidget};
+
+/// Resource tracking which RatatuiCamera the mouse is over and which has keyboard focus, for
+/// routing input to the right pane of a layout with several cameras. The hovered camera follows
+/// the mouse, and clicking a camera focuses it. Focus can also be moved from code (e.g. with Tab)
+/// with [RatatuiCameraFocus::set_focused]. Requires the `mouse` feature.
+///
+/// Cameras are hovered where their image was last drawn, excluding the gutters around it (see
+/// [FillMode](crate::FillMode) and [ImageAlignment](crate::ImageAlignment)). Where cameras were
+/// last drawn in overlapping areas, the one with the highest [RatatuiCameraOrder] is hovered, as
+/// it is drawn on top when drawn in order. A [RatatuiCameraFocusChanged] message is written
+/// whenever either camera changes, including when a hovered or focused camera is despawned.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::event::KeyMessage;
+/// # use bevy_ratatui_camera::RatatuiCameraFocus;
+/// #
+/// fn focused_input_system(
+///     mut key_messages: MessageReader<KeyMessage>,
+///     focus: Res<RatatuiCameraFocus>,
+///     mut cameras: Query<&mut Transform, With<Camera>>,
+/// ) {
+///     let Some(mut transform) = focus.focused().and_then(|entity| cameras.get_mut(entity).ok())
+///     else {
+///         return;
+///     };
+///
+///     for _ in key_messages.read() {
+///         transform.rotate_z(0.1);
+///     }
+/// }
+/// ```
+///
+#[derive(Resource, Clone, Debug, Default)]
+pub struct RatatuiCameraFocus {
+    hovered: Option<Entity>,
+    focused: Option<Entity>,
+    previous_hovered: Option<Entity>,
+    previous_focused: Option<Entity>,
+}
+
+impl RatatuiCameraFocus {
+    /// The camera the mouse is over, if any.
+    pub fn hovered(&self) -> Option<Entity> {
+        self.hovered
+    }
+
+    /// The camera with keyboard focus, if any.
+    pub fn focused(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    /// Whether the mouse is over the provided camera.
+    pub fn is_hovered(&self, entity: Entity) -> bool {
+        self.hovered == Some(entity)
+    }
+
+    /// Whether the provided camera has keyboard focus.
+    pub fn is_focused(&self, entity: Entity) -> bool {
+        self.focused == Some(entity)
+    }
+
+    /// Give keyboard focus to the provided camera, or to no camera.
+    pub fn set_focused(&mut self, entity: Option<Entity>) {
+        self.focused = entity;
+    }
+}
+
+/// Message written when the camera the mouse is over or the camera with keyboard focus changes,
+/// see [RatatuiCameraFocus].
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RatatuiCameraFocusChanged {
+    /// The camera the mouse is now over, if any.
+    pub hovered: Option<Entity>,
+
+    /// The camera that now has keyboard focus, if any.
+    pub focused: Option<Entity>,
+
+    /// The camera the mouse was previously over, if any.
+    pub previous_hovered: Option<Entity>,
+
+    /// The camera that previously had keyboard focus, if any.
+    pub previous_focused: Option<Entity>,
+}
+
+pub struct RatatuiCameraFocusPlugin;
+
+impl Plugin for RatatuiCameraFocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RatatuiCameraFocus>()
+            .add_message::<MouseMessage>()
+            .add_message::<RatatuiCameraFocusChanged>()
+            .add_systems(PreUpdate, update_camera_focus_system);
+    }
+}
+
+fn update_camera_focus_system(
+    mut mouse_messages: MessageReader<MouseMessage>,
+    ratatui_cameras: Query<(
+        Entity,
+        &RatatuiCameraWidget,
+        &RatatuiCameraLastArea,
+        Option<&RatatuiCameraOrder>,
+    )>,
+    mut focus: ResMut<RatatuiCameraFocus>,
+    mut focus_changed: MessageWriter<RatatuiCameraFocusChanged>,
+) {
+    for message in mouse_messages.read() {
+        let position = Position::new(message.column, message.row);
+        let hovered = ratatui_cameras
+            .iter()
+            .filter(|(_, widget, last_area, _)| widget.drawn_area_contains(**last_area, position))
+            .max_by_key(|(entity, _, _, order)| (order.copied().unwrap_or_default(), *entity))
+            .map(|(entity, _, _, _)| entity);
+
+        focus.hovered = hovered;
+
+        if matches!(message.kind, MouseEventKind::Down(_)) && hovered.is_some() {
+            focus.focused = hovered;
+        }
+    }
+
+    if focus
+        .hovered
+        .is_some_and(|entity| !ratatui_cameras.contains(entity))
+    {
+        focus.hovered = None;
+    }
+
+    if focus
+        .focused
+        .is_some_and(|entity| !ratatui_cameras.contains(entity))
+    {
+        focus.focused = None;
+    }
+
+    if focus.hovered != focus.previous_hovered || focus.focused != focus.previous_focused {
+        focus_changed.write(RatatuiCameraFocusChanged {
+            hovered: focus.hovered,
+            focused: focus.focused,
+            previous_hovered: focus.previous_hovered,
+            previous_focused: focus.previous_focused,
+        });
+
+        focus.previous_hovered = focus.hovered;
+        focus.previous_focused = focus.focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_ratatui::event::MouseMessage;
+    use crossterm::event::{KeyModifiers, MouseEvent, MouseEventKind};
+    use image::{DynamicImage, Rgba, RgbaImage};
+    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+    use super::{RatatuiCameraFocus, RatatuiCameraFocusPlugin};
+    use crate::test_utils::{RatatuiCameraTestAppBuilder, RatatuiCameraTestImages};
+    use crate::{RatatuiCamera, RatatuiCameraWidget};
+
+    fn move_mouse(app: &mut App, column: u16, row: u16) {
+        app.world_mut().write_message(MouseMessage(MouseEvent {
+            kind: MouseEventKind::Moved,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }));
+        app.update();
+    }
+
+    #[test]
+    fn letterboxed_cameras_are_not_hovered_over_their_gutters() {
+        let mut app = RatatuiCameraTestAppBuilder::new().build();
+        app.add_plugins(RatatuiCameraFocusPlugin);
+
+        let image = RgbaImage::from_pixel(8, 8, Rgba([255, 255, 255, 255]));
+        let entity = app
+            .world_mut()
+            .spawn((
+                Camera::default(),
+                RatatuiCamera::new(8, 8),
+                RatatuiCameraTestImages::new(DynamicImage::ImageRgba8(image)),
+            ))
+            .id();
+        app.update();
+
+        // a square image in a wide area is letterboxed, leaving gutters on either side.
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        let mut widget = app
+            .world_mut()
+            .get_mut::<RatatuiCameraWidget>(entity)
+            .unwrap();
+        Widget::render(&mut *widget, area, &mut buf);
+        app.update();
+
+        move_mouse(&mut app, 1, 1);
+        assert_eq!(app.world().resource::<RatatuiCameraFocus>().hovered(), None);
+
+        move_mouse(&mut app, 20, 1);
+        assert_eq!(
+            app.world().resource::<RatatuiCameraFocus>().hovered(),
+            Some(entity)
+        );
+    }
+}
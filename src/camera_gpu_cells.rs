@@ -0,0 +1,446 @@
+use std::path::Path;
+
+#[cfg(feature = "core_2d")]
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+#[cfg(feature = "core_3d")]
+use bevy::core_pipeline::core_3d::graph::{Core3d, Node3d};
+use bevy::{
+    asset::{AssetPath, embedded_asset, io::AssetSourceId},
+    ecs::query::QueryItem,
+    prelude::*,
+    render::{
+        Render, RenderApp, RenderSystems,
+        extract_component::{
+            ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+            UniformComponentPlugin,
+        },
+        render_asset::RenderAssets,
+        render_graph::{
+            NodeRunError, RenderGraphContext, RenderGraphExt, ViewNode, ViewNodeRunner,
+        },
+        render_resource::{
+            BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedComputePipelineId,
+            CachedPipelineState, ComputePassDescriptor, ComputePipelineDescriptor, PipelineCache,
+            ShaderStages, ShaderType, StorageTextureAccess, TextureFormat, TextureSampleType,
+            TextureUsages,
+            binding_types::{texture_2d, texture_storage_2d, uniform_buffer},
+        },
+        renderer::{RenderContext, RenderDevice},
+        texture::GpuImage,
+    },
+};
+
+use crate::{
+    RatatuiCamera, RatatuiCameraError, RatatuiCameraSet, RatatuiCameraStrategy,
+    RatatuiCameraWidget,
+    camera::{RatatuiCameraLastArea, RatatuiCameraLatency, RatatuiCameraLowLatencySet},
+    camera_image_pipe::{
+        ImageBufferPool, ImageReceiver, ImageSender, create_image_pipe_with_format, receive_image,
+        send_image_buffer,
+    },
+    camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind, copy_texture_to_buffer},
+    camera_readback::{
+        RatatuiCameraSender, create_ratatui_camera_widgets_system, pipe_matches, standard_latency,
+    },
+    character_support::RatatuiCameraCharacterSupport,
+    font_metrics::RatatuiCameraFontMetrics,
+};
+
+/// Size of each side of the compute shader's workgroups, in cells.
+const WORKGROUP_SIZE: u32 = 8;
+
+/// Luminance character lists longer than this are converted on the CPU, as the character index is
+/// read back as a single byte with the last value reserved for transparent cells.
+const MAX_GPU_CHARACTERS: usize = u8::MAX as usize;
+
+pub struct RatatuiCameraGpuCellsPlugin;
+
+impl Plugin for RatatuiCameraGpuCellsPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "src/", "shaders/gpu_cells.wgsl");
+
+        app.add_plugins((
+            ExtractComponentPlugin::<RatatuiCellsSender>::default(),
+            ExtractComponentPlugin::<RatatuiCameraGpuCellsSettings>::default(),
+            UniformComponentPlugin::<RatatuiCameraGpuCellsSettings>::default(),
+        ))
+        .add_observer(ratatui_gpu_cells_insert_observer)
+        .add_observer(ratatui_gpu_cells_removal_observer)
+        .add_systems(
+            First,
+            (
+                update_ratatui_cells_readback_system,
+                (receive_cells_images_system, attach_cells_images_system)
+                    .chain()
+                    .after(create_ratatui_camera_widgets_system)
+                    .run_if(standard_latency),
+            )
+                .in_set(RatatuiCameraSet),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                update_gpu_cells_settings_system,
+                (receive_cells_images_system, attach_cells_images_system)
+                    .chain()
+                    .after(create_ratatui_camera_widgets_system)
+                    .in_set(RatatuiCameraLowLatencySet)
+                    .run_if(not(standard_latency)),
+            ),
+        );
+
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::GpuCells);
+        let render_app = app.sub_app_mut(RenderApp);
+
+        render_app.add_systems(
+            Render,
+            send_cells_images_system.after(RenderSystems::Render),
+        );
+
+        #[cfg(feature = "core_3d")]
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeGpuCells>>(Core3d, label)
+            .add_render_graph_edge(Core3d, Node3d::Upscaling, label);
+
+        #[cfg(feature = "core_2d")]
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeGpuCells>>(Core2d, label)
+            .add_render_graph_edge(Core2d, Node2d::Upscaling, label);
+    }
+
+    fn finish(&self, app: &mut App) {
+        let render_app = app.sub_app_mut(RenderApp);
+        render_app.init_resource::<RatatuiCameraNodeGpuCellsPipeline>();
+    }
+}
+
+/// When within a camera entity alongside a RatatuiCamera using the luminance strategy, each
+/// terminal cell's character and foreground color are chosen by a compute shader on the GPU, and
+/// only a single pixel per cell is copied back, so the CPU only needs to write the cells into the
+/// terminal buffer. Requires the `gpu_cells` feature.
+///
+/// The widget falls back to converting the camera's image on the CPU whenever the GPU output
+/// cannot be used: for other strategies, character lists longer than 255 characters, colors
+/// configs that adjust the image (tone adjustments, grayscale, fog, or a LUT), and frames where
+/// the drawn area has changed since the cells were computed. Image statistics are not recorded
+/// for frames drawn from the GPU output.
+///
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RatatuiCameraGpuCells;
+
+/// Settings for the compute shader, as stored in a uniform buffer in the render world. Only
+/// present while the camera's strategy can be converted on the GPU.
+#[derive(Component, ExtractComponent, ShaderType, Clone, Copy, Debug)]
+pub struct RatatuiCameraGpuCellsSettings {
+    scale: f32,
+    character_count: u32,
+}
+
+#[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
+pub struct RatatuiCellsSender(ImageSender);
+
+#[derive(Component, Deref, DerefMut, Debug)]
+pub struct RatatuiCellsReceiver(ImageReceiver);
+
+fn ratatui_gpu_cells_insert_observer(
+    insert: On<Insert, RatatuiCameraGpuCells>,
+    mut commands: Commands,
+    ratatui_cameras: Query<(&RatatuiCamera, &RatatuiCameraLastArea)>,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    if let Ok((ratatui_camera, last_area)) = ratatui_cameras.get(insert.entity) {
+        insert_cells_readback_components(
+            commands.reborrow(),
+            insert.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+            cells_dimensions(ratatui_camera, last_area, &font_metrics),
+        );
+    }
+}
+
+fn ratatui_gpu_cells_removal_observer(
+    remove: On<Remove, RatatuiCameraGpuCells>,
+    mut commands: Commands,
+) {
+    let mut entity = commands.entity(remove.entity);
+    entity.remove::<(
+        RatatuiCellsSender,
+        RatatuiCellsReceiver,
+        RatatuiCameraGpuCellsSettings,
+    )>();
+}
+
+fn update_ratatui_cells_readback_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (
+            Entity,
+            &RatatuiCamera,
+            &RatatuiCameraLastArea,
+            Option<&RatatuiCellsSender>,
+        ),
+        (
+            With<RatatuiCameraGpuCells>,
+            Or<(Changed<RatatuiCamera>, Changed<RatatuiCameraLastArea>)>,
+        ),
+    >,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    for (entity, ratatui_camera, last_area, sender) in &ratatui_cameras {
+        let dimensions = cells_dimensions(ratatui_camera, last_area, &font_metrics);
+        if pipe_matches(sender, dimensions, ratatui_camera) {
+            continue;
+        }
+
+        insert_cells_readback_components(
+            commands.reborrow(),
+            entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+            dimensions,
+        );
+    }
+}
+
+/// Keep the compute shader's settings in line with the strategy the widget will actually use,
+/// after unsupported characters have been replaced.
+fn update_gpu_cells_settings_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<(
+        Entity,
+        Ref<RatatuiCameraStrategy>,
+        Ref<RatatuiCameraGpuCells>,
+    )>,
+    character_support: Res<RatatuiCameraCharacterSupport>,
+) {
+    for (entity, strategy, gpu_cells) in &ratatui_cameras {
+        if !strategy.is_changed() && !gpu_cells.is_changed() && !character_support.is_changed() {
+            continue;
+        }
+
+        let mut strategy = strategy.clone();
+        character_support.downgrade_strategy(&mut strategy);
+
+        let mut entity = commands.entity(entity);
+
+        match strategy {
+            RatatuiCameraStrategy::Luminance(config)
+                if config.characters.list.len() <= MAX_GPU_CHARACTERS =>
+            {
+                entity.insert(RatatuiCameraGpuCellsSettings {
+                    scale: config.characters.scale,
+                    character_count: config.characters.list.len() as u32,
+                });
+            }
+            _ => {
+                entity.remove::<RatatuiCameraGpuCellsSettings>();
+            }
+        }
+    }
+}
+
+fn send_cells_images_system(
+    ratatui_cells_senders: Query<&RatatuiCellsSender>,
+    render_device: Res<RenderDevice>,
+) {
+    for cells_sender in &ratatui_cells_senders {
+        send_image_buffer(&render_device, cells_sender);
+    }
+}
+
+fn receive_cells_images_system(
+    mut cells_receivers: Query<(Entity, &mut RatatuiCellsReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut cells_receiver, camera) in &mut cells_receivers {
+        if let Err(reason) = receive_image(&mut cells_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
+    }
+}
+
+fn attach_cells_images_system(
+    mut ratatui_cameras: Query<(&mut RatatuiCameraWidget, &RatatuiCellsReceiver)>,
+) {
+    for (mut widget, cells_receiver) in &mut ratatui_cameras {
+        widget.gpu_cells_image = Some(cells_receiver.image.clone());
+    }
+}
+
+fn insert_cells_readback_components(
+    mut commands: Commands,
+    entity: Entity,
+    image_assets: &mut Assets<Image>,
+    buffer_pool: &ImageBufferPool,
+    ratatui_camera: &RatatuiCamera,
+    dimensions: UVec2,
+) {
+    // cells are written as raw bytes, so the texture must not be in an sRGB format.
+    let (sender, receiver) = match create_image_pipe_with_format(
+        image_assets,
+        buffer_pool,
+        dimensions,
+        TextureFormat::Rgba8Unorm,
+        ratatui_camera.max_frame_latency,
+    ) {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(entity);
+
+    if let Some(sender_image) = image_assets.get_mut(&sender.sender_image) {
+        sender_image.texture_descriptor.usage |= TextureUsages::STORAGE_BINDING;
+    }
+
+    entity.insert((RatatuiCellsSender(sender), RatatuiCellsReceiver(receiver)));
+}
+
+/// Dimensions of the cells texture, one pixel per terminal cell of the area the camera's image was
+/// last drawn within.
+fn cells_dimensions(
+    ratatui_camera: &RatatuiCamera,
+    last_area: &RatatuiCameraLastArea,
+    font_metrics: &RatatuiCameraFontMetrics,
+) -> UVec2 {
+    ratatui_camera
+        .render_area_size(**last_area, font_metrics)
+        .max(UVec2::ONE)
+}
+
+/// Converts the camera's rendered texture into one pixel per terminal cell in the cells sender's
+/// image, holding each cell's character index and foreground color, and copies it for readback.
+#[derive(Default)]
+pub struct RatatuiCameraNodeGpuCells;
+
+impl ViewNode for RatatuiCameraNodeGpuCells {
+    type ViewQuery = (
+        &'static RatatuiCameraSender,
+        &'static RatatuiCellsSender,
+        &'static DynamicUniformIndex<RatatuiCameraGpuCellsSettings>,
+    );
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext<'_>,
+        render_context: &mut RenderContext<'w>,
+        (camera_sender, cells_sender, settings_index): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
+        let cells_pipeline = world.resource::<RatatuiCameraNodeGpuCellsPipeline>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let settings_uniforms =
+            world.resource::<ComponentUniforms<RatatuiCameraGpuCellsSettings>>();
+
+        if let CachedPipelineState::Err(pipeline_error) =
+            pipeline_cache.get_compute_pipeline_state(cells_pipeline.pipeline_id)
+        {
+            log::error!("{pipeline_error:?}");
+        };
+
+        let Some(pipeline) = pipeline_cache.get_compute_pipeline(cells_pipeline.pipeline_id) else {
+            return Ok(());
+        };
+
+        let (Some(source), Some(destination), Some(settings_binding)) = (
+            gpu_images.get(&camera_sender.sender_image),
+            gpu_images.get(&cells_sender.sender_image),
+            settings_uniforms.uniforms().binding(),
+        ) else {
+            return Ok(());
+        };
+
+        let bind_group = render_context.render_device().create_bind_group(
+            "ratatui_camera_node_gpu_cells_bind_group",
+            &cells_pipeline.layout,
+            &BindGroupEntries::sequential((
+                &source.texture_view,
+                &destination.texture_view,
+                settings_binding,
+            )),
+        );
+
+        {
+            let mut compute_pass =
+                render_context
+                    .command_encoder()
+                    .begin_compute_pass(&ComputePassDescriptor {
+                        label: Some("ratatui_camera_node_gpu_cells_pass"),
+                        timestamp_writes: None,
+                    });
+
+            compute_pass.set_pipeline(pipeline);
+            compute_pass.set_bind_group(0, &bind_group, &[settings_index.index()]);
+            compute_pass.dispatch_workgroups(
+                destination.size.width.div_ceil(WORKGROUP_SIZE),
+                destination.size.height.div_ceil(WORKGROUP_SIZE),
+                1,
+            );
+        }
+
+        copy_texture_to_buffer(render_context, world, &destination.texture, cells_sender);
+
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct RatatuiCameraNodeGpuCellsPipeline {
+    layout: BindGroupLayout,
+    pipeline_id: CachedComputePipelineId,
+}
+
+impl FromWorld for RatatuiCameraNodeGpuCellsPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let layout = render_device.create_bind_group_layout(
+            "ratatui_camera_node_gpu_cells_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::COMPUTE,
+                (
+                    // camera texture
+                    texture_2d(TextureSampleType::Float { filterable: false }),
+                    // cells texture
+                    texture_storage_2d(TextureFormat::Rgba8Unorm, StorageTextureAccess::WriteOnly),
+                    // settings
+                    uniform_buffer::<RatatuiCameraGpuCellsSettings>(true),
+                ),
+            ),
+        );
+
+        let path = Path::new("bevy_ratatui_camera").join("shaders/gpu_cells.wgsl");
+        let source = AssetSourceId::from("embedded");
+        let asset_path = AssetPath::from_path(&path).with_source(source);
+        let shader_handle: Handle<Shader> = world.load_asset(asset_path);
+
+        let pipeline_cache = world.resource_mut::<PipelineCache>();
+
+        let pipeline_id = pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
+            label: Some("ratatui_camera_node_gpu_cells_pipeline".into()),
+            layout: vec![layout.clone()],
+            push_constant_ranges: vec![],
+            shader: shader_handle,
+            shader_defs: vec![],
+            entry_point: Some("convert".into()),
+            zero_initialize_workgroup_memory: true,
+        });
+
+        Self {
+            layout,
+            pipeline_id,
+        }
+    }
+}
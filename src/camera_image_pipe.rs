@@ -1,6 +1,17 @@
+use std::{
+    ops::Deref,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU8, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
 use bevy::{
     asset::RenderAssetUsages,
     image::TextureFormatPixelInfo,
+    log::info_span,
+    platform::{collections::HashMap, time::Instant},
     prelude::*,
     render::{
         render_resource::{
@@ -11,62 +22,324 @@ use bevy::{
     },
 };
 use crossbeam_channel::{Receiver, Sender};
+use image::DynamicImage;
 
 #[derive(Clone, Debug)]
 pub struct ImageSender {
-    pub sender: Sender<Vec<u8>>,
+    pub sender: Sender<ReadbackFrame>,
     pub sender_image: Handle<Image>,
-    pub buffer: Buffer,
+    /// Dimensions of the sender image, for checking whether the pipe needs to be replaced.
+    dimensions: UVec2,
+    /// Staging buffers that rendered textures are copied into, used in turn so that a buffer can
+    /// be copied into while earlier frames are still being read back from the GPU.
+    buffers: Arc<StagingBuffers>,
+    /// Index of the staging buffer to copy the next frame into, which is also the oldest frame
+    /// still being read back if that buffer is not free.
+    next_buffer: Arc<AtomicUsize>,
+}
+
+/// Staging buffers are free, then hold a copied frame, then are being mapped, then are mapped and
+/// ready to be read, after which they are free again.
+const BUFFER_FREE: u8 = 0;
+const BUFFER_COPIED: u8 = 1;
+const BUFFER_MAPPING: u8 = 2;
+const BUFFER_MAPPED: u8 = 3;
+
+/// Maximum number of released staging buffers of each size kept for reuse.
+const MAX_POOLED_BUFFERS: usize = 8;
+
+#[derive(Clone, Debug)]
+struct StagingBuffer {
+    buffer: Buffer,
+    /// Number of bytes of the buffer used by each frame, as buffers are allocated in power of two
+    /// sizes so that they can be reused for images of other sizes.
+    size: u64,
+    state: Arc<AtomicU8>,
+    /// When the buffer's most recent frame started being mapped, and how long mapping took.
+    mapping_times: Arc<Mutex<MappingTimes>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MappingTimes {
+    started: Instant,
+    duration: Duration,
+}
+
+impl Default for MappingTimes {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            duration: Duration::ZERO,
+        }
+    }
+}
+
+/// A frame read back from the GPU, along with when it was rendered and how long it took to read
+/// back.
+#[derive(Debug)]
+pub struct ReadbackFrame {
+    data: Vec<u8>,
+    rendered_at: Instant,
+    readback_time: Duration,
+}
+
+/// Measurements of the most recent frame received through an image pipe.
+#[derive(Clone, Copy, Debug)]
+pub struct ReadbackMeasurement {
+    /// Time between the frame's staging buffer starting to be mapped and it being ready to read.
+    pub time: Duration,
+
+    /// Number of bytes read back from the GPU for the frame.
+    pub bytes: usize,
+}
+
+impl StagingBuffer {
+    /// Prepare a released buffer for reuse, returning `false` if it is still being mapped.
+    fn reset(&self) -> bool {
+        match self.state.load(Ordering::Acquire) {
+            BUFFER_MAPPING => false,
+            BUFFER_MAPPED => {
+                self.buffer.unmap();
+                self.state.store(BUFFER_FREE, Ordering::Release);
+                true
+            }
+            _ => {
+                self.state.store(BUFFER_FREE, Ordering::Release);
+                true
+            }
+        }
+    }
+}
+
+type PooledBuffers = Arc<Mutex<HashMap<u64, Vec<StagingBuffer>>>>;
+
+/// The staging buffers of a pipe, which are returned to the pool once every copy of the pipe's
+/// sender (in both the main and render worlds) has been dropped.
+#[derive(Debug)]
+struct StagingBuffers {
+    buffers: Box<[StagingBuffer]>,
+    pool: PooledBuffers,
+}
+
+impl Deref for StagingBuffers {
+    type Target = [StagingBuffer];
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffers
+    }
+}
+
+impl Drop for StagingBuffers {
+    fn drop(&mut self) {
+        let Ok(mut pool) = self.pool.lock() else {
+            return;
+        };
+
+        for staging_buffer in self.buffers.iter() {
+            let pooled = pool.entry(staging_buffer.buffer.size()).or_default();
+            if pooled.len() < MAX_POOLED_BUFFERS {
+                pooled.push(staging_buffer.clone());
+            }
+        }
+    }
+}
+
+/// Allocates the staging buffers that image pipes copy rendered textures into. Buffers of pipes
+/// that have been replaced (e.g. while the terminal is being resized) are kept and reused by new
+/// pipes, including buffers still being read back when their pipe was replaced, rather than
+/// allocating new buffers for every replacement. Buffers are allocated in power of two sizes, so
+/// a buffer can be reused for any image needing more than half of it.
+#[derive(Resource, Clone)]
+pub struct ImageBufferPool {
+    render_device: RenderDevice,
+    buffers: PooledBuffers,
+}
+
+impl FromWorld for ImageBufferPool {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            render_device: world.resource::<RenderDevice>().clone(),
+            buffers: default(),
+        }
+    }
+}
+
+impl ImageBufferPool {
+    /// Take a released buffer that fits the provided size from the pool, or allocate a new one.
+    fn acquire(&self, size: u64) -> StagingBuffer {
+        let capacity = size.next_power_of_two();
+
+        let pooled = self.buffers.lock().ok().and_then(|mut pool| {
+            let pooled = pool.get_mut(&capacity)?;
+            let index = pooled.iter().position(StagingBuffer::reset)?;
+            Some(pooled.swap_remove(index))
+        });
+
+        match pooled {
+            Some(staging_buffer) => StagingBuffer {
+                size,
+                ..staging_buffer
+            },
+            None => StagingBuffer {
+                buffer: create_image_copy_buffer(&self.render_device, capacity),
+                size,
+                state: Arc::new(AtomicU8::new(BUFFER_FREE)),
+                mapping_times: default(),
+            },
+        }
+    }
+}
+
+impl ImageSender {
+    /// Size of the image data in each staging buffer, in bytes.
+    pub fn buffer_size(&self) -> u64 {
+        self.buffers[0].size
+    }
+
+    /// Whether this pipe already sends images of the provided dimensions through the provided
+    /// number of staging buffers, in which case it does not need to be replaced.
+    pub fn matches(&self, dimensions: UVec2, buffer_count: u32) -> bool {
+        self.dimensions == dimensions && self.buffers.len() == buffer_count.max(1) as usize
+    }
+
+    /// Claim the next staging buffer for copying this frame's texture into, or `None` if every
+    /// staging buffer is still in use by earlier frames (in which case this frame is skipped).
+    pub fn acquire_buffer(&self) -> Option<&Buffer> {
+        let index = self.next_buffer.load(Ordering::Acquire);
+        let staging_buffer = &self.buffers[index];
+
+        staging_buffer
+            .state
+            .compare_exchange(
+                BUFFER_FREE,
+                BUFFER_COPIED,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .ok()?;
+
+        self.next_buffer
+            .store((index + 1) % self.buffers.len(), Ordering::Release);
+
+        Some(&staging_buffer.buffer)
+    }
 }
 
 #[derive(Debug)]
 pub struct ImageReceiver {
-    pub receiver: Receiver<Vec<u8>>,
-    pub receiver_image: Image,
+    pub receiver: Receiver<ReadbackFrame>,
+    /// Dimensions and format of the received images, without any pixel data.
+    receiver_image: Image,
+    /// The most recently received image, only replaced when a new frame arrives, and shared with
+    /// every widget built from it rather than copied.
+    pub image: Arc<DynamicImage>,
+    /// Measurements of the frame received by the most recent call to [receive_image], or `None`
+    /// if no new frame arrived.
+    pub measurement: Option<ReadbackMeasurement>,
+    /// Number of frames received through the pipe, so `0` while `image` is still the blank
+    /// image the pipe was created with.
+    pub frame_number: u64,
+    /// When the most recently received frame was rendered (or rather, when its readback began,
+    /// immediately after rendering), or `None` if no frame has been received.
+    pub rendered_at: Option<Instant>,
+}
+
+impl ImageReceiver {
+    /// Create a receiver that is never sent any frames, holding the provided image as if it was
+    /// the `frame_number`th frame received (used to inject images without a GPU).
+    #[cfg(any(test, feature = "test_utils"))]
+    pub(crate) fn from_image(image: DynamicImage, frame_number: u64) -> Self {
+        let (_, receiver) = crossbeam_channel::unbounded();
+
+        Self {
+            receiver,
+            receiver_image: Image::default(),
+            image: Arc::new(image),
+            measurement: None,
+            frame_number,
+            rendered_at: Some(Instant::now()),
+        }
+    }
 }
 
 pub fn create_image_pipe(
     images: &mut Assets<Image>,
-    render_device: &RenderDevice,
+    buffer_pool: &ImageBufferPool,
+    dimensions: UVec2,
+    buffer_count: u32,
+) -> Result<(ImageSender, ImageReceiver), String> {
+    create_image_pipe_with_format(
+        images,
+        buffer_pool,
+        dimensions,
+        TextureFormat::bevy_default(),
+        buffer_count,
+    )
+}
+
+pub fn create_image_pipe_with_format(
+    images: &mut Assets<Image>,
+    buffer_pool: &ImageBufferPool,
     dimensions: UVec2,
-) -> (ImageSender, ImageReceiver) {
-    let (sender, receiver, buffer, sender_image, receiver_image) =
-        create_image_copy_objects(render_device, images, dimensions);
+    format: TextureFormat,
+    buffer_count: u32,
+) -> Result<(ImageSender, ImageReceiver), String> {
+    let (sender, receiver, sender_image, mut receiver_image) =
+        create_image_copy_objects(images, dimensions, format);
+
+    let image = receiver_image
+        .clone()
+        .try_into_dynamic()
+        .map(Arc::new)
+        .map_err(|e| format!("failed to create image from receiver texture: {e}"))?;
+    receiver_image.data = None;
+
+    let buffer_size = calculate_buffer_size(dimensions.x, dimensions.y);
+    let buffers = StagingBuffers {
+        buffers: (0..buffer_count.max(1))
+            .map(|_| buffer_pool.acquire(buffer_size))
+            .collect(),
+        pool: buffer_pool.buffers.clone(),
+    };
 
     let camera_sender = ImageSender {
         sender,
         sender_image,
-        buffer,
+        dimensions,
+        buffers: Arc::new(buffers),
+        next_buffer: Arc::new(AtomicUsize::new(0)),
     };
 
     let camera_receiver = ImageReceiver {
         receiver,
         receiver_image,
+        image,
+        measurement: None,
+        frame_number: 0,
+        rendered_at: None,
     };
 
-    (camera_sender, camera_receiver)
+    Ok((camera_sender, camera_receiver))
 }
 
 fn create_image_copy_objects(
-    render_device: &RenderDevice,
     images: &mut Assets<Image>,
     dimensions: UVec2,
+    format: TextureFormat,
 ) -> (
-    Sender<Vec<u8>>,
-    Receiver<Vec<u8>>,
-    Buffer,
+    Sender<ReadbackFrame>,
+    Receiver<ReadbackFrame>,
     Handle<Image>,
     Image,
 ) {
     let (sender, receiver) = crossbeam_channel::unbounded();
-    let (sender_texture, receiver_texture) = create_image_copy_textures(dimensions);
-    let buffer = create_image_copy_buffer(render_device, dimensions);
+    let (sender_texture, receiver_texture) = create_image_copy_textures(dimensions, format);
     let sender_handle = images.add(sender_texture);
 
-    (sender, receiver, buffer, sender_handle, receiver_texture)
+    (sender, receiver, sender_handle, receiver_texture)
 }
 
-fn create_image_copy_textures(dimensions: UVec2) -> (Image, Image) {
+fn create_image_copy_textures(dimensions: UVec2, format: TextureFormat) -> (Image, Image) {
     let size = Extent3d {
         width: dimensions.x,
         height: dimensions.y,
@@ -77,7 +350,7 @@ fn create_image_copy_textures(dimensions: UVec2) -> (Image, Image) {
         size,
         TextureDimension::D2,
         &[0; 4],
-        TextureFormat::bevy_default(),
+        format,
         RenderAssetUsages::default(),
     );
 
@@ -89,10 +362,10 @@ fn create_image_copy_textures(dimensions: UVec2) -> (Image, Image) {
     (sender_texture, receiver_texture)
 }
 
-fn create_image_copy_buffer(render_device: &RenderDevice, dimensions: UVec2) -> Buffer {
+fn create_image_copy_buffer(render_device: &RenderDevice, size: u64) -> Buffer {
     let buffer_descriptor = BufferDescriptor {
         label: None,
-        size: calculate_buffer_size(dimensions.x, dimensions.y),
+        size,
         usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
         mapped_at_creation: false,
     };
@@ -105,56 +378,153 @@ pub fn calculate_buffer_size(width: u32, height: u32) -> u64 {
     padded_row_bytes as u64 * height as u64
 }
 
-pub fn send_image_buffer(render_device: &RenderDevice, buffer: &Buffer, sender: &Sender<Vec<u8>>) {
-    let buffer_slice = buffer.slice(..);
+/// Start mapping any staging buffers that frames were copied into, and send the contents of any
+/// that have finished mapping, oldest first. With a single staging buffer, this blocks until the
+/// frame has been read back. With more, it never blocks, and frames arrive up to one frame later
+/// per extra buffer, instead of stalling the render world on slow readbacks.
+pub fn send_image_buffer(render_device: &RenderDevice, image_sender: &ImageSender) {
+    let _span = info_span!("ratatui_camera_send_image_buffer").entered();
 
-    let (s, r) = crossbeam_channel::bounded(1);
+    for staging_buffer in image_sender.buffers.iter() {
+        if staging_buffer
+            .state
+            .compare_exchange(
+                BUFFER_COPIED,
+                BUFFER_MAPPING,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_err()
+        {
+            continue;
+        }
 
-    buffer_slice.map_async(MapMode::Read, move |r| match r {
-        Ok(r) => s.send(r).expect("failed to send map update"),
-        Err(err) => panic!("failed to map buffer: {err}"),
-    });
+        let state = staging_buffer.state.clone();
+        let mapping_times = staging_buffer.mapping_times.clone();
+        let started = Instant::now();
+        staging_buffer
+            .buffer
+            .slice(..staging_buffer.size)
+            .map_async(MapMode::Read, move |result| {
+                if let Ok(mut mapping_times) = mapping_times.lock() {
+                    *mapping_times = MappingTimes {
+                        started,
+                        duration: started.elapsed(),
+                    };
+                }
+
+                // mapping fails if the buffer is destroyed while mapping, in which case the frame
+                // is discarded.
+                let new_state = if result.is_ok() {
+                    BUFFER_MAPPED
+                } else {
+                    BUFFER_FREE
+                };
+                state.store(new_state, Ordering::Release);
+            });
+    }
 
-    let _ = render_device.poll(PollType::wait());
+    let poll_type = if image_sender.buffers.len() == 1 {
+        PollType::wait()
+    } else {
+        PollType::Poll
+    };
 
-    r.recv().expect("failed to receive the map_async message");
+    let _ = render_device.poll(poll_type);
 
-    let _ = sender.send(buffer_slice.get_mapped_range().to_vec());
+    // send finished frames in the order they were copied, stopping at the first unfinished one so
+    // that a newer frame is never followed by an older one.
+    let buffer_count = image_sender.buffers.len();
+    let oldest = image_sender.next_buffer.load(Ordering::Acquire);
+    for offset in 0..buffer_count {
+        let staging_buffer = &image_sender.buffers[(oldest + offset) % buffer_count];
 
-    buffer.unmap();
+        match staging_buffer.state.load(Ordering::Acquire) {
+            BUFFER_MAPPED => {
+                let buffer_slice = staging_buffer.buffer.slice(..staging_buffer.size);
+                let mapping_times = staging_buffer
+                    .mapping_times
+                    .lock()
+                    .map(|mapping_times| *mapping_times)
+                    .unwrap_or_default();
+                let _ = image_sender.sender.send(ReadbackFrame {
+                    data: buffer_slice.get_mapped_range().to_vec(),
+                    rendered_at: mapping_times.started,
+                    readback_time: mapping_times.duration,
+                });
+                staging_buffer.buffer.unmap();
+                staging_buffer.state.store(BUFFER_FREE, Ordering::Release);
+            }
+            BUFFER_FREE => continue,
+            _ => break,
+        }
+    }
 }
 
-pub fn receive_image(image_receiver: &mut ImageReceiver) {
-    let mut image_data = Vec::new();
-    while let Ok(data) = image_receiver.receiver.try_recv() {
-        image_data = data;
+/// Receive the latest frame sent through the pipe, replacing the receiver's image only if a new
+/// frame has arrived. Returns the reason if a frame arrived but could not be decoded, in which
+/// case the previous image is kept.
+pub fn receive_image(
+    image_receiver: &mut ImageReceiver,
+    wait: Option<Duration>,
+) -> Result<(), String> {
+    let _span = info_span!("ratatui_camera_receive_image").entered();
+
+    image_receiver.measurement = None;
+
+    let mut frame = None;
+    while let Ok(received) = image_receiver.receiver.try_recv() {
+        frame = Some(received);
     }
 
-    if !image_data.is_empty() {
-        let row_bytes = image_receiver.receiver_image.width() as usize
-            * image_receiver
-                .receiver_image
-                .texture_descriptor
-                .format
-                .pixel_size()
-                .expect("Image receiver received a compressed image.");
-
-        let aligned_row_bytes = RenderDevice::align_copy_bytes_per_row(row_bytes);
-
-        if row_bytes == aligned_row_bytes {
-            image_receiver
-                .receiver_image
-                .data
-                .clone_from(&Some(image_data));
-        } else {
-            image_receiver.receiver_image.data = Some(
-                image_data
-                    .chunks(aligned_row_bytes)
-                    .take(image_receiver.receiver_image.height() as usize)
-                    .flat_map(|row| &row[..row_bytes.min(row.len())])
-                    .cloned()
-                    .collect(),
-            );
-        }
+    if let (None, Some(wait)) = (&frame, wait) {
+        frame = image_receiver.receiver.recv_timeout(wait).ok();
     }
+
+    let Some(ReadbackFrame {
+        data: image_data,
+        rendered_at,
+        readback_time,
+    }) = frame
+    else {
+        return Ok(());
+    };
+
+    image_receiver.measurement = Some(ReadbackMeasurement {
+        time: readback_time,
+        bytes: image_data.len(),
+    });
+
+    let pixel_size = image_receiver
+        .receiver_image
+        .texture_descriptor
+        .format
+        .pixel_size()
+        .map_err(|_| "image receiver received a compressed image".to_string())?;
+    let row_bytes = image_receiver.receiver_image.width() as usize * pixel_size;
+
+    let aligned_row_bytes = RenderDevice::align_copy_bytes_per_row(row_bytes);
+
+    let mut image = image_receiver.receiver_image.clone();
+    image.data = if row_bytes == aligned_row_bytes {
+        Some(image_data)
+    } else {
+        Some(
+            image_data
+                .chunks(aligned_row_bytes)
+                .take(image.height() as usize)
+                .flat_map(|row| &row[..row_bytes.min(row.len())])
+                .cloned()
+                .collect(),
+        )
+    };
+
+    let image = image
+        .try_into_dynamic()
+        .map_err(|e| format!("failed to create image from buffer {e:?}"))?;
+    image_receiver.image = Arc::new(image);
+    image_receiver.frame_number += 1;
+    image_receiver.rendered_at = Some(rendered_at);
+
+    Ok(())
 }
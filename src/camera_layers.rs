@@ -0,0 +1,148 @@
+use bevy::{prelude::*, transform::TransformSystems};
+
+use crate::{
+    RatatuiCamera, RatatuiCameraLatency, RatatuiCameraLowLatencySet, RatatuiCameraPaused,
+    RatatuiCameraSet, RatatuiCameraWidget,
+};
+
+pub struct RatatuiCameraLayersPlugin;
+
+impl Plugin for RatatuiCameraLayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(ratatui_camera_layer_insert_observer)
+            .add_systems(
+                First,
+                (
+                    sync_ratatui_camera_layer_dimensions_system,
+                    composite_ratatui_camera_layers_system,
+                )
+                    .after(RatatuiCameraSet),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    sync_ratatui_camera_layer_views_system.before(TransformSystems::Propagate),
+                    composite_ratatui_camera_layers_system
+                        .after(RatatuiCameraLowLatencySet)
+                        .run_if(|latency: Res<RatatuiCameraLatency>| {
+                            *latency != RatatuiCameraLatency::Standard
+                        }),
+                ),
+            );
+    }
+}
+
+/// Bevy relation that allows you to draw parts of a scene with a different strategy (e.g.
+/// a different character set) than the rest, composited into a single widget. A camera entity
+/// with a `RatatuiCameraLayer` renders to its own image like a `RatatuiCamera` (its dimensions
+/// are kept in sync with the main camera's), and when the main camera's widget is drawn, the
+/// layer's image is converted using the layer camera's own `RatatuiCameraStrategy` and drawn over
+/// the main camera's output, skipping transparent pixels.
+///
+/// Use bevy's `RenderLayers` to choose which entities each camera renders. Layer cameras are
+/// cleared to transparent automatically, and their transform and projection are kept in sync with
+/// the main camera's. To have objects in
+/// the main camera occlude objects in a layer, add
+/// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection) to every camera and render
+/// the main widget with a depth buffer.
+///
+/// Example:
+///
+/// The following draws entities in render layer 1 (e.g. water) using '~' characters, and all
+/// other entities using the main camera's default strategy.
+///
+/// ```no_run
+/// # use bevy::{camera::visibility::RenderLayers, prelude::*};
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraLayers, RatatuiCameraStrategy};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     Camera3d::default(),
+///     RenderLayers::layer(0),
+///     related!(RatatuiCameraLayers[(
+///         Camera3d::default(),
+///         Camera { order: 1, ..default() },
+///         RenderLayers::layer(1),
+///         RatatuiCameraStrategy::luminance_with_characters(&[' ', '~']),
+///     )]),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Debug)]
+#[relationship(relationship_target = RatatuiCameraLayers)]
+#[require(RatatuiCamera = RatatuiCamera::new(1, 1))]
+pub struct RatatuiCameraLayer(pub Entity);
+
+/// Bevy relation target for layer cameras that are composited into this camera entity's widget.
+#[derive(Component, Debug)]
+#[relationship_target(relationship = RatatuiCameraLayer)]
+pub struct RatatuiCameraLayers(Vec<Entity>);
+
+fn ratatui_camera_layer_insert_observer(
+    insert: On<Insert, RatatuiCameraLayer>,
+    mut cameras: Query<&mut Camera>,
+) {
+    if let Ok(mut camera) = cameras.get_mut(insert.entity) {
+        camera.clear_color = ClearColorConfig::Custom(Color::NONE);
+    }
+}
+
+fn sync_ratatui_camera_layer_dimensions_system(
+    main_cameras: Query<(&RatatuiCamera, &RatatuiCameraLayers), Without<RatatuiCameraLayer>>,
+    mut layer_cameras: Query<&mut RatatuiCamera, With<RatatuiCameraLayer>>,
+) {
+    for (main_camera, layers) in &main_cameras {
+        for layer in layers.iter() {
+            let Ok(mut layer_camera) = layer_cameras.get_mut(layer) else {
+                continue;
+            };
+
+            if layer_camera.dimensions != main_camera.dimensions || layer_camera.autoresize {
+                layer_camera.autoresize = false;
+                layer_camera.dimensions = main_camera.dimensions;
+            }
+        }
+    }
+}
+
+fn composite_ratatui_camera_layers_system(
+    mut main_widgets: Query<
+        (&mut RatatuiCameraWidget, &RatatuiCameraLayers),
+        (Without<RatatuiCameraLayer>, Without<RatatuiCameraPaused>),
+    >,
+    layer_widgets: Query<&RatatuiCameraWidget, With<RatatuiCameraLayer>>,
+) {
+    for (mut widget, layers) in &mut main_widgets {
+        widget.layers = layers
+            .iter()
+            .filter_map(|layer| layer_widgets.get(layer).ok())
+            .cloned()
+            .collect();
+    }
+}
+
+fn sync_ratatui_camera_layer_views_system(
+    main_cameras: Query<
+        (&RatatuiCameraLayers, &Transform, Ref<Projection>),
+        Without<RatatuiCameraLayer>,
+    >,
+    mut layer_cameras: Query<(Ref<RatatuiCameraLayer>, &mut Transform, &mut Projection)>,
+) {
+    for (layers, main_transform, main_projection) in &main_cameras {
+        for layer in layers.iter() {
+            let Ok((layer_relation, mut layer_transform, mut layer_projection)) =
+                layer_cameras.get_mut(layer)
+            else {
+                continue;
+            };
+
+            layer_transform.set_if_neq(*main_transform);
+
+            if main_projection.is_changed() || layer_relation.is_added() {
+                *layer_projection = main_projection.clone();
+            }
+        }
+    }
+}
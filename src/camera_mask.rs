@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+/// When inserted into a mesh entity, the mesh's visible pixels are recorded with this mask value
+/// by cameras with [RatatuiCameraMaskDetection](crate::RatatuiCameraMaskDetection), and can be
+/// read back per terminal cell with
+/// [RatatuiCameraWidget::mask_at_cell](crate::RatatuiCameraWidget::mask_at_cell). Pixels not
+/// covered by a masked mesh have a mask value of `0`, so use non-zero values (e.g. an id per kind
+/// of object, or a set of bit flags).
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiCameraMask;
+/// #
+/// # fn setup_scene_system(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+/// const WATER: u8 = 1;
+///
+/// commands.spawn((
+///     Mesh3d(meshes.add(Plane3d::default())),
+///     RatatuiCameraMask(WATER),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RatatuiCameraMask(pub u8);
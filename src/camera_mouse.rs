@@ -0,0 +1,275 @@
+use bevy::prelude::*;
+use bevy_ratatui::event::MouseMessage;
+use crossterm::event::{MouseButton as TerminalMouseButton, MouseEventKind};
+
+use crate::{RatatuiCameraLastArea, RatatuiCameraWidget};
+
+/// Message written when the mouse moves (or is dragged) over the area a RatatuiCamera's widget
+/// was last drawn in, excluding the gutters around the image. Requires the `mouse` feature, and
+/// bevy_ratatui's mouse capture (`RatatuiPlugins { enable_mouse_capture: true, .. }`).
+///
+/// Cameras drawn in overlapping areas (e.g. a minimap over a main view) each get a message.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiCameraMouseMoved;
+/// #
+/// fn mouse_follow_system(
+///     mut mouse_moved: MessageReader<RatatuiCameraMouseMoved>,
+///     mut cursor: Single<&mut Transform, With<Mesh3d>>,
+/// ) {
+///     let Some(message) = mouse_moved.read().last() else {
+///         return;
+///     };
+///
+///     if let Some(distance) = message
+///         .world_ray
+///         .intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y))
+///     {
+///         cursor.translation = message.world_ray.get_point(distance);
+///     }
+/// }
+/// ```
+///
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraMouseMoved {
+    /// The RatatuiCamera entity.
+    pub entity: Entity,
+
+    /// The terminal buffer cell under the mouse (number of characters from the left edge and top
+    /// edge of the buffer, respectively).
+    pub cell: IVec2,
+
+    /// The cell converted to NDC (see [RatatuiCameraWidget::cell_to_ndc]).
+    pub ndc: Vec3,
+
+    /// Ray cast from the camera through the center of the cell (see
+    /// [RatatuiCameraWidget::cell_to_world_ray]).
+    pub world_ray: Ray3d,
+}
+
+/// Message written when a mouse button is pressed over the area a RatatuiCamera's widget was last
+/// drawn in, excluding the gutters around the image. See [RatatuiCameraMouseMoved].
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraMouseClicked {
+    /// The RatatuiCamera entity.
+    pub entity: Entity,
+
+    /// The mouse button that was pressed.
+    pub button: MouseButton,
+
+    /// The terminal buffer cell under the mouse (number of characters from the left edge and top
+    /// edge of the buffer, respectively).
+    pub cell: IVec2,
+
+    /// The cell converted to NDC (see [RatatuiCameraWidget::cell_to_ndc]).
+    pub ndc: Vec3,
+
+    /// Ray cast from the camera through the center of the cell (see
+    /// [RatatuiCameraWidget::cell_to_world_ray]).
+    pub world_ray: Ray3d,
+}
+
+/// Message written alongside [RatatuiCameraMouseMoved] and [RatatuiCameraMouseClicked] when the
+/// mouse is over rendered geometry, holding the world-space position of the surface under the
+/// cursor's cell (see [RatatuiCameraWidget::cell_to_world]) rather than just a ray, for selecting
+/// objects in the scene. Only written for cameras with
+/// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection), and not where nothing was
+/// rendered.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraMouseHit {
+    /// The RatatuiCamera entity.
+    pub entity: Entity,
+
+    /// The mouse button that was pressed, or `None` if the mouse moved.
+    pub button: Option<MouseButton>,
+
+    /// The terminal buffer cell under the mouse (number of characters from the left edge and top
+    /// edge of the buffer, respectively).
+    pub cell: IVec2,
+
+    /// World-space position of the rendered surface under the center of the cell.
+    pub position: Vec3,
+
+    /// The [RatatuiCameraMask](crate::RatatuiCameraMask) value of the mesh under the cell (see
+    /// [RatatuiCameraWidget::mask_at_cell]), if the camera has
+    /// [RatatuiCameraMaskDetection](crate::RatatuiCameraMaskDetection).
+    pub mask: Option<u8>,
+}
+
+pub struct RatatuiCameraMousePlugin;
+
+impl Plugin for RatatuiCameraMousePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<MouseMessage>()
+            .add_message::<RatatuiCameraMouseMoved>()
+            .add_message::<RatatuiCameraMouseClicked>()
+            .add_message::<RatatuiCameraMouseHit>()
+            .add_systems(PreUpdate, camera_mouse_system);
+    }
+}
+
+fn camera_mouse_system(
+    mut mouse_messages: MessageReader<MouseMessage>,
+    ratatui_cameras: Query<(
+        Entity,
+        &Camera,
+        &GlobalTransform,
+        &RatatuiCameraWidget,
+        &RatatuiCameraLastArea,
+    )>,
+    mut mouse_moved: MessageWriter<RatatuiCameraMouseMoved>,
+    mut mouse_clicked: MessageWriter<RatatuiCameraMouseClicked>,
+    mut mouse_hit: MessageWriter<RatatuiCameraMouseHit>,
+) {
+    for message in mouse_messages.read() {
+        let button = match message.kind {
+            MouseEventKind::Moved | MouseEventKind::Drag(_) => None,
+            MouseEventKind::Down(button) => Some(match button {
+                TerminalMouseButton::Left => MouseButton::Left,
+                TerminalMouseButton::Right => MouseButton::Right,
+                TerminalMouseButton::Middle => MouseButton::Middle,
+            }),
+            _ => continue,
+        };
+
+        let cell = IVec2::new(message.column as i32, message.row as i32);
+
+        for (entity, camera, camera_transform, widget, last_area) in &ratatui_cameras {
+            if !widget.drawn_area_contains(**last_area, (message.column, message.row).into()) {
+                continue;
+            }
+
+            let Some(world_ray) =
+                widget.cell_to_world_ray(**last_area, cell, camera, camera_transform)
+            else {
+                continue;
+            };
+
+            let ndc = widget.cell_to_ndc(**last_area, cell);
+
+            if let Some(position) =
+                widget.cell_to_world(**last_area, cell, camera, camera_transform)
+            {
+                mouse_hit.write(RatatuiCameraMouseHit {
+                    entity,
+                    button,
+                    cell,
+                    position,
+                    mask: widget.mask_at_cell(**last_area, cell),
+                });
+            }
+
+            match button {
+                None => {
+                    mouse_moved.write(RatatuiCameraMouseMoved {
+                        entity,
+                        cell,
+                        ndc,
+                        world_ray,
+                    });
+                }
+                Some(button) => {
+                    mouse_clicked.write(RatatuiCameraMouseClicked {
+                        entity,
+                        button,
+                        cell,
+                        ndc,
+                        world_ray,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_ratatui::event::MouseMessage;
+    use crossterm::event::{KeyModifiers, MouseEvent, MouseEventKind};
+    use image::{DynamicImage, Rgba, RgbaImage};
+    use ratatui::{buffer::Buffer, layout::Rect};
+
+    use super::{RatatuiCameraMouseHit, RatatuiCameraMousePlugin};
+    use crate::test_utils::{RatatuiCameraTestAppBuilder, RatatuiCameraTestImages};
+    use crate::{
+        FillMode, ImageAlignment, RatatuiCamera, RatatuiCameraRenderOptions, RatatuiCameraWidget,
+    };
+
+    /// Depth image with nothing rendered in its top half, and a surface in its bottom half.
+    fn half_rendered_depth() -> DynamicImage {
+        let depth = RgbaImage::from_fn(8, 8, |_, y| {
+            let depth: f32 = if y < 4 { 0. } else { 0.5 };
+            Rgba(depth.to_le_bytes())
+        });
+
+        DynamicImage::ImageRgba8(depth)
+    }
+
+    /// Draw a camera in a wide area with the provided options, move the mouse over the cell, and
+    /// return the hit messages written.
+    fn hits_over_cell(
+        options: RatatuiCameraRenderOptions,
+        cell: UVec2,
+    ) -> Vec<RatatuiCameraMouseHit> {
+        let mut app = RatatuiCameraTestAppBuilder::new().build();
+        app.add_plugins(RatatuiCameraMousePlugin);
+
+        let mut camera = Camera::default();
+        camera.computed.clip_from_view = Mat4::perspective_infinite_reverse_rh(1., 1., 0.1);
+        let image = RgbaImage::from_pixel(8, 8, Rgba([255, 255, 255, 255]));
+        let entity = app
+            .world_mut()
+            .spawn((
+                camera,
+                RatatuiCamera::new(8, 8),
+                RatatuiCameraTestImages::new(DynamicImage::ImageRgba8(image))
+                    .with_depth(half_rendered_depth()),
+            ))
+            .id();
+        app.update();
+
+        // the area and options are kept for the next frame's widget, which the mouse is mapped
+        // through.
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        app.world_mut()
+            .get_mut::<RatatuiCameraWidget>(entity)
+            .unwrap()
+            .render_with_options(area, &mut buf, None, &options);
+        app.update();
+
+        app.world_mut().write_message(MouseMessage(MouseEvent {
+            kind: MouseEventKind::Moved,
+            column: cell.x as u16,
+            row: cell.y as u16,
+            modifiers: KeyModifiers::NONE,
+        }));
+        app.update();
+
+        app.world()
+            .resource::<Messages<RatatuiCameraMouseHit>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn cropped_cameras_hit_the_part_of_the_image_under_the_mouse() {
+        let crop = |alignment| RatatuiCameraRenderOptions {
+            fill_mode: FillMode::Crop,
+            alignment,
+        };
+
+        // cropped to the top of the image, where nothing was rendered.
+        assert!(hits_over_cell(crop(ImageAlignment::Top), UVec2::new(20, 3)).is_empty());
+
+        // cropped to the bottom of the image, which covers the entire area, gutters included.
+        let hits = hits_over_cell(crop(ImageAlignment::Bottom), UVec2::new(2, 0));
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].cell, IVec2::new(2, 0));
+    }
+}
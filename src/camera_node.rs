@@ -1,8 +1,14 @@
+use std::{
+    any::TypeId,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+#[cfg(feature = "core_2d")]
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+#[cfg(feature = "core_3d")]
+use bevy::core_pipeline::core_3d::graph::{Core3d, Node3d};
 use bevy::{
-    core_pipeline::{
-        core_2d::graph::{Core2d, Node2d},
-        core_3d::graph::{Core3d, Node3d},
-    },
+    app::SubApp,
     ecs::query::QueryItem,
     prelude::*,
     render::{
@@ -12,8 +18,8 @@ use bevy::{
             NodeRunError, RenderGraphContext, RenderGraphExt, RenderLabel, ViewNode, ViewNodeRunner,
         },
         render_resource::{
-            Buffer, CommandEncoderDescriptor, Extent3d, TexelCopyBufferInfo, TexelCopyBufferLayout,
-            Texture,
+            CommandEncoderDescriptor, Extent3d, TexelCopyBufferInfo, TexelCopyBufferLayout,
+            Texture, TextureFormat,
         },
         renderer::{RenderContext, RenderDevice, RenderQueue},
         texture::GpuImage,
@@ -22,73 +28,178 @@ use bevy::{
 };
 
 use crate::{
-    camera_image_pipe::calculate_buffer_size,
-    camera_readback::{RatatuiCameraSender, RatatuiDepthSender, RatatuiSobelSender},
+    camera::RatatuiCameraDepthDownsampling,
+    camera_image_pipe::{ImageSender, calculate_buffer_size},
+    camera_readback::{
+        RatatuiCameraSender, RatatuiDepthSender, RatatuiEdgeColorSender, RatatuiEmissiveSender,
+        RatatuiMaskSender, RatatuiNormalSender, RatatuiSobelSender,
+    },
 };
 
 pub struct RatatuiCameraNodePlugin;
 
 impl Plugin for RatatuiCameraNodePlugin {
     fn build(&self, app: &mut App) {
-        let render_app = app.sub_app_mut(RenderApp);
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::Readback);
+        add_readback_nodes(app.sub_app_mut(RenderApp), label);
+    }
+}
 
+fn add_readback_nodes(render_app: &mut SubApp, label: RatatuiCameraNodeLabel) {
+    #[cfg(feature = "core_3d")]
+    {
         render_app
-            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNode>>(Core3d, RatatuiCameraLabel);
-        render_app.add_render_graph_edge(Core3d, Node3d::Upscaling, RatatuiCameraLabel);
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeReadback>>(Core3d, label);
+        render_app.add_render_graph_edge(Core3d, Node3d::Upscaling, label);
+    }
 
+    #[cfg(feature = "core_2d")]
+    {
         render_app
-            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNode>>(Core2d, RatatuiCameraLabel);
-        render_app.add_render_graph_edge(Core2d, Node2d::Upscaling, RatatuiCameraLabel);
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeReadback>>(Core2d, label);
+        render_app.add_render_graph_edge(Core2d, Node2d::Upscaling, label);
     }
 }
 
-#[derive(Default)]
-pub struct RatatuiCameraNode;
+/// Identifies the [RatatuiCameraPlugin](crate::RatatuiCameraPlugin) instance an app's render
+/// graph nodes were added by, available as a resource once the plugin is added. Every render
+/// graph node added by this crate is labelled with a [RatatuiCameraNodeLabel] namespaced by the
+/// instance, so nodes added by separate instances (e.g. in separate apps sharing a render graph
+/// setup) never collide with each other, or with nodes added by your application.
+#[derive(Resource, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RatatuiCameraInstance(u32);
+
+impl RatatuiCameraInstance {
+    /// The instance of the app's RatatuiCameraPlugin, allocating a new one if the app does not
+    /// have one yet.
+    pub fn of(app: &mut App) -> Self {
+        *app.world_mut().get_resource_or_insert_with(Self::next)
+    }
+
+    /// The label of one of the instance's render graph nodes, e.g. for ordering your own nodes
+    /// relative to it.
+    pub fn label(self, kind: RatatuiCameraNodeKind) -> RatatuiCameraNodeLabel {
+        RatatuiCameraNodeLabel {
+            instance: self,
+            kind,
+        }
+    }
+
+    fn next() -> Self {
+        static NEXT_INSTANCE: AtomicU32 = AtomicU32::new(0);
+        Self(NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Label for a render graph node added by this crate: which node it is, namespaced by the
+/// [RatatuiCameraInstance] that added it. Get one with [RatatuiCameraInstance::label].
+#[derive(RenderLabel, Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RatatuiCameraNodeLabel {
+    /// The plugin instance that added the node.
+    pub instance: RatatuiCameraInstance,
+
+    /// Which of the instance's nodes this is.
+    pub kind: RatatuiCameraNodeKind,
+}
+
+/// Which of a [RatatuiCameraInstance]'s render graph nodes a [RatatuiCameraNodeLabel] refers to.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RatatuiCameraNodeKind {
+    /// Copies the rendered textures into buffers to be read back from the GPU. Runs after
+    /// upscaling in both the 2d and 3d render graphs.
+    Readback,
 
-#[derive(RenderLabel, Clone, Debug, Eq, Hash, PartialEq)]
-pub struct RatatuiCameraLabel;
+    /// Performs edge detection for cameras with edge detection enabled. Runs after the end of the
+    /// main pass in the 3d render graph.
+    Sobel,
 
-impl ViewNode for RatatuiCameraNode {
+    /// Downsamples the depth texture to two pixels per terminal cell for cameras with depth
+    /// downsampling enabled. Runs after the end of the main pass in the 3d render graph.
+    DepthDownsample,
+
+    /// Copies the normal prepass for cameras with normal detection enabled. Runs after the end of
+    /// the main pass in the 3d render graph.
+    Normal,
+
+    /// Records the brightness of the HDR main texture for cameras with emissive detection enabled.
+    /// Runs after the end of the main pass (and after bloom, if present), before tonemapping, in
+    /// the 3d render graph.
+    Emissive,
+
+    /// Draws meshes with an edge color or mask for cameras with edge color or mask detection
+    /// enabled. Runs after the main opaque pass in the 3d render graph.
+    EdgeColor,
+
+    /// Chooses each cell's character and color in a compute shader for cameras with
+    /// [RatatuiCameraGpuCells](crate::RatatuiCameraGpuCells). Runs after upscaling in both the 2d
+    /// and 3d render graphs. Requires the `gpu_cells` feature.
+    #[cfg(feature = "gpu_cells")]
+    GpuCells,
+
+    /// Runs the fragment shader of the
+    /// [RatatuiCameraPostProcess](crate::RatatuiCameraPostProcess) component with this type id,
+    /// for cameras with that component. Runs after tonemapping (and after edge detection, if
+    /// present) in both the 2d and 3d render graphs.
+    PostProcess(TypeId),
+}
+
+#[derive(Default)]
+pub struct RatatuiCameraNodeReadback;
+
+impl ViewNode for RatatuiCameraNodeReadback {
     type ViewQuery = (
         &'static ViewDepthTexture,
         &'static RatatuiCameraSender,
         Option<&'static RatatuiDepthSender>,
+        Has<RatatuiCameraDepthDownsampling>,
         Option<&'static RatatuiSobelSender>,
+        Option<&'static RatatuiNormalSender>,
+        Option<&'static RatatuiEmissiveSender>,
+        Option<&'static RatatuiEdgeColorSender>,
+        Option<&'static RatatuiMaskSender>,
     );
 
     fn run<'w>(
         &self,
         _graph: &mut RenderGraphContext,
         render_context: &mut RenderContext<'w>,
-        (depth_texture, camera_sender, depth_sender, sobel_sender): QueryItem<
-            'w,
-            '_,
-            Self::ViewQuery,
-        >,
+        (
+            depth_texture,
+            camera_sender,
+            depth_sender,
+            depth_downsampling,
+            sobel_sender,
+            normal_sender,
+            emissive_sender,
+            edge_color_sender,
+            mask_sender,
+        ): QueryItem<'w, '_, Self::ViewQuery>,
         world: &'w World,
     ) -> Result<(), NodeRunError> {
         let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
 
         let src_image = gpu_images.get(&camera_sender.sender_image).unwrap();
-        copy_texture_to_buffer(
-            render_context,
-            world,
-            &src_image.texture,
-            &camera_sender.buffer,
-        );
-
-        if let Some(depth_sender) = depth_sender {
+        copy_texture_to_buffer(render_context, world, &src_image.texture, camera_sender);
+
+        if let Some(depth_sender) = depth_sender
+            && depth_downsampling
+            && let Some(src_image_depth) = gpu_images.get(&depth_sender.sender_image)
+            && src_image_depth.texture_format == TextureFormat::Rgba8Unorm
+        {
+            // already downsampled into the sender image by the depth downsampling node.
+            copy_texture_to_buffer(
+                render_context,
+                world,
+                &src_image_depth.texture,
+                depth_sender,
+            );
+        } else if let Some(depth_sender) = depth_sender {
             let expected_buffer_size = calculate_buffer_size(
                 depth_texture.texture.width(),
                 depth_texture.texture.height(),
             );
-            if expected_buffer_size == depth_sender.buffer.size() {
-                copy_texture_to_buffer(
-                    render_context,
-                    world,
-                    &depth_texture.texture,
-                    &depth_sender.buffer,
-                );
+            if expected_buffer_size == depth_sender.buffer_size() {
+                copy_texture_to_buffer(render_context, world, &depth_texture.texture, depth_sender);
             }
         }
 
@@ -98,20 +209,61 @@ impl ViewNode for RatatuiCameraNode {
                 render_context,
                 world,
                 &src_image_sobel.texture,
-                &sobel_sender.buffer,
+                sobel_sender,
+            );
+        }
+
+        if let Some(normal_sender) = normal_sender {
+            let src_image_normal = gpu_images.get(&normal_sender.sender_image).unwrap();
+            copy_texture_to_buffer(
+                render_context,
+                world,
+                &src_image_normal.texture,
+                normal_sender,
             );
         }
 
+        if let Some(emissive_sender) = emissive_sender {
+            let src_image_emissive = gpu_images.get(&emissive_sender.sender_image).unwrap();
+            copy_texture_to_buffer(
+                render_context,
+                world,
+                &src_image_emissive.texture,
+                emissive_sender,
+            );
+        }
+
+        if let Some(edge_color_sender) = edge_color_sender {
+            let src_image_edge_color = gpu_images.get(&edge_color_sender.sender_image).unwrap();
+            copy_texture_to_buffer(
+                render_context,
+                world,
+                &src_image_edge_color.texture,
+                edge_color_sender,
+            );
+        }
+
+        if let Some(mask_sender) = mask_sender {
+            let src_image_mask = gpu_images.get(&mask_sender.sender_image).unwrap();
+            copy_texture_to_buffer(render_context, world, &src_image_mask.texture, mask_sender);
+        }
+
         Ok(())
     }
 }
 
-fn copy_texture_to_buffer(
+/// Copy the texture into the sender's next free staging buffer, skipping the copy if all of its
+/// staging buffers are still in use by earlier frames.
+pub(crate) fn copy_texture_to_buffer(
     render_context: &mut RenderContext,
     world: &World,
     src_texture: &Texture,
-    buffer: &Buffer,
+    image_sender: &ImageSender,
 ) {
+    let Some(buffer) = image_sender.acquire_buffer() else {
+        return;
+    };
+
     let mut encoder = render_context
         .render_device()
         .create_command_encoder(&CommandEncoderDescriptor::default());
@@ -149,3 +301,78 @@ fn copy_texture_to_buffer(
     let render_queue = world.get_resource::<RenderQueue>().unwrap();
     render_queue.submit(std::iter::once(encoder.finish()));
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::render::render_graph::{EmptyNode, RenderGraph};
+
+    use super::*;
+
+    fn render_app_with_core_graphs() -> SubApp {
+        let mut graph = RenderGraph::default();
+
+        #[cfg(feature = "core_3d")]
+        {
+            let mut core_3d = RenderGraph::default();
+            core_3d.add_node(Node3d::Upscaling, EmptyNode);
+            graph.add_sub_graph(Core3d, core_3d);
+        }
+
+        #[cfg(feature = "core_2d")]
+        {
+            let mut core_2d = RenderGraph::default();
+            core_2d.add_node(Node2d::Upscaling, EmptyNode);
+            graph.add_sub_graph(Core2d, core_2d);
+        }
+
+        let mut render_app = SubApp::new();
+        render_app.insert_resource(graph);
+        render_app
+    }
+
+    #[test]
+    fn instances_are_allocated_once_per_app() {
+        let mut first_app = App::new();
+        let mut second_app = App::new();
+
+        let first = RatatuiCameraInstance::of(&mut first_app);
+        let second = RatatuiCameraInstance::of(&mut second_app);
+
+        assert_ne!(first, second);
+        assert_eq!(first, RatatuiCameraInstance::of(&mut first_app));
+        assert_eq!(second, RatatuiCameraInstance::of(&mut second_app));
+    }
+
+    #[test]
+    fn readback_nodes_of_two_instances_coexist() {
+        let mut render_app = render_app_with_core_graphs();
+
+        let first = RatatuiCameraInstance::next().label(RatatuiCameraNodeKind::Readback);
+        let second = RatatuiCameraInstance::next().label(RatatuiCameraNodeKind::Readback);
+        assert_ne!(first, second);
+
+        add_readback_nodes(&mut render_app, first);
+        add_readback_nodes(&mut render_app, second);
+
+        let graph = render_app.world().resource::<RenderGraph>();
+        for label in [first, second] {
+            #[cfg(feature = "core_3d")]
+            assert!(
+                graph
+                    .get_sub_graph(Core3d)
+                    .unwrap()
+                    .get_node_state(label)
+                    .is_ok()
+            );
+
+            #[cfg(feature = "core_2d")]
+            assert!(
+                graph
+                    .get_sub_graph(Core2d)
+                    .unwrap()
+                    .get_node_state(label)
+                    .is_ok()
+            );
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::path::Path;
+
+use bevy::{
+    asset::{AssetPath, embedded_asset, io::AssetSourceId},
+    core_pipeline::{
+        FullscreenShader,
+        core_3d::{
+            DEPTH_TEXTURE_SAMPLING_SUPPORTED,
+            graph::{Core3d, Node3d},
+        },
+    },
+    ecs::query::QueryItem,
+    prelude::*,
+    render::{
+        RenderApp,
+        extract_component::ExtractComponentPlugin,
+        render_asset::RenderAssets,
+        render_graph::{
+            NodeRunError, RenderGraphContext, RenderGraphExt, ViewNode, ViewNodeRunner,
+        },
+        render_resource::{
+            BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedPipelineState,
+            CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState, MultisampleState,
+            Operations, PipelineCache, PrimitiveState, RenderPassColorAttachment,
+            RenderPassDescriptor, RenderPipelineDescriptor, ShaderStages, TextureFormat,
+            binding_types::texture_depth_2d,
+        },
+        renderer::{RenderContext, RenderDevice},
+        texture::GpuImage,
+        view::ViewDepthTexture,
+    },
+};
+
+use crate::{
+    camera::RatatuiCameraDepthDownsampling,
+    camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind},
+    camera_readback::RatatuiDepthSender,
+};
+
+pub struct RatatuiCameraNodeDepthPlugin;
+
+impl Plugin for RatatuiCameraNodeDepthPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "src/", "shaders/depth_downsample.wgsl");
+
+        app.add_plugins(ExtractComponentPlugin::<RatatuiCameraDepthDownsampling>::default());
+
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::DepthDownsample);
+        let render_app = app.sub_app_mut(RenderApp);
+
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeDepth>>(Core3d, label)
+            .add_render_graph_edges(Core3d, (Node3d::EndMainPass, label, Node3d::Upscaling));
+    }
+
+    fn finish(&self, app: &mut App) {
+        let render_app = app.sub_app_mut(RenderApp);
+        render_app.init_resource::<RatatuiCameraNodeDepthPipeline>();
+    }
+}
+
+/// Downsamples the camera's depth texture into the depth sender's image, for cameras with depth
+/// downsampling enabled.
+#[derive(Default)]
+pub struct RatatuiCameraNodeDepth;
+
+impl ViewNode for RatatuiCameraNodeDepth {
+    type ViewQuery = (
+        &'static ViewDepthTexture,
+        &'static RatatuiDepthSender,
+        Has<RatatuiCameraDepthDownsampling>,
+    );
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext<'_>,
+        render_context: &mut RenderContext<'w>,
+        (depth_texture, depth_sender, downsampling): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        if !downsampling {
+            return Ok(());
+        }
+
+        let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
+        let depth_pipeline = world.resource::<RatatuiCameraNodeDepthPipeline>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+
+        if let CachedPipelineState::Err(pipeline_error) =
+            pipeline_cache.get_render_pipeline_state(depth_pipeline.pipeline_id)
+        {
+            log::error!("{pipeline_error:?}");
+        };
+
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(depth_pipeline.pipeline_id) else {
+            return Ok(());
+        };
+
+        let Some(destination) = gpu_images.get(&depth_sender.sender_image) else {
+            return Ok(());
+        };
+
+        // the sender image is only in the downsampled format once the readback components have
+        // been recreated for downsampling.
+        if destination.texture_format != TextureFormat::Rgba8Unorm {
+            return Ok(());
+        }
+
+        let bind_group = render_context.render_device().create_bind_group(
+            "ratatui_camera_node_depth_bind_group",
+            &depth_pipeline.layout,
+            &BindGroupEntries::single(depth_texture.view()),
+        );
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("ratatui_camera_node_depth_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &destination.texture_view,
+                resolve_target: None,
+                ops: Operations::default(),
+                depth_slice: None,
+            })],
+            ..default()
+        });
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct RatatuiCameraNodeDepthPipeline {
+    layout: BindGroupLayout,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+impl FromWorld for RatatuiCameraNodeDepthPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let layout = render_device.create_bind_group_layout(
+            "ratatui_camera_node_depth_bind_group_layout",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::FRAGMENT,
+                // depth texture
+                texture_depth_2d(),
+            ),
+        );
+
+        let path = Path::new("bevy_ratatui_camera").join("shaders/depth_downsample.wgsl");
+        let source = AssetSourceId::from("embedded");
+        let asset_path = AssetPath::from_path(&path).with_source(source);
+        let shader_handle: Handle<Shader> = world.load_asset(asset_path);
+
+        let vertex_state = world.resource::<FullscreenShader>().to_vertex_state();
+        let pipeline_cache = world.resource_mut::<PipelineCache>();
+
+        let mut shader_defs = Vec::new();
+
+        if DEPTH_TEXTURE_SAMPLING_SUPPORTED {
+            shader_defs.push("DEPTH_TEXTURE_SAMPLING_SUPPORTED".into());
+        }
+
+        let pipeline_id = pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+            label: Some("ratatui_camera_node_depth_pipeline".into()),
+            layout: vec![layout.clone()],
+            vertex: vertex_state,
+            fragment: Some(FragmentState {
+                shader: shader_handle,
+                shader_defs,
+                entry_point: Some("fragment".into()),
+                targets: vec![Some(ColorTargetState {
+                    format: TextureFormat::Rgba8Unorm,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: vec![],
+            zero_initialize_workgroup_memory: true,
+        });
+
+        Self {
+            layout,
+            pipeline_id,
+        }
+    }
+}
@@ -0,0 +1,689 @@
+use std::ops::Range;
+
+use bevy::{
+    asset::{AssetPath, embedded_asset, io::AssetSourceId},
+    camera::{MainPassResolutionOverride, Viewport},
+    core_pipeline::{
+        core_3d::{
+            CORE_3D_DEPTH_FORMAT,
+            graph::{Core3d, Node3d},
+        },
+        oit::OrderIndependentTransparencySettings,
+        prepass::ViewPrepassTextures,
+    },
+    ecs::{
+        query::{QueryItem, ROQueryItem},
+        system::{
+            SystemParamItem,
+            lifetimeless::{Read, SRes},
+        },
+    },
+    math::FloatOrd,
+    mesh::MeshVertexBufferLayoutRef,
+    pbr::{
+        DrawMesh, MeshInputUniform, MeshPipeline, MeshPipelineKey, MeshPipelineViewLayoutKey,
+        MeshUniform, RenderMeshInstances, SetMeshBindGroup, SetMeshViewBindGroup,
+    },
+    platform::collections::HashSet,
+    prelude::*,
+    render::{
+        Extract, Render, RenderApp, RenderDebugFlags, RenderStartup, RenderSystems,
+        batching::{
+            GetBatchData, GetFullBatchData,
+            gpu_preprocessing::{
+                IndirectParametersCpuMetadata, UntypedPhaseIndirectParametersBuffers,
+                batch_and_prepare_sorted_render_phase,
+            },
+        },
+        camera::ExtractedCamera,
+        extract_component::{
+            ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+            UniformComponentPlugin,
+        },
+        mesh::{RenderMesh, allocator::MeshAllocator},
+        render_asset::RenderAssets,
+        render_graph::{
+            NodeRunError, RenderGraphContext, RenderGraphExt, ViewNode, ViewNodeRunner,
+        },
+        render_phase::{
+            AddRenderCommand, CachedRenderPipelinePhaseItem, DrawFunctionId, DrawFunctions,
+            PhaseItem, PhaseItemExtraIndex, RenderCommand, RenderCommandResult, SetItemPipeline,
+            SortedPhaseItem, SortedRenderPhasePlugin, TrackedRenderPass, ViewSortedRenderPhases,
+            sort_phase_system,
+        },
+        render_resource::{
+            BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries,
+            CachedRenderPipelineId, ColorTargetState, ColorWrites, CompareFunction,
+            DepthStencilState, Face, FragmentState, Operations, PipelineCache, PrimitiveState,
+            RenderPassColorAttachment, RenderPassDescriptor, RenderPipelineDescriptor,
+            ShaderStages, ShaderType, SpecializedMeshPipeline, SpecializedMeshPipelineError,
+            SpecializedMeshPipelines, StoreOp, TextureFormat, VertexState,
+            binding_types::uniform_buffer,
+        },
+        renderer::{RenderContext, RenderDevice},
+        sync_component::SyncComponentPlugin,
+        sync_world::MainEntity,
+        texture::GpuImage,
+        view::{ExtractedView, RenderVisibleEntities, RetainedViewEntity, ViewDepthTexture},
+    },
+};
+use nonmax::NonMaxU32;
+
+use crate::{
+    RatatuiCameraEdgeColor, RatatuiCameraMask,
+    camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind},
+    camera_readback::{RatatuiEdgeColorSender, RatatuiMaskSender},
+};
+
+pub struct RatatuiCameraNodeEdgeColorPlugin;
+
+impl Plugin for RatatuiCameraNodeEdgeColorPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "src/", "shaders/edge_color.wgsl");
+
+        app.add_plugins((
+            ExtractComponentPlugin::<RatatuiCameraEdgeColor>::default(),
+            SyncComponentPlugin::<RatatuiCameraMask>::default(),
+            UniformComponentPlugin::<RatatuiCameraEdgeColorUniform>::default(),
+            SortedRenderPhasePlugin::<RatatuiEdgeColor3d, MeshPipeline>::new(
+                RenderDebugFlags::default(),
+            ),
+        ));
+
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::EdgeColor);
+        let render_app = app.sub_app_mut(RenderApp);
+
+        render_app
+            .init_resource::<SpecializedMeshPipelines<RatatuiCameraEdgeColorPipeline>>()
+            .init_resource::<DrawFunctions<RatatuiEdgeColor3d>>()
+            .init_resource::<RatatuiCameraEdgeColorBindGroup>()
+            .add_render_command::<RatatuiEdgeColor3d, DrawRatatuiEdgeColor>()
+            .init_resource::<ViewSortedRenderPhases<RatatuiEdgeColor3d>>()
+            .add_systems(RenderStartup, init_edge_color_pipeline_system)
+            .add_systems(ExtractSchedule, extract_edge_color_phases_system)
+            .add_systems(
+                Render,
+                (
+                    queue_edge_color_meshes_system.in_set(RenderSystems::QueueMeshes),
+                    sort_phase_system::<RatatuiEdgeColor3d>.in_set(RenderSystems::PhaseSort),
+                    batch_and_prepare_sorted_render_phase::<
+                        RatatuiEdgeColor3d,
+                        RatatuiCameraEdgeColorPipeline,
+                    >
+                        .in_set(RenderSystems::PrepareResources),
+                    prepare_edge_color_bind_group_system.in_set(RenderSystems::PrepareBindGroups),
+                ),
+            );
+
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeEdgeColor>>(Core3d, label)
+            .add_render_graph_edges(Core3d, (Node3d::MainOpaquePass, label, Node3d::EndMainPass));
+    }
+}
+
+/// Edge color and mask of a mesh entity, as stored in a uniform buffer in the render world. Meshes
+/// with only a mask have a transparent edge color, and meshes with only an edge color have a mask
+/// of `0`.
+#[derive(Component, ShaderType, Clone, Copy, Debug)]
+pub struct RatatuiCameraEdgeColorUniform {
+    color: Vec4,
+    mask: u32,
+}
+
+impl ExtractComponent for RatatuiCameraEdgeColor {
+    type QueryData = (Option<&'static Self>, Option<&'static RatatuiCameraMask>);
+    type QueryFilter = Or<(With<Self>, With<RatatuiCameraMask>)>;
+    type Out = RatatuiCameraEdgeColorUniform;
+
+    fn extract_component(
+        (edge_color, mask): QueryItem<'_, '_, Self::QueryData>,
+    ) -> Option<Self::Out> {
+        Some(RatatuiCameraEdgeColorUniform {
+            color: edge_color.map_or(Vec4::ZERO, |edge_color| {
+                edge_color.0.to_linear().with_alpha(1.0).to_vec4()
+            }),
+            mask: mask.map_or(0, |mask| mask.0.into()),
+        })
+    }
+}
+
+/// Draws meshes with an edge color or mask, recording them into the edge color and mask sender
+/// images, for cameras with edge color or mask detection enabled. Meshes are depth tested against
+/// the main pass's depth texture, so that they are only drawn where they are visible.
+#[derive(Default)]
+pub struct RatatuiCameraNodeEdgeColor;
+
+impl ViewNode for RatatuiCameraNodeEdgeColor {
+    type ViewQuery = (
+        &'static ExtractedCamera,
+        &'static ExtractedView,
+        &'static ViewDepthTexture,
+        Option<&'static RatatuiEdgeColorSender>,
+        Option<&'static RatatuiMaskSender>,
+        Option<&'static MainPassResolutionOverride>,
+    );
+
+    fn run<'w>(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext<'w>,
+        (camera, view, depth_texture, edge_color_sender, mask_sender, resolution_override): QueryItem<
+            'w,
+            '_,
+            Self::ViewQuery,
+        >,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
+        let edge_color_phases = world.resource::<ViewSortedRenderPhases<RatatuiEdgeColor3d>>();
+
+        let Some(edge_color_phase) = edge_color_phases.get(&view.retained_view_entity) else {
+            return Ok(());
+        };
+
+        // one color attachment per enabled output, in the same order as the pipeline's targets.
+        let color_attachments = [
+            edge_color_sender.map(|sender| &sender.sender_image),
+            mask_sender.map(|sender| &sender.sender_image),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|sender_image| {
+            gpu_images
+                .get(sender_image)
+                .map(|destination| RenderPassColorAttachment {
+                    view: &destination.texture_view,
+                    resolve_target: None,
+                    ops: Operations::default(),
+                    depth_slice: None,
+                })
+        })
+        .collect::<Vec<_>>();
+
+        if color_attachments.is_empty() || color_attachments.iter().any(Option::is_none) {
+            return Ok(());
+        }
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("ratatui_camera_node_edge_color_pass"),
+            color_attachments: &color_attachments,
+            depth_stencil_attachment: Some(depth_texture.get_attachment(StoreOp::Store)),
+            ..default()
+        });
+
+        if let Some(viewport) =
+            Viewport::from_viewport_and_override(camera.viewport.as_ref(), resolution_override)
+        {
+            render_pass.set_camera_viewport(&viewport);
+        }
+
+        if let Err(error) = edge_color_phase.render(&mut render_pass, world, graph.view_entity()) {
+            log::error!("{error:?}");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+pub struct RatatuiCameraEdgeColorPipeline {
+    mesh_pipeline: MeshPipeline,
+    color_layout: BindGroupLayout,
+    shader_handle: Handle<Shader>,
+}
+
+fn init_edge_color_pipeline_system(
+    mut commands: Commands,
+    mesh_pipeline: Res<MeshPipeline>,
+    render_device: Res<RenderDevice>,
+    asset_server: Res<AssetServer>,
+) {
+    let color_layout = render_device.create_bind_group_layout(
+        "ratatui_camera_node_edge_color_bind_group_layout",
+        &BindGroupLayoutEntries::single(
+            ShaderStages::FRAGMENT,
+            uniform_buffer::<RatatuiCameraEdgeColorUniform>(true),
+        ),
+    );
+
+    let path = std::path::Path::new("bevy_ratatui_camera").join("shaders/edge_color.wgsl");
+    let source = AssetSourceId::from("embedded");
+    let asset_path = AssetPath::from_path(&path).with_source(source);
+
+    commands.insert_resource(RatatuiCameraEdgeColorPipeline {
+        mesh_pipeline: mesh_pipeline.clone(),
+        color_layout,
+        shader_handle: asset_server.load(asset_path),
+    });
+}
+
+/// Specializes the edge color pipeline for a mesh, and for which outputs the view records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RatatuiCameraEdgeColorPipelineKey {
+    mesh_key: MeshPipelineKey,
+    edge_color: bool,
+    mask: bool,
+}
+
+impl SpecializedMeshPipeline for RatatuiCameraEdgeColorPipeline {
+    type Key = RatatuiCameraEdgeColorPipelineKey;
+
+    fn specialize(
+        &self,
+        RatatuiCameraEdgeColorPipelineKey {
+            mesh_key: key,
+            edge_color,
+            mask,
+        }: Self::Key,
+        layout: &MeshVertexBufferLayoutRef,
+    ) -> Result<RenderPipelineDescriptor, SpecializedMeshPipelineError> {
+        let vertex_buffer_layout = layout
+            .0
+            .get_layout(&[Mesh::ATTRIBUTE_POSITION.at_shader_location(0)])?;
+
+        let view_layout = self
+            .mesh_pipeline
+            .get_view_layout(MeshPipelineViewLayoutKey::from(key));
+
+        let mut shader_defs = Vec::new();
+        let mut targets = Vec::new();
+
+        if edge_color {
+            shader_defs.push("EDGE_COLOR".into());
+            targets.push(Some(ColorTargetState {
+                format: TextureFormat::bevy_default(),
+                blend: None,
+                write_mask: ColorWrites::ALL,
+            }));
+        }
+
+        // mask values are written as raw bytes, so the texture is not in an sRGB format.
+        if mask {
+            shader_defs.push("MASK".into());
+            targets.push(Some(ColorTargetState {
+                format: TextureFormat::Rgba8Unorm,
+                blend: None,
+                write_mask: ColorWrites::ALL,
+            }));
+        }
+
+        Ok(RenderPipelineDescriptor {
+            label: Some("ratatui_camera_node_edge_color_pipeline".into()),
+            layout: vec![
+                view_layout.main_layout.clone(),
+                self.color_layout.clone(),
+                self.mesh_pipeline.mesh_layouts.model_only.clone(),
+            ],
+            vertex: VertexState {
+                shader: self.shader_handle.clone(),
+                buffers: vec![vertex_buffer_layout],
+                ..default()
+            },
+            fragment: Some(FragmentState {
+                shader: self.shader_handle.clone(),
+                shader_defs,
+                targets,
+                ..default()
+            }),
+            primitive: PrimitiveState {
+                topology: key.primitive_topology(),
+                cull_mode: Some(Face::Back),
+                ..default()
+            },
+            // only draw the fragments that made it into the main pass (reversed z, so closer
+            // fragments have greater depths), without affecting the depth texture.
+            depth_stencil: Some(DepthStencilState {
+                format: CORE_3D_DEPTH_FORMAT,
+                depth_write_enabled: false,
+                depth_compare: CompareFunction::GreaterEqual,
+                stencil: default(),
+                bias: default(),
+            }),
+            ..default()
+        })
+    }
+}
+
+type DrawRatatuiEdgeColor = (
+    SetItemPipeline,
+    SetMeshViewBindGroup<0>,
+    SetEdgeColorBindGroup<1>,
+    SetMeshBindGroup<2>,
+    DrawMesh,
+);
+
+#[derive(Resource, Default)]
+struct RatatuiCameraEdgeColorBindGroup(Option<BindGroup>);
+
+fn prepare_edge_color_bind_group_system(
+    render_device: Res<RenderDevice>,
+    pipeline: Option<Res<RatatuiCameraEdgeColorPipeline>>,
+    edge_color_uniforms: Res<ComponentUniforms<RatatuiCameraEdgeColorUniform>>,
+    mut bind_group: ResMut<RatatuiCameraEdgeColorBindGroup>,
+) {
+    let (Some(pipeline), Some(binding)) = (pipeline, edge_color_uniforms.uniforms().binding())
+    else {
+        bind_group.0 = None;
+        return;
+    };
+
+    bind_group.0 = Some(render_device.create_bind_group(
+        "ratatui_camera_node_edge_color_bind_group",
+        &pipeline.color_layout,
+        &BindGroupEntries::single(binding),
+    ));
+}
+
+struct SetEdgeColorBindGroup<const I: usize>;
+
+impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetEdgeColorBindGroup<I> {
+    type Param = SRes<RatatuiCameraEdgeColorBindGroup>;
+    type ViewQuery = ();
+    type ItemQuery = Read<DynamicUniformIndex<RatatuiCameraEdgeColorUniform>>;
+
+    fn render<'w>(
+        _item: &P,
+        _view: ROQueryItem<'w, '_, Self::ViewQuery>,
+        uniform_index: Option<ROQueryItem<'w, '_, Self::ItemQuery>>,
+        bind_group: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let (Some(uniform_index), Some(bind_group)) = (uniform_index, &bind_group.into_inner().0)
+        else {
+            return RenderCommandResult::Skip;
+        };
+
+        pass.set_bind_group(I, bind_group, &[uniform_index.index()]);
+
+        RenderCommandResult::Success
+    }
+}
+
+pub struct RatatuiEdgeColor3d {
+    sort_key: FloatOrd,
+    entity: (Entity, MainEntity),
+    pipeline: CachedRenderPipelineId,
+    draw_function: DrawFunctionId,
+    batch_range: Range<u32>,
+    extra_index: PhaseItemExtraIndex,
+    indexed: bool,
+}
+
+impl PhaseItem for RatatuiEdgeColor3d {
+    #[inline]
+    fn entity(&self) -> Entity {
+        self.entity.0
+    }
+
+    #[inline]
+    fn main_entity(&self) -> MainEntity {
+        self.entity.1
+    }
+
+    #[inline]
+    fn draw_function(&self) -> DrawFunctionId {
+        self.draw_function
+    }
+
+    #[inline]
+    fn batch_range(&self) -> &Range<u32> {
+        &self.batch_range
+    }
+
+    #[inline]
+    fn batch_range_mut(&mut self) -> &mut Range<u32> {
+        &mut self.batch_range
+    }
+
+    #[inline]
+    fn extra_index(&self) -> PhaseItemExtraIndex {
+        self.extra_index.clone()
+    }
+
+    #[inline]
+    fn batch_range_and_extra_index_mut(&mut self) -> (&mut Range<u32>, &mut PhaseItemExtraIndex) {
+        (&mut self.batch_range, &mut self.extra_index)
+    }
+}
+
+impl SortedPhaseItem for RatatuiEdgeColor3d {
+    type SortKey = FloatOrd;
+
+    #[inline]
+    fn sort_key(&self) -> Self::SortKey {
+        self.sort_key
+    }
+
+    #[inline]
+    fn sort(items: &mut [Self]) {
+        items.sort_by_key(SortedPhaseItem::sort_key);
+    }
+
+    #[inline]
+    fn indexed(&self) -> bool {
+        self.indexed
+    }
+}
+
+impl CachedRenderPipelinePhaseItem for RatatuiEdgeColor3d {
+    #[inline]
+    fn cached_pipeline(&self) -> CachedRenderPipelineId {
+        self.pipeline
+    }
+}
+
+impl GetBatchData for RatatuiCameraEdgeColorPipeline {
+    type Param = (SRes<RenderMeshInstances>, SRes<MeshAllocator>);
+    type CompareData = AssetId<Mesh>;
+    type BufferData = MeshUniform;
+
+    fn get_batch_data(
+        (mesh_instances, mesh_allocator): &SystemParamItem<Self::Param>,
+        (_entity, main_entity): (Entity, MainEntity),
+    ) -> Option<(Self::BufferData, Option<Self::CompareData>)> {
+        let RenderMeshInstances::CpuBuilding(ref mesh_instances) = **mesh_instances else {
+            return None;
+        };
+        let mesh_instance = mesh_instances.get(&main_entity)?;
+        let first_vertex_index = mesh_allocator
+            .mesh_vertex_slice(&mesh_instance.mesh_asset_id)
+            .map(|mesh_vertex_slice| mesh_vertex_slice.range.start)
+            .unwrap_or_default();
+
+        Some((
+            MeshUniform::new(
+                &mesh_instance.transforms,
+                first_vertex_index,
+                mesh_instance.material_bindings_index.slot,
+                None,
+                None,
+                None,
+            ),
+            None,
+        ))
+    }
+}
+
+impl GetFullBatchData for RatatuiCameraEdgeColorPipeline {
+    type BufferInputData = MeshInputUniform;
+
+    fn get_index_and_compare_data(
+        (mesh_instances, _): &SystemParamItem<Self::Param>,
+        main_entity: MainEntity,
+    ) -> Option<(NonMaxU32, Option<Self::CompareData>)> {
+        let RenderMeshInstances::GpuBuilding(ref mesh_instances) = **mesh_instances else {
+            return None;
+        };
+        let mesh_instance = mesh_instances.get(&main_entity)?;
+
+        Some((
+            mesh_instance.current_uniform_index,
+            mesh_instance
+                .should_batch()
+                .then_some(mesh_instance.mesh_asset_id),
+        ))
+    }
+
+    fn get_binned_batch_data(
+        param: &SystemParamItem<Self::Param>,
+        main_entity: MainEntity,
+    ) -> Option<Self::BufferData> {
+        Self::get_batch_data(param, (Entity::PLACEHOLDER, main_entity))
+            .map(|(buffer_data, _)| buffer_data)
+    }
+
+    fn write_batch_indirect_parameters_metadata(
+        indexed: bool,
+        base_output_index: u32,
+        batch_set_index: Option<NonMaxU32>,
+        indirect_parameters_buffers: &mut UntypedPhaseIndirectParametersBuffers,
+        indirect_parameters_offset: u32,
+    ) {
+        let indirect_parameters = IndirectParametersCpuMetadata {
+            base_output_index,
+            batch_set_index: batch_set_index.map(u32::from).unwrap_or(!0),
+        };
+
+        if indexed {
+            indirect_parameters_buffers
+                .indexed
+                .set(indirect_parameters_offset, indirect_parameters);
+        } else {
+            indirect_parameters_buffers
+                .non_indexed
+                .set(indirect_parameters_offset, indirect_parameters);
+        }
+    }
+
+    fn get_binned_index(
+        _param: &SystemParamItem<Self::Param>,
+        _main_entity: MainEntity,
+    ) -> Option<NonMaxU32> {
+        None
+    }
+}
+
+fn extract_edge_color_phases_system(
+    mut edge_color_phases: ResMut<ViewSortedRenderPhases<RatatuiEdgeColor3d>>,
+    cameras: Extract<
+        Query<(Entity, &Camera), Or<(With<RatatuiEdgeColorSender>, With<RatatuiMaskSender>)>>,
+    >,
+    mut live_entities: Local<HashSet<RetainedViewEntity>>,
+) {
+    live_entities.clear();
+
+    for (main_entity, camera) in &cameras {
+        if !camera.is_active {
+            continue;
+        }
+
+        let retained_view_entity = RetainedViewEntity::new(main_entity.into(), None, 0);
+
+        edge_color_phases.insert_or_clear(retained_view_entity);
+        live_entities.insert(retained_view_entity);
+    }
+
+    edge_color_phases.retain(|camera_entity, _| live_entities.contains(camera_entity));
+}
+
+fn queue_edge_color_meshes_system(
+    draw_functions: Res<DrawFunctions<RatatuiEdgeColor3d>>,
+    (mut pipelines, pipeline_cache, edge_color_pipeline): (
+        ResMut<SpecializedMeshPipelines<RatatuiCameraEdgeColorPipeline>>,
+        Res<PipelineCache>,
+        Option<Res<RatatuiCameraEdgeColorPipeline>>,
+    ),
+    render_meshes: Res<RenderAssets<RenderMesh>>,
+    render_mesh_instances: Res<RenderMeshInstances>,
+    mut edge_color_phases: ResMut<ViewSortedRenderPhases<RatatuiEdgeColor3d>>,
+    views: Query<(
+        &ExtractedView,
+        &RenderVisibleEntities,
+        &Msaa,
+        Option<&ViewPrepassTextures>,
+        Has<OrderIndependentTransparencySettings>,
+        (Has<RatatuiEdgeColorSender>, Has<RatatuiMaskSender>),
+    )>,
+    edge_colored: Query<(), With<RatatuiCameraEdgeColorUniform>>,
+) {
+    let Some(edge_color_pipeline) = edge_color_pipeline else {
+        return;
+    };
+
+    let draw_function = draw_functions.read().id::<DrawRatatuiEdgeColor>();
+
+    for (view, visible_entities, msaa, prepass_textures, has_oit, (edge_color, mask)) in &views {
+        let Some(edge_color_phase) = edge_color_phases.get_mut(&view.retained_view_entity) else {
+            continue;
+        };
+
+        // the view bind group is shared with the main pass, so the key must produce the same
+        // view layout that bevy used when creating it.
+        let mut view_key = MeshPipelineKey::from_msaa_samples(msaa.samples())
+            | MeshPipelineKey::from_hdr(view.hdr);
+        if let Some(prepass_textures) = prepass_textures {
+            if prepass_textures.depth.is_some() {
+                view_key |= MeshPipelineKey::DEPTH_PREPASS;
+            }
+            if prepass_textures.normal.is_some() {
+                view_key |= MeshPipelineKey::NORMAL_PREPASS;
+            }
+            if prepass_textures.motion_vectors.is_some() {
+                view_key |= MeshPipelineKey::MOTION_VECTOR_PREPASS;
+            }
+            if prepass_textures.deferred.is_some() {
+                view_key |= MeshPipelineKey::DEFERRED_PREPASS;
+            }
+        }
+        if has_oit {
+            view_key |= MeshPipelineKey::OIT_ENABLED;
+        }
+
+        let rangefinder = view.rangefinder3d();
+
+        for (render_entity, visible_entity) in visible_entities.iter::<Mesh3d>() {
+            if edge_colored.get(*render_entity).is_err() {
+                continue;
+            }
+
+            let Some(mesh_instance) = render_mesh_instances.render_mesh_queue_data(*visible_entity)
+            else {
+                continue;
+            };
+
+            let Some(mesh) = render_meshes.get(mesh_instance.mesh_asset_id) else {
+                continue;
+            };
+
+            let mesh_key = RatatuiCameraEdgeColorPipelineKey {
+                mesh_key: view_key
+                    | MeshPipelineKey::from_primitive_topology(mesh.primitive_topology()),
+                edge_color,
+                mask,
+            };
+
+            let pipeline = match pipelines.specialize(
+                &pipeline_cache,
+                &edge_color_pipeline,
+                mesh_key,
+                &mesh.layout,
+            ) {
+                Ok(pipeline) => pipeline,
+                Err(error) => {
+                    log::error!("{error}");
+                    continue;
+                }
+            };
+
+            edge_color_phase.add(RatatuiEdgeColor3d {
+                sort_key: FloatOrd(rangefinder.distance_translation(&mesh_instance.translation)),
+                entity: (*render_entity, *visible_entity),
+                pipeline,
+                draw_function,
+                batch_range: 0..1,
+                extra_index: PhaseItemExtraIndex::None,
+                indexed: mesh.indexed(),
+            });
+        }
+    }
+}
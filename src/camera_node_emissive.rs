@@ -0,0 +1,175 @@
+use std::path::Path;
+
+use bevy::{
+    asset::{AssetPath, embedded_asset, io::AssetSourceId},
+    core_pipeline::{
+        FullscreenShader,
+        core_3d::graph::{Core3d, Node3d},
+    },
+    ecs::query::QueryItem,
+    prelude::*,
+    render::{
+        RenderApp,
+        render_asset::RenderAssets,
+        render_graph::{
+            NodeRunError, RenderGraph, RenderGraphContext, RenderGraphExt, ViewNode, ViewNodeRunner,
+        },
+        render_resource::{
+            BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedPipelineState,
+            CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState, MultisampleState,
+            Operations, PipelineCache, PrimitiveState, RenderPassColorAttachment,
+            RenderPassDescriptor, RenderPipelineDescriptor, ShaderStages, TextureFormat,
+            TextureSampleType, binding_types::texture_2d,
+        },
+        renderer::{RenderContext, RenderDevice},
+        texture::GpuImage,
+        view::ViewTarget,
+    },
+};
+
+use crate::{
+    camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind},
+    camera_readback::RatatuiEmissiveSender,
+};
+
+pub struct RatatuiCameraNodeEmissivePlugin;
+
+impl Plugin for RatatuiCameraNodeEmissivePlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "src/", "shaders/emissive.wgsl");
+
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::Emissive);
+        let render_app = app.sub_app_mut(RenderApp);
+
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeEmissive>>(Core3d, label)
+            .add_render_graph_edges(Core3d, (Node3d::EndMainPass, label, Node3d::Tonemapping));
+    }
+
+    fn finish(&self, app: &mut App) {
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::Emissive);
+        let render_app = app.sub_app_mut(RenderApp);
+        render_app.init_resource::<RatatuiCameraNodeEmissivePipeline>();
+
+        // bloom is optional, but when present, run after it so that the glow around bright
+        // pixels is picked up as well.
+        let mut render_graph = render_app.world_mut().resource_mut::<RenderGraph>();
+        if let Some(core_3d) = render_graph.get_sub_graph_mut(Core3d)
+            && core_3d.get_node_state(Node3d::Bloom).is_ok()
+        {
+            core_3d.add_node_edge(Node3d::Bloom, label);
+        }
+    }
+}
+
+/// Records the brightness of the camera's HDR main texture (before tonemapping) into the emissive
+/// sender's image.
+#[derive(Default)]
+pub struct RatatuiCameraNodeEmissive;
+
+impl ViewNode for RatatuiCameraNodeEmissive {
+    type ViewQuery = (&'static ViewTarget, &'static RatatuiEmissiveSender);
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext<'_>,
+        render_context: &mut RenderContext<'w>,
+        (view_target, emissive_sender): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
+        let emissive_pipeline = world.resource::<RatatuiCameraNodeEmissivePipeline>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+
+        if let CachedPipelineState::Err(pipeline_error) =
+            pipeline_cache.get_render_pipeline_state(emissive_pipeline.pipeline_id)
+        {
+            log::error!("{pipeline_error:?}");
+        };
+
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(emissive_pipeline.pipeline_id)
+        else {
+            return Ok(());
+        };
+
+        let destination = gpu_images.get(&emissive_sender.sender_image).unwrap();
+
+        let bind_group = render_context.render_device().create_bind_group(
+            "ratatui_camera_node_emissive_bind_group",
+            &emissive_pipeline.layout,
+            &BindGroupEntries::single(view_target.main_texture_view()),
+        );
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("ratatui_camera_node_emissive_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &destination.texture_view,
+                resolve_target: None,
+                ops: Operations::default(),
+                depth_slice: None,
+            })],
+            ..default()
+        });
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct RatatuiCameraNodeEmissivePipeline {
+    layout: BindGroupLayout,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+impl FromWorld for RatatuiCameraNodeEmissivePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let layout = render_device.create_bind_group_layout(
+            "ratatui_camera_node_emissive_bind_group_layout",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::FRAGMENT,
+                // main texture
+                texture_2d(TextureSampleType::Float { filterable: true }),
+            ),
+        );
+
+        let path = Path::new("bevy_ratatui_camera").join("shaders/emissive.wgsl");
+        let source = AssetSourceId::from("embedded");
+        let asset_path = AssetPath::from_path(&path).with_source(source);
+        let shader_handle: Handle<Shader> = world.load_asset(asset_path);
+
+        let vertex_state = world.resource::<FullscreenShader>().to_vertex_state();
+        let pipeline_cache = world.resource_mut::<PipelineCache>();
+
+        let pipeline_id = pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+            label: Some("ratatui_camera_node_emissive_pipeline".into()),
+            layout: vec![layout.clone()],
+            vertex: vertex_state,
+            fragment: Some(FragmentState {
+                shader: shader_handle,
+                shader_defs: Vec::new(),
+                entry_point: Some("fragment".into()),
+                targets: vec![Some(ColorTargetState {
+                    format: TextureFormat::bevy_default(),
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: vec![],
+            zero_initialize_workgroup_memory: true,
+        });
+
+        Self {
+            layout,
+            pipeline_id,
+        }
+    }
+}
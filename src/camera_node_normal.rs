@@ -0,0 +1,168 @@
+use std::path::Path;
+
+use bevy::{
+    asset::{AssetPath, embedded_asset, io::AssetSourceId},
+    core_pipeline::{
+        FullscreenShader,
+        core_3d::graph::{Core3d, Node3d},
+        prepass::ViewPrepassTextures,
+    },
+    ecs::query::QueryItem,
+    prelude::*,
+    render::{
+        RenderApp,
+        render_asset::RenderAssets,
+        render_graph::{
+            NodeRunError, RenderGraphContext, RenderGraphExt, ViewNode, ViewNodeRunner,
+        },
+        render_resource::{
+            BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedPipelineState,
+            CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState, MultisampleState,
+            Operations, PipelineCache, PrimitiveState, RenderPassColorAttachment,
+            RenderPassDescriptor, RenderPipelineDescriptor, ShaderStages, TextureFormat,
+            TextureSampleType, binding_types::texture_2d,
+        },
+        renderer::{RenderContext, RenderDevice},
+        texture::GpuImage,
+    },
+};
+
+use crate::{
+    camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind},
+    camera_readback::RatatuiNormalSender,
+};
+
+pub struct RatatuiCameraNodeNormalPlugin;
+
+impl Plugin for RatatuiCameraNodeNormalPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "src/", "shaders/normal.wgsl");
+
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::Normal);
+        let render_app = app.sub_app_mut(RenderApp);
+
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeNormal>>(Core3d, label)
+            .add_render_graph_edge(Core3d, Node3d::EndMainPass, label);
+    }
+
+    fn finish(&self, app: &mut App) {
+        let render_app = app.sub_app_mut(RenderApp);
+        render_app.init_resource::<RatatuiCameraNodeNormalPipeline>();
+    }
+}
+
+/// Copies the normal prepass texture into the normal sender's image, as the prepass texture
+/// itself cannot be copied into a buffer directly.
+#[derive(Default)]
+pub struct RatatuiCameraNodeNormal;
+
+impl ViewNode for RatatuiCameraNodeNormal {
+    type ViewQuery = (&'static ViewPrepassTextures, &'static RatatuiNormalSender);
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext<'_>,
+        render_context: &mut RenderContext<'w>,
+        (prepass_textures, normal_sender): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
+        let normal_pipeline = world.resource::<RatatuiCameraNodeNormalPipeline>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+
+        if let CachedPipelineState::Err(pipeline_error) =
+            pipeline_cache.get_render_pipeline_state(normal_pipeline.pipeline_id)
+        {
+            log::error!("{pipeline_error:?}");
+        };
+
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(normal_pipeline.pipeline_id) else {
+            return Ok(());
+        };
+
+        let Some(normal_prepass) = prepass_textures.normal_view() else {
+            return Ok(());
+        };
+
+        let destination = gpu_images.get(&normal_sender.sender_image).unwrap();
+
+        let bind_group = render_context.render_device().create_bind_group(
+            "ratatui_camera_node_normal_bind_group",
+            &normal_pipeline.layout,
+            &BindGroupEntries::single(normal_prepass),
+        );
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("ratatui_camera_node_normal_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &destination.texture_view,
+                resolve_target: None,
+                ops: Operations::default(),
+                depth_slice: None,
+            })],
+            ..default()
+        });
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct RatatuiCameraNodeNormalPipeline {
+    layout: BindGroupLayout,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+impl FromWorld for RatatuiCameraNodeNormalPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let layout = render_device.create_bind_group_layout(
+            "ratatui_camera_node_normal_bind_group_layout",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::FRAGMENT,
+                // normal prepass
+                texture_2d(TextureSampleType::Float { filterable: true }),
+            ),
+        );
+
+        let path = Path::new("bevy_ratatui_camera").join("shaders/normal.wgsl");
+        let source = AssetSourceId::from("embedded");
+        let asset_path = AssetPath::from_path(&path).with_source(source);
+        let shader_handle: Handle<Shader> = world.load_asset(asset_path);
+
+        let vertex_state = world.resource::<FullscreenShader>().to_vertex_state();
+        let pipeline_cache = world.resource_mut::<PipelineCache>();
+
+        let pipeline_id = pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+            label: Some("ratatui_camera_node_normal_pipeline".into()),
+            layout: vec![layout.clone()],
+            vertex: vertex_state,
+            fragment: Some(FragmentState {
+                shader: shader_handle,
+                shader_defs: Vec::new(),
+                entry_point: Some("fragment".into()),
+                targets: vec![Some(ColorTargetState {
+                    format: TextureFormat::bevy_default(),
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: vec![],
+            zero_initialize_workgroup_memory: true,
+        });
+
+        Self {
+            layout,
+            pipeline_id,
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use std::{any::TypeId, fmt, marker::PhantomData};
+
+#[cfg(feature = "core_2d")]
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+use bevy::{
+    core_pipeline::FullscreenShader,
+    ecs::query::QueryItem,
+    prelude::*,
+    render::{
+        Render, RenderApp, RenderSystems,
+        extract_component::{
+            ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+            UniformComponentPlugin,
+        },
+        render_graph::{
+            NodeRunError, RenderGraphContext, RenderGraphExt, ViewNode, ViewNodeRunner,
+        },
+        render_resource::{
+            BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedPipelineState,
+            CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState, Operations,
+            PipelineCache, RenderPassColorAttachment, RenderPassDescriptor,
+            RenderPipelineDescriptor, Sampler, SamplerBindingType, SamplerDescriptor, ShaderStages,
+            ShaderType, SpecializedRenderPipeline, SpecializedRenderPipelines, TextureFormat,
+            TextureSampleType,
+            binding_types::{sampler, texture_2d, uniform_buffer},
+            encase::internal::WriteInto,
+        },
+        renderer::{RenderContext, RenderDevice},
+        view::ViewTarget,
+    },
+    shader::ShaderRef,
+};
+#[cfg(feature = "core_3d")]
+use bevy::{
+    core_pipeline::core_3d::graph::{Core3d, Node3d},
+    render::render_graph::RenderGraph,
+};
+
+use crate::camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind};
+
+/// Add this plugin (once for each post-process component) to run the component's fullscreen
+/// fragment shader over the rendered image of every camera the component is inserted on, see
+/// [RatatuiCameraPostProcess].
+pub struct RatatuiCameraPostProcessPlugin<T>(PhantomData<T>);
+
+impl<T> fmt::Debug for RatatuiCameraPostProcessPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RatatuiCameraPostProcessPlugin").finish()
+    }
+}
+
+impl<T> Default for RatatuiCameraPostProcessPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: RatatuiCameraPostProcess> Plugin for RatatuiCameraPostProcessPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ExtractComponentPlugin::<T>::default(),
+            UniformComponentPlugin::<T>::default(),
+        ));
+
+        let label = RatatuiCameraInstance::of(app)
+            .label(RatatuiCameraNodeKind::PostProcess(TypeId::of::<T>()));
+        let render_app = app.sub_app_mut(RenderApp);
+
+        render_app.add_systems(
+            Render,
+            prepare_post_process_pipelines_system::<T>.in_set(RenderSystems::Prepare),
+        );
+
+        #[cfg(feature = "core_3d")]
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodePostProcess<T>>>(Core3d, label)
+            .add_render_graph_edges(
+                Core3d,
+                (
+                    Node3d::Tonemapping,
+                    label,
+                    Node3d::EndMainPassPostProcessing,
+                ),
+            );
+
+        #[cfg(feature = "core_2d")]
+        render_app
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodePostProcess<T>>>(Core2d, label)
+            .add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Tonemapping,
+                    label,
+                    Node2d::EndMainPassPostProcessing,
+                ),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        #[cfg(feature = "core_3d")]
+        let instance = RatatuiCameraInstance::of(app);
+        let render_app = app.sub_app_mut(RenderApp);
+        render_app
+            .init_resource::<RatatuiCameraPostProcessPipeline<T>>()
+            .init_resource::<SpecializedRenderPipelines<RatatuiCameraPostProcessPipeline<T>>>();
+
+        // edge detection is optional, but when present, detect edges in the image as rendered
+        // rather than as stylized.
+        #[cfg(feature = "core_3d")]
+        {
+            let mut render_graph = render_app.world_mut().resource_mut::<RenderGraph>();
+            if let Some(core_3d) = render_graph.get_sub_graph_mut(Core3d)
+                && core_3d
+                    .get_node_state(instance.label(RatatuiCameraNodeKind::Sobel))
+                    .is_ok()
+            {
+                core_3d.add_node_edge(
+                    instance.label(RatatuiCameraNodeKind::Sobel),
+                    instance.label(RatatuiCameraNodeKind::PostProcess(TypeId::of::<T>())),
+                );
+            }
+        }
+    }
+}
+
+/// Implement this trait for a component to run a custom fullscreen fragment shader over the
+/// rendered image of each camera the component is inserted on, before the image is read back and
+/// converted into unicode characters. This allows stylization effects (e.g. color grading,
+/// dithering, or distortion) to run on the GPU without setting up a render graph node yourself.
+///
+/// Register the component with a [RatatuiCameraPostProcessPlugin]. The component itself is the
+/// shader's uniform, uploaded each frame, so derive `ShaderType` for it along with `Component`,
+/// `ExtractComponent` and `Clone`. The pass runs after tonemapping, so the colors it reads are the
+/// same as those displayed. Edge detection still sees the image as rendered, before any
+/// post-process.
+///
+/// The shader receives the following bindings, and must have a `fragment` entry point using
+/// bevy's fullscreen vertex shader:
+///
+/// ```wgsl
+/// #import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+///
+/// struct Settings {
+///     brightness: f32,
+/// };
+///
+/// @group(0) @binding(0) var screen_texture: texture_2d<f32>;
+/// @group(0) @binding(1) var texture_sampler: sampler;
+/// @group(0) @binding(2) var<uniform> settings: Settings;
+///
+/// @fragment
+/// fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+///     let color = textureSample(screen_texture, texture_sampler, in.uv);
+///     return vec4f(color.rgb * settings.brightness, color.a);
+/// }
+/// ```
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy::render::{extract_component::ExtractComponent, render_resource::ShaderType};
+/// # use bevy::shader::ShaderRef;
+/// # use bevy_ratatui_camera::{
+/// #     RatatuiCamera, RatatuiCameraPostProcess, RatatuiCameraPostProcessPlugin,
+/// # };
+/// #
+/// #[derive(Component, ExtractComponent, ShaderType, Clone)]
+/// struct Settings {
+///     brightness: f32,
+/// }
+///
+/// impl RatatuiCameraPostProcess for Settings {
+///     fn fragment_shader() -> ShaderRef {
+///         "shaders/brightness.wgsl".into()
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(RatatuiCameraPostProcessPlugin::<Settings>::default());
+///
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     Camera3d::default(),
+///     RatatuiCamera::default(),
+///     Settings { brightness: 1.5 },
+/// ));
+/// # };
+/// ```
+///
+pub trait RatatuiCameraPostProcess:
+    Component + ExtractComponent<Out = Self> + ShaderType + WriteInto + Clone
+{
+    /// The fragment shader run over the rendered image, see [RatatuiCameraPostProcess] for its
+    /// bindings.
+    fn fragment_shader() -> ShaderRef;
+}
+
+/// Runs the post-process component's fragment shader over the view's main texture.
+pub struct RatatuiCameraNodePostProcess<T>(PhantomData<T>);
+
+impl<T> Default for RatatuiCameraNodePostProcess<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: RatatuiCameraPostProcess> ViewNode for RatatuiCameraNodePostProcess<T> {
+    type ViewQuery = (
+        &'static ViewTarget,
+        &'static DynamicUniformIndex<T>,
+        &'static RatatuiCameraPostProcessPipelineId<T>,
+    );
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext<'_>,
+        render_context: &mut RenderContext<'w>,
+        (view_target, settings_index, pipeline_id): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        let post_process_pipeline = world.resource::<RatatuiCameraPostProcessPipeline<T>>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let settings_uniforms = world.resource::<ComponentUniforms<T>>();
+
+        if let CachedPipelineState::Err(pipeline_error) =
+            pipeline_cache.get_render_pipeline_state(pipeline_id.0)
+        {
+            log::error!("{pipeline_error:?}");
+        };
+
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(pipeline_id.0) else {
+            return Ok(());
+        };
+
+        let Some(settings_binding) = settings_uniforms.uniforms().binding() else {
+            return Ok(());
+        };
+
+        // flips the main texture, so the destination must be written.
+        let post_process = view_target.post_process_write();
+
+        let bind_group = render_context.render_device().create_bind_group(
+            "ratatui_camera_node_post_process_bind_group",
+            &post_process_pipeline.layout,
+            &BindGroupEntries::sequential((
+                post_process.source,
+                &post_process_pipeline.sampler,
+                settings_binding,
+            )),
+        );
+
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("ratatui_camera_node_post_process_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                resolve_target: None,
+                ops: Operations::default(),
+                depth_slice: None,
+            })],
+            ..default()
+        });
+
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[settings_index.index()]);
+        render_pass.draw(0..3, 0..1);
+
+        Ok(())
+    }
+}
+
+/// The post-process pipeline specialized for the format of a view's main texture.
+#[derive(Component)]
+pub struct RatatuiCameraPostProcessPipelineId<T>(CachedRenderPipelineId, PhantomData<T>);
+
+fn prepare_post_process_pipelines_system<T: RatatuiCameraPostProcess>(
+    mut commands: Commands,
+    pipeline_cache: Res<PipelineCache>,
+    post_process_pipeline: Res<RatatuiCameraPostProcessPipeline<T>>,
+    mut pipelines: ResMut<SpecializedRenderPipelines<RatatuiCameraPostProcessPipeline<T>>>,
+    views: Query<(Entity, &ViewTarget), With<T>>,
+) {
+    if post_process_pipeline.shader.is_none() {
+        return;
+    }
+
+    for (entity, view_target) in &views {
+        let pipeline_id = pipelines.specialize(
+            &pipeline_cache,
+            &post_process_pipeline,
+            view_target.main_texture_format(),
+        );
+
+        commands
+            .entity(entity)
+            .insert(RatatuiCameraPostProcessPipelineId::<T>(
+                pipeline_id,
+                PhantomData,
+            ));
+    }
+}
+
+#[derive(Resource)]
+pub struct RatatuiCameraPostProcessPipeline<T> {
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    shader: Option<Handle<Shader>>,
+    fullscreen_shader: FullscreenShader,
+    marker: PhantomData<T>,
+}
+
+impl<T: RatatuiCameraPostProcess> FromWorld for RatatuiCameraPostProcessPipeline<T> {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let layout = render_device.create_bind_group_layout(
+            "ratatui_camera_node_post_process_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::FRAGMENT,
+                (
+                    // rendered texture
+                    texture_2d(TextureSampleType::Float { filterable: true }),
+                    sampler(SamplerBindingType::Filtering),
+                    // settings
+                    uniform_buffer::<T>(true),
+                ),
+            ),
+        );
+
+        let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+
+        let shader = match T::fragment_shader() {
+            ShaderRef::Handle(handle) => Some(handle),
+            ShaderRef::Path(path) => Some(world.load_asset(path)),
+            ShaderRef::Default => {
+                log::warn!(
+                    "RatatuiCameraPostProcess has no default shader, provide one through \
+                    fragment_shader(). Skipping the post-process."
+                );
+                None
+            }
+        };
+
+        let fullscreen_shader = world.resource::<FullscreenShader>().clone();
+
+        Self {
+            layout,
+            sampler,
+            shader,
+            fullscreen_shader,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: RatatuiCameraPostProcess> SpecializedRenderPipeline
+    for RatatuiCameraPostProcessPipeline<T>
+{
+    type Key = TextureFormat;
+
+    fn specialize(&self, format: Self::Key) -> RenderPipelineDescriptor {
+        RenderPipelineDescriptor {
+            label: Some("ratatui_camera_node_post_process_pipeline".into()),
+            layout: vec![self.layout.clone()],
+            vertex: self.fullscreen_shader.to_vertex_state(),
+            fragment: Some(FragmentState {
+                shader: self.shader.clone().unwrap_or_default(),
+                entry_point: Some("fragment".into()),
+                targets: vec![Some(ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+                ..default()
+            }),
+            ..default()
+        }
+    }
+}
@@ -15,18 +15,18 @@ use bevy::{
     prelude::*,
     render::{
         Render, RenderApp, RenderSystems,
-        extract_component::ExtractComponentPlugin,
+        extract_component::{ExtractComponent, ExtractComponentPlugin},
         render_asset::RenderAssets,
         render_graph::{
-            NodeRunError, RenderGraphContext, RenderGraphExt, RenderLabel, ViewNode, ViewNodeRunner,
+            NodeRunError, RenderGraphContext, RenderGraphExt, ViewNode, ViewNodeRunner,
         },
         render_resource::{
-            BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedPipelineState,
-            CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState, MultisampleState,
-            Operations, PipelineCache, PrimitiveState, RenderPassColorAttachment,
-            RenderPassDescriptor, RenderPipelineDescriptor, Sampler, SamplerBindingType,
-            SamplerDescriptor, ShaderStages, ShaderType, TextureFormat, TextureSampleType,
-            UniformBuffer,
+            BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, BlendComponent, BlendFactor,
+            BlendOperation, BlendState, CachedPipelineState, CachedRenderPipelineId,
+            ColorTargetState, ColorWrites, FragmentState, LoadOp, MultisampleState, Operations,
+            PipelineCache, PrimitiveState, RenderPassColorAttachment, RenderPassDescriptor,
+            RenderPipelineDescriptor, Sampler, SamplerBindingType, SamplerDescriptor, ShaderStages,
+            ShaderType, StoreOp, TextureFormat, TextureSampleType, UniformBuffer,
             binding_types::{
                 sampler, texture_2d, texture_depth_2d, uniform_buffer, uniform_buffer_sized,
             },
@@ -38,16 +38,27 @@ use bevy::{
     },
 };
 
-use crate::{RatatuiCameraEdgeDetection, camera_readback::RatatuiSobelSender};
+use crate::{
+    EdgeThicknessUnit, RatatuiCamera, RatatuiCameraEdgeDetection, RatatuiCameraFontMetrics,
+    camera::RatatuiCameraLastArea,
+    camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind},
+    camera_readback::{RatatuiSobelIntermediate, RatatuiSobelSender},
+};
 
 pub struct RatatuiCameraNodeSobelPlugin;
 
 impl Plugin for RatatuiCameraNodeSobelPlugin {
     fn build(&self, app: &mut App) {
         embedded_asset!(app, "src/", "shaders/sobel.wgsl");
+        embedded_asset!(app, "src/", "shaders/sobel_downsample.wgsl");
 
-        app.add_plugins(ExtractComponentPlugin::<RatatuiCameraEdgeDetection>::default());
+        app.add_plugins((
+            ExtractComponentPlugin::<RatatuiCameraEdgeDetection>::default(),
+            ExtractComponentPlugin::<RatatuiCameraEdgePixelThickness>::default(),
+        ))
+        .add_systems(PostUpdate, update_edge_pixel_thickness_system);
 
+        let label = RatatuiCameraInstance::of(app).label(RatatuiCameraNodeKind::Sobel);
         let render_app = app.sub_app_mut(RenderApp);
 
         render_app.add_systems(
@@ -56,17 +67,15 @@ impl Plugin for RatatuiCameraNodeSobelPlugin {
         );
 
         render_app
-            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeSobel>>(
-                Core3d,
-                RatatuiCameraNodeSobelLabel,
-            )
-            .add_render_graph_edge(Core3d, Node3d::EndMainPass, RatatuiCameraNodeSobelLabel);
+            .add_render_graph_node::<ViewNodeRunner<RatatuiCameraNodeSobel>>(Core3d, label)
+            .add_render_graph_edge(Core3d, Node3d::EndMainPass, label);
     }
 
     fn finish(&self, app: &mut App) {
         let render_app = app.sub_app_mut(RenderApp);
         render_app
             .init_resource::<RatatuiCameraNodeSobelPipeline>()
+            .init_resource::<RatatuiCameraNodeSobelDownsamplePipeline>()
             .init_resource::<RatatuiCameraEdgeDetectionBuffers>();
     }
 }
@@ -74,27 +83,30 @@ impl Plugin for RatatuiCameraNodeSobelPlugin {
 #[derive(Default)]
 pub struct RatatuiCameraNodeSobel;
 
-#[derive(RenderLabel, Clone, Debug, Eq, Hash, PartialEq)]
-pub struct RatatuiCameraNodeSobelLabel;
-
 impl ViewNode for RatatuiCameraNodeSobel {
     type ViewQuery = (
         &'static MainEntity,
         &'static ViewTarget,
         &'static ViewPrepassTextures,
         &'static ViewUniformOffset,
+        &'static RatatuiCameraEdgeDetection,
         &'static RatatuiSobelSender,
+        Option<&'static RatatuiSobelIntermediate>,
     );
 
     fn run<'w>(
         &self,
         _graph: &mut RenderGraphContext<'_>,
         render_context: &mut RenderContext<'w>,
-        (entity, view_target, prepass_textures, view_uniform_offset, sobel_sender): QueryItem<
-            'w,
-            '_,
-            Self::ViewQuery,
-        >,
+        (
+            entity,
+            view_target,
+            prepass_textures,
+            view_uniform_offset,
+            edge_detection,
+            sobel_sender,
+            sobel_intermediate,
+        ): QueryItem<'w, '_, Self::ViewQuery>,
         world: &'w World,
     ) -> Result<(), NodeRunError> {
         let gpu_images = world.get_resource::<RenderAssets<GpuImage>>().unwrap();
@@ -117,7 +129,21 @@ impl ViewNode for RatatuiCameraNodeSobel {
         };
 
         let source = view_target.main_texture_view();
-        let destination = gpu_images.get(&sobel_sender.sender_image).unwrap();
+        let Some(sender_image) = gpu_images.get(&sobel_sender.sender_image) else {
+            return Ok(());
+        };
+
+        // with edge downsampling, edges are detected at full resolution into the intermediate
+        // texture, which is then downsampled into the sender's image.
+        let destination = match sobel_intermediate {
+            Some(intermediate) => {
+                let Some(intermediate_image) = gpu_images.get(&**intermediate) else {
+                    return Ok(());
+                };
+                intermediate_image
+            }
+            None => sender_image,
+        };
         let view_uniforms = world.resource::<ViewUniforms>();
 
         let (Some(depth_prepass), Some(normal_prepass)) = (
@@ -149,7 +175,11 @@ impl ViewNode for RatatuiCameraNodeSobel {
             color_attachments: &[Some(RenderPassColorAttachment {
                 view: &destination.texture_view,
                 resolve_target: None,
-                ops: Operations::default(),
+                // the previous frame's edges are kept, to be blended with the current frame's.
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
                 depth_slice: None,
             })],
             ..default()
@@ -157,7 +187,49 @@ impl ViewNode for RatatuiCameraNodeSobel {
 
         render_pass.set_render_pipeline(pipeline);
         render_pass.set_bind_group(0, &bind_group, &[view_uniform_offset.offset]);
+
+        let smoothing = edge_detection.temporal_smoothing.clamp(0.0, 0.99);
+        render_pass.set_blend_constant(LinearRgba::new(smoothing, smoothing, smoothing, smoothing));
+
         render_pass.draw(0..3, 0..1);
+        drop(render_pass);
+
+        if sobel_intermediate.is_some() {
+            let downsample_pipeline = world.resource::<RatatuiCameraNodeSobelDownsamplePipeline>();
+
+            if let CachedPipelineState::Err(pipeline_error) =
+                pipeline_cache.get_render_pipeline_state(downsample_pipeline.pipeline_id)
+            {
+                log::error!("{pipeline_error:?}");
+            };
+
+            let Some(pipeline) =
+                pipeline_cache.get_render_pipeline(downsample_pipeline.pipeline_id)
+            else {
+                return Ok(());
+            };
+
+            let bind_group = render_context.render_device().create_bind_group(
+                "ratatui_camera_node_sobel_downsample_bind_group",
+                &downsample_pipeline.layout,
+                &BindGroupEntries::single(&destination.texture_view),
+            );
+
+            let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+                label: Some("ratatui_camera_node_sobel_downsample_pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &sender_image.texture_view,
+                    resolve_target: None,
+                    ops: Operations::default(),
+                    depth_slice: None,
+                })],
+                ..default()
+            });
+
+            render_pass.set_render_pipeline(pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
 
         Ok(())
     }
@@ -172,6 +244,8 @@ pub struct RatatuiCameraNodeSobelConfig {
     depth_threshold: f32,
     normal_enabled: u32,
     normal_threshold: f32,
+    softness: f32,
+    kernel: [Vec4; 9],
 }
 
 impl From<&RatatuiCameraEdgeDetection> for RatatuiCameraNodeSobelConfig {
@@ -184,6 +258,49 @@ impl From<&RatatuiCameraEdgeDetection> for RatatuiCameraNodeSobelConfig {
             depth_threshold: value.depth_threshold,
             normal_enabled: value.normal_enabled.into(),
             normal_threshold: value.normal_threshold,
+            softness: value.softness,
+            kernel: value.kernel.directional_weights(),
+        }
+    }
+}
+
+/// Edge thickness in render texture pixels, resolved from the edge detection's thickness and
+/// thickness unit.
+#[derive(Component, ExtractComponent, Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraEdgePixelThickness(f32);
+
+fn update_edge_pixel_thickness_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<(
+        Entity,
+        &RatatuiCamera,
+        &RatatuiCameraLastArea,
+        &RatatuiCameraEdgeDetection,
+        Option<&RatatuiCameraEdgePixelThickness>,
+    )>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    for (entity, ratatui_camera, last_area, edge_detection, pixel_thickness) in &ratatui_cameras {
+        let thickness = match edge_detection.thickness_unit {
+            EdgeThicknessUnit::Pixels => edge_detection.thickness,
+            EdgeThicknessUnit::Cells => {
+                let render_width = ratatui_camera
+                    .render_area_size(**last_area, &font_metrics)
+                    .x as f32;
+
+                let pixels_per_cell = if render_width > 0. {
+                    ratatui_camera.dimensions.x as f32 / render_width
+                } else {
+                    2.
+                };
+
+                edge_detection.thickness * pixels_per_cell
+            }
+        };
+
+        let pixel_thickness_new = RatatuiCameraEdgePixelThickness(thickness);
+        if pixel_thickness != Some(&pixel_thickness_new) {
+            commands.entity(entity).insert(pixel_thickness_new);
         }
     }
 }
@@ -196,11 +313,18 @@ pub struct RatatuiCameraEdgeDetectionBuffers {
 fn prepare_config_buffer_system(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
-    mut ratatui_cameras: Query<(&MainEntity, &RatatuiCameraEdgeDetection)>,
+    mut ratatui_cameras: Query<(
+        &MainEntity,
+        &RatatuiCameraEdgeDetection,
+        Option<&RatatuiCameraEdgePixelThickness>,
+    )>,
     mut config_buffers: ResMut<RatatuiCameraEdgeDetectionBuffers>,
 ) {
-    for (entity_id, edge_detection) in &mut ratatui_cameras {
-        let config = RatatuiCameraNodeSobelConfig::from(edge_detection);
+    for (entity_id, edge_detection, pixel_thickness) in &mut ratatui_cameras {
+        let mut config = RatatuiCameraNodeSobelConfig::from(edge_detection);
+        if let Some(RatatuiCameraEdgePixelThickness(thickness)) = pixel_thickness {
+            config.thickness = *thickness;
+        }
 
         let buffer = config_buffers.buffers.entry(*entity_id).or_default();
         buffer.set(config);
@@ -208,6 +332,12 @@ fn prepare_config_buffer_system(
     }
 }
 
+const TEMPORAL_SMOOTHING_BLEND: BlendComponent = BlendComponent {
+    src_factor: BlendFactor::OneMinusConstant,
+    dst_factor: BlendFactor::Constant,
+    operation: BlendOperation::Add,
+};
+
 #[derive(Resource)]
 struct RatatuiCameraNodeSobelPipeline {
     layout: BindGroupLayout,
@@ -265,7 +395,12 @@ impl FromWorld for RatatuiCameraNodeSobelPipeline {
                 entry_point: Some("fragment".into()),
                 targets: vec![Some(ColorTargetState {
                     format: TextureFormat::bevy_default(),
-                    blend: None,
+                    // blends with the previous frame's edges by the blend constant (set from
+                    // the temporal smoothing), which at zero simply replaces them.
+                    blend: Some(BlendState {
+                        color: TEMPORAL_SMOOTHING_BLEND,
+                        alpha: TEMPORAL_SMOOTHING_BLEND,
+                    }),
                     write_mask: ColorWrites::ALL,
                 })],
             }),
@@ -283,3 +418,58 @@ impl FromWorld for RatatuiCameraNodeSobelPipeline {
         }
     }
 }
+
+#[derive(Resource)]
+struct RatatuiCameraNodeSobelDownsamplePipeline {
+    layout: BindGroupLayout,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+impl FromWorld for RatatuiCameraNodeSobelDownsamplePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+
+        let layout = render_device.create_bind_group_layout(
+            "ratatui_camera_node_sobel_downsample_bind_group_layout",
+            &BindGroupLayoutEntries::single(
+                ShaderStages::FRAGMENT,
+                // full resolution edges
+                texture_2d(TextureSampleType::Float { filterable: false }),
+            ),
+        );
+
+        let path = Path::new("bevy_ratatui_camera").join("shaders/sobel_downsample.wgsl");
+        let source = AssetSourceId::from("embedded");
+        let asset_path = AssetPath::from_path(&path).with_source(source);
+        let shader_handle: Handle<Shader> = world.load_asset(asset_path);
+
+        let vertex_state = world.resource::<FullscreenShader>().to_vertex_state();
+        let pipeline_cache = world.resource_mut::<PipelineCache>();
+
+        let pipeline_id = pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+            label: Some("ratatui_camera_node_sobel_downsample_pipeline".into()),
+            layout: vec![layout.clone()],
+            vertex: vertex_state,
+            fragment: Some(FragmentState {
+                shader: shader_handle,
+                shader_defs: vec![],
+                entry_point: Some("fragment".into()),
+                targets: vec![Some(ColorTargetState {
+                    format: TextureFormat::bevy_default(),
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            primitive: PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            push_constant_ranges: vec![],
+            zero_initialize_workgroup_memory: true,
+        });
+
+        Self {
+            layout,
+            pipeline_id,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use bevy::prelude::*;
+
+use crate::{RatatuiCamera, RatatuiCameraLayers, RatatuiCameraLowLatencySet, RatatuiSubcameras};
+
+/// When spawned with a RatatuiCamera, the camera (and any of its subcameras and layers) only
+/// renders when a render is requested, rather than every frame. While no render is requested the
+/// camera is inactive, so static scenes (menus, paused views, etcetera) stop using GPU time and
+/// readback bandwidth, and the widget keeps drawing the last image it received.
+///
+/// A render is requested when the component is inserted and whenever the camera is resized, and
+/// can be requested manually with [RatatuiCameraOnDemand::request_render] when the scene changes.
+/// Note that this takes control of the `is_active` field of the camera's `Camera` component.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraOnDemand};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraOnDemand::default(),
+///     Camera3d::default(),
+/// ));
+/// # };
+/// #
+/// # #[derive(Component)]
+/// # struct Selection;
+/// #
+/// fn invalidate_system(
+///     selection: Query<(), Changed<Selection>>,
+///     mut on_demand: Single<&mut RatatuiCameraOnDemand>,
+/// ) {
+///     if !selection.is_empty() {
+///         on_demand.request_render();
+///     }
+/// }
+/// ```
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiCameraOnDemand {
+    /// Whether the camera will render this frame. Reset once the frame is rendered.
+    pub render_requested: bool,
+}
+
+impl Default for RatatuiCameraOnDemand {
+    fn default() -> Self {
+        Self {
+            render_requested: true,
+        }
+    }
+}
+
+impl RatatuiCameraOnDemand {
+    /// Render the camera's scene this frame, e.g. after something in the scene has changed.
+    pub fn request_render(&mut self) {
+        self.render_requested = true;
+    }
+}
+
+/// Insert into a RatatuiCamera entity to pause it, and remove to resume it. While paused, the
+/// camera (and any of its subcameras and layers) stops rendering and reading back images, and its
+/// widget is no longer regenerated each frame, so the widget keeps drawing the last image it
+/// received. Useful for cheaply backgrounding a view without blanking it.
+///
+/// The widget is not resized while paused, so if the area it is drawn in changes, it is skipped
+/// until the camera is resumed. Like [RatatuiCameraOnDemand], this takes control of the
+/// `is_active` field of the camera's `Camera` component.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraPaused};
+/// #
+/// # fn toggle_pause_system(
+/// #     mut commands: Commands,
+/// #     camera: Single<(Entity, Has<RatatuiCameraPaused>), With<RatatuiCamera>>,
+/// # ) {
+/// let (entity, paused) = *camera;
+/// if paused {
+///     commands.entity(entity).remove::<RatatuiCameraPaused>();
+/// } else {
+///     commands.entity(entity).insert(RatatuiCameraPaused);
+/// }
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RatatuiCameraPaused;
+
+pub struct RatatuiCameraOnDemandPlugin;
+
+impl Plugin for RatatuiCameraOnDemandPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(ratatui_camera_paused_insert_observer)
+            .add_observer(ratatui_camera_paused_removal_observer)
+            .add_systems(
+                PostUpdate,
+                update_on_demand_cameras_system.after(RatatuiCameraLowLatencySet),
+            );
+    }
+}
+
+/// The camera entity along with its subcameras and layers, which render alongside it.
+fn related_cameras(
+    entity: Entity,
+    subcameras: Option<&RatatuiSubcameras>,
+    layers: Option<&RatatuiCameraLayers>,
+) -> impl Iterator<Item = Entity> {
+    let subcameras = subcameras
+        .into_iter()
+        .flat_map(|subcameras| subcameras.iter());
+    let layers = layers.into_iter().flat_map(|layers| layers.iter());
+
+    std::iter::once(entity).chain(subcameras).chain(layers)
+}
+
+fn set_cameras_active(
+    cameras: &mut Query<&mut Camera>,
+    entities: impl Iterator<Item = Entity>,
+    active: bool,
+) {
+    for entity in entities {
+        if let Ok(mut camera) = cameras.get_mut(entity)
+            && camera.is_active != active
+        {
+            camera.is_active = active;
+        }
+    }
+}
+
+fn ratatui_camera_paused_insert_observer(
+    insert: On<Insert, RatatuiCameraPaused>,
+    ratatui_cameras: Query<(Option<&RatatuiSubcameras>, Option<&RatatuiCameraLayers>)>,
+    mut cameras: Query<&mut Camera>,
+) -> Result {
+    let (subcameras, layers) = ratatui_cameras.get(insert.entity)?;
+    set_cameras_active(
+        &mut cameras,
+        related_cameras(insert.entity, subcameras, layers),
+        false,
+    );
+
+    Ok(())
+}
+
+/// Resumes rendering, or for on-demand cameras, requests a render to catch up with any changes
+/// made while paused.
+fn ratatui_camera_paused_removal_observer(
+    remove: On<Remove, RatatuiCameraPaused>,
+    mut ratatui_cameras: Query<(
+        Option<&mut RatatuiCameraOnDemand>,
+        Option<&RatatuiSubcameras>,
+        Option<&RatatuiCameraLayers>,
+    )>,
+    mut cameras: Query<&mut Camera>,
+) -> Result {
+    let (on_demand, subcameras, layers) = ratatui_cameras.get_mut(remove.entity)?;
+
+    match on_demand {
+        Some(mut on_demand) => on_demand.request_render(),
+        None => set_cameras_active(
+            &mut cameras,
+            related_cameras(remove.entity, subcameras, layers),
+            true,
+        ),
+    }
+
+    Ok(())
+}
+
+/// Activates on-demand cameras for the frame if a render was requested (or the camera was resized),
+/// and deactivates them otherwise. Paused cameras are left inactive until they are resumed.
+fn update_on_demand_cameras_system(
+    mut on_demand_cameras: Query<
+        (
+            Entity,
+            &mut RatatuiCameraOnDemand,
+            Ref<RatatuiCamera>,
+            Option<&RatatuiSubcameras>,
+            Option<&RatatuiCameraLayers>,
+        ),
+        Without<RatatuiCameraPaused>,
+    >,
+    mut cameras: Query<&mut Camera>,
+) {
+    for (entity, mut on_demand, ratatui_camera, subcameras, layers) in &mut on_demand_cameras {
+        let active = on_demand.render_requested || ratatui_camera.is_changed();
+        if on_demand.render_requested {
+            on_demand.render_requested = false;
+        }
+
+        set_cameras_active(
+            &mut cameras,
+            related_cameras(entity, subcameras, layers),
+            active,
+        );
+    }
+}
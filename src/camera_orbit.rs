@@ -0,0 +1,234 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use bevy_ratatui::event::{KeyMessage, MouseMessage};
+use crossterm::event::{KeyCode, KeyEventKind, MouseButton as TerminalMouseButton, MouseEventKind};
+use ratatui::layout::Position;
+
+use crate::{RatatuiCameraFocus, RatatuiCameraLastArea, RatatuiCameraWidget};
+
+/// Insert into an entity with a `RatatuiCamera` to inspect the scene interactively with
+/// bevy_ratatui input events: drag with the left mouse button to orbit around `focus`, scroll to
+/// zoom towards or away from it, and press `W`/`A`/`S`/`D` to pan the camera and its focus
+/// together. Requires the `orbit` feature, and bevy_ratatui's mouse capture for orbiting and
+/// zooming.
+///
+/// Speeds are per terminal cell rather than per pixel, as a mouse in the terminal moves a whole
+/// cell at a time. Rows are about twice as tall as columns, so vertical drags orbit twice as far
+/// per row as horizontal drags do per column. Mouse input is only handled over the area the
+/// camera's widget was last drawn within, so each camera in a layout can be orbited separately,
+/// and once a camera has been focused (see [RatatuiCameraFocus]) only that camera is panned.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiOrbitController};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     Camera3d::default(),
+///     Transform::from_xyz(2.5, 2.5, 2.5).looking_at(Vec3::ZERO, Vec3::Z),
+///     RatatuiOrbitController::new(Vec3::ZERO).with_up(Dir3::Z),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiOrbitController {
+    /// Point the camera orbits around and looks at.
+    pub focus: Vec3,
+
+    /// Axis the camera orbits around horizontally, also used as the camera's up direction.
+    pub up: Dir3,
+
+    /// Angle (in radians) the camera orbits per column dragged.
+    pub orbit_speed: f32,
+
+    /// Fraction of the distance to `focus` the camera moves per scroll step.
+    pub zoom_speed: f32,
+
+    /// Fraction of the distance to `focus` the camera pans per key press.
+    pub pan_speed: f32,
+
+    /// Closest the camera can zoom in to `focus`.
+    pub min_distance: f32,
+
+    /// Furthest the camera can zoom out from `focus`.
+    pub max_distance: f32,
+
+    drag: Option<IVec2>,
+}
+
+impl Default for RatatuiOrbitController {
+    fn default() -> Self {
+        Self {
+            focus: Vec3::ZERO,
+            up: Dir3::Y,
+            orbit_speed: 0.05,
+            zoom_speed: 0.1,
+            pan_speed: 0.05,
+            min_distance: 0.1,
+            max_distance: 1000.,
+            drag: None,
+        }
+    }
+}
+
+impl RatatuiOrbitController {
+    /// Orbit around the provided point.
+    pub fn new(focus: Vec3) -> Self {
+        Self { focus, ..default() }
+    }
+
+    /// Set [RatatuiOrbitController::up].
+    pub fn with_up(mut self, up: Dir3) -> Self {
+        self.up = up;
+        self
+    }
+
+    /// Set [RatatuiOrbitController::orbit_speed].
+    pub fn with_orbit_speed(mut self, orbit_speed: f32) -> Self {
+        self.orbit_speed = orbit_speed;
+        self
+    }
+
+    /// Set [RatatuiOrbitController::zoom_speed].
+    pub fn with_zoom_speed(mut self, zoom_speed: f32) -> Self {
+        self.zoom_speed = zoom_speed;
+        self
+    }
+
+    /// Set [RatatuiOrbitController::pan_speed].
+    pub fn with_pan_speed(mut self, pan_speed: f32) -> Self {
+        self.pan_speed = pan_speed;
+        self
+    }
+
+    /// Set [RatatuiOrbitController::min_distance] and [RatatuiOrbitController::max_distance].
+    pub fn with_distance_limits(mut self, min_distance: f32, max_distance: f32) -> Self {
+        self.min_distance = min_distance;
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Orbit the camera by a number of cells dragged.
+    fn orbit(&self, transform: &mut Transform, cells: IVec2) {
+        let offset = transform.translation - self.focus;
+
+        // rows are about twice as tall as columns, so orbit twice as far per row.
+        let pitch = offset
+            .normalize_or_zero()
+            .dot(*self.up)
+            .clamp(-1., 1.)
+            .asin();
+        let max_pitch = FRAC_PI_2 - 0.01;
+        let pitch_delta =
+            (pitch + cells.y as f32 * self.orbit_speed * 2.).clamp(-max_pitch, max_pitch) - pitch;
+        let pitch_rotation = Dir3::new(offset.cross(*self.up))
+            .map(|axis| Quat::from_axis_angle(*axis, pitch_delta))
+            .unwrap_or_default();
+
+        let yaw_rotation = Quat::from_axis_angle(*self.up, -cells.x as f32 * self.orbit_speed);
+
+        transform.translation = self.focus + yaw_rotation * pitch_rotation * offset;
+        transform.look_at(self.focus, self.up);
+    }
+
+    /// Zoom the camera by a number of scroll steps, positive being away from the focus.
+    fn zoom(&self, transform: &mut Transform, steps: f32) {
+        let offset = transform.translation - self.focus;
+        let distance = (offset.length() * (1. + self.zoom_speed).powf(steps))
+            .clamp(self.min_distance, self.max_distance);
+
+        transform.translation = self.focus + offset.normalize_or(*transform.back()) * distance;
+        transform.look_at(self.focus, self.up);
+    }
+
+    /// Pan the camera and its focus along the camera's right and up directions.
+    fn pan(&mut self, transform: &mut Transform, direction: Vec2) {
+        let distance = transform.translation.distance(self.focus);
+        let translation = (transform.right() * direction.x + transform.up() * direction.y)
+            * self.pan_speed
+            * distance;
+
+        self.focus += translation;
+        transform.translation += translation;
+    }
+}
+
+pub struct RatatuiOrbitControllerPlugin;
+
+impl Plugin for RatatuiOrbitControllerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<KeyMessage>()
+            .add_message::<MouseMessage>()
+            .add_systems(Update, orbit_controller_system);
+    }
+}
+
+fn orbit_controller_system(
+    mut mouse_messages: MessageReader<MouseMessage>,
+    mut key_messages: MessageReader<KeyMessage>,
+    mut controllers: Query<(
+        Entity,
+        &mut RatatuiOrbitController,
+        &mut Transform,
+        &RatatuiCameraLastArea,
+        Option<&RatatuiCameraWidget>,
+    )>,
+    focus: Res<RatatuiCameraFocus>,
+) {
+    let mouse_messages = mouse_messages.read().collect::<Vec<_>>();
+    let pan_directions = key_messages
+        .read()
+        .filter(|message| message.kind != KeyEventKind::Release)
+        .filter_map(|message| match message.code {
+            KeyCode::Char('w') => Some(Vec2::Y),
+            KeyCode::Char('a') => Some(Vec2::NEG_X),
+            KeyCode::Char('s') => Some(Vec2::NEG_Y),
+            KeyCode::Char('d') => Some(Vec2::X),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if mouse_messages.is_empty() && pan_directions.is_empty() {
+        return;
+    }
+
+    for (entity, mut controller, mut transform, last_area, widget) in &mut controllers {
+        for message in &mouse_messages {
+            let cell = IVec2::new(message.column as i32, message.row as i32);
+            let position = Position::new(message.column, message.row);
+            let hovered =
+                widget.is_some_and(|widget| widget.drawn_area_contains(**last_area, position));
+
+            match message.kind {
+                MouseEventKind::Down(TerminalMouseButton::Left) if hovered => {
+                    controller.drag = Some(cell);
+                }
+                MouseEventKind::Drag(TerminalMouseButton::Left) => {
+                    if let Some(previous) = controller.drag {
+                        controller.orbit(&mut transform, cell - previous);
+                        controller.drag = Some(cell);
+                    }
+                }
+                MouseEventKind::Up(_) => {
+                    controller.drag = None;
+                }
+                MouseEventKind::ScrollUp if hovered => controller.zoom(&mut transform, -1.),
+                MouseEventKind::ScrollDown if hovered => controller.zoom(&mut transform, 1.),
+                _ => {}
+            }
+        }
+
+        if !focus.focused().is_none_or(|focused| focused == entity) {
+            continue;
+        }
+
+        for direction in &pan_directions {
+            controller.pan(&mut transform, *direction);
+        }
+    }
+}
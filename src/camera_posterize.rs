@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use bevy::{
+    asset::{AssetPath, embedded_asset, io::AssetSourceId},
+    prelude::*,
+    render::{extract_component::ExtractComponent, render_resource::ShaderType},
+    shader::ShaderRef,
+};
+
+use crate::{
+    RatatuiCamera, RatatuiCameraFontMetrics, RatatuiCameraPostProcess,
+    RatatuiCameraPostProcessPlugin, camera::RatatuiCameraLastArea,
+};
+
+pub struct RatatuiCameraPosterizePlugin;
+
+impl Plugin for RatatuiCameraPosterizePlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "src/", "shaders/posterize.wgsl");
+
+        app.add_plugins(RatatuiCameraPostProcessPlugin::<
+            RatatuiCameraPosterizeSettings,
+        >::default());
+
+        app.add_observer(ratatui_posterize_removal_observer)
+            .add_systems(PostUpdate, update_posterize_settings_system);
+    }
+}
+
+/// When spawned with a RatatuiCamera, the camera's image is pixelated to the grid of terminal
+/// cells it will be drawn into, and its colors are reduced to a limited number of levels per
+/// channel, on the GPU before the image is read back. Every cell is then converted from a single
+/// flat color, giving consistently "chunky" output without cells flickering between neighboring
+/// colors as the scene moves.
+///
+/// The grid is recalculated whenever the area the camera is drawn within changes, so it lines up
+/// with the cells the image is converted into.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraPosterize};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraPosterize {
+///         levels: 6,
+///         // keep the upper and lower half of each cell distinct for the half blocks strategy.
+///         cell_subdivisions: UVec2::new(1, 2),
+///         ..default()
+///     },
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RatatuiCameraPosterize {
+    /// Number of levels each color channel is reduced to (at least `2`).
+    pub levels: u32,
+
+    /// Pixelate the image to the cell grid. When false, only the colors are reduced.
+    pub pixelate: bool,
+
+    /// Number of blocks each terminal cell is split into horizontally and vertically when
+    /// pixelating.
+    pub cell_subdivisions: UVec2,
+}
+
+impl Default for RatatuiCameraPosterize {
+    fn default() -> Self {
+        Self {
+            levels: 4,
+            pixelate: true,
+            cell_subdivisions: UVec2::ONE,
+        }
+    }
+}
+
+/// Settings for the posterize shader, as stored in a uniform buffer in the render world, resolved
+/// from the camera's [RatatuiCameraPosterize] and the area it is drawn within.
+#[derive(Component, ExtractComponent, ShaderType, Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraPosterizeSettings {
+    grid: Vec2,
+    levels: f32,
+    pixelate: u32,
+}
+
+impl RatatuiCameraPostProcess for RatatuiCameraPosterizeSettings {
+    fn fragment_shader() -> ShaderRef {
+        let path = Path::new("bevy_ratatui_camera").join("shaders/posterize.wgsl");
+        let source = AssetSourceId::from("embedded");
+        AssetPath::from_path(&path)
+            .with_source(source)
+            .into_owned()
+            .into()
+    }
+}
+
+fn update_posterize_settings_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<(
+        Entity,
+        &RatatuiCamera,
+        &RatatuiCameraLastArea,
+        &RatatuiCameraPosterize,
+        Option<&RatatuiCameraPosterizeSettings>,
+    )>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    for (entity, ratatui_camera, last_area, posterize, settings) in &ratatui_cameras {
+        // assumes two pixels per cell vertically until the camera is drawn.
+        let cells = match ratatui_camera.render_area_size(**last_area, &font_metrics) {
+            UVec2 { x: 0, .. } | UVec2 { y: 0, .. } => ratatui_camera.dimensions / UVec2::new(1, 2),
+            cells => cells,
+        };
+
+        let settings_new = RatatuiCameraPosterizeSettings {
+            grid: (cells * posterize.cell_subdivisions.max(UVec2::ONE))
+                .max(UVec2::ONE)
+                .as_vec2(),
+            levels: posterize.levels.max(2) as f32,
+            pixelate: posterize.pixelate.into(),
+        };
+
+        if settings != Some(&settings_new) {
+            commands.entity(entity).insert(settings_new);
+        }
+    }
+}
+
+fn ratatui_posterize_removal_observer(
+    remove: On<Remove, RatatuiCameraPosterize>,
+    mut commands: Commands,
+) {
+    commands
+        .entity(remove.entity)
+        .remove::<RatatuiCameraPosterizeSettings>();
+}
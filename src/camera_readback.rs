@@ -1,89 +1,234 @@
+use std::ops::Deref;
+
 use bevy::{
-    camera::RenderTarget,
-    core_pipeline::prepass::{DepthPrepass, NormalPrepass},
+    camera::{ImageRenderTarget, RenderTarget},
+    core_pipeline::{core_3d::DEPTH_TEXTURE_SAMPLING_SUPPORTED, prepass::NormalPrepass},
+    log::info_span,
+    math::FloatOrd,
     prelude::*,
     render::{
         Render, RenderApp, RenderSystems,
         extract_component::{ExtractComponent, ExtractComponentPlugin},
+        render_resource::TextureFormat,
         renderer::RenderDevice,
+        view::Hdr,
     },
 };
 
 use crate::{
-    RatatuiCamera, RatatuiCameraEdgeDetection, RatatuiCameraSet, RatatuiCameraStrategy,
-    RatatuiCameraWidget, RatatuiSubcamera, RatatuiSubcameras,
-    camera::{RatatuiCameraDepthDetection, RatatuiCameraLastArea},
+    RatatuiCamera, RatatuiCameraCellDiffing, RatatuiCameraDepthJoints, RatatuiCameraEdgeDetection,
+    RatatuiCameraEmissiveDetection, RatatuiCameraError, RatatuiCameraPaused, RatatuiCameraRecorder,
+    RatatuiCameraSet, RatatuiCameraSmoothResize, RatatuiCameraStrategy, RatatuiCameraUi,
+    RatatuiCameraWidget, RatatuiSubcamera, RatatuiSubcameras, ViewportConfig,
+    camera::{
+        RatatuiCameraEdgeColorDetection, RatatuiCameraLastArea, RatatuiCameraLatency,
+        RatatuiCameraLowLatencySet, RatatuiCameraMaskDetection, RatatuiCameraNormalDetection,
+    },
     camera_image_pipe::{
-        ImageReceiver, ImageSender, create_image_pipe, receive_image, send_image_buffer,
+        ImageBufferPool, ImageReceiver, ImageSender, create_image_pipe,
+        create_image_pipe_with_format, receive_image, send_image_buffer,
     },
+    character_support::RatatuiCameraCharacterSupport,
+    color_lut::{ColorLut, ImageColorLuts},
+    font_metrics::RatatuiCameraFontMetrics,
+    widget::{DrawState, SharedDrawState},
+    widget_depth_buffer::RatatuiCameraDepthBuffers,
 };
 
+#[cfg(any(feature = "depth", feature = "sobel"))]
+use bevy::core_pipeline::prepass::DepthPrepass;
+
+#[cfg(feature = "depth")]
+use crate::camera::{RatatuiCameraDepthDetection, RatatuiCameraDepthDownsampling};
+#[cfg(feature = "sobel")]
+use crate::camera_edge_detection::RatatuiCameraEdgeDownsampling;
+
 pub struct RatatuiCameraReadbackPlugin;
 
 impl Plugin for RatatuiCameraReadbackPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
             ExtractComponentPlugin::<RatatuiCameraSender>::default(),
+            #[cfg(feature = "depth")]
             ExtractComponentPlugin::<RatatuiDepthSender>::default(),
+            #[cfg(feature = "sobel")]
             ExtractComponentPlugin::<RatatuiSobelSender>::default(),
+            #[cfg(feature = "sobel")]
+            ExtractComponentPlugin::<RatatuiSobelIntermediate>::default(),
+            ExtractComponentPlugin::<RatatuiNormalSender>::default(),
+            ExtractComponentPlugin::<RatatuiEmissiveSender>::default(),
+            ExtractComponentPlugin::<RatatuiEdgeColorSender>::default(),
+            ExtractComponentPlugin::<RatatuiMaskSender>::default(),
         ))
+        .init_resource::<RatatuiCameraFontMetrics>()
+        .init_resource::<RatatuiCameraCharacterSupport>()
+        .init_resource::<RatatuiCameraLatency>()
+        .init_resource::<RatatuiCameraDepthBuffers>()
         .add_message::<CameraTargetingMessage>()
+        .add_message::<RatatuiCameraError>()
+        .add_message::<RatatuiCameraReady>()
         .add_observer(handle_ratatui_camera_insert_observer)
         .add_observer(handle_ratatui_subcamera_insert_observer)
-        .add_observer(ratatui_depth_readback_insert_observer)
-        .add_observer(handle_ratatui_edge_detection_insert_observer)
+        .add_observer(ratatui_normal_readback_insert_observer)
+        .add_observer(ratatui_emissive_readback_insert_observer)
+        .add_observer(ratatui_edge_color_readback_insert_observer)
+        .add_observer(ratatui_mask_readback_insert_observer)
         .add_observer(handle_ratatui_camera_removal_observer)
-        .add_observer(ratatui_depth_readback_removal_observer)
-        .add_observer(handle_ratatui_edge_detection_removal_observer)
+        .add_observer(ratatui_normal_readback_removal_observer)
+        .add_observer(ratatui_emissive_readback_removal_observer)
+        .add_observer(ratatui_edge_color_readback_removal_observer)
+        .add_observer(ratatui_mask_readback_removal_observer)
         .add_observer(resize_ratatui_camera_observer)
         .add_systems(
             First,
             (
-                create_ratatui_camera_widgets_system,
+                #[cfg(feature = "crossterm")]
+                crate::font_metrics::detect_font_metrics_system,
+                create_ratatui_camera_widgets_system.run_if(standard_latency),
                 handle_camera_targeting_messages_system,
+                report_depth_sampling_unsupported_system
+                    .run_if(|| !DEPTH_TEXTURE_SAMPLING_SUPPORTED),
                 (
                     update_ratatui_camera_readback_system,
+                    #[cfg(feature = "depth")]
                     update_ratatui_depth_readback_system,
+                    #[cfg(feature = "sobel")]
                     update_ratatui_edge_detection_readback_system,
+                    update_ratatui_normal_readback_system,
+                    update_ratatui_emissive_readback_system,
+                    update_ratatui_edge_color_readback_system,
+                    update_ratatui_mask_readback_system,
+                    (
+                        receive_camera_images_system,
+                        #[cfg(feature = "depth")]
+                        receive_depth_images_system,
+                        #[cfg(feature = "sobel")]
+                        receive_sobel_images_system,
+                        receive_normal_images_system,
+                        receive_emissive_images_system,
+                        receive_edge_color_images_system,
+                        receive_mask_images_system,
+                    )
+                        .run_if(standard_latency),
+                ),
+            )
+                .chain()
+                .in_set(RatatuiCameraSet),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                (
                     receive_camera_images_system,
+                    #[cfg(feature = "depth")]
                     receive_depth_images_system,
+                    #[cfg(feature = "sobel")]
                     receive_sobel_images_system,
+                    receive_normal_images_system,
+                    receive_emissive_images_system,
+                    receive_edge_color_images_system,
+                    receive_mask_images_system,
                 ),
+                create_ratatui_camera_widgets_system,
             )
                 .chain()
-                .in_set(RatatuiCameraSet),
+                .in_set(RatatuiCameraLowLatencySet)
+                .run_if(not(standard_latency)),
         );
 
+        #[cfg(feature = "depth")]
+        app.add_observer(ratatui_depth_readback_insert_observer)
+            .add_observer(ratatui_depth_downsampling_insert_observer)
+            .add_observer(ratatui_depth_readback_removal_observer)
+            .add_observer(ratatui_depth_downsampling_removal_observer);
+
+        #[cfg(feature = "sobel")]
+        app.add_observer(handle_ratatui_edge_detection_insert_observer)
+            .add_observer(ratatui_edge_downsampling_insert_observer)
+            .add_observer(handle_ratatui_edge_detection_removal_observer)
+            .add_observer(ratatui_edge_downsampling_removal_observer);
+
         let render_app = app.sub_app_mut(RenderApp);
         render_app.add_systems(
             Render,
             (
                 send_camera_images_system,
+                #[cfg(feature = "depth")]
                 send_depth_images_system,
+                #[cfg(feature = "sobel")]
                 send_sobel_images_system,
+                send_normal_images_system,
+                send_emissive_images_system,
+                send_edge_color_images_system,
+                send_mask_images_system,
             )
                 .after(RenderSystems::Render),
         );
     }
+
+    fn finish(&self, app: &mut App) {
+        app.init_resource::<ImageBufferPool>();
+    }
 }
 
 #[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
 pub struct RatatuiCameraSender(ImageSender);
 
 #[derive(Component, Deref, DerefMut, Debug)]
-pub struct RatatuiCameraReceiver(ImageReceiver);
+pub struct RatatuiCameraReceiver(pub(crate) ImageReceiver);
 
+// still queried by the readback node when the feature is disabled, but never inserted.
+#[cfg_attr(not(feature = "sobel"), allow(dead_code))]
 #[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
 pub struct RatatuiSobelSender(ImageSender);
 
 #[derive(Component, Deref, DerefMut, Debug)]
-pub struct RatatuiSobelReceiver(ImageReceiver);
+pub struct RatatuiSobelReceiver(pub(crate) ImageReceiver);
+
+/// Full resolution texture edges are detected into before being downsampled into the
+/// [RatatuiSobelSender]'s image, present while [RatatuiCameraEdgeDownsampling] is enabled.
+#[cfg_attr(not(feature = "sobel"), allow(dead_code))]
+#[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
+pub struct RatatuiSobelIntermediate(Handle<Image>);
 
+#[cfg_attr(not(feature = "depth"), allow(dead_code))]
 #[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
 pub struct RatatuiDepthSender(ImageSender);
 
 #[derive(Component, Deref, DerefMut, Debug)]
-pub struct RatatuiDepthReceiver(ImageReceiver);
+pub struct RatatuiDepthReceiver(pub(crate) ImageReceiver);
+
+#[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
+pub struct RatatuiNormalSender(ImageSender);
+
+#[derive(Component, Deref, DerefMut, Debug)]
+pub struct RatatuiNormalReceiver(pub(crate) ImageReceiver);
+
+#[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
+pub struct RatatuiEmissiveSender(ImageSender);
+
+#[derive(Component, Deref, DerefMut, Debug)]
+pub struct RatatuiEmissiveReceiver(pub(crate) ImageReceiver);
+
+#[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
+pub struct RatatuiEdgeColorSender(ImageSender);
+
+#[derive(Component, Deref, DerefMut, Debug)]
+pub struct RatatuiEdgeColorReceiver(pub(crate) ImageReceiver);
+
+#[derive(Component, ExtractComponent, Deref, DerefMut, Clone, Debug)]
+pub struct RatatuiMaskSender(ImageSender);
+
+#[derive(Component, Deref, DerefMut, Debug)]
+pub struct RatatuiMaskReceiver(pub(crate) ImageReceiver);
+
+/// Message written when a RatatuiCamera's widget first holds an image actually rendered by the
+/// camera (see [RatatuiCameraWidget::is_ready]), including again after the camera is resized.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RatatuiCameraReady {
+    /// The RatatuiCamera entity.
+    pub entity: Entity,
+}
 
 #[derive(Message, Debug)]
 pub struct CameraTargetingMessage {
@@ -97,14 +242,14 @@ fn handle_ratatui_camera_insert_observer(
     ratatui_cameras: Query<&RatatuiCamera>,
     mut camera_targeting_messages: MessageWriter<CameraTargetingMessage>,
     mut image_assets: ResMut<Assets<Image>>,
-    render_device: Res<RenderDevice>,
+    buffer_pool: Res<ImageBufferPool>,
 ) {
     if let Ok(ratatui_camera) = ratatui_cameras.get(insert.entity) {
         insert_camera_readback_components(
             commands.reborrow(),
             insert.entity,
             &mut image_assets,
-            &render_device,
+            &buffer_pool,
             ratatui_camera,
             &mut camera_targeting_messages,
         );
@@ -116,45 +261,187 @@ fn handle_ratatui_subcamera_insert_observer(
     mut ratatui_subcameras: Query<&RatatuiSubcamera>,
     mut camera_targeting_messages: MessageWriter<CameraTargetingMessage>,
 ) {
-    let RatatuiSubcamera(target_entity) = ratatui_subcameras.get_mut(insert.entity).unwrap();
+    let subcamera = ratatui_subcameras.get_mut(insert.entity).unwrap();
 
     camera_targeting_messages.write(CameraTargetingMessage {
         targeter_entity: insert.entity,
-        target_entity: *target_entity,
+        target_entity: subcamera.target,
     });
 }
 
+#[cfg(feature = "depth")]
 fn ratatui_depth_readback_insert_observer(
     insert: On<Insert, RatatuiCameraDepthDetection>,
     mut commands: Commands,
-    ratatui_cameras: Query<&RatatuiCamera>,
+    ratatui_cameras: Query<(
+        &RatatuiCamera,
+        &RatatuiCameraLastArea,
+        Has<RatatuiCameraDepthDownsampling>,
+    )>,
     mut image_assets: ResMut<Assets<Image>>,
-    render_device: Res<RenderDevice>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
 ) {
-    if let Ok(ratatui_camera) = ratatui_cameras.get(insert.entity) {
+    if let Ok((ratatui_camera, last_area, downsampling)) = ratatui_cameras.get(insert.entity) {
+        insert_camera_depth_readback_components(
+            commands.reborrow(),
+            insert.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+            downsampling.then(|| downsampled_dimensions(ratatui_camera, last_area, &font_metrics)),
+        );
+    }
+}
+
+#[cfg(feature = "depth")]
+fn ratatui_depth_downsampling_insert_observer(
+    insert: On<Insert, RatatuiCameraDepthDownsampling>,
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (&RatatuiCamera, &RatatuiCameraLastArea),
+        With<RatatuiCameraDepthDetection>,
+    >,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    if let Ok((ratatui_camera, last_area)) = ratatui_cameras.get(insert.entity) {
         insert_camera_depth_readback_components(
             commands.reborrow(),
             insert.entity,
             &mut image_assets,
-            &render_device,
+            &buffer_pool,
             ratatui_camera,
+            Some(downsampled_dimensions(
+                ratatui_camera,
+                last_area,
+                &font_metrics,
+            )),
         );
     }
 }
 
+#[cfg(feature = "sobel")]
 fn handle_ratatui_edge_detection_insert_observer(
     insert: On<Insert, RatatuiCameraEdgeDetection>,
     mut commands: Commands,
+    ratatui_cameras: Query<(
+        &RatatuiCamera,
+        &RatatuiCameraLastArea,
+        Has<RatatuiCameraEdgeDownsampling>,
+    )>,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    if let Ok((ratatui_camera, last_area, downsampling)) = ratatui_cameras.get(insert.entity) {
+        insert_edge_detection_readback_components(
+            commands.reborrow(),
+            insert.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+            downsampling.then(|| downsampled_dimensions(ratatui_camera, last_area, &font_metrics)),
+        );
+    }
+}
+
+#[cfg(feature = "sobel")]
+fn ratatui_edge_downsampling_insert_observer(
+    insert: On<Insert, RatatuiCameraEdgeDownsampling>,
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (&RatatuiCamera, &RatatuiCameraLastArea),
+        With<RatatuiCameraEdgeDetection>,
+    >,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+) {
+    if let Ok((ratatui_camera, last_area)) = ratatui_cameras.get(insert.entity) {
+        insert_edge_detection_readback_components(
+            commands.reborrow(),
+            insert.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+            Some(downsampled_dimensions(
+                ratatui_camera,
+                last_area,
+                &font_metrics,
+            )),
+        );
+    }
+}
+
+fn ratatui_normal_readback_insert_observer(
+    insert: On<Insert, RatatuiCameraNormalDetection>,
+    mut commands: Commands,
     ratatui_cameras: Query<&RatatuiCamera>,
     mut image_assets: ResMut<Assets<Image>>,
-    render_device: Res<RenderDevice>,
+    buffer_pool: Res<ImageBufferPool>,
 ) {
     if let Ok(ratatui_camera) = ratatui_cameras.get(insert.entity) {
-        insert_edge_detection_readback_components(
+        insert_camera_normal_readback_components(
+            commands.reborrow(),
+            insert.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+        );
+    }
+}
+
+fn ratatui_emissive_readback_insert_observer(
+    insert: On<Insert, RatatuiCameraEmissiveDetection>,
+    mut commands: Commands,
+    ratatui_cameras: Query<&RatatuiCamera>,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    if let Ok(ratatui_camera) = ratatui_cameras.get(insert.entity) {
+        insert_camera_emissive_readback_components(
+            commands.reborrow(),
+            insert.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+        );
+    }
+}
+
+fn ratatui_edge_color_readback_insert_observer(
+    insert: On<Insert, RatatuiCameraEdgeColorDetection>,
+    mut commands: Commands,
+    ratatui_cameras: Query<&RatatuiCamera>,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    if let Ok(ratatui_camera) = ratatui_cameras.get(insert.entity) {
+        insert_camera_edge_color_readback_components(
             commands.reborrow(),
             insert.entity,
             &mut image_assets,
-            &render_device,
+            &buffer_pool,
+            ratatui_camera,
+        );
+    }
+}
+
+fn ratatui_mask_readback_insert_observer(
+    insert: On<Insert, RatatuiCameraMaskDetection>,
+    mut commands: Commands,
+    ratatui_cameras: Query<&RatatuiCamera>,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    if let Ok(ratatui_camera) = ratatui_cameras.get(insert.entity) {
+        insert_camera_mask_readback_components(
+            commands.reborrow(),
+            insert.entity,
+            &mut image_assets,
+            &buffer_pool,
             ratatui_camera,
         );
     }
@@ -163,11 +450,34 @@ fn handle_ratatui_edge_detection_insert_observer(
 fn handle_ratatui_camera_removal_observer(
     remove: On<Remove, RatatuiCamera>,
     mut commands: Commands,
+    mut depth_buffers: ResMut<RatatuiCameraDepthBuffers>,
 ) {
     let mut entity = commands.entity(remove.entity);
     entity.remove::<(RatatuiCameraSender, RatatuiCameraReceiver)>();
+    depth_buffers.remove(remove.entity);
 }
 
+#[cfg(feature = "depth")]
+fn ratatui_depth_downsampling_removal_observer(
+    remove: On<Remove, RatatuiCameraDepthDownsampling>,
+    mut commands: Commands,
+    ratatui_cameras: Query<&RatatuiCamera, With<RatatuiCameraDepthDetection>>,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    if let Ok(ratatui_camera) = ratatui_cameras.get(remove.entity) {
+        insert_camera_depth_readback_components(
+            commands.reborrow(),
+            remove.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+            None,
+        );
+    }
+}
+
+#[cfg(feature = "depth")]
 fn ratatui_depth_readback_removal_observer(
     remove: On<Remove, RatatuiCameraDepthDetection>,
     mut commands: Commands,
@@ -176,191 +486,631 @@ fn ratatui_depth_readback_removal_observer(
     entity.remove::<(RatatuiDepthSender, RatatuiDepthReceiver)>();
 }
 
+#[cfg(feature = "sobel")]
+fn ratatui_edge_downsampling_removal_observer(
+    remove: On<Remove, RatatuiCameraEdgeDownsampling>,
+    mut commands: Commands,
+    ratatui_cameras: Query<&RatatuiCamera, With<RatatuiCameraEdgeDetection>>,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    if let Ok(ratatui_camera) = ratatui_cameras.get(remove.entity) {
+        insert_edge_detection_readback_components(
+            commands.reborrow(),
+            remove.entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+            None,
+        );
+    }
+}
+
+#[cfg(feature = "sobel")]
 fn handle_ratatui_edge_detection_removal_observer(
     remove: On<Remove, RatatuiCameraEdgeDetection>,
     mut commands: Commands,
 ) {
     let mut entity = commands.entity(remove.entity);
-    entity.remove::<(RatatuiSobelSender, RatatuiSobelReceiver)>();
+    entity.remove::<(
+        RatatuiSobelSender,
+        RatatuiSobelReceiver,
+        RatatuiSobelIntermediate,
+    )>();
+}
+
+fn ratatui_normal_readback_removal_observer(
+    remove: On<Remove, RatatuiCameraNormalDetection>,
+    mut commands: Commands,
+) {
+    let mut entity = commands.entity(remove.entity);
+    entity.remove::<(RatatuiNormalSender, RatatuiNormalReceiver)>();
+}
+
+fn ratatui_emissive_readback_removal_observer(
+    remove: On<Remove, RatatuiCameraEmissiveDetection>,
+    mut commands: Commands,
+) {
+    let mut entity = commands.entity(remove.entity);
+    entity.remove::<(RatatuiEmissiveSender, RatatuiEmissiveReceiver)>();
+}
+
+fn ratatui_edge_color_readback_removal_observer(
+    remove: On<Remove, RatatuiCameraEdgeColorDetection>,
+    mut commands: Commands,
+) {
+    let mut entity = commands.entity(remove.entity);
+    entity.remove::<(RatatuiEdgeColorSender, RatatuiEdgeColorReceiver)>();
+}
+
+fn ratatui_mask_readback_removal_observer(
+    remove: On<Remove, RatatuiCameraMaskDetection>,
+    mut commands: Commands,
+) {
+    let mut entity = commands.entity(remove.entity);
+    entity.remove::<(RatatuiMaskSender, RatatuiMaskReceiver)>();
 }
 
 fn update_ratatui_camera_readback_system(
     mut commands: Commands,
-    ratatui_cameras: Query<(Entity, &RatatuiCamera), Changed<RatatuiCamera>>,
+    ratatui_cameras: Query<
+        (Entity, &RatatuiCamera, Option<&RatatuiCameraSender>),
+        Changed<RatatuiCamera>,
+    >,
     mut camera_targeting_messages: MessageWriter<CameraTargetingMessage>,
     mut image_assets: ResMut<Assets<Image>>,
-    render_device: Res<RenderDevice>,
+    buffer_pool: Res<ImageBufferPool>,
 ) {
-    for (entity, ratatui_camera) in &ratatui_cameras {
+    for (entity, ratatui_camera, sender) in &ratatui_cameras {
+        if pipe_matches(sender, ratatui_camera.dimensions, ratatui_camera) {
+            continue;
+        }
+
         insert_camera_readback_components(
             commands.reborrow(),
             entity,
             &mut image_assets,
-            &render_device,
+            &buffer_pool,
             ratatui_camera,
             &mut camera_targeting_messages,
         );
     }
 }
 
+#[cfg(feature = "depth")]
 fn update_ratatui_depth_readback_system(
     mut commands: Commands,
     ratatui_cameras: Query<
-        (Entity, &RatatuiCamera),
-        (With<RatatuiCameraDepthDetection>, Changed<RatatuiCamera>),
+        (
+            Entity,
+            Ref<RatatuiCamera>,
+            &RatatuiCameraLastArea,
+            Has<RatatuiCameraDepthDownsampling>,
+            Option<&RatatuiDepthSender>,
+        ),
+        (
+            With<RatatuiCameraDepthDetection>,
+            Or<(Changed<RatatuiCamera>, Changed<RatatuiCameraLastArea>)>,
+        ),
     >,
     mut image_assets: ResMut<Assets<Image>>,
-    render_device: Res<RenderDevice>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
 ) {
-    for (entity, ratatui_camera) in &ratatui_cameras {
+    for (entity, ratatui_camera, last_area, downsampling, sender) in &ratatui_cameras {
+        // without downsampling, the depth readback only depends on the camera's dimensions.
+        if !downsampling && !ratatui_camera.is_changed() {
+            continue;
+        }
+
+        let downsampled_dimensions =
+            downsampling.then(|| downsampled_dimensions(&ratatui_camera, last_area, &font_metrics));
+
+        let dimensions = downsampled_dimensions.unwrap_or(ratatui_camera.dimensions);
+        if pipe_matches(sender, dimensions, &ratatui_camera) {
+            continue;
+        }
+
         insert_camera_depth_readback_components(
             commands.reborrow(),
             entity,
             &mut image_assets,
-            &render_device,
-            ratatui_camera,
+            &buffer_pool,
+            &ratatui_camera,
+            downsampled_dimensions,
         );
     }
 }
 
+#[cfg(feature = "sobel")]
 fn update_ratatui_edge_detection_readback_system(
     mut commands: Commands,
     ratatui_cameras: Query<
-        (Entity, &RatatuiCamera),
-        (With<RatatuiCameraEdgeDetection>, Changed<RatatuiCamera>),
+        (
+            Entity,
+            Ref<RatatuiCamera>,
+            &RatatuiCameraLastArea,
+            Has<RatatuiCameraEdgeDownsampling>,
+            Option<&RatatuiSobelSender>,
+        ),
+        (
+            With<RatatuiCameraEdgeDetection>,
+            Or<(Changed<RatatuiCamera>, Changed<RatatuiCameraLastArea>)>,
+        ),
     >,
     mut image_assets: ResMut<Assets<Image>>,
-    render_device: Res<RenderDevice>,
+    buffer_pool: Res<ImageBufferPool>,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
 ) {
-    for (entity, ratatui_camera) in &ratatui_cameras {
+    for (entity, ratatui_camera, last_area, downsampling, sender) in &ratatui_cameras {
+        // without downsampling, the edge readback only depends on the camera's dimensions.
+        if !downsampling && !ratatui_camera.is_changed() {
+            continue;
+        }
+
+        let downsampled_dimensions =
+            downsampling.then(|| downsampled_dimensions(&ratatui_camera, last_area, &font_metrics));
+
+        let dimensions = downsampled_dimensions.unwrap_or(ratatui_camera.dimensions);
+        if pipe_matches(sender, dimensions, &ratatui_camera) {
+            continue;
+        }
+
         insert_edge_detection_readback_components(
             commands.reborrow(),
             entity,
             &mut image_assets,
-            &render_device,
+            &buffer_pool,
+            &ratatui_camera,
+            downsampled_dimensions,
+        );
+    }
+}
+
+fn update_ratatui_normal_readback_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (Entity, &RatatuiCamera, Option<&RatatuiNormalSender>),
+        (With<RatatuiCameraNormalDetection>, Changed<RatatuiCamera>),
+    >,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    for (entity, ratatui_camera, sender) in &ratatui_cameras {
+        if pipe_matches(sender, ratatui_camera.dimensions, ratatui_camera) {
+            continue;
+        }
+
+        insert_camera_normal_readback_components(
+            commands.reborrow(),
+            entity,
+            &mut image_assets,
+            &buffer_pool,
             ratatui_camera,
         );
     }
 }
 
+fn update_ratatui_emissive_readback_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (Entity, &RatatuiCamera, Option<&RatatuiEmissiveSender>),
+        (With<RatatuiCameraEmissiveDetection>, Changed<RatatuiCamera>),
+    >,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    for (entity, ratatui_camera, sender) in &ratatui_cameras {
+        if pipe_matches(sender, ratatui_camera.dimensions, ratatui_camera) {
+            continue;
+        }
+
+        insert_camera_emissive_readback_components(
+            commands.reborrow(),
+            entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+        );
+    }
+}
+
+fn update_ratatui_edge_color_readback_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (Entity, &RatatuiCamera, Option<&RatatuiEdgeColorSender>),
+        (
+            With<RatatuiCameraEdgeColorDetection>,
+            Changed<RatatuiCamera>,
+        ),
+    >,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    for (entity, ratatui_camera, sender) in &ratatui_cameras {
+        if pipe_matches(sender, ratatui_camera.dimensions, ratatui_camera) {
+            continue;
+        }
+
+        insert_camera_edge_color_readback_components(
+            commands.reborrow(),
+            entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+        );
+    }
+}
+
+fn update_ratatui_mask_readback_system(
+    mut commands: Commands,
+    ratatui_cameras: Query<
+        (Entity, &RatatuiCamera, Option<&RatatuiMaskSender>),
+        (With<RatatuiCameraMaskDetection>, Changed<RatatuiCamera>),
+    >,
+    mut image_assets: ResMut<Assets<Image>>,
+    buffer_pool: Res<ImageBufferPool>,
+) {
+    for (entity, ratatui_camera, sender) in &ratatui_cameras {
+        if pipe_matches(sender, ratatui_camera.dimensions, ratatui_camera) {
+            continue;
+        }
+
+        insert_camera_mask_readback_components(
+            commands.reborrow(),
+            entity,
+            &mut image_assets,
+            &buffer_pool,
+            ratatui_camera,
+        );
+    }
+}
+
+/// Whether an existing pipe already has the dimensions and number of staging buffers needed, in
+/// which case it is kept rather than replaced when the camera changes.
+pub(crate) fn pipe_matches(
+    sender: Option<&impl Deref<Target = ImageSender>>,
+    dimensions: UVec2,
+    ratatui_camera: &RatatuiCamera,
+) -> bool {
+    sender.is_some_and(|sender| sender.matches(dimensions, ratatui_camera.max_frame_latency))
+}
+
 fn send_camera_images_system(
     ratatui_camera_senders: Query<&RatatuiCameraSender>,
     render_device: Res<RenderDevice>,
 ) {
     for camera_sender in &ratatui_camera_senders {
-        send_image_buffer(&render_device, &camera_sender.buffer, &camera_sender.sender);
+        send_image_buffer(&render_device, camera_sender);
     }
 }
 
+#[cfg(feature = "depth")]
 fn send_depth_images_system(
     ratatui_depth_senders: Query<&RatatuiDepthSender>,
     render_device: Res<RenderDevice>,
 ) {
     for depth_sender in &ratatui_depth_senders {
-        send_image_buffer(&render_device, &depth_sender.buffer, &depth_sender.sender);
+        send_image_buffer(&render_device, depth_sender);
     }
 }
 
+#[cfg(feature = "sobel")]
 fn send_sobel_images_system(
     ratatui_sobel_senders: Query<&RatatuiSobelSender>,
     render_device: Res<RenderDevice>,
 ) {
     for sobel_sender in &ratatui_sobel_senders {
-        send_image_buffer(&render_device, &sobel_sender.buffer, &sobel_sender.sender);
+        send_image_buffer(&render_device, sobel_sender);
+    }
+}
+
+fn send_normal_images_system(
+    ratatui_normal_senders: Query<&RatatuiNormalSender>,
+    render_device: Res<RenderDevice>,
+) {
+    for normal_sender in &ratatui_normal_senders {
+        send_image_buffer(&render_device, normal_sender);
+    }
+}
+
+fn send_emissive_images_system(
+    ratatui_emissive_senders: Query<&RatatuiEmissiveSender>,
+    render_device: Res<RenderDevice>,
+) {
+    for emissive_sender in &ratatui_emissive_senders {
+        send_image_buffer(&render_device, emissive_sender);
+    }
+}
+
+fn send_edge_color_images_system(
+    ratatui_edge_color_senders: Query<&RatatuiEdgeColorSender>,
+    render_device: Res<RenderDevice>,
+) {
+    for edge_color_sender in &ratatui_edge_color_senders {
+        send_image_buffer(&render_device, edge_color_sender);
+    }
+}
+
+fn send_mask_images_system(
+    ratatui_mask_senders: Query<&RatatuiMaskSender>,
+    render_device: Res<RenderDevice>,
+) {
+    for mask_sender in &ratatui_mask_senders {
+        send_image_buffer(&render_device, mask_sender);
     }
 }
 
-fn receive_camera_images_system(mut camera_receivers: Query<&mut RatatuiCameraReceiver>) {
-    for mut camera_receiver in &mut camera_receivers {
-        receive_image(&mut camera_receiver);
+fn receive_camera_images_system(
+    mut camera_receivers: Query<(Entity, &mut RatatuiCameraReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut camera_receiver, camera) in &mut camera_receivers {
+        if let Err(reason) = receive_image(&mut camera_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
     }
 }
 
-fn receive_depth_images_system(mut depth_receivers: Query<&mut RatatuiDepthReceiver>) {
-    for mut depth_receiver in &mut depth_receivers {
-        receive_image(&mut depth_receiver);
+#[cfg(feature = "depth")]
+fn receive_depth_images_system(
+    mut depth_receivers: Query<(Entity, &mut RatatuiDepthReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut depth_receiver, camera) in &mut depth_receivers {
+        if let Err(reason) = receive_image(&mut depth_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
     }
 }
 
-fn receive_sobel_images_system(mut sobel_receivers: Query<&mut RatatuiSobelReceiver>) {
-    for mut sobel_receiver in &mut sobel_receivers {
-        receive_image(&mut sobel_receiver);
+#[cfg(feature = "sobel")]
+fn receive_sobel_images_system(
+    mut sobel_receivers: Query<(Entity, &mut RatatuiSobelReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut sobel_receiver, camera) in &mut sobel_receivers {
+        if let Err(reason) = receive_image(&mut sobel_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
     }
 }
 
-fn create_ratatui_camera_widgets_system(
+fn receive_normal_images_system(
+    mut normal_receivers: Query<(Entity, &mut RatatuiNormalReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut normal_receiver, camera) in &mut normal_receivers {
+        if let Err(reason) = receive_image(&mut normal_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
+    }
+}
+
+fn receive_emissive_images_system(
+    mut emissive_receivers: Query<(Entity, &mut RatatuiEmissiveReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut emissive_receiver, camera) in &mut emissive_receivers {
+        if let Err(reason) = receive_image(&mut emissive_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
+    }
+}
+
+fn receive_edge_color_images_system(
+    mut edge_color_receivers: Query<(Entity, &mut RatatuiEdgeColorReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut edge_color_receiver, camera) in &mut edge_color_receivers {
+        if let Err(reason) = receive_image(&mut edge_color_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
+    }
+}
+
+fn receive_mask_images_system(
+    mut mask_receivers: Query<(Entity, &mut RatatuiMaskReceiver, &Camera)>,
+    latency: Res<RatatuiCameraLatency>,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, mut mask_receiver, camera) in &mut mask_receivers {
+        if let Err(reason) = receive_image(&mut mask_receiver, latency.wait(camera)) {
+            RatatuiCameraError::Readback { entity, reason }.report(&mut errors);
+        }
+    }
+}
+
+pub(crate) fn standard_latency(latency: Res<RatatuiCameraLatency>) -> bool {
+    *latency == RatatuiCameraLatency::Standard
+}
+
+pub(crate) fn create_ratatui_camera_widgets_system(
     mut commands: Commands,
-    ratatui_cameras: Query<(
-        Entity,
-        &RatatuiCameraStrategy,
-        &RatatuiCameraLastArea,
-        Option<&RatatuiCameraEdgeDetection>,
-        &RatatuiCameraReceiver,
-        Option<&RatatuiDepthReceiver>,
-        Option<&RatatuiSobelReceiver>,
-    )>,
+    ratatui_cameras: Query<
+        (
+            Entity,
+            &Camera,
+            &RatatuiCameraStrategy,
+            &RatatuiCameraLastArea,
+            Option<&RatatuiCameraEdgeDetection>,
+            &RatatuiCameraReceiver,
+            Option<&RatatuiDepthReceiver>,
+            Option<&RatatuiSobelReceiver>,
+            Option<&RatatuiNormalReceiver>,
+            Option<&RatatuiEmissiveReceiver>,
+            Option<&RatatuiCameraEmissiveDetection>,
+            Option<&RatatuiEdgeColorReceiver>,
+            Option<&RatatuiMaskReceiver>,
+            Option<&RatatuiCameraDepthJoints>,
+            (
+                Option<&RatatuiCameraCellDiffing>,
+                Option<&RatatuiCameraWidget>,
+                Option<&ViewportConfig>,
+                Has<RatatuiCameraSmoothResize>,
+            ),
+        ),
+        Without<RatatuiCameraPaused>,
+    >,
+    font_metrics: Res<RatatuiCameraFontMetrics>,
+    character_support: Res<RatatuiCameraCharacterSupport>,
+    (color_luts, image_color_luts, recorder): (
+        Res<Assets<ColorLut>>,
+        Res<ImageColorLuts>,
+        Option<Res<RatatuiCameraRecorder>>,
+    ),
+    mut ready_messages: MessageWriter<RatatuiCameraReady>,
 ) {
     for (
         entity_id,
+        camera,
         strategy,
         last_area,
         edge_detection,
         camera_receiver,
         depth_receiver,
         sobel_receiver,
+        normal_receiver,
+        emissive_receiver,
+        emissive_detection,
+        edge_color_receiver,
+        mask_receiver,
+        depth_joints,
+        (cell_diffing, previous_widget, viewport, smooth_resize),
     ) in &ratatui_cameras
     {
+        let _span = info_span!("ratatui_camera_create_widget", camera = %entity_id).entered();
+
         let mut entity = commands.entity(entity_id);
 
-        let camera_image = match camera_receiver.receiver_image.clone().try_into_dynamic() {
-            Ok(image) => image,
-            Err(e) => panic!("failed to create camera image from buffer {e:?}"),
+        // a resize recreates the readbacks, which hold blank images until the first frame of the
+        // new size arrives, so keep showing the previous widget's images until then.
+        let resizing_widget =
+            previous_widget.filter(|_| smooth_resize && camera_receiver.frame_number == 0);
+
+        let (
+            camera_image,
+            depth_image,
+            sobel_image,
+            normal_image,
+            emissive_image,
+            edge_color_image,
+            mask_image,
+        ) = match resizing_widget {
+            Some(previous_widget) => (
+                previous_widget.camera_image.clone(),
+                previous_widget.depth_image.clone(),
+                previous_widget.sobel_image.clone(),
+                previous_widget.normal_image.clone(),
+                previous_widget.emissive_image.clone(),
+                previous_widget.edge_color_image.clone(),
+                previous_widget.mask_image.clone(),
+            ),
+            None => (
+                camera_receiver.image.clone(),
+                depth_receiver.map(|receiver| receiver.image.clone()),
+                sobel_receiver.map(|receiver| receiver.image.clone()),
+                normal_receiver.map(|receiver| receiver.image.clone()),
+                emissive_receiver.map(|receiver| receiver.image.clone()),
+                edge_color_receiver.map(|receiver| receiver.image.clone()),
+                mask_receiver.map(|receiver| receiver.image.clone()),
+            ),
         };
 
-        let depth_image = depth_receiver.as_ref().map(|image_depth| {
-            match image_depth.receiver_image.clone().try_into_dynamic() {
-                Ok(image) => image,
-                Err(e) => panic!("failed to create depth image from buffer {e:?}"),
-            }
-        });
+        let mut strategy = strategy.clone();
+        character_support.downgrade_strategy(&mut strategy);
+        if !DEPTH_TEXTURE_SAMPLING_SUPPORTED {
+            strategy.downgrade_depth();
+        }
 
-        let sobel_image = sobel_receiver.as_ref().map(|image_sobel| {
-            match image_sobel.receiver_image.clone().try_into_dynamic() {
-                Ok(image) => image,
-                Err(e) => panic!("failed to create sobel image buffer {e:?}"),
-            }
-        });
+        let color_lut = strategy
+            .colors()
+            .and_then(|colors| colors.lut.as_ref())
+            .and_then(|lut| image_color_luts.resolve(lut, &color_luts));
+
+        let mut edge_detection = edge_detection.cloned();
+        if let Some(edge_detection) = &mut edge_detection {
+            character_support.downgrade_edge_detection(edge_detection);
+        }
+
+        // like the area, the options the previous widget was rendered with are kept for
+        // converting coordinates this frame.
+        let render_options = previous_widget
+            .map(|widget| widget.draw_state.lock().next_render_options)
+            .unwrap_or_default();
 
         let widget = RatatuiCameraWidget {
             entity: entity_id,
             camera_image,
             depth_image,
             sobel_image,
-            strategy: strategy.clone(),
-            edge_detection: edge_detection.cloned(),
+            normal_image,
+            emissive_image,
+            edge_color_image,
+            mask_image,
+            #[cfg(feature = "gpu_cells")]
+            gpu_cells_image: None,
+            emissive_detection: emissive_detection.copied(),
+            depth_joints: depth_joints.copied(),
+            cell_diffing: cell_diffing.copied(),
+            viewport: viewport.copied().unwrap_or_default(),
+            strategy,
+            edge_detection,
+            color_lut,
+            view_from_clip: camera.clip_from_view().inverse(),
+            font_metrics: *font_metrics,
+            character_support: *character_support,
+            layers: Vec::new(),
+            frame_number: camera_receiver.frame_number,
+            rendered_at: camera_receiver.rendered_at,
+            staleness: camera_receiver
+                .rendered_at
+                .map(|rendered_at| rendered_at.elapsed()),
             last_area: **last_area,
-            next_last_area: **last_area,
+            last_render_options: render_options,
+            draw_state: SharedDrawState::new(DrawState {
+                next_last_area: **last_area,
+                next_render_options: render_options,
+                drawn_cells: cell_diffing
+                    .and(previous_widget)
+                    .and_then(|widget| widget.draw_state.lock().drawn_cells.clone()),
+                ..default()
+            }),
+            cell_cache: default(),
+            recorder: recorder.as_deref().cloned(),
+            smooth_resize,
+            overlays: Vec::new(),
+            wireframe_edges: Vec::new(),
         };
 
+        if widget.is_ready() && !previous_widget.is_some_and(RatatuiCameraWidget::is_ready) {
+            ready_messages.write(RatatuiCameraReady { entity: entity_id });
+        }
+
         entity.insert(widget);
     }
 }
 
-fn resize_ratatui_camera_observer(
+pub(crate) fn resize_ratatui_camera_observer(
     replace: On<Replace, RatatuiCameraWidget>,
     mut commands: Commands,
     widgets: Query<(&RatatuiCameraWidget, &RatatuiCameraLastArea)>,
     mut ratatui_cameras: Query<&mut RatatuiCamera>,
 ) -> Result {
     let (widget, last_area) = widgets.get(replace.entity)?;
+    let next_last_area = widget.draw_state.lock().next_last_area;
 
     commands
         .entity(replace.entity)
-        .insert(RatatuiCameraLastArea(widget.next_last_area));
+        .insert(RatatuiCameraLastArea(next_last_area));
 
-    if last_area.width == widget.next_last_area.width
-        && last_area.height == widget.next_last_area.height
-    {
+    if last_area.width == next_last_area.width && last_area.height == next_last_area.height {
         return Ok(());
     }
 
@@ -368,15 +1118,43 @@ fn resize_ratatui_camera_observer(
         return Ok(());
     }
 
+    // two pixels per cell horizontally, and as many vertically as keeps the pixels square.
+    let cell_aspect_ratio = widget.font_metrics.cell_aspect_ratio();
     let mut ratatui_camera = ratatui_cameras.get_mut(replace.entity)?;
-    ratatui_camera.dimensions = UVec2::new(
-        (widget.next_last_area.width as u32 * 2).max(1),
-        (widget.next_last_area.height as u32 * 4).max(1),
-    );
+    ratatui_camera.dimensions = ratatui_camera.clamp_dimensions(UVec2::new(
+        (next_last_area.width as u32 * 2).max(1),
+        ((next_last_area.height as f32 * 2. * cell_aspect_ratio).round() as u32).max(1),
+    ));
 
     Ok(())
 }
 
+/// Reports cameras that rely on the depth texture on platforms that cannot sample it, whenever
+/// their strategy or edge detection changes. The strategies themselves are downgraded when each
+/// frame's widget is created.
+fn report_depth_sampling_unsupported_system(
+    ratatui_cameras: Query<
+        (
+            Entity,
+            &RatatuiCameraStrategy,
+            Option<&RatatuiCameraEdgeDetection>,
+        ),
+        Or<(
+            Changed<RatatuiCameraStrategy>,
+            Changed<RatatuiCameraEdgeDetection>,
+        )>,
+    >,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    for (entity, strategy, edge_detection) in &ratatui_cameras {
+        let edge_depth = edge_detection.is_some_and(|edge_detection| edge_detection.depth_enabled);
+
+        if strategy.uses_depth() || edge_depth {
+            RatatuiCameraError::DepthSamplingUnsupported { entity }.report(&mut errors);
+        }
+    }
+}
+
 // TODO: When observers can be explicitly ordered, use another observer ordered after the
 // RatatuiCamera observers instead.
 //
@@ -387,20 +1165,36 @@ fn resize_ratatui_camera_observer(
 /// RatatuiSubcamera is spawned in a single system run, we could potentially try to update the
 /// subcamera's render target before the main camera's render texture is created.
 fn handle_camera_targeting_messages_system(
-    target_cameras: Query<(&RatatuiCameraSender, Option<&RatatuiSubcameras>), With<RatatuiCamera>>,
+    target_cameras: Query<
+        (
+            &RatatuiCameraSender,
+            Option<&RatatuiSubcameras>,
+            Option<&RatatuiCameraUi>,
+        ),
+        With<RatatuiCamera>,
+    >,
     mut cameras: Query<&mut Camera>,
     mut camera_targeting_messages: MessageReader<CameraTargetingMessage>,
+    mut errors: MessageWriter<RatatuiCameraError>,
 ) {
     for CameraTargetingMessage {
         targeter_entity,
         target_entity,
     } in camera_targeting_messages.read()
     {
-        let (sender, targeting_subcameras) = target_cameras
-            .get(*target_entity)
-            .expect("CameraTargetingMessage sent with invalid targeting entity");
+        let Ok((sender, targeting_subcameras, camera_ui)) = target_cameras.get(*target_entity)
+        else {
+            RatatuiCameraError::StaleTarget {
+                entity: *target_entity,
+            }
+            .report(&mut errors);
+            continue;
+        };
 
-        let render_target = RenderTarget::from(sender.sender_image.clone());
+        let render_target = RenderTarget::Image(ImageRenderTarget {
+            handle: sender.sender_image.clone(),
+            scale_factor: FloatOrd(camera_ui.map_or(1., |camera_ui| camera_ui.scale_factor)),
+        });
 
         if let Some(targeting_subcameras) = targeting_subcameras {
             for targeting_subcamera in targeting_subcameras.iter() {
@@ -410,9 +1204,13 @@ fn handle_camera_targeting_messages_system(
             }
         }
 
-        let mut camera = cameras
-            .get_mut(*targeter_entity)
-            .expect("CameraTargetingMessage sent with invalid target entity");
+        let Ok(mut camera) = cameras.get_mut(*targeter_entity) else {
+            RatatuiCameraError::StaleTarget {
+                entity: *targeter_entity,
+            }
+            .report(&mut errors);
+            continue;
+        };
 
         camera.target = render_target;
     }
@@ -422,14 +1220,24 @@ fn insert_camera_readback_components(
     mut commands: Commands,
     entity: Entity,
     image_assets: &mut Assets<Image>,
-    render_device: &RenderDevice,
+    buffer_pool: &ImageBufferPool,
     ratatui_camera: &RatatuiCamera,
     camera_targeting_messages: &mut MessageWriter<CameraTargetingMessage>,
 ) {
-    let mut entity_commands = commands.entity(entity);
+    let (sender, receiver) = match create_image_pipe(
+        image_assets,
+        buffer_pool,
+        ratatui_camera.dimensions,
+        ratatui_camera.max_frame_latency,
+    ) {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
 
-    let (sender, receiver) =
-        create_image_pipe(image_assets, render_device, ratatui_camera.dimensions);
+    let mut entity_commands = commands.entity(entity);
 
     camera_targeting_messages.write(CameraTargetingMessage {
         targeter_entity: entity,
@@ -439,17 +1247,45 @@ fn insert_camera_readback_components(
     entity_commands.insert((RatatuiCameraSender(sender), RatatuiCameraReceiver(receiver)));
 }
 
+#[cfg(feature = "sobel")]
 fn insert_edge_detection_readback_components(
     mut commands: Commands,
     entity: Entity,
     image_assets: &mut Assets<Image>,
-    render_device: &RenderDevice,
+    buffer_pool: &ImageBufferPool,
     ratatui_camera: &RatatuiCamera,
+    downsampled_dimensions: Option<UVec2>,
 ) {
+    let (sender, receiver) = match create_image_pipe(
+        image_assets,
+        buffer_pool,
+        downsampled_dimensions.unwrap_or(ratatui_camera.dimensions),
+        ratatui_camera.max_frame_latency,
+    ) {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
+
     let mut entity = commands.entity(entity);
 
-    let (sender, receiver) =
-        create_image_pipe(image_assets, render_device, ratatui_camera.dimensions);
+    // when downsampling, edges are detected at full resolution into the intermediate texture, and
+    // then downsampled into the sender's image.
+    match downsampled_dimensions {
+        Some(_) => {
+            let intermediate = Image::new_target_texture(
+                ratatui_camera.dimensions.x,
+                ratatui_camera.dimensions.y,
+                TextureFormat::bevy_default(),
+            );
+            entity.insert(RatatuiSobelIntermediate(image_assets.add(intermediate)));
+        }
+        None => {
+            entity.remove::<RatatuiSobelIntermediate>();
+        }
+    }
 
     entity.insert((
         RatatuiSobelSender(sender),
@@ -460,17 +1296,41 @@ fn insert_edge_detection_readback_components(
     ));
 }
 
+#[cfg(feature = "depth")]
 fn insert_camera_depth_readback_components(
     mut commands: Commands,
     entity: Entity,
     image_assets: &mut Assets<Image>,
-    render_device: &RenderDevice,
+    buffer_pool: &ImageBufferPool,
     ratatui_camera: &RatatuiCamera,
+    downsampled_dimensions: Option<UVec2>,
 ) {
-    let mut entity = commands.entity(entity);
+    // downsampled depth is written as raw bytes, so the texture must not be in an sRGB format.
+    let pipe = match downsampled_dimensions {
+        Some(dimensions) => create_image_pipe_with_format(
+            image_assets,
+            buffer_pool,
+            dimensions,
+            TextureFormat::Rgba8Unorm,
+            ratatui_camera.max_frame_latency,
+        ),
+        None => create_image_pipe(
+            image_assets,
+            buffer_pool,
+            ratatui_camera.dimensions,
+            ratatui_camera.max_frame_latency,
+        ),
+    };
 
-    let (sender, receiver) =
-        create_image_pipe(image_assets, render_device, ratatui_camera.dimensions);
+    let (sender, receiver) = match pipe {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(entity);
 
     entity.insert((
         RatatuiDepthSender(sender),
@@ -479,3 +1339,134 @@ fn insert_camera_depth_readback_components(
         Msaa::Off,
     ));
 }
+
+/// Dimensions of downsampled readback textures (depth or edges), two pixels per terminal cell of
+/// the area the camera's image was last drawn within.
+#[cfg(any(feature = "depth", feature = "sobel"))]
+fn downsampled_dimensions(
+    ratatui_camera: &RatatuiCamera,
+    last_area: &RatatuiCameraLastArea,
+    font_metrics: &RatatuiCameraFontMetrics,
+) -> UVec2 {
+    let cells = ratatui_camera.render_area_size(**last_area, font_metrics);
+    (cells * UVec2::new(1, 2)).max(UVec2::ONE)
+}
+
+fn insert_camera_normal_readback_components(
+    mut commands: Commands,
+    entity: Entity,
+    image_assets: &mut Assets<Image>,
+    buffer_pool: &ImageBufferPool,
+    ratatui_camera: &RatatuiCamera,
+) {
+    let (sender, receiver) = match create_image_pipe(
+        image_assets,
+        buffer_pool,
+        ratatui_camera.dimensions,
+        ratatui_camera.max_frame_latency,
+    ) {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(entity);
+
+    entity.insert((
+        RatatuiNormalSender(sender),
+        RatatuiNormalReceiver(receiver),
+        NormalPrepass,
+        Msaa::Off,
+    ));
+}
+
+fn insert_camera_emissive_readback_components(
+    mut commands: Commands,
+    entity: Entity,
+    image_assets: &mut Assets<Image>,
+    buffer_pool: &ImageBufferPool,
+    ratatui_camera: &RatatuiCamera,
+) {
+    let (sender, receiver) = match create_image_pipe(
+        image_assets,
+        buffer_pool,
+        ratatui_camera.dimensions,
+        ratatui_camera.max_frame_latency,
+    ) {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(entity);
+
+    entity.insert((
+        RatatuiEmissiveSender(sender),
+        RatatuiEmissiveReceiver(receiver),
+        Hdr,
+    ));
+}
+
+fn insert_camera_edge_color_readback_components(
+    mut commands: Commands,
+    entity: Entity,
+    image_assets: &mut Assets<Image>,
+    buffer_pool: &ImageBufferPool,
+    ratatui_camera: &RatatuiCamera,
+) {
+    let (sender, receiver) = match create_image_pipe(
+        image_assets,
+        buffer_pool,
+        ratatui_camera.dimensions,
+        ratatui_camera.max_frame_latency,
+    ) {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(entity);
+
+    entity.insert((
+        RatatuiEdgeColorSender(sender),
+        RatatuiEdgeColorReceiver(receiver),
+        Msaa::Off,
+    ));
+}
+
+fn insert_camera_mask_readback_components(
+    mut commands: Commands,
+    entity: Entity,
+    image_assets: &mut Assets<Image>,
+    buffer_pool: &ImageBufferPool,
+    ratatui_camera: &RatatuiCamera,
+) {
+    // mask values are written as raw bytes, so the texture must not be in an sRGB format.
+    let (sender, receiver) = match create_image_pipe_with_format(
+        image_assets,
+        buffer_pool,
+        ratatui_camera.dimensions,
+        TextureFormat::Rgba8Unorm,
+        ratatui_camera.max_frame_latency,
+    ) {
+        Ok(pipe) => pipe,
+        Err(reason) => {
+            RatatuiCameraError::ImagePipe { entity, reason }.report_deferred(&mut commands);
+            return;
+        }
+    };
+
+    let mut entity = commands.entity(entity);
+
+    entity.insert((
+        RatatuiMaskSender(sender),
+        RatatuiMaskReceiver(receiver),
+        Msaa::Off,
+    ));
+}
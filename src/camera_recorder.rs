@@ -0,0 +1,326 @@
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{platform::time::Instant, prelude::*};
+use image::{
+    Delay, Frame, RgbaImage,
+    codecs::gif::{GifEncoder, Repeat},
+};
+use ratatui::{buffer::Buffer, layout::Rect};
+
+use crate::{buffer_ansi::buffer_area_to_ansi, buffer_image::buffer_to_image};
+
+/// Draws closer together than this are combined into one frame of an animated image (e.g. when
+/// several widgets are drawn in the same terminal frame), as GIF delays are in hundredths of a
+/// second.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(10);
+
+/// How long the last frame of an animated image is shown before it loops.
+const LAST_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+pub struct RatatuiCameraRecorderPlugin;
+
+impl Plugin for RatatuiCameraRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Last,
+            save_recording_on_exit_system.run_if(resource_exists::<RatatuiCameraRecorder>),
+        );
+    }
+}
+
+/// Insert this resource to record every frame drawn by each RatatuiCameraWidget (the terminal
+/// cells after conversion, with their timestamps), and write them to a file when the app exits,
+/// in the format chosen by the file's extension (see [RatatuiCameraRecordingFormat]):
+///
+/// - `.cast`: an [asciinema](https://asciinema.org) v2 cast file, to play back with
+///   `asciinema play` or share anywhere asciinema recordings are accepted.
+/// - `.gif`, `.png` or `.apng`: an animated image, with each cell's character rasterized using an
+///   embedded bitmap font (as by
+///   [RatatuiCameraWidget::to_image](crate::RatatuiCameraWidget::to_image)), for sharing previews
+///   anywhere images are accepted.
+///
+/// Every draw other than through `detached()` is recorded, each at the position in the terminal
+/// it was drawn within, so the recording's dimensions cover every area drawn. Call
+/// [RatatuiCameraRecorder::save] to write the file before exiting.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiCameraRecorder;
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.insert_resource(RatatuiCameraRecorder::new("demo.cast"));
+/// # };
+/// ```
+///
+#[derive(Resource, Clone, Debug)]
+pub struct RatatuiCameraRecorder {
+    path: PathBuf,
+
+    /// The format the recording is written in.
+    pub format: RatatuiCameraRecordingFormat,
+
+    /// For animated images, the number of image pixels each pixel of the bitmap font covers
+    /// horizontally and vertically (cells are `4 * scale` by `8 * scale` pixels).
+    pub scale: u32,
+
+    recording: Arc<Mutex<Recording>>,
+}
+
+/// File formats that a [RatatuiCameraRecorder] can write.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RatatuiCameraRecordingFormat {
+    /// An asciinema v2 cast file, replaying the recorded frames as terminal output.
+    #[default]
+    Cast,
+
+    /// An animated GIF, with the recorded frames rasterized.
+    Gif,
+
+    /// An animated PNG, with the recorded frames rasterized. Unlike GIF, colors are not reduced to
+    /// a palette, at the cost of larger files.
+    Apng,
+}
+
+impl RatatuiCameraRecordingFormat {
+    /// The format matching a path's extension, defaulting to [RatatuiCameraRecordingFormat::Cast]
+    /// for unrecognized extensions.
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+
+        match extension.as_deref() {
+            Some("gif") => Self::Gif,
+            Some("png" | "apng") => Self::Apng,
+            _ => Self::Cast,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Recording {
+    started: Option<(Instant, SystemTime)>,
+    size: (u16, u16),
+    events: Vec<(Duration, Buffer)>,
+}
+
+impl RatatuiCameraRecorder {
+    /// Record drawn frames, to be written to the file at the provided path, in the format matching
+    /// its extension.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+
+        Self {
+            format: RatatuiCameraRecordingFormat::from_path(&path),
+            scale: 2,
+            path,
+            recording: Arc::default(),
+        }
+    }
+
+    /// Write the recording in the provided format, regardless of the path's extension.
+    pub fn with_format(mut self, format: RatatuiCameraRecordingFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Scale animated images by the provided factor (see [RatatuiCameraRecorder::scale]).
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// The path the recording is written to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of frames recorded so far.
+    pub fn frame_count(&self) -> usize {
+        self.recording().events.len()
+    }
+
+    /// Write the frames recorded so far to the recorder's path, in the recorder's format.
+    pub fn save(&self) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&self.path)?);
+
+        match self.format {
+            RatatuiCameraRecordingFormat::Cast => writer.write_all(self.to_cast().as_bytes())?,
+            RatatuiCameraRecordingFormat::Gif => self.write_gif(&mut writer)?,
+            RatatuiCameraRecordingFormat::Apng => self.write_apng(&mut writer)?,
+        }
+
+        writer.flush()
+    }
+
+    /// The frames recorded so far, in the asciinema v2 cast file format.
+    pub fn to_cast(&self) -> String {
+        let recording = self.recording();
+        let (width, height) = recording.size;
+        let timestamp = recording
+            .started
+            .and_then(|(_, started)| started.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |since_epoch| since_epoch.as_secs());
+
+        let mut cast = format!(
+            "{{\"version\": 2, \"width\": {width}, \"height\": {height}, \
+            \"timestamp\": {timestamp}}}\n"
+        );
+
+        for (time, frame) in &recording.events {
+            let data = buffer_area_to_ansi(frame, frame.area);
+            let _ = writeln!(
+                cast,
+                "[{:.6}, \"o\", {}]",
+                time.as_secs_f64(),
+                json_string(&data)
+            );
+        }
+
+        cast
+    }
+
+    /// Write the frames recorded so far as an animated GIF, looping forever.
+    fn write_gif(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = GifEncoder::new_with_speed(writer, 10);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for (image, delay) in self.rasterized_frames() {
+            let delay = Delay::from_saturating_duration(delay);
+            encoder
+                .encode_frame(Frame::from_parts(image, 0, 0, delay))
+                .map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the frames recorded so far as an animated PNG, looping forever.
+    fn write_apng(&self, writer: impl Write) -> io::Result<()> {
+        let frames = self.rasterized_frames();
+        let Some((first, _)) = frames.first() else {
+            return Ok(());
+        };
+
+        let mut encoder = png::Encoder::new(writer, first.width(), first.height());
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .set_animated(frames.len() as u32, 0)
+            .map_err(io::Error::other)?;
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        for (image, delay) in &frames {
+            let delay_ms = delay.as_millis().min(u16::MAX as u128) as u16;
+            writer
+                .set_frame_delay(delay_ms, 1000)
+                .map_err(io::Error::other)?;
+            writer
+                .write_image_data(image.as_raw())
+                .map_err(io::Error::other)?;
+        }
+
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Composite the recorded frames onto a terminal-sized canvas and rasterize it after each
+    /// group of draws closer together than [MIN_FRAME_DELAY], along with how long each image is
+    /// shown.
+    fn rasterized_frames(&self) -> Vec<(RgbaImage, Duration)> {
+        let recording = self.recording();
+        let (width, height) = recording.size;
+
+        let mut canvas = Buffer::empty(Rect::new(0, 0, width, height));
+        let mut images = Vec::new();
+
+        for (index, (time, frame)) in recording.events.iter().enumerate() {
+            canvas.merge(frame);
+
+            let delay = match recording.events.get(index + 1) {
+                Some((next_time, _)) => next_time.saturating_sub(*time),
+                None => LAST_FRAME_DELAY,
+            };
+
+            if delay >= MIN_FRAME_DELAY {
+                images.push((buffer_to_image(&canvas, self.scale), delay));
+            }
+        }
+
+        images
+    }
+
+    /// Record the cells of the buffer within the area as a frame.
+    pub(crate) fn record(&self, area: Rect, buf: &Buffer) {
+        let area = area.intersection(buf.area);
+        let mut frame = Buffer::empty(area);
+        for position in area.positions() {
+            frame[position] = buf[position].clone();
+        }
+
+        let mut recording = self.recording();
+        let (started, _) = *recording
+            .started
+            .get_or_insert_with(|| (Instant::now(), SystemTime::now()));
+
+        recording.size.0 = recording.size.0.max(area.right());
+        recording.size.1 = recording.size.1.max(area.bottom());
+        recording.events.push((started.elapsed(), frame));
+    }
+
+    fn recording(&self) -> std::sync::MutexGuard<'_, Recording> {
+        self.recording
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Quote and escape text as a JSON string.
+fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+
+    for character in text.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                let _ = write!(json, "\\u{:04x}", character as u32);
+            }
+            character => json.push(character),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+fn save_recording_on_exit_system(
+    mut exit_messages: MessageReader<AppExit>,
+    recorder: Res<RatatuiCameraRecorder>,
+) {
+    if exit_messages.read().last().is_none() {
+        return;
+    }
+
+    if let Err(e) = recorder.save() {
+        log::warn!(
+            "failed to save ratatui camera recording {:?}: {e}",
+            recorder.path
+        );
+    }
+}
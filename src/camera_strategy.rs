@@ -1,14 +1,21 @@
 use std::{fmt::Debug, sync::Arc};
 
 use bevy::prelude::*;
+use ratatui::style::Modifier;
+use serde::{Deserialize, Serialize};
 
-use crate::color_support::ColorSupport;
+use crate::{
+    CharacterRamp,
+    color_lut::ColorLutHandle,
+    color_support::{ColorDistance, ColorSupport},
+};
 
 /// Specify the strategy used for converting the camera's rendered image to unicode characters for
 /// the terminal buffer. Insert a variant of this component alongside your `RatatuiCamera` to
 /// change the default behavior.
 ///
-#[derive(Component, Clone, Debug)]
+#[derive(Component, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 pub enum RatatuiCameraStrategy {
     /// Print to the terminal using unicode halfblock characters. By using both the halfblock
     /// (foreground) color and the background color, we can draw two pixels per buffer cell.
@@ -26,6 +33,36 @@ pub enum RatatuiCameraStrategy {
     /// texture.
     Depth(DepthConfig),
 
+    /// Given a range of unicode characters sorted in increasing order of opacity, use a weighted
+    /// combination of each pixel's luminance and depth to select a character from the range, so
+    /// that distant objects fade into sparser characters while near objects stay dense.
+    ///
+    /// NOTE: The [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection) component is
+    /// required on the same camera entity for this strategy to function, as it relies on the depth
+    /// texture.
+    LuminanceDepth(LuminanceDepthConfig),
+
+    /// Given a set of characters, compare each cell's block of pixels against a rasterized
+    /// coverage mask of each character, and select the character whose shape best matches the
+    /// rendered image (rather than just its density). Characters without an embedded coverage
+    /// mask are ignored, see [RatatuiCameraStrategy::CHARACTERS_GLYPHS] and
+    /// [RatatuiCameraStrategy::CHARACTERS_QUADRANTS] for supported characters.
+    Glyphs(GlyphsConfig),
+
+    /// Compare each pixel's luminance against a threshold, and print strictly two-toned output
+    /// using either a pair of colors or a pair of characters. Optionally dithered, to preserve
+    /// some of the shading of the rendered image.
+    Threshold(ThresholdConfig),
+
+    /// Shade each cell based on the direction its surface faces relative to a light, selecting
+    /// a character from a range sorted in increasing order of opacity. With a short character
+    /// list, this produces banded, cel-shaded output independent of the scene's own lighting.
+    ///
+    /// NOTE: The [RatatuiCameraNormalDetection](crate::RatatuiCameraNormalDetection) component is
+    /// required on the same camera entity for this strategy to function, as it relies on the normal
+    /// prepass texture.
+    Normals(NormalsConfig),
+
     /// Does not print characters by itself, but edge detection will still print. Use with edge
     /// detection for a "wireframe".
     None,
@@ -44,6 +81,108 @@ impl RatatuiCameraStrategy {
 
     /// A range of block characters in increasing order of size.
     pub const CHARACTERS_BLOCKS: &'static [char] = &[' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    /// A set of ASCII characters with distinct shapes, for use with the glyphs strategy.
+    pub const CHARACTERS_GLYPHS: &'static [char] = &[
+        ' ', '.', ',', '\'', '`', '"', '^', ':', ';', '-', '_', '=', '~', '+', '*', '|', '/', '\\',
+        '(', ')', '[', ']', '<', '>', 'v', 'o', 'x', 'L', 'T', 'V', 'X', 'Y', 'O', 'H', 'A', 'M',
+        'W', '#', '%', '&', '@',
+    ];
+
+    /// A set of block quadrant characters, for use with the glyphs strategy.
+    pub const CHARACTERS_QUADRANTS: &'static [char] = &[
+        ' ', '▀', '▄', '▌', '▐', '▘', '▝', '▖', '▗', '▚', '▞', '▙', '▛', '▜', '▟', '█',
+    ];
+}
+
+impl RatatuiCameraStrategy {
+    /// The character configuration of the strategy, if it has one.
+    pub fn characters(&self) -> Option<&CharactersConfig> {
+        match self {
+            Self::Luminance(config) => Some(&config.characters),
+            Self::Depth(config) => Some(&config.characters),
+            Self::LuminanceDepth(config) => Some(&config.characters),
+            Self::Glyphs(config) => Some(&config.characters),
+            Self::Normals(config) => Some(&config.characters),
+            Self::HalfBlocks(_) | Self::Threshold(_) | Self::None => None,
+        }
+    }
+
+    /// Mutable access to the character configuration of the strategy, if it has one.
+    pub fn characters_mut(&mut self) -> Option<&mut CharactersConfig> {
+        match self {
+            Self::Luminance(config) => Some(&mut config.characters),
+            Self::Depth(config) => Some(&mut config.characters),
+            Self::LuminanceDepth(config) => Some(&mut config.characters),
+            Self::Glyphs(config) => Some(&mut config.characters),
+            Self::Normals(config) => Some(&mut config.characters),
+            Self::HalfBlocks(_) | Self::Threshold(_) | Self::None => None,
+        }
+    }
+
+    /// Whether the strategy relies on the depth texture (see
+    /// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection)).
+    pub fn uses_depth(&self) -> bool {
+        matches!(self, Self::Depth(_) | Self::LuminanceDepth(_))
+    }
+
+    /// Replace a strategy that relies on the depth texture with a luminance strategy that keeps
+    /// its common and color configuration, for platforms that cannot sample depth textures.
+    pub(crate) fn downgrade_depth(&mut self) {
+        let (common, colors) = match self {
+            Self::Depth(config) => (config.common.clone(), config.colors.clone()),
+            Self::LuminanceDepth(config) => (config.common.clone(), config.colors.clone()),
+            _ => return,
+        };
+
+        *self = Self::Luminance(LuminanceConfig {
+            common,
+            colors,
+            ..default()
+        });
+    }
+
+    /// The configuration common to all strategies, if the strategy has one.
+    pub fn common(&self) -> Option<&CommonConfig> {
+        match self {
+            Self::HalfBlocks(config) => Some(&config.common),
+            Self::Luminance(config) => Some(&config.common),
+            Self::Depth(config) => Some(&config.common),
+            Self::LuminanceDepth(config) => Some(&config.common),
+            Self::Glyphs(config) => Some(&config.common),
+            Self::Threshold(config) => Some(&config.common),
+            Self::Normals(config) => Some(&config.common),
+            Self::None => None,
+        }
+    }
+
+    /// The color configuration of the strategy, if it has one.
+    pub fn colors(&self) -> Option<&ColorsConfig> {
+        match self {
+            Self::HalfBlocks(config) => Some(&config.colors),
+            Self::Luminance(config) => Some(&config.colors),
+            Self::Depth(config) => Some(&config.colors),
+            Self::LuminanceDepth(config) => Some(&config.colors),
+            Self::Glyphs(config) => Some(&config.colors),
+            Self::Threshold(config) => Some(&config.colors),
+            Self::Normals(config) => Some(&config.colors),
+            Self::None => None,
+        }
+    }
+
+    /// Mutable access to the color configuration of the strategy, if it has one.
+    pub fn colors_mut(&mut self) -> Option<&mut ColorsConfig> {
+        match self {
+            Self::HalfBlocks(config) => Some(&mut config.colors),
+            Self::Luminance(config) => Some(&mut config.colors),
+            Self::Depth(config) => Some(&mut config.colors),
+            Self::LuminanceDepth(config) => Some(&mut config.colors),
+            Self::Glyphs(config) => Some(&mut config.colors),
+            Self::Threshold(config) => Some(&mut config.colors),
+            Self::Normals(config) => Some(&mut config.colors),
+            Self::None => None,
+        }
+    }
 }
 
 impl Default for RatatuiCameraStrategy {
@@ -65,6 +204,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: characters.into(),
                 scale: DepthConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -76,6 +216,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_BRAILLE.into(),
                 scale: DepthConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -87,6 +228,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_MISC.into(),
                 scale: DepthConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -98,6 +240,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_SHADING.into(),
                 scale: DepthConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -109,6 +252,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_BLOCKS.into(),
                 scale: DepthConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -120,6 +264,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: characters.into(),
                 scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -131,6 +276,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_BRAILLE.into(),
                 scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -142,6 +288,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_MISC.into(),
                 scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -153,6 +300,7 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_SHADING.into(),
                 scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -164,6 +312,84 @@ impl RatatuiCameraStrategy {
             characters: CharactersConfig {
                 list: Self::CHARACTERS_BLOCKS.into(),
                 scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
+            },
+            ..default()
+        })
+    }
+
+    /// Luminance and depth strategy with a provided list of characters, weighting luminance and
+    /// depth equally.
+    pub fn luminance_depth_with_characters(characters: &[char]) -> Self {
+        Self::LuminanceDepth(LuminanceDepthConfig {
+            characters: CharactersConfig {
+                list: characters.into(),
+                scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
+            },
+            ..default()
+        })
+    }
+
+    /// Glyphs strategy with a provided list of characters.
+    pub fn glyphs_with_characters(characters: &[char]) -> Self {
+        Self::Glyphs(GlyphsConfig {
+            characters: CharactersConfig {
+                list: characters.into(),
+                scale: GlyphsConfig::SCALE_DEFAULT,
+                ramp: None,
+            },
+            ..default()
+        })
+    }
+
+    /// Glyphs strategy with a set of ASCII characters with distinct shapes.
+    pub fn glyphs_ascii() -> Self {
+        Self::Glyphs(GlyphsConfig::default())
+    }
+
+    /// Glyphs strategy with a set of block quadrant characters.
+    pub fn glyphs_quadrants() -> Self {
+        Self::Glyphs(GlyphsConfig {
+            characters: CharactersConfig {
+                list: Self::CHARACTERS_QUADRANTS.into(),
+                scale: GlyphsConfig::SCALE_DEFAULT,
+                ramp: None,
+            },
+            ..default()
+        })
+    }
+
+    /// Threshold strategy drawing each pixel in either white or black.
+    pub fn threshold() -> Self {
+        Self::Threshold(ThresholdConfig::default())
+    }
+
+    /// Threshold strategy drawing each cell as one of the two provided characters.
+    pub fn threshold_with_characters(off: char, on: char) -> Self {
+        Self::Threshold(ThresholdConfig {
+            output: ThresholdOutput::Characters { off, on },
+            ..default()
+        })
+    }
+
+    /// Normals strategy lit from the provided direction, with a range of shading block characters
+    /// in increasing order of opacity.
+    pub fn normals(light_direction: Vec3) -> Self {
+        Self::Normals(NormalsConfig {
+            light_direction,
+            ..default()
+        })
+    }
+
+    /// Normals strategy lit from the provided direction, with a provided list of characters.
+    pub fn normals_with_characters(light_direction: Vec3, characters: &[char]) -> Self {
+        Self::Normals(NormalsConfig {
+            light_direction,
+            characters: CharactersConfig {
+                list: characters.into(),
+                scale: NormalsConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             ..default()
         })
@@ -185,24 +411,62 @@ impl RatatuiCameraStrategy {
 /// # fn setup_scene_system(mut commands: Commands) {
 /// # commands.spawn((
 /// #     RatatuiCamera::default(),
-///     RatatuiCameraStrategy::HalfBlocks(HalfBlocksConfig {
-///         colors: ColorsConfig {
-///             support: ColorSupport::ANSI16,
-///             ..default()
-///         },
-///         ..default()
-///     }),
+///     RatatuiCameraStrategy::HalfBlocks(
+///         HalfBlocksConfig::default()
+///             .with_colors(ColorsConfig::default().with_support(ColorSupport::ANSI16)),
+///     ),
 /// # ));
 /// # };
 /// ```
 ///
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct HalfBlocksConfig {
     /// Configuration options common to all strategies.
     pub common: CommonConfig,
 
     /// Configuration for determining the resulting colors.
     pub colors: ColorsConfig,
+
+    /// How the pixels of each cell are sampled and turned into a character and colors.
+    pub mode: HalfBlocksMode,
+}
+
+impl HalfBlocksConfig {
+    /// Set [HalfBlocksConfig::common].
+    pub fn with_common(mut self, common: CommonConfig) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// Set [HalfBlocksConfig::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Set [HalfBlocksConfig::mode].
+    pub fn with_mode(mut self, mode: HalfBlocksMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// Options for how the halfblocks strategy converts the region of the image covered by each cell.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Reflect)]
+pub enum HalfBlocksMode {
+    /// The image is downscaled to two pixels per cell, and the top and bottom pixels are used
+    /// directly as the background and foreground colors of a lower halfblock character.
+    #[default]
+    Pixels,
+
+    /// The full region of the image covered by each cell is sampled, and the block character
+    /// (halfblocks, quadrants, etc.) and pair of foreground and background colors that best
+    /// represent that region are chosen by splitting its pixels into two color clusters. Gives
+    /// sharper results when the rendered image is larger than the terminal cell grid (e.g. with
+    /// autoresize, or a large fixed size), at a higher conversion cost.
+    TwoColor,
 }
 
 /// Configuration for the RatatuiCameraStrategy::Depth terminal rendering strategy.
@@ -225,17 +489,15 @@ pub struct HalfBlocksConfig {
 /// # fn setup_scene_system(mut commands: Commands) {
 /// # commands.spawn((
 /// #     RatatuiCamera::default(),
-///     RatatuiCameraStrategy::Depth(DepthConfig {
-///         characters: CharactersConfig {
-///             list: vec![' ', '+', '@'],
-///             scale: DepthConfig::SCALE_DEFAULT,
-///         },
-///         ..default()
-///     }),
+///     RatatuiCameraStrategy::Depth(DepthConfig::default().with_characters(
+///         CharactersConfig::new([' ', '+', '@'], DepthConfig::SCALE_DEFAULT),
+///     )),
 /// # ));
 /// # };
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct DepthConfig {
     /// Configuration options common to all strategies.
     pub common: CommonConfig,
@@ -245,11 +507,48 @@ pub struct DepthConfig {
 
     /// Configuration for determining the resulting colors.
     pub colors: ColorsConfig,
+
+    /// How each pixel's depth is mapped to a character. When `None`, the raw depth value is
+    /// multiplied by `characters.scale`.
+    pub mapping: Option<DepthMapping>,
 }
 
 impl DepthConfig {
     /// The default scaling value to multiply pixel depth by.
     pub const SCALE_DEFAULT: f32 = 30.;
+
+    /// Map a raw depth value to the range `0.0` (far) to `1.0` (near), used for selecting a
+    /// character from the character list.
+    pub(crate) fn scaled_depth(&self, depth: f32, view_from_clip: &Mat4) -> f32 {
+        match self.mapping {
+            Some(mapping) => mapping.map(depth, view_from_clip),
+            None => (depth * self.characters.scale).min(1.0),
+        }
+    }
+
+    /// Set [DepthConfig::common].
+    pub fn with_common(mut self, common: CommonConfig) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// Set [DepthConfig::characters].
+    pub fn with_characters(mut self, characters: CharactersConfig) -> Self {
+        self.characters = characters;
+        self
+    }
+
+    /// Set [DepthConfig::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Set [DepthConfig::mapping].
+    pub fn with_mapping(mut self, mapping: DepthMapping) -> Self {
+        self.mapping = Some(mapping);
+        self
+    }
 }
 
 impl Default for DepthConfig {
@@ -259,8 +558,63 @@ impl Default for DepthConfig {
             characters: CharactersConfig {
                 list: RatatuiCameraStrategy::CHARACTERS_MISC.into(),
                 scale: DepthConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             colors: ColorsConfig::default(),
+            mapping: None,
+        }
+    }
+}
+
+/// Options for how the depth strategy maps each pixel's depth to a character.
+///
+/// Bevy's depth values are reversed and (for perspective projections) non-linear, so raw values
+/// change quickly near the camera and slowly far from it. The linear mapping converts them back to
+/// distances from the camera first, so that characters correspond to actual world distances for
+/// both perspective and orthographic projections.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Reflect)]
+pub enum DepthMapping {
+    /// Multiply the raw depth value by a scale, capped at `1.0`.
+    Raw {
+        /// Scaling value to multiply the raw depth by.
+        scale: f32,
+    },
+
+    /// Map distances from the camera linearly, with surfaces at `near` or closer using the densest
+    /// character and surfaces at `far` or beyond using the sparsest character.
+    Linear {
+        /// Distance from the camera (in world units) mapped to the densest character.
+        near: f32,
+        /// Distance from the camera (in world units) mapped to the sparsest character.
+        far: f32,
+    },
+}
+
+impl Default for DepthMapping {
+    fn default() -> Self {
+        Self::Raw {
+            scale: DepthConfig::SCALE_DEFAULT,
+        }
+    }
+}
+
+impl DepthMapping {
+    /// Map a raw depth value to the range `0.0` (far) to `1.0` (near), using the inverse of the
+    /// camera's projection matrix to recover distances from the camera when needed.
+    pub fn map(&self, depth: f32, view_from_clip: &Mat4) -> f32 {
+        match *self {
+            Self::Raw { scale } => (depth * scale).clamp(0.0, 1.0),
+            Self::Linear { near, far } => {
+                let view_position = *view_from_clip * Vec4::new(0.0, 0.0, depth, 1.0);
+                let distance = -view_position.z / view_position.w;
+
+                if !distance.is_finite() || far <= near {
+                    return 0.0;
+                }
+
+                1.0 - ((distance - near) / (far - near)).clamp(0.0, 1.0)
+            }
         }
     }
 }
@@ -281,18 +635,17 @@ impl Default for DepthConfig {
 /// # fn setup_scene_system(mut commands: Commands) {
 /// # commands.spawn((
 /// #     RatatuiCamera::default(),
-///     RatatuiCameraStrategy::Luminance(LuminanceConfig {
-///         characters: CharactersConfig {
-///             list: vec![' ', '.', '+', '#'],
-///             scale: 5.0,
-///         },
-///         ..default()
-///     }),
+///     RatatuiCameraStrategy::Luminance(
+///         LuminanceConfig::default()
+///             .with_characters(CharactersConfig::new([' ', '.', '+', '#'], 5.0)),
+///     ),
 /// # ));
 /// # };
 /// ```
 ///
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct LuminanceConfig {
     /// Configuration options common to all strategies.
     pub common: CommonConfig,
@@ -307,6 +660,24 @@ pub struct LuminanceConfig {
 impl LuminanceConfig {
     /// The default scaling value to multiply pixel luminance by.
     pub const SCALE_DEFAULT: f32 = 10.;
+
+    /// Set [LuminanceConfig::common].
+    pub fn with_common(mut self, common: CommonConfig) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// Set [LuminanceConfig::characters].
+    pub fn with_characters(mut self, characters: CharactersConfig) -> Self {
+        self.characters = characters;
+        self
+    }
+
+    /// Set [LuminanceConfig::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = colors;
+        self
+    }
 }
 
 impl Default for LuminanceConfig {
@@ -316,14 +687,476 @@ impl Default for LuminanceConfig {
             characters: CharactersConfig {
                 list: RatatuiCameraStrategy::CHARACTERS_MISC.into(),
                 scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
+            },
+            colors: ColorsConfig::default(),
+        }
+    }
+}
+
+/// Configuration for the RatatuiCameraStrategy::LuminanceDepth terminal rendering strategy.
+///
+/// Each pixel's luminance is multiplied by `characters.scale` and its depth by `depth_scale`
+/// (each capped at 1.0), and the two are averaged using `luminance_weight` and `depth_weight` to
+/// select a character.
+///
+/// NOTE: The [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection) component is
+/// required on the same camera entity for this strategy to function, as it relies on the depth
+/// texture.
+///
+/// # Example:
+///
+/// The following configures the widget to select characters mostly by depth, with luminance
+/// adding some shading detail.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{
+/// #   RatatuiCamera, RatatuiCameraDepthDetection, RatatuiCameraStrategy, LuminanceDepthConfig
+/// # };
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// # commands.spawn((
+/// #     RatatuiCamera::default(),
+///     RatatuiCameraDepthDetection,
+///     RatatuiCameraStrategy::LuminanceDepth(
+///         LuminanceDepthConfig::default()
+///             .with_luminance_weight(0.25)
+///             .with_depth_weight(0.75),
+///     ),
+/// # ));
+/// # };
+/// ```
+///
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LuminanceDepthConfig {
+    /// Configuration options common to all strategies.
+    pub common: CommonConfig,
+
+    /// Configuration for determining the resulting characters. The scale is applied to
+    /// luminance.
+    pub characters: CharactersConfig,
+
+    /// Configuration for determining the resulting colors.
+    pub colors: ColorsConfig,
+
+    /// The number that each pixel's depth is multiplied by before being combined with luminance.
+    pub depth_scale: f32,
+
+    /// Relative weight of luminance in character selection.
+    pub luminance_weight: f32,
+
+    /// Relative weight of depth in character selection.
+    pub depth_weight: f32,
+}
+
+impl LuminanceDepthConfig {
+    /// Set [LuminanceDepthConfig::common].
+    pub fn with_common(mut self, common: CommonConfig) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// Set [LuminanceDepthConfig::characters].
+    pub fn with_characters(mut self, characters: CharactersConfig) -> Self {
+        self.characters = characters;
+        self
+    }
+
+    /// Set [LuminanceDepthConfig::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Set [LuminanceDepthConfig::depth_scale].
+    pub fn with_depth_scale(mut self, depth_scale: f32) -> Self {
+        self.depth_scale = depth_scale;
+        self
+    }
+
+    /// Set [LuminanceDepthConfig::luminance_weight].
+    pub fn with_luminance_weight(mut self, luminance_weight: f32) -> Self {
+        self.luminance_weight = luminance_weight;
+        self
+    }
+
+    /// Set [LuminanceDepthConfig::depth_weight].
+    pub fn with_depth_weight(mut self, depth_weight: f32) -> Self {
+        self.depth_weight = depth_weight;
+        self
+    }
+}
+
+impl Default for LuminanceDepthConfig {
+    fn default() -> Self {
+        Self {
+            common: CommonConfig::default(),
+            characters: CharactersConfig {
+                list: RatatuiCameraStrategy::CHARACTERS_MISC.into(),
+                scale: LuminanceConfig::SCALE_DEFAULT,
+                ramp: None,
+            },
+            colors: ColorsConfig::default(),
+            depth_scale: DepthConfig::SCALE_DEFAULT,
+            luminance_weight: 0.5,
+            depth_weight: 0.5,
+        }
+    }
+}
+
+/// Configuration for the RatatuiCameraStrategy::Glyphs terminal rendering strategy.
+///
+/// Each cell's block of pixels is compared against the coverage mask of each character in the
+/// `characters` list, and the closest match is printed. Characters without an embedded coverage
+/// mask are skipped. The `characters.scale` value multiplies each pixel's luminance before it is
+/// compared against the coverage masks.
+///
+/// # Example:
+///
+/// The following configures the widget to draw using only slashes, pipes, and dashes.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{
+/// #   RatatuiCamera, RatatuiCameraStrategy, GlyphsConfig, CharactersConfig
+/// # };
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// # commands.spawn((
+/// #     RatatuiCamera::default(),
+///     RatatuiCameraStrategy::Glyphs(GlyphsConfig::default().with_characters(
+///         CharactersConfig::new([' ', '/', '\\', '|', '-'], GlyphsConfig::SCALE_DEFAULT),
+///     )),
+/// # ));
+/// # };
+/// ```
+///
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GlyphsConfig {
+    /// Configuration options common to all strategies.
+    pub common: CommonConfig,
+
+    /// Configuration for determining the resulting characters.
+    pub characters: CharactersConfig,
+
+    /// Configuration for determining the resulting colors.
+    pub colors: ColorsConfig,
+}
+
+impl GlyphsConfig {
+    /// The default scaling value to multiply pixel luminance by.
+    pub const SCALE_DEFAULT: f32 = 5.;
+
+    /// Set [GlyphsConfig::common].
+    pub fn with_common(mut self, common: CommonConfig) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// Set [GlyphsConfig::characters].
+    pub fn with_characters(mut self, characters: CharactersConfig) -> Self {
+        self.characters = characters;
+        self
+    }
+
+    /// Set [GlyphsConfig::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = colors;
+        self
+    }
+}
+
+impl Default for GlyphsConfig {
+    fn default() -> Self {
+        Self {
+            common: CommonConfig::default(),
+            characters: CharactersConfig {
+                list: RatatuiCameraStrategy::CHARACTERS_GLYPHS.into(),
+                scale: GlyphsConfig::SCALE_DEFAULT,
+                ramp: None,
+            },
+            colors: ColorsConfig::default(),
+        }
+    }
+}
+
+/// Configuration for the RatatuiCameraStrategy::Threshold terminal rendering strategy.
+///
+/// # Example:
+///
+/// The following configures the widget to print '#' in bright areas and '.' in dim areas, using
+/// ordered dithering to approximate the shading in between.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{
+/// #   RatatuiCamera, RatatuiCameraStrategy, ThresholdConfig, ThresholdDithering, ThresholdOutput
+/// # };
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// # commands.spawn((
+/// #     RatatuiCamera::default(),
+///     RatatuiCameraStrategy::Threshold(
+///         ThresholdConfig::default()
+///             .with_output(ThresholdOutput::Characters { off: '.', on: '#' })
+///             .with_dithering(ThresholdDithering::Ordered),
+///     ),
+/// # ));
+/// # };
+/// ```
+///
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ThresholdConfig {
+    /// Configuration options common to all strategies.
+    pub common: CommonConfig,
+
+    /// Configuration for determining the resulting colors. The foreground and background color
+    /// choices are applied after thresholding, so they can be used to tint the output.
+    pub colors: ColorsConfig,
+
+    /// Luminance value between 0.0 and 1.0, at or above which a pixel is considered "on".
+    pub threshold: f32,
+
+    /// If present, dither the image before thresholding it, trading spatial detail for an
+    /// approximation of the shading lost by reducing the image to two tones.
+    pub dithering: Option<ThresholdDithering>,
+
+    /// How "on" and "off" pixels are printed to the terminal.
+    pub output: ThresholdOutput,
+}
+
+impl ThresholdConfig {
+    /// The default luminance threshold.
+    pub const THRESHOLD_DEFAULT: f32 = 0.5;
+
+    /// Set [ThresholdConfig::common].
+    pub fn with_common(mut self, common: CommonConfig) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// Set [ThresholdConfig::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Set [ThresholdConfig::threshold].
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set [ThresholdConfig::dithering].
+    pub fn with_dithering(mut self, dithering: ThresholdDithering) -> Self {
+        self.dithering = Some(dithering);
+        self
+    }
+
+    /// Set [ThresholdConfig::output].
+    pub fn with_output(mut self, output: ThresholdOutput) -> Self {
+        self.output = output;
+        self
+    }
+}
+
+impl Default for ThresholdConfig {
+    fn default() -> Self {
+        Self {
+            common: CommonConfig::default(),
+            colors: ColorsConfig::default(),
+            threshold: ThresholdConfig::THRESHOLD_DEFAULT,
+            dithering: None,
+            output: ThresholdOutput::default(),
+        }
+    }
+}
+
+/// Options for how the threshold strategy prints "on" and "off" pixels.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
+pub enum ThresholdOutput {
+    /// Each cell is printed as a halfblock character, with the top and bottom pixels thresholded
+    /// separately and drawn using the `on` or `off` color.
+    Colors {
+        /// Color for pixels below the threshold.
+        #[reflect(ignore)]
+        off: ratatui::style::Color,
+
+        /// Color for pixels at or above the threshold.
+        #[reflect(ignore)]
+        on: ratatui::style::Color,
+    },
+
+    /// The top and bottom pixels of each cell are averaged and thresholded together, and the cell
+    /// is printed as the `on` or `off` character, in white on black.
+    Characters {
+        /// Character for cells below the threshold.
+        off: char,
+
+        /// Character for cells at or above the threshold.
+        on: char,
+    },
+}
+
+impl Default for ThresholdOutput {
+    fn default() -> Self {
+        Self::Colors {
+            off: ratatui::style::Color::Black,
+            on: ratatui::style::Color::White,
+        }
+    }
+}
+
+/// Dithering algorithms available to the threshold strategy.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
+pub enum ThresholdDithering {
+    /// Offset each pixel by a value from a repeating 4x4 Bayer matrix, producing a regular
+    /// crosshatch-like pattern that stays stable as the scene moves.
+    Ordered,
+
+    /// Floyd-Steinberg error diffusion, spreading the error from thresholding each pixel onto its
+    /// unprocessed neighbors. Produces finer detail, but the pattern can shimmer in motion.
+    ErrorDiffusion,
+}
+
+/// Configuration for the RatatuiCameraStrategy::Normals terminal rendering strategy.
+///
+/// Each cell's surface normal (from the normal prepass, in world space) is compared against
+/// `light_direction` using a Lambertian dot product, and the resulting intensity (multiplied by
+/// `characters.scale`) selects a character from `characters.list`.
+///
+/// NOTE: The [RatatuiCameraNormalDetection](crate::RatatuiCameraNormalDetection) component is
+/// required on the same camera entity for this strategy to function, as it relies on the normal
+/// prepass texture.
+///
+/// # Example:
+///
+/// The following configures the widget to light the scene from above, in three flat bands that
+/// also darken the rendered colors.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{
+/// #   RatatuiCamera, RatatuiCameraNormalDetection, RatatuiCameraStrategy, NormalsConfig,
+/// #   CharactersConfig
+/// # };
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// # commands.spawn((
+/// #     RatatuiCamera::default(),
+///     RatatuiCameraNormalDetection,
+///     RatatuiCameraStrategy::Normals(
+///         NormalsConfig::default()
+///             .with_characters(CharactersConfig::new(
+///                 ['.', '+', '#'],
+///                 NormalsConfig::SCALE_DEFAULT,
+///             ))
+///             .with_light_direction(Vec3::Y)
+///             .with_shade_colors(true),
+///     ),
+/// # ));
+/// # };
+/// ```
+///
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct NormalsConfig {
+    /// Configuration options common to all strategies.
+    pub common: CommonConfig,
+
+    /// Configuration for determining the resulting characters.
+    pub characters: CharactersConfig,
+
+    /// Configuration for determining the resulting colors.
+    pub colors: ColorsConfig,
+
+    /// Direction pointing from surfaces towards the light, in world space. Does not need to be
+    /// normalized.
+    pub light_direction: Vec3,
+
+    /// Minimum intensity between 0.0 and 1.0, applied to surfaces facing away from the light.
+    pub ambient: f32,
+
+    /// If true, the foreground color of each cell is also darkened to match its selected
+    /// character's band, rather than using the rendered color as-is.
+    pub shade_colors: bool,
+}
+
+impl NormalsConfig {
+    /// The default scaling value to multiply light intensity by.
+    pub const SCALE_DEFAULT: f32 = 1.;
+
+    /// The default minimum intensity for surfaces facing away from the light.
+    pub const AMBIENT_DEFAULT: f32 = 0.1;
+
+    /// Set [NormalsConfig::common].
+    pub fn with_common(mut self, common: CommonConfig) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// Set [NormalsConfig::characters].
+    pub fn with_characters(mut self, characters: CharactersConfig) -> Self {
+        self.characters = characters;
+        self
+    }
+
+    /// Set [NormalsConfig::colors].
+    pub fn with_colors(mut self, colors: ColorsConfig) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Set [NormalsConfig::light_direction].
+    pub fn with_light_direction(mut self, light_direction: Vec3) -> Self {
+        self.light_direction = light_direction;
+        self
+    }
+
+    /// Set [NormalsConfig::ambient].
+    pub fn with_ambient(mut self, ambient: f32) -> Self {
+        self.ambient = ambient;
+        self
+    }
+
+    /// Set [NormalsConfig::shade_colors].
+    pub fn with_shade_colors(mut self, shade_colors: bool) -> Self {
+        self.shade_colors = shade_colors;
+        self
+    }
+}
+
+impl Default for NormalsConfig {
+    fn default() -> Self {
+        Self {
+            common: CommonConfig::default(),
+            characters: CharactersConfig {
+                // skips the leading space, so that surfaces facing away from the light are still
+                // drawn.
+                list: RatatuiCameraStrategy::CHARACTERS_SHADING[1..].into(),
+                scale: NormalsConfig::SCALE_DEFAULT,
+                ramp: None,
             },
             colors: ColorsConfig::default(),
+            light_direction: Vec3::ONE,
+            ambient: NormalsConfig::AMBIENT_DEFAULT,
+            shade_colors: false,
         }
     }
 }
 
 /// General configuration not specific to particular strategies.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct CommonConfig {
     /// If the alpha value of a rendered pixel is zero, skip writing that character to the ratatui
     /// buffer. Useful for compositing camera images together.
@@ -337,16 +1170,117 @@ pub struct CommonConfig {
     /// transparent camera entity. Only fully transparent pixels will be skipped. See the
     /// `transparency` example for more detail.
     pub transparent: bool,
+
+    /// If the alpha value of a rendered pixel is zero, reset that character in the ratatui buffer
+    /// to the terminal's default colors (`Color::Reset` for both foreground and background),
+    /// rather than skipping it or drawing it black. This lets the terminal's own background (and
+    /// theme) show through behind the rendered scene. Takes priority over `transparent`.
+    ///
+    /// As with `transparent`, set the `Camera` component's `clear_color` to fully transparent.
+    pub passthrough: bool,
+
+    /// Convert the rendered image to characters and colors in chunks across bevy's compute task
+    /// pool, before writing them to the ratatui buffer serially. Speeds up the more expensive
+    /// strategies (e.g. `Glyphs` or two-color `HalfBlocks`) in large terminals, but adds overhead
+    /// for small ones.
+    pub parallel: bool,
+
+    /// Style modifiers added to cells whose luminance or depth falls within a range (e.g. `DIM`
+    /// for dark cells and `BOLD` for bright ones), which increases the apparent dynamic range on
+    /// terminals with limited colors. Applied in order, after the strategy has drawn the cells.
+    pub modifiers: Vec<ModifierMapping>,
+}
+
+impl CommonConfig {
+    /// Set [CommonConfig::transparent].
+    pub fn with_transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    /// Set [CommonConfig::passthrough].
+    pub fn with_passthrough(mut self, passthrough: bool) -> Self {
+        self.passthrough = passthrough;
+        self
+    }
+
+    /// Set [CommonConfig::parallel].
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Set [CommonConfig::modifiers].
+    pub fn with_modifiers(mut self, modifiers: impl Into<Vec<ModifierMapping>>) -> Self {
+        self.modifiers = modifiers.into();
+        self
+    }
 }
 
 impl Default for CommonConfig {
     fn default() -> Self {
-        Self { transparent: true }
+        Self {
+            transparent: true,
+            passthrough: false,
+            parallel: false,
+            modifiers: Vec::new(),
+        }
     }
 }
 
+/// Adds a ratatui style modifier to each cell whose luminance or depth falls within a range. See
+/// [CommonConfig::modifiers].
+///
+/// ```no_run
+/// # use bevy_ratatui_camera::{ModifierMapping, ModifierSource};
+/// # use ratatui::style::Modifier;
+/// let dim_shadows = ModifierMapping::new(ModifierSource::Luminance, 0.0, 0.2, Modifier::DIM);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+pub struct ModifierMapping {
+    /// The value that is compared against the range.
+    pub source: ModifierSource,
+
+    /// Lowest value (inclusive) that receives the modifier, between 0.0 and 1.0.
+    pub min: f32,
+
+    /// Highest value (inclusive) that receives the modifier, between 0.0 and 1.0.
+    pub max: f32,
+
+    /// Modifier added to cells within the range.
+    #[reflect(ignore)]
+    pub modifier: Modifier,
+}
+
+impl ModifierMapping {
+    /// Add `modifier` to cells whose `source` value falls between `min` and `max` (inclusive).
+    pub fn new(source: ModifierSource, min: f32, max: f32, modifier: Modifier) -> Self {
+        Self {
+            source,
+            min,
+            max,
+            modifier,
+        }
+    }
+}
+
+/// The per-cell value compared against a [ModifierMapping]'s range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize, Reflect)]
+pub enum ModifierSource {
+    /// Luminance of the cell's rendered color, from `0.0` (black) to `1.0` (white).
+    #[default]
+    Luminance,
+
+    /// Depth of the nearest surface in the cell, mapped from `0.0` (far) to `1.0` (near). Requires
+    /// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection), and cells where nothing
+    /// was rendered are left as they are.
+    Depth(DepthMapping),
+}
+
 /// Configuration pertaining to character selection, based on criteria determined by the strategy.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
 pub struct CharactersConfig {
     /// The list of characters, in increasing order of opacity, to use for printing. For example,
     /// put an '@' symbol after a '+' symbol because it is more "opaque", taking up more space in
@@ -359,10 +1293,69 @@ pub struct CharactersConfig {
     /// selection metrics will not occupy the full range between 0.0 and 1.0, and so each luminance
     /// value can be multiplied by a scaling value first to tune the character selection.
     pub scale: f32,
+
+    /// If present, `list` (and `scale`, if the ramp provides one) will be kept in sync with the
+    /// contents of this character ramp asset once it has loaded. See
+    /// [CharactersConfig::from_asset]. Not included when serializing.
+    #[serde(skip)]
+    pub ramp: Option<Handle<CharacterRamp>>,
+}
+
+impl CharactersConfig {
+    /// Characters config with the provided list of characters (in increasing order of opacity)
+    /// and scale.
+    pub fn new(list: impl Into<Vec<char>>, scale: f32) -> Self {
+        Self {
+            list: list.into(),
+            scale,
+            ramp: None,
+        }
+    }
+
+    /// Characters config that follows the provided [CharacterRamp] asset, updating whenever the
+    /// asset is loaded or modified. Nothing is printed until the asset has loaded. If the ramp
+    /// file does not provide a scale, override the placeholder scale of `1.0`:
+    ///
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_ratatui_camera::{CharactersConfig, LuminanceConfig};
+    /// #
+    /// # fn setup_scene_system(asset_server: Res<AssetServer>) {
+    /// let characters = CharactersConfig::from_asset(asset_server.load("ramps/dense.ramp.ron"))
+    ///     .with_scale(LuminanceConfig::SCALE_DEFAULT);
+    /// # }
+    /// ```
+    pub fn from_asset(ramp: Handle<CharacterRamp>) -> Self {
+        Self {
+            list: Vec::new(),
+            scale: 1.,
+            ramp: Some(ramp),
+        }
+    }
+
+    /// Set [CharactersConfig::list].
+    pub fn with_list(mut self, list: impl Into<Vec<char>>) -> Self {
+        self.list = list.into();
+        self
+    }
+
+    /// Set [CharactersConfig::scale].
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set [CharactersConfig::ramp].
+    pub fn with_ramp(mut self, ramp: Handle<CharacterRamp>) -> Self {
+        self.ramp = Some(ramp);
+        self
+    }
 }
 
 /// Configuration pertaining to color selection.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ColorsConfig {
     /// If present, customizes how the foreground color should be chosen per character.
     pub foreground: Option<ColorChoice>,
@@ -374,8 +1367,8 @@ pub struct ColorsConfig {
     /// but some only support pre-defined sets of 16 or 256 ANSI colors. By default the `RGB` enum
     /// variant will be used, which transparently uses the rgb u8 triplet to create a ratatui
     /// `Color::RGB` color. If set to the `ANSI16` or `ANSI256` enum variants, this strategy will
-    /// find the ANSI color within those sets closest to the original rgb color (by Euclidean
-    /// distance), and then convert to the corresponding ratatui `Color::Indexed` (for 256 colors)
+    /// find the ANSI color within those sets closest to the original rgb color (as measured by
+    /// `distance`), and then convert to the corresponding ratatui `Color::Indexed` (for 256 colors)
     /// or named ANSI color, like `Color::Cyan` (for 16 colors).
     ///
     /// Colors that are from a more limited set will not be converted "upwards" to the more
@@ -386,15 +1379,243 @@ pub struct ColorsConfig {
     /// Reference for terminal color support:
     /// https://en.wikipedia.org/wiki/ANSI_escape_code#Colors
     pub support: ColorSupport,
+
+    /// How the closest ANSI color is measured when `support` is `ANSI16` or `ANSI256`. Defaults to
+    /// Euclidean distance between rgb values, `ColorDistance::OkLab` is perceptually closer.
+    pub distance: ColorDistance,
+
+    /// Multiplier applied to each pixel's color channels before characters and colors are
+    /// selected, like adjusting a camera's exposure. `1.0` leaves the image unchanged, higher
+    /// values brighten dark scenes without changing scene lighting.
+    pub brightness: f32,
+
+    /// Scales each pixel's color channels away from (or towards) middle gray. `1.0` leaves the
+    /// image unchanged, higher values increase contrast, and `0.0` flattens the image to gray.
+    pub contrast: f32,
+
+    /// Scales each pixel's color away from (or towards) its own luminance. `1.0` leaves the image
+    /// unchanged, higher values make colors more vivid, and `0.0` removes all color.
+    pub saturation: f32,
+
+    /// Gamma correction applied to each pixel's color channels, as `channel ^ (1 / gamma)`. `1.0`
+    /// leaves the image unchanged, higher values lift dark tones while keeping highlights.
+    pub gamma: f32,
+
+    /// If present, each pixel of the rendered image will be converted to grayscale (using the
+    /// provided channel weights) before characters and colors are selected.
+    pub grayscale: Option<GrayscaleWeights>,
+
+    /// If present, each pixel of the rendered image will be faded towards a fog color based on its
+    /// depth, before characters and colors are selected. Requires the
+    /// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection) component on the same
+    /// camera entity, and is skipped otherwise.
+    pub fog: Option<DepthFog>,
+
+    /// If present, each pixel of the rendered image will be color graded using this 3D lookup
+    /// table once it has loaded, after any other adjustments. Accepts either a `.cube` file
+    /// loaded as a [ColorLut](crate::ColorLut), or an image containing a LUT (see
+    /// [ColorLut::from_image](crate::ColorLut::from_image)). Not included when serializing.
+    #[serde(skip)]
+    pub lut: Option<ColorLutHandle>,
+}
+
+impl Default for ColorsConfig {
+    fn default() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            support: ColorSupport::default(),
+            distance: ColorDistance::default(),
+            brightness: 1.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            gamma: 1.0,
+            grayscale: None,
+            fog: None,
+            lut: None,
+        }
+    }
+}
+
+impl ColorsConfig {
+    /// Whether any of the brightness, contrast, saturation, or gamma adjustments change the
+    /// image.
+    pub(crate) fn has_tone_adjustments(&self) -> bool {
+        [self.brightness, self.contrast, self.saturation, self.gamma] != [1.0; 4]
+    }
+
+    /// Set [ColorsConfig::foreground].
+    pub fn with_foreground(mut self, foreground: ColorChoice) -> Self {
+        self.foreground = Some(foreground);
+        self
+    }
+
+    /// Set [ColorsConfig::background].
+    pub fn with_background(mut self, background: ColorChoice) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Set [ColorsConfig::support].
+    pub fn with_support(mut self, support: ColorSupport) -> Self {
+        self.support = support;
+        self
+    }
+
+    /// Set [ColorsConfig::distance].
+    pub fn with_distance(mut self, distance: ColorDistance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Set [ColorsConfig::brightness].
+    pub fn with_brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness;
+        self
+    }
+
+    /// Set [ColorsConfig::contrast].
+    pub fn with_contrast(mut self, contrast: f32) -> Self {
+        self.contrast = contrast;
+        self
+    }
+
+    /// Set [ColorsConfig::saturation].
+    pub fn with_saturation(mut self, saturation: f32) -> Self {
+        self.saturation = saturation;
+        self
+    }
+
+    /// Set [ColorsConfig::gamma].
+    pub fn with_gamma(mut self, gamma: f32) -> Self {
+        self.gamma = gamma;
+        self
+    }
+
+    /// Set [ColorsConfig::grayscale].
+    pub fn with_grayscale(mut self, grayscale: GrayscaleWeights) -> Self {
+        self.grayscale = Some(grayscale);
+        self
+    }
+
+    /// Set [ColorsConfig::fog].
+    pub fn with_fog(mut self, fog: DepthFog) -> Self {
+        self.fog = Some(fog);
+        self
+    }
+
+    /// Set [ColorsConfig::lut].
+    pub fn with_lut(mut self, lut: ColorLutHandle) -> Self {
+        self.lut = Some(lut);
+        self
+    }
+}
+
+/// Fades the rendered image towards a color with distance from the camera, for cheap atmospheric
+/// fog without changing the bevy scene.
+///
+/// # Example:
+///
+/// The following fades surfaces towards a dark blue, starting 10 units from the camera and fully
+/// fogged at 40 units away.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{ColorsConfig, DepthFog, DepthMapping};
+/// #
+/// ColorsConfig::default().with_fog(
+///     DepthFog::default()
+///         .with_color([10, 20, 40])
+///         .with_mapping(DepthMapping::Linear { near: 10.0, far: 40.0 }),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct DepthFog {
+    /// The sRGB color that distant pixels are faded towards. Black darkens distant pixels.
+    pub color: [u8; 3],
+
+    /// How each pixel's depth is mapped to fog, where the sparsest end of the mapping (`0.0`) is
+    /// fully fogged and the densest end (`1.0`) is clear.
+    pub mapping: DepthMapping,
+
+    /// The maximum fraction that a pixel's color is faded towards the fog color, from `0.0` to
+    /// `1.0`.
+    pub max_amount: f32,
+}
+
+impl DepthFog {
+    /// Set [DepthFog::color].
+    pub fn with_color(mut self, color: [u8; 3]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set [DepthFog::mapping].
+    pub fn with_mapping(mut self, mapping: DepthMapping) -> Self {
+        self.mapping = mapping;
+        self
+    }
+
+    /// Set [DepthFog::max_amount].
+    pub fn with_max_amount(mut self, max_amount: f32) -> Self {
+        self.max_amount = max_amount;
+        self
+    }
+}
+
+impl Default for DepthFog {
+    fn default() -> Self {
+        Self {
+            color: [0, 0, 0],
+            mapping: DepthMapping::Linear {
+                near: 5.0,
+                far: 50.0,
+            },
+            max_amount: 1.0,
+        }
+    }
+}
+
+/// Weights used for combining the red, green, and blue channels of a pixel into a single gray
+/// value, when converting the rendered image to grayscale.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Reflect)]
+pub enum GrayscaleWeights {
+    /// Weights from ITU-R BT.709, matching the perceived brightness of modern displays.
+    #[default]
+    Rec709,
+
+    /// Weights from ITU-R BT.601, matching the perceived brightness of older displays.
+    Rec601,
+
+    /// Each channel is weighted equally.
+    Average,
+
+    /// Custom weights for the red, green, and blue channels, respectively. Weights should usually
+    /// add up to 1.0, otherwise the image will be brightened or darkened.
+    Custom([f32; 3]),
+}
+
+impl GrayscaleWeights {
+    /// The weights for the red, green, and blue channels, respectively.
+    pub fn weights(&self) -> [f32; 3] {
+        match self {
+            Self::Rec709 => [0.2126, 0.7152, 0.0722],
+            Self::Rec601 => [0.299, 0.587, 0.114],
+            Self::Average => [1. / 3., 1. / 3., 1. / 3.],
+            Self::Custom(weights) => *weights,
+        }
+    }
 }
 
 /// Options for customizing a terminal buffer color (foreground or background). Customization
 /// happens after depth detection and edge detection, and before the conversion for color support
 /// and the transparency check.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize, Reflect)]
 pub enum ColorChoice {
     /// Overrides the color with a single provided color.
-    Color(ratatui::style::Color),
+    Color(#[reflect(ignore)] ratatui::style::Color),
 
     /// Color will be determined by scaling the foreground color by the provided value. For
     /// example, `ColorChoice::Scale(0.5)` will be half as bright as the calculated foreground
@@ -410,7 +1631,12 @@ pub enum ColorChoice {
     /// background should be skipped by conditionally returning `None` from the callback. Your
     /// callback needs to be wrapped in an `Arc` as `RatatuiCameraStrategy` is cloned during
     /// render (or you can use the `from_callback()` convenience method which wraps it for you).
+    ///
+    /// Callbacks cannot be serialized or deserialized, so serializing a config containing this
+    /// variant will fail.
+    #[serde(skip)]
     Callback(
+        #[reflect(ignore, default = "ColorChoice::passthrough_callback")]
         Arc<
             dyn Fn(
                     Option<ratatui::style::Color>,
@@ -454,4 +1680,18 @@ impl ColorChoice {
     {
         Self::Callback(Arc::new(callback))
     }
+
+    /// Callback that keeps the foreground color, used in place of callbacks when a
+    /// `ColorChoice::Callback` is created through reflection, as callbacks are not reflected.
+    fn passthrough_callback() -> Arc<
+        dyn Fn(
+                Option<ratatui::style::Color>,
+                Option<ratatui::style::Color>,
+            ) -> Option<ratatui::style::Color>
+            + Send
+            + Sync
+            + 'static,
+    > {
+        Arc::new(|foreground, _| foreground)
+    }
 }
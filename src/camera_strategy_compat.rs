@@ -0,0 +1,137 @@
+// Deprecated accessors mapping the field and constant names used by previous releases onto the
+// current strategy configuration structs, to smooth upgrades. These will be removed in a future
+// release.
+
+use crate::{ColorSupport, DepthConfig, HalfBlocksConfig, LuminanceConfig, RatatuiCameraStrategy};
+
+impl LuminanceConfig {
+    /// Previous name for [RatatuiCameraStrategy::CHARACTERS_BRAILLE].
+    #[deprecated(note = "use `RatatuiCameraStrategy::CHARACTERS_BRAILLE` instead")]
+    pub const LUMINANCE_CHARACTERS_BRAILLE: &'static [char] =
+        RatatuiCameraStrategy::CHARACTERS_BRAILLE;
+
+    /// Previous name for [RatatuiCameraStrategy::CHARACTERS_MISC].
+    #[deprecated(note = "use `RatatuiCameraStrategy::CHARACTERS_MISC` instead")]
+    pub const LUMINANCE_CHARACTERS_MISC: &'static [char] = RatatuiCameraStrategy::CHARACTERS_MISC;
+
+    /// Previous name for [RatatuiCameraStrategy::CHARACTERS_SHADING].
+    #[deprecated(note = "use `RatatuiCameraStrategy::CHARACTERS_SHADING` instead")]
+    pub const LUMINANCE_CHARACTERS_SHADING: &'static [char] =
+        RatatuiCameraStrategy::CHARACTERS_SHADING;
+
+    /// Previous name for [LuminanceConfig::SCALE_DEFAULT].
+    #[deprecated(note = "use `LuminanceConfig::SCALE_DEFAULT` instead")]
+    pub const LUMINANCE_SCALE_DEFAULT: f32 = LuminanceConfig::SCALE_DEFAULT;
+
+    /// Previous location of `characters.list`.
+    #[deprecated(note = "use the `characters.list` field instead")]
+    pub fn luminance_characters(&self) -> &[char] {
+        &self.characters.list
+    }
+
+    /// Previous location of `characters.list`.
+    #[deprecated(note = "use the `characters.list` field instead")]
+    pub fn set_luminance_characters(&mut self, characters: &[char]) {
+        self.characters.list = characters.into();
+    }
+
+    /// Previous location of `characters.scale`.
+    #[deprecated(note = "use the `characters.scale` field instead")]
+    pub fn luminance_scale(&self) -> f32 {
+        self.characters.scale
+    }
+
+    /// Previous location of `characters.scale`.
+    #[deprecated(note = "use the `characters.scale` field instead")]
+    pub fn set_luminance_scale(&mut self, scale: f32) {
+        self.characters.scale = scale;
+    }
+
+    /// Previous location of `colors.support`.
+    #[deprecated(note = "use the `colors.support` field instead")]
+    pub fn color_support(&self) -> ColorSupport {
+        self.colors.support
+    }
+
+    /// Previous location of `colors.support`.
+    #[deprecated(note = "use the `colors.support` field instead")]
+    pub fn set_color_support(&mut self, support: ColorSupport) {
+        self.colors.support = support;
+    }
+
+    /// Previous location of `common.transparent`.
+    #[deprecated(note = "use the `common.transparent` field instead")]
+    pub fn transparent(&self) -> bool {
+        self.common.transparent
+    }
+
+    /// Previous location of `common.transparent`.
+    #[deprecated(note = "use the `common.transparent` field instead")]
+    pub fn set_transparent(&mut self, transparent: bool) {
+        self.common.transparent = transparent;
+    }
+}
+
+impl DepthConfig {
+    /// Previous location of `characters.list`.
+    #[deprecated(note = "use the `characters.list` field instead")]
+    pub fn depth_characters(&self) -> &[char] {
+        &self.characters.list
+    }
+
+    /// Previous location of `characters.list`.
+    #[deprecated(note = "use the `characters.list` field instead")]
+    pub fn set_depth_characters(&mut self, characters: &[char]) {
+        self.characters.list = characters.into();
+    }
+
+    /// Previous location of `characters.scale`.
+    #[deprecated(note = "use the `characters.scale` field instead")]
+    pub fn depth_scale(&self) -> f32 {
+        self.characters.scale
+    }
+
+    /// Previous location of `characters.scale`.
+    #[deprecated(note = "use the `characters.scale` field instead")]
+    pub fn set_depth_scale(&mut self, scale: f32) {
+        self.characters.scale = scale;
+    }
+
+    /// Previous location of `colors.support`.
+    #[deprecated(note = "use the `colors.support` field instead")]
+    pub fn color_support(&self) -> ColorSupport {
+        self.colors.support
+    }
+
+    /// Previous location of `colors.support`.
+    #[deprecated(note = "use the `colors.support` field instead")]
+    pub fn set_color_support(&mut self, support: ColorSupport) {
+        self.colors.support = support;
+    }
+}
+
+impl HalfBlocksConfig {
+    /// Previous location of `colors.support`.
+    #[deprecated(note = "use the `colors.support` field instead")]
+    pub fn color_support(&self) -> ColorSupport {
+        self.colors.support
+    }
+
+    /// Previous location of `colors.support`.
+    #[deprecated(note = "use the `colors.support` field instead")]
+    pub fn set_color_support(&mut self, support: ColorSupport) {
+        self.colors.support = support;
+    }
+
+    /// Previous location of `common.transparent`.
+    #[deprecated(note = "use the `common.transparent` field instead")]
+    pub fn transparent(&self) -> bool {
+        self.common.transparent
+    }
+
+    /// Previous location of `common.transparent`.
+    #[deprecated(note = "use the `common.transparent` field instead")]
+    pub fn set_transparent(&mut self, transparent: bool) {
+        self.common.transparent = transparent;
+    }
+}
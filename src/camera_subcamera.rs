@@ -0,0 +1,166 @@
+use bevy::{camera::RenderTarget, prelude::*};
+
+use crate::{RatatuiCamera, RatatuiSubcamera, RatatuiSubcameras};
+
+pub struct RatatuiCameraSubcameraPlugin;
+
+impl Plugin for RatatuiCameraSubcameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<RatatuiSubcameraTargetChanged>()
+            .add_observer(ratatui_subcamera_insert_observer)
+            .add_observer(ratatui_subcamera_removal_observer)
+            .add_systems(PostUpdate, update_subcamera_order_system);
+    }
+}
+
+/// By default, subcameras are set not to clear the render texture they share with their main
+/// camera (`ClearColorConfig::None`), so that they draw over the main camera's output instead of
+/// erasing it. Insert this alongside a [RatatuiSubcamera] to opt out and keep the `clear_color`
+/// set on its `Camera` component.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiSubcamera, RatatuiSubcameraManualClear};
+/// #
+/// # fn spawn_subcamera(mut commands: Commands, main_camera: Entity) {
+/// // replaces the main camera's output with a solid background before drawing.
+/// commands.spawn((
+///     Camera3d::default(),
+///     Camera {
+///         clear_color: ClearColorConfig::Custom(Color::BLACK),
+///         ..default()
+///     },
+///     RatatuiSubcamera::new(main_camera),
+///     RatatuiSubcameraManualClear,
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct RatatuiSubcameraManualClear;
+
+/// Message written when a subcamera is related to a RatatuiCamera (including when it is retargeted
+/// by re-inserting its [RatatuiSubcamera] with a different target), or detached from one because
+/// the relation was removed or the main camera was despawned.
+///
+/// Detached subcameras stop rendering (their `Camera` is deactivated and no longer targets the
+/// main camera's render texture) until they are related to a RatatuiCamera again.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiSubcamera, RatatuiSubcameraTargetChanged};
+/// #
+/// // relations cannot be mutated in place, so re-insert to point a subcamera at another camera.
+/// fn retarget_system(
+///     mut commands: Commands,
+///     subcamera: Single<(Entity, &RatatuiSubcamera)>,
+///     other_camera: Single<Entity, With<Camera>>,
+/// ) {
+///     let (entity, subcamera) = *subcamera;
+///     commands.entity(entity).insert(RatatuiSubcamera {
+///         target: *other_camera,
+///         ..*subcamera
+///     });
+/// }
+///
+/// fn detached_system(mut target_changed: MessageReader<RatatuiSubcameraTargetChanged>) {
+///     for RatatuiSubcameraTargetChanged { entity, target } in target_changed.read() {
+///         if target.is_none() {
+///             info!("subcamera {entity} no longer renders anywhere");
+///         }
+///     }
+/// }
+/// ```
+///
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RatatuiSubcameraTargetChanged {
+    /// The subcamera entity.
+    pub entity: Entity,
+
+    /// The RatatuiCamera entity the subcamera now renders to, or `None` if it was detached.
+    pub target: Option<Entity>,
+}
+
+fn ratatui_subcamera_insert_observer(
+    insert: On<Insert, RatatuiSubcamera>,
+    ratatui_subcameras: Query<(&RatatuiSubcamera, Has<RatatuiSubcameraManualClear>)>,
+    mut cameras: Query<&mut Camera>,
+    mut target_changed: MessageWriter<RatatuiSubcameraTargetChanged>,
+) {
+    let Ok((subcamera, manual_clear)) = ratatui_subcameras.get(insert.entity) else {
+        return;
+    };
+
+    if let Ok(mut camera) = cameras.get_mut(insert.entity) {
+        if !manual_clear {
+            camera.clear_color = ClearColorConfig::None;
+        }
+
+        // reactivate subcameras that were previously detached.
+        if matches!(camera.target, RenderTarget::None { .. }) {
+            camera.is_active = true;
+        }
+    }
+
+    target_changed.write(RatatuiSubcameraTargetChanged {
+        entity: insert.entity,
+        target: Some(subcamera.target),
+    });
+}
+
+/// Detaches the subcamera from the main camera's render texture when the relation is removed
+/// (including when the main camera is despawned), so that it doesn't keep rendering to a texture
+/// nothing reads back.
+fn ratatui_subcamera_removal_observer(
+    remove: On<Remove, RatatuiSubcamera>,
+    mut cameras: Query<&mut Camera>,
+    mut target_changed: MessageWriter<RatatuiSubcameraTargetChanged>,
+) {
+    if let Ok(mut camera) = cameras.get_mut(remove.entity) {
+        camera.is_active = false;
+        camera.target = RenderTarget::None { size: UVec2::ONE };
+    }
+
+    target_changed.write(RatatuiSubcameraTargetChanged {
+        entity: remove.entity,
+        target: None,
+    });
+}
+
+/// Keeps each subcamera's `Camera::order` just above its main camera's, ranked by the
+/// subcamera's order (and then by the order they were related in), so that subcameras sharing a
+/// render texture composite deterministically and never share an order.
+fn update_subcamera_order_system(
+    ratatui_cameras: Query<(Entity, &RatatuiSubcameras), With<RatatuiCamera>>,
+    ratatui_subcameras: Query<&RatatuiSubcamera>,
+    mut cameras: Query<&mut Camera>,
+    mut ranked: Local<Vec<(isize, Entity)>>,
+) {
+    for (entity, subcameras) in &ratatui_cameras {
+        let Ok(main_order) = cameras.get(entity).map(|camera| camera.order) else {
+            continue;
+        };
+
+        ranked.clear();
+        ranked.extend(subcameras.iter().filter_map(|subcamera_entity| {
+            let subcamera = ratatui_subcameras.get(subcamera_entity).ok()?;
+            Some((subcamera.order, subcamera_entity))
+        }));
+
+        // stable, so that ties keep the order the subcameras were related in.
+        ranked.sort_by_key(|(order, _)| *order);
+
+        for (rank, (_, subcamera_entity)) in ranked.iter().enumerate() {
+            let order = main_order + 1 + rank as isize;
+            if let Ok(mut camera) = cameras.get_mut(*subcamera_entity)
+                && camera.order != order
+            {
+                camera.order = order;
+            }
+        }
+    }
+}
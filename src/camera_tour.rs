@@ -0,0 +1,256 @@
+use std::path::{Path, PathBuf};
+
+use bevy::{prelude::*, transform::TransformSystems};
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+use crate::{RatatuiCameraWidget, buffer_ansi::buffer_to_ansi};
+
+/// Utility plugin that animates cameras containing a [RatatuiCameraTour] component along the
+/// tour's path, capturing the converted terminal output of each step. Not included in
+/// [RatatuiCameraPlugin](crate::RatatuiCameraPlugin), add it alongside when you want to record
+/// demo reels of a scene.
+#[derive(Debug)]
+pub struct RatatuiCameraTourPlugin;
+
+impl Plugin for RatatuiCameraTourPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<RatatuiCameraTourFinished>().add_systems(
+            PostUpdate,
+            update_ratatui_camera_tours_system.before(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// Insert into an entity with a `RatatuiCamera` to move the camera along a path, one step per
+/// frame read back from the camera, capturing the converted terminal output of each step. When the tour is complete the
+/// component is removed, a [RatatuiCameraTourFinished] message is written containing the
+/// captured frames, and (if `output` is set) each frame is saved as an ANSI text file. Requires
+/// [RatatuiCameraTourPlugin].
+///
+/// # Example:
+///
+/// The following orbits a camera once around the origin over 120 frames, saving each frame to
+/// the `reel` directory.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraTour};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     Camera3d::default(),
+///     Transform::from_xyz(2.5, 2.5, 2.5).looking_at(Vec3::ZERO, Vec3::Z),
+///     RatatuiCameraTour::turntable(Vec3::ZERO, Dir3::Z, 120).with_output("reel"),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiCameraTour {
+    /// The path the camera follows.
+    pub path: RatatuiCameraTourPath,
+
+    /// The number of steps (and captured frames) in the tour.
+    pub frame_count: u32,
+
+    /// Dimensions (columns, rows) of the terminal area each frame is captured at. If `None`, the
+    /// area the camera widget was last drawn within is used, so that captured frames match what
+    /// is displayed in the terminal. Set this when the camera widget is not drawn anywhere else
+    /// (e.g. when recording headlessly), as drawing the widget in two differently sized areas
+    /// each frame will cause it to be resized continuously.
+    pub size: Option<(u16, u16)>,
+
+    /// If present, a directory that each captured frame will be saved into as an ANSI text file
+    /// (`frame_0000.ans`, `frame_0001.ans`, etc.).
+    pub output: Option<PathBuf>,
+
+    step: u32,
+    step_frame_number: u64,
+    start: Option<Transform>,
+    frames: Vec<Buffer>,
+}
+
+/// Paths that a [RatatuiCameraTour] can move a camera along.
+#[derive(Clone, Debug)]
+pub enum RatatuiCameraTourPath {
+    /// Orbit once around `center`, rotating around `axis`, while looking at `center`. The
+    /// camera's transform at the start of the tour determines its distance and height.
+    Turntable {
+        /// Point the camera orbits around and looks at.
+        center: Vec3,
+
+        /// Axis the camera orbits around, also used as the camera's up direction.
+        axis: Dir3,
+    },
+
+    /// Move through each transform in order, interpolating between them. The first and last
+    /// steps of the tour are placed exactly at the first and last keyframes.
+    Keyframes(Vec<Transform>),
+}
+
+impl RatatuiCameraTour {
+    /// Create a tour along the provided path, with the provided number of steps.
+    pub fn new(path: RatatuiCameraTourPath, frame_count: u32) -> Self {
+        Self {
+            path,
+            frame_count,
+            size: None,
+            output: None,
+            step: 0,
+            step_frame_number: 0,
+            start: None,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Create a tour that orbits once around `center`, see [RatatuiCameraTourPath::Turntable].
+    pub fn turntable(center: Vec3, axis: Dir3, frame_count: u32) -> Self {
+        Self::new(
+            RatatuiCameraTourPath::Turntable { center, axis },
+            frame_count,
+        )
+    }
+
+    /// Create a tour that moves through the provided keyframes, see
+    /// [RatatuiCameraTourPath::Keyframes].
+    pub fn keyframes(keyframes: Vec<Transform>, frame_count: u32) -> Self {
+        Self::new(RatatuiCameraTourPath::Keyframes(keyframes), frame_count)
+    }
+
+    /// Capture each frame at a fixed size, see [RatatuiCameraTour::size].
+    pub fn with_size(mut self, columns: u16, rows: u16) -> Self {
+        self.size = Some((columns, rows));
+        self
+    }
+
+    /// Save each captured frame into the provided directory, see [RatatuiCameraTour::output].
+    pub fn with_output(mut self, output: impl Into<PathBuf>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+
+    /// The camera transform for the provided step of the tour.
+    fn transform_for_step(&self, step: u32, start: &Transform) -> Transform {
+        match &self.path {
+            RatatuiCameraTourPath::Turntable { center, axis } => {
+                let angle = std::f32::consts::TAU * step as f32 / self.frame_count.max(1) as f32;
+                let offset = Quat::from_axis_angle(**axis, angle) * (start.translation - *center);
+
+                Transform::from_translation(*center + offset).looking_at(*center, *axis)
+            }
+            RatatuiCameraTourPath::Keyframes(keyframes) => {
+                let (Some(first), Some(last)) = (keyframes.first(), keyframes.last()) else {
+                    return *start;
+                };
+
+                if keyframes.len() == 1 || self.frame_count <= 1 {
+                    return *first;
+                }
+
+                let progress =
+                    step as f32 / (self.frame_count - 1) as f32 * (keyframes.len() - 1) as f32;
+                let index = progress.floor() as usize;
+
+                let (Some(from), Some(to)) = (keyframes.get(index), keyframes.get(index + 1))
+                else {
+                    return *last;
+                };
+
+                let t = progress - index as f32;
+
+                Transform {
+                    translation: from.translation.lerp(to.translation, t),
+                    rotation: from.rotation.slerp(to.rotation, t),
+                    scale: from.scale.lerp(to.scale, t),
+                }
+            }
+        }
+    }
+}
+
+/// Written when a [RatatuiCameraTour] completes, containing the captured frames.
+#[derive(Message, Debug)]
+pub struct RatatuiCameraTourFinished {
+    /// The camera entity that completed the tour.
+    pub entity: Entity,
+
+    /// The captured terminal output for each step of the tour.
+    pub frames: Vec<Buffer>,
+}
+
+fn update_ratatui_camera_tours_system(
+    mut commands: Commands,
+    mut ratatui_cameras: Query<(
+        Entity,
+        &mut RatatuiCameraTour,
+        &mut Transform,
+        Option<&RatatuiCameraWidget>,
+    )>,
+    mut tour_finished_messages: MessageWriter<RatatuiCameraTourFinished>,
+) {
+    for (entity, mut tour, mut transform, widget) in &mut ratatui_cameras {
+        let start = *tour.start.get_or_insert(*transform);
+
+        // the camera image lags behind the transform, so each step's frame is captured once a
+        // frame rendered after the step was applied has been read back, before moving on.
+        if tour.step > 0 {
+            let Some(widget) = widget else {
+                continue;
+            };
+
+            // frame numbers restart when the camera is resized, so any change is a newer frame.
+            if !widget.is_ready() || widget.frame_number == tour.step_frame_number {
+                continue;
+            }
+
+            let area = tour
+                .size
+                .map(|(columns, rows)| Rect::new(0, 0, columns, rows))
+                .unwrap_or(widget.last_area);
+
+            // if the widget is resizing to fit the capture area, wait for the resize instead of
+            // capturing an empty frame.
+            let resizing = widget.last_area != area;
+
+            let mut buffer = Buffer::empty(area);
+            widget.render(area, &mut buffer);
+
+            if resizing {
+                continue;
+            }
+
+            tour.frames.push(buffer);
+        }
+
+        if tour.step >= tour.frame_count {
+            let frames = std::mem::take(&mut tour.frames);
+
+            if let Some(output) = &tour.output {
+                save_frames(output, &frames);
+            }
+
+            commands.entity(entity).remove::<RatatuiCameraTour>();
+            tour_finished_messages.write(RatatuiCameraTourFinished { entity, frames });
+            continue;
+        }
+
+        *transform = tour.transform_for_step(tour.step, &start);
+        tour.step += 1;
+        tour.step_frame_number = widget.map_or(0, |widget| widget.frame_number);
+    }
+}
+
+fn save_frames(output: &Path, frames: &[Buffer]) {
+    if let Err(e) = std::fs::create_dir_all(output) {
+        log::warn!("failed to create camera tour output directory {output:?}: {e}");
+        return;
+    }
+
+    for (index, frame) in frames.iter().enumerate() {
+        let path = output.join(format!("frame_{index:04}.ans"));
+        if let Err(e) = std::fs::write(&path, buffer_to_ansi(frame)) {
+            log::warn!("failed to save camera tour frame {path:?}: {e}");
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use crate::{RatatuiCamera, camera_readback::CameraTargetingMessage};
+
+/// When spawned with a RatatuiCamera, bevy UI nodes are drawn into the camera's render texture
+/// along with the rest of the scene, so buttons, health bars, and other bevy UI elements show up
+/// in the terminal. Makes the camera the default UI camera (see [IsDefaultUiCamera]); if more than
+/// one camera should draw UI, target each root node at its camera with [UiTargetCamera] instead.
+///
+/// The render texture usually has only a few pixels per terminal cell, so prefer sizing nodes with
+/// percentages, or raise the `scale_factor` to make pixel sizes smaller relative to the image.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraUi};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::default(),
+///     RatatuiCameraUi::default(),
+///     Camera3d::default(),
+/// ));
+///
+/// commands.spawn((
+///     Node {
+///         width: Val::Percent(30.),
+///         height: Val::Percent(10.),
+///         ..default()
+///     },
+///     BackgroundColor(Color::srgb(0.8, 0.1, 0.1)),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug)]
+#[require(IsDefaultUiCamera)]
+pub struct RatatuiCameraUi {
+    /// Scale factor of the camera's render texture, which UI pixel sizes (e.g. `Val::Px`) are
+    /// multiplied by, in the same way as a window's scale factor.
+    pub scale_factor: f32,
+}
+
+impl Default for RatatuiCameraUi {
+    fn default() -> Self {
+        Self { scale_factor: 1. }
+    }
+}
+
+pub struct RatatuiCameraUiPlugin;
+
+impl Plugin for RatatuiCameraUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(ratatui_camera_ui_insert_observer);
+    }
+}
+
+/// Re-targets the camera at its render texture, so that the new scale factor is used.
+fn ratatui_camera_ui_insert_observer(
+    insert: On<Insert, RatatuiCameraUi>,
+    ratatui_cameras: Query<(), With<RatatuiCamera>>,
+    mut camera_targeting_messages: MessageWriter<CameraTargetingMessage>,
+) {
+    if ratatui_cameras.contains(insert.entity) {
+        camera_targeting_messages.write(CameraTargetingMessage {
+            targeter_entity: insert.entity,
+            target_entity: insert.entity,
+        });
+    }
+}
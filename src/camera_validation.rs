@@ -0,0 +1,121 @@
+use bevy::{platform::collections::HashSet, prelude::*};
+
+use crate::{
+    RatatuiCamera, RatatuiCameraDepthDetection, RatatuiCameraEdgeDetection,
+    RatatuiCameraNormalDetection, RatatuiCameraStrategy, RatatuiSubcamera,
+};
+
+pub struct RatatuiCameraValidationPlugin;
+
+impl Plugin for RatatuiCameraValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, validate_ratatui_cameras_system);
+    }
+}
+
+/// Combinations of components that are allowed, but will not do what was probably intended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ConfigurationIssue {
+    DepthWithoutDetection,
+    NormalsWithoutDetection,
+    EdgeDetectionWithMsaa,
+    AutoresizeWithDimensions,
+    SubcameraTargetsNonRatatuiCamera,
+    DepthDetectionWithoutFeature,
+    EdgeDetectionWithoutFeature,
+}
+
+impl ConfigurationIssue {
+    fn message(self) -> &'static str {
+        match self {
+            Self::DepthWithoutDetection => {
+                "uses a depth strategy without RatatuiCameraDepthDetection, so no depth is drawn"
+            }
+            Self::NormalsWithoutDetection => {
+                "uses the normals strategy without RatatuiCameraNormalDetection, so nothing is drawn"
+            }
+            Self::EdgeDetectionWithMsaa => {
+                "has edge detection but MSAA was turned back on, which edge detection does not \
+                support (keep Msaa::Off)"
+            }
+            Self::AutoresizeWithDimensions => {
+                "has autoresize enabled along with fixed dimensions, which autoresize will \
+                overwrite (use RatatuiCamera::new for fixed dimensions)"
+            }
+            Self::SubcameraTargetsNonRatatuiCamera => {
+                "is a RatatuiSubcamera of an entity without a RatatuiCamera, so it renders nowhere"
+            }
+            Self::DepthDetectionWithoutFeature => {
+                "has RatatuiCameraDepthDetection, but the \"depth\" feature is disabled, so no \
+                depth is read back"
+            }
+            Self::EdgeDetectionWithoutFeature => {
+                "has RatatuiCameraEdgeDetection, but the \"sobel\" feature is disabled, so no \
+                edges are drawn"
+            }
+        }
+    }
+}
+
+/// Warns (once for each camera and issue) about incoherent camera setups.
+fn validate_ratatui_cameras_system(
+    ratatui_cameras: Query<(
+        Entity,
+        Ref<RatatuiCamera>,
+        Option<&RatatuiCameraStrategy>,
+        Option<&Msaa>,
+        (
+            Has<RatatuiCameraDepthDetection>,
+            Has<RatatuiCameraNormalDetection>,
+            Has<RatatuiCameraEdgeDetection>,
+        ),
+    )>,
+    ratatui_subcameras: Query<(Entity, &RatatuiSubcamera)>,
+    mut warned: Local<HashSet<(Entity, ConfigurationIssue)>>,
+) {
+    let mut issues = Vec::new();
+
+    for (entity, ratatui_camera, strategy, msaa, (depth, normals, edge_detection)) in
+        &ratatui_cameras
+    {
+        if strategy.is_some_and(RatatuiCameraStrategy::uses_depth) && !depth {
+            issues.push((entity, ConfigurationIssue::DepthWithoutDetection));
+        }
+
+        if matches!(strategy, Some(RatatuiCameraStrategy::Normals(_))) && !normals {
+            issues.push((entity, ConfigurationIssue::NormalsWithoutDetection));
+        }
+
+        if depth && !cfg!(feature = "depth") {
+            issues.push((entity, ConfigurationIssue::DepthDetectionWithoutFeature));
+        }
+
+        if edge_detection && !cfg!(feature = "sobel") {
+            issues.push((entity, ConfigurationIssue::EdgeDetectionWithoutFeature));
+        }
+
+        if edge_detection && msaa.is_some_and(|msaa| *msaa != Msaa::Off) {
+            issues.push((entity, ConfigurationIssue::EdgeDetectionWithMsaa));
+        }
+
+        // autoresize changes the dimensions itself later, so only check them as inserted.
+        if ratatui_camera.is_added()
+            && ratatui_camera.autoresize
+            && ratatui_camera.dimensions != RatatuiCamera::default().dimensions
+        {
+            issues.push((entity, ConfigurationIssue::AutoresizeWithDimensions));
+        }
+    }
+
+    for (entity, subcamera) in &ratatui_subcameras {
+        if !ratatui_cameras.contains(subcamera.target) {
+            issues.push((entity, ConfigurationIssue::SubcameraTargetsNonRatatuiCamera));
+        }
+    }
+
+    for (entity, issue) in issues {
+        if warned.insert((entity, issue)) {
+            log::warn!("ratatui camera {entity} {}", issue.message());
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::math::{Rect as ImageRect, Vec2};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// When spawned with a RatatuiCamera, only part of the rendered image is drawn by the widget,
+/// magnified to fill the space the entire image would have. Useful for panning and zooming around
+/// a high resolution render (e.g. a map view) without moving the bevy camera or re-rendering.
+///
+/// The viewport is copied into the widget's [viewport](crate::RatatuiCameraWidget::viewport) each
+/// frame, which can also be changed directly before the widget is rendered. The widget's
+/// coordinate conversion methods (e.g. `cell_to_ndc()`) map cells through the viewport.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCamera, ViewportConfig};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     RatatuiCamera::new(1920, 1080),
+///     ViewportConfig {
+///         offset: Vec2::new(0.25, 0.),
+///         zoom: 4.,
+///     },
+///     Camera3d::default(),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewportConfig {
+    /// Position of the center of the viewport relative to the center of the image, as a fraction
+    /// of the image's width and height (positive `y` is down). The viewport is kept within the
+    /// image, so the offset stops having an effect once the viewport reaches an edge.
+    pub offset: Vec2,
+
+    /// Magnification of the viewport, where `2.0` shows half of the image's width and height.
+    /// Values below `1.0` are treated as `1.0`.
+    pub zoom: f32,
+}
+
+impl Default for ViewportConfig {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::ZERO,
+            zoom: 1.,
+        }
+    }
+}
+
+impl ViewportConfig {
+    /// Region of the image covered by the viewport, in normalized image coordinates.
+    pub(crate) fn region(&self) -> ImageRect {
+        let size = Vec2::splat(1. / self.zoom.max(1.));
+        let center = (Vec2::splat(0.5) + self.offset).clamp(size / 2., Vec2::ONE - size / 2.);
+
+        ImageRect::from_center_size(center, size)
+    }
+}
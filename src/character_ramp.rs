@@ -0,0 +1,145 @@
+use std::fmt::Display;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::Deserialize;
+
+use crate::{RatatuiCameraSet, RatatuiCameraStrategy};
+
+pub struct RatatuiCameraCharacterRampPlugin;
+
+impl Plugin for RatatuiCameraCharacterRampPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<CharacterRamp>()
+            .init_asset_loader::<CharacterRampLoader>()
+            .add_systems(
+                First,
+                update_character_ramps_system.before(RatatuiCameraSet),
+            );
+    }
+}
+
+/// A list of characters (and optionally a scale) for use in a
+/// [CharactersConfig](crate::CharactersConfig), loaded from a `.ramp.ron` asset file. Use
+/// [CharactersConfig::from_asset](crate::CharactersConfig::from_asset) to create a characters
+/// config that follows the loaded asset, including any changes if bevy's `file_watcher` feature
+/// is enabled, which allows tuning the look of a scene without recompiling.
+///
+/// # Example:
+///
+/// ```ron
+/// (
+///     characters: [' ', '.', ':', '+', '#', '@'],
+///     scale: Some(8.0),
+/// )
+/// ```
+///
+#[derive(Asset, TypePath, Clone, Debug, Deserialize)]
+pub struct CharacterRamp {
+    /// The characters of the ramp, in increasing order of opacity.
+    pub characters: Vec<char>,
+
+    /// If present, replaces the `scale` of any characters config following this ramp.
+    #[serde(default)]
+    pub scale: Option<f32>,
+}
+
+/// Asset loader for [CharacterRamp] assets, stored in RON files with the `.ramp.ron` extension.
+#[derive(Default, Debug, TypePath)]
+pub struct CharacterRampLoader;
+
+/// Errors that can occur while loading a [CharacterRamp].
+#[derive(Debug)]
+pub enum CharacterRampLoaderError {
+    /// The asset file could not be read.
+    Io(std::io::Error),
+
+    /// The asset file is not a valid RON character ramp.
+    Ron(ron::error::SpannedError),
+
+    /// The character ramp does not contain any characters.
+    Empty,
+}
+
+impl Display for CharacterRampLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read character ramp: {error}"),
+            Self::Ron(error) => write!(f, "could not parse character ramp: {error}"),
+            Self::Empty => write!(f, "character ramp does not contain any characters"),
+        }
+    }
+}
+
+impl std::error::Error for CharacterRampLoaderError {}
+
+impl From<std::io::Error> for CharacterRampLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for CharacterRampLoaderError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Ron(error)
+    }
+}
+
+impl AssetLoader for CharacterRampLoader {
+    type Asset = CharacterRamp;
+    type Settings = ();
+    type Error = CharacterRampLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let ramp = ron::de::from_bytes::<CharacterRamp>(&bytes)?;
+
+        if ramp.characters.is_empty() {
+            return Err(CharacterRampLoaderError::Empty);
+        }
+
+        Ok(ramp)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ramp.ron"]
+    }
+}
+
+/// Copy the contents of loaded character ramps into any strategies that follow them. Strategies
+/// are only mutated when the ramp differs, so this also picks up newly spawned cameras and
+/// hot-reloaded ramps without triggering change detection every frame.
+fn update_character_ramps_system(
+    ramps: Res<Assets<CharacterRamp>>,
+    mut strategies: Query<&mut RatatuiCameraStrategy>,
+) {
+    for mut strategy in &mut strategies {
+        let Some(characters) = strategy.characters() else {
+            continue;
+        };
+
+        let Some(ramp) = characters.ramp.as_ref().and_then(|ramp| ramps.get(ramp)) else {
+            continue;
+        };
+
+        let scale = ramp.scale.unwrap_or(characters.scale);
+        if characters.list == ramp.characters && characters.scale == scale {
+            continue;
+        }
+
+        let list = ramp.characters.clone();
+        if let Some(characters) = strategy.characters_mut() {
+            characters.list = list;
+            characters.scale = scale;
+        }
+    }
+}
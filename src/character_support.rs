@@ -0,0 +1,263 @@
+use bevy::prelude::*;
+use ratatui::{buffer::Buffer, layout::Rect};
+
+use crate::{
+    EdgeCharacters, RatatuiCameraEdgeDetection, RatatuiCameraStrategy, ThresholdConfig,
+    ThresholdOutput,
+};
+
+/// Characters 128-255 of code page 437, in order.
+const CP437_UPPER: &str = "ÇüéâäàåçêëèïîìÄÅÉæÆôöòûùÿÖÜ¢£¥₧ƒáíóúñÑªº¿⌐¬½¼¡«»░▒▓│┤╡╢╖╕╣║╗╝╜╛┐└┴┬├─┼╞\
+    ╟╚╔╩╦╠═╬╧╨╤╥╙╘╒╓╫╪┘┌█▄▌▐▀αßΓπΣσµτΦΘΩδ∞φε∩≡±≥≤⌠⌡÷≈°∙·√ⁿ²■\u{a0}";
+
+/// Resource describing which characters the terminal is able to display. When set to anything
+/// other than `Unicode`, camera widgets replace character lists containing unsupported characters
+/// with a safe ramp, map edge characters to the closest supported character, and replace any
+/// remaining unsupported characters (e.g. the halfblocks used by some strategies) as they are
+/// drawn, so that the output never contains mojibake.
+///
+/// By default this is detected from the environment, see [RatatuiCameraCharacterSupport::detect].
+/// Insert the resource yourself to override the detected value.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiCameraCharacterSupport;
+/// #
+/// # fn setup_system(mut commands: Commands) {
+/// commands.insert_resource(RatatuiCameraCharacterSupport::Ascii);
+/// # }
+/// ```
+///
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RatatuiCameraCharacterSupport {
+    /// Any unicode character.
+    Unicode,
+
+    /// Characters in code page 437, the character set of the original IBM PC and of legacy
+    /// Windows consoles, which includes shading, block, and box drawing characters.
+    Cp437,
+
+    /// Printable ASCII characters only.
+    Ascii,
+}
+
+impl Default for RatatuiCameraCharacterSupport {
+    fn default() -> Self {
+        Self::detect()
+    }
+}
+
+impl RatatuiCameraCharacterSupport {
+    /// Environment variable that overrides detection, set to `unicode`, `cp437`, or `ascii`.
+    pub const OVERRIDE_VAR: &'static str = "BEVY_RATATUI_CAMERA_CHARSET";
+
+    /// A safe range of ASCII characters in increasing order of opacity.
+    pub const CHARACTERS_ASCII: &'static [char] = RatatuiCameraStrategy::CHARACTERS_MISC;
+
+    /// A safe range of code page 437 characters in increasing order of opacity.
+    pub const CHARACTERS_CP437: &'static [char] = RatatuiCameraStrategy::CHARACTERS_SHADING;
+
+    /// Detect character support from the environment. The override variable
+    /// ([RatatuiCameraCharacterSupport::OVERRIDE_VAR]) is checked first, followed by the locale
+    /// variables `LC_ALL`, `LC_CTYPE`, and `LANG` (the first one that is set and non-empty is
+    /// used). A locale whose encoding is not UTF-8 results in `Ascii`. If no locale is set at all
+    /// (e.g. on Windows), `Unicode` is assumed.
+    pub fn detect() -> Self {
+        if let Some(support) = std::env::var(Self::OVERRIDE_VAR)
+            .ok()
+            .and_then(|value| Self::from_name(&value))
+        {
+            return support;
+        }
+
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty());
+
+        match locale {
+            Some(locale) => Self::from_locale(&locale),
+            None => Self::Unicode,
+        }
+    }
+
+    /// Determine character support from a locale string such as `en_US.UTF-8` or `C`.
+    pub fn from_locale(locale: &str) -> Self {
+        let encoding = locale
+            .split_once('.')
+            .map(|(_, encoding)| encoding.split('@').next().unwrap_or(encoding))
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+            .replace('-', "");
+
+        if encoding == "utf8" {
+            Self::Unicode
+        } else {
+            Self::Ascii
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "unicode" | "utf8" | "utf-8" => Some(Self::Unicode),
+            "cp437" => Some(Self::Cp437),
+            "ascii" => Some(Self::Ascii),
+            _ => None,
+        }
+    }
+
+    /// Whether the terminal can display the provided character.
+    pub fn supports(&self, character: char) -> bool {
+        match self {
+            Self::Unicode => true,
+            Self::Cp437 => (' '..='~').contains(&character) || CP437_UPPER.contains(character),
+            Self::Ascii => (' '..='~').contains(&character),
+        }
+    }
+
+    /// The closest supported character to the provided character. Shading, block, braille, and
+    /// line drawing characters are approximated, anything else unsupported becomes `'#'`.
+    pub fn downgrade_character(&self, character: char) -> char {
+        if self.supports(character) {
+            return character;
+        }
+
+        if *self == Self::Cp437 {
+            let downgraded = match character {
+                '―' | '━' | '╌' => '─',
+                '┃' | '╎' => '│',
+                '▁' => '_',
+                '▂' | '▃' | '▖' | '▗' => '▄',
+                '▔' | '▘' | '▝' => '▀',
+                '▅' | '▆' | '▇' | '▙' | '▛' | '▜' | '▟' => '█',
+                '▚' | '▞' => '▒',
+                _ => Self::Ascii.downgrade_character(character),
+            };
+
+            return downgraded;
+        }
+
+        match character {
+            '―' | '─' | '━' | '═' | '╌' | '▔' => '-',
+            '│' | '┃' | '║' | '╎' | '▌' | '▐' => '|',
+            '╱' => '/',
+            '╲' => '\\',
+            '▁' => '_',
+            '░' => '.',
+            '▒' | '▚' | '▞' => ':',
+            '▓' => '#',
+            '█' | '▇' | '▆' | '▙' | '▛' | '▜' | '▟' => '@',
+            '▂' | '▃' | '▅' => '=',
+            // halfblocks are drawn with the background color as the other half, so a blank keeps
+            // that half of the cell's pixels visible.
+            '▀' | '▄' | '▘' | '▝' | '▖' | '▗' => ' ',
+            '\u{2800}'..='\u{28ff}' => {
+                let dots = (character as u32 - 0x2800).count_ones() as usize;
+                Self::CHARACTERS_ASCII[dots * (Self::CHARACTERS_ASCII.len() - 1) / 8]
+            }
+            _ => '#',
+        }
+    }
+
+    /// Replace the strategy's character list with a safe ramp if it contains unsupported
+    /// characters.
+    pub fn downgrade_strategy(&self, strategy: &mut RatatuiCameraStrategy) {
+        if *self == Self::Unicode {
+            return;
+        }
+
+        let safe_list = match (&*strategy, self) {
+            (RatatuiCameraStrategy::Glyphs(_), _) => RatatuiCameraStrategy::CHARACTERS_GLYPHS,
+            (_, Self::Cp437) => Self::CHARACTERS_CP437,
+            _ => Self::CHARACTERS_ASCII,
+        };
+
+        if let Some(characters) = strategy.characters_mut()
+            && characters.list.iter().any(|&c| !self.supports(c))
+        {
+            characters.list = safe_list.into();
+        }
+
+        if let RatatuiCameraStrategy::Threshold(ThresholdConfig {
+            output: ThresholdOutput::Characters { off, on },
+            ..
+        }) = strategy
+        {
+            *off = self.downgrade_character(*off);
+            *on = self.downgrade_character(*on);
+        }
+    }
+
+    /// Map the edge detection's characters to the closest supported characters.
+    pub fn downgrade_edge_detection(&self, edge_detection: &mut RatatuiCameraEdgeDetection) {
+        if *self == Self::Unicode {
+            return;
+        }
+
+        edge_detection.edge_characters = match edge_detection.edge_characters {
+            EdgeCharacters::Single(character) => {
+                EdgeCharacters::Single(self.downgrade_character(character))
+            }
+            EdgeCharacters::Directional {
+                vertical,
+                horizontal,
+                forward_diagonal,
+                backward_diagonal,
+            } => EdgeCharacters::Directional {
+                vertical: self.downgrade_character(vertical),
+                horizontal: self.downgrade_character(horizontal),
+                forward_diagonal: self.downgrade_character(forward_diagonal),
+                backward_diagonal: self.downgrade_character(backward_diagonal),
+            },
+        };
+    }
+
+    /// Replace any unsupported characters within the provided area of a buffer.
+    pub(crate) fn downgrade_buffer(&self, area: Rect, buf: &mut Buffer) {
+        if *self == Self::Unicode {
+            return;
+        }
+
+        for position in area.positions() {
+            let Some(cell) = buf.cell_mut(position) else {
+                continue;
+            };
+
+            let mut characters = cell.symbol().chars();
+            if let (Some(character), None) = (characters.next(), characters.next())
+                && !self.supports(character)
+            {
+                cell.set_char(self.downgrade_character(character));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RatatuiCameraCharacterSupport;
+
+    #[test]
+    fn utf8_locales_support_unicode() {
+        for locale in ["en_US.UTF-8", "en_US.utf8", "C.UTF-8", "de_DE.UTF-8@euro"] {
+            assert_eq!(
+                RatatuiCameraCharacterSupport::from_locale(locale),
+                RatatuiCameraCharacterSupport::Unicode,
+                "{locale}",
+            );
+        }
+    }
+
+    #[test]
+    fn other_locales_support_ascii() {
+        for locale in ["C", "POSIX", "en_US", "en_US.ISO-8859-1", "ja_JP.eucJP"] {
+            assert_eq!(
+                RatatuiCameraCharacterSupport::from_locale(locale),
+                RatatuiCameraCharacterSupport::Ascii,
+                "{locale}",
+            );
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use std::{fmt::Display, sync::Arc};
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::*,
+    render::render_resource::TextureDimension,
+};
+
+use crate::{RatatuiCameraSet, RatatuiCameraStrategy};
+
+pub struct RatatuiCameraColorLutPlugin;
+
+impl Plugin for RatatuiCameraColorLutPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ColorLut>()
+            .init_asset_loader::<ColorLutLoader>()
+            .init_resource::<ImageColorLuts>()
+            .add_systems(
+                First,
+                update_image_color_luts_system.before(RatatuiCameraSet),
+            );
+    }
+}
+
+/// A 3D color lookup table, used for color grading the terminal output independently of the bevy
+/// scene's tonemapping (see [ColorsConfig::lut](crate::ColorsConfig::lut)). Each rendered color
+/// is replaced by the table's color at that position, interpolated between the nearest entries.
+///
+/// Loaded from standard `.cube` files, or converted from an image with [ColorLut::from_image].
+///
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct ColorLut {
+    size: usize,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+    table: Arc<[[f32; 3]]>,
+}
+
+impl ColorLut {
+    /// Parse the contents of a `.cube` file containing a 3D LUT.
+    pub fn from_cube(source: &str) -> Result<Self, ColorLutLoaderError> {
+        let mut size = None;
+        let mut domain_min = [0.0; 3];
+        let mut domain_max = [1.0; 3];
+        let mut table = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let Some(keyword) = words.next() else {
+                continue;
+            };
+
+            let parse_triplet = |words: &mut dyn Iterator<Item = &str>| {
+                let values = words
+                    .take(3)
+                    .map(str::parse::<f32>)
+                    .collect::<Result<Vec<_>, _>>()
+                    .ok()?;
+                <[f32; 3]>::try_from(values).ok()
+            };
+
+            match keyword {
+                "TITLE" => {}
+                "LUT_1D_SIZE" => return Err(ColorLutLoaderError::Unsupported1d),
+                "LUT_3D_SIZE" => {
+                    size = Some(
+                        words
+                            .next()
+                            .and_then(|word| word.parse::<usize>().ok())
+                            .filter(|size| *size >= 2)
+                            .ok_or(ColorLutLoaderError::Parse(line_number))?,
+                    );
+                }
+                "DOMAIN_MIN" => {
+                    domain_min =
+                        parse_triplet(&mut words).ok_or(ColorLutLoaderError::Parse(line_number))?;
+                }
+                "DOMAIN_MAX" => {
+                    domain_max =
+                        parse_triplet(&mut words).ok_or(ColorLutLoaderError::Parse(line_number))?;
+                }
+                // other keywords (e.g. `LUT_3D_INPUT_RANGE`) are not needed for lookups.
+                _ if keyword.starts_with(|character: char| character.is_ascii_alphabetic()) => {}
+                _ => {
+                    let mut words = line.split_whitespace();
+                    let color =
+                        parse_triplet(&mut words).ok_or(ColorLutLoaderError::Parse(line_number))?;
+                    table.push(color);
+                }
+            }
+        }
+
+        let size = size.ok_or(ColorLutLoaderError::MissingSize)?;
+        if table.len() != size.pow(3) {
+            return Err(ColorLutLoaderError::WrongLength {
+                expected: size.pow(3),
+                found: table.len(),
+            });
+        }
+
+        Ok(Self {
+            size,
+            domain_min,
+            domain_max,
+            table: table.into(),
+        })
+    }
+
+    /// Convert an image containing a LUT, either a 3D image with equal dimensions (red along the
+    /// width, green along the height, and blue along the depth), or the common 2D strip layout of
+    /// side by side square slices (e.g. 1024x32 for a LUT of size 32), with blue increasing
+    /// from slice to slice. Returns `None` if the image matches neither layout.
+    pub fn from_image(image: &Image) -> Option<Self> {
+        let (width, height) = (image.width(), image.height());
+        let depth = image.texture_descriptor.size.depth_or_array_layers;
+
+        let (size, color_at): (u32, Box<dyn Fn(u32, u32, u32) -> Option<Color>>) =
+            match image.texture_descriptor.dimension {
+                TextureDimension::D3 if width == height && height == depth => (
+                    width,
+                    Box::new(|r, g, b| image.get_color_at_3d(r, g, b).ok()),
+                ),
+                TextureDimension::D2 if width == height * height => (
+                    height,
+                    Box::new(move |r, g, b| image.get_color_at(b * height + r, g).ok()),
+                ),
+                _ => return None,
+            };
+
+        if size < 2 {
+            return None;
+        }
+
+        let table = (0..size.pow(3))
+            .map(|index| {
+                let (r, g, b) = (index % size, index / size % size, index / size / size);
+                let color = color_at(r, g, b)?.to_srgba();
+                Some([color.red, color.green, color.blue])
+            })
+            .collect::<Option<Arc<[_]>>>()?;
+
+        Some(Self {
+            size: size as usize,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+            table,
+        })
+    }
+
+    /// Number of entries along each axis of the table.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Identifies the table, which is never modified and is shared between clones of the LUT.
+    pub(crate) fn table_id(&self) -> usize {
+        Arc::as_ptr(&self.table).cast::<[f32; 3]>() as usize
+    }
+
+    /// Look up the graded color for an sRGB color, interpolating between the nearest entries.
+    pub fn apply(&self, color: [u8; 3]) -> [u8; 3] {
+        let last = (self.size - 1) as f32;
+
+        let position: [f32; 3] = std::array::from_fn(|channel| {
+            let range = self.domain_max[channel] - self.domain_min[channel];
+            let value = color[channel] as f32 / u8::MAX as f32;
+            let normalized = if range > 0.0 {
+                (value - self.domain_min[channel]) / range
+            } else {
+                0.0
+            };
+            normalized.clamp(0.0, 1.0) * last
+        });
+
+        let low = position.map(|value| (value.floor() as usize).min(self.size - 2));
+        let fraction: [f32; 3] =
+            std::array::from_fn(|channel| position[channel] - low[channel] as f32);
+
+        let entry = |r: usize, g: usize, b: usize| {
+            self.table[(low[0] + r) + (low[1] + g) * self.size + (low[2] + b) * self.size.pow(2)]
+        };
+
+        let lerp = |a: [f32; 3], b: [f32; 3], t: f32| -> [f32; 3] {
+            std::array::from_fn(|channel| a[channel] + (b[channel] - a[channel]) * t)
+        };
+
+        let along_r = |g, b| lerp(entry(0, g, b), entry(1, g, b), fraction[0]);
+        let along_g = |b| lerp(along_r(0, b), along_r(1, b), fraction[1]);
+        let graded = lerp(along_g(0), along_g(1), fraction[2]);
+
+        graded.map(|value| (value.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8)
+    }
+}
+
+/// Source of the [ColorLut] used for color grading in a [ColorsConfig](crate::ColorsConfig).
+#[derive(Clone, Debug, Reflect)]
+pub enum ColorLutHandle {
+    /// A LUT asset, e.g. loaded from a `.cube` file.
+    Cube(Handle<ColorLut>),
+
+    /// An image containing a LUT, converted with [ColorLut::from_image] once loaded.
+    Image(Handle<Image>),
+}
+
+impl From<Handle<ColorLut>> for ColorLutHandle {
+    fn from(handle: Handle<ColorLut>) -> Self {
+        Self::Cube(handle)
+    }
+}
+
+impl From<Handle<Image>> for ColorLutHandle {
+    fn from(handle: Handle<Image>) -> Self {
+        Self::Image(handle)
+    }
+}
+
+/// LUTs converted from images used as a [ColorLutHandle::Image], or `None` for images that are
+/// not valid LUTs.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct ImageColorLuts(HashMap<AssetId<Image>, Option<ColorLut>>);
+
+impl ImageColorLuts {
+    /// Get the loaded LUT for a handle, if available.
+    pub(crate) fn resolve(
+        &self,
+        handle: &ColorLutHandle,
+        color_luts: &Assets<ColorLut>,
+    ) -> Option<ColorLut> {
+        match handle {
+            ColorLutHandle::Cube(handle) => color_luts.get(handle).cloned(),
+            ColorLutHandle::Image(handle) => self.0.get(&handle.id()).cloned().flatten(),
+        }
+    }
+}
+
+/// Asset loader for [ColorLut] assets, stored in `.cube` files.
+#[derive(Default, Debug, TypePath)]
+pub struct ColorLutLoader;
+
+/// Errors that can occur while loading a [ColorLut].
+#[derive(Debug)]
+pub enum ColorLutLoaderError {
+    /// The asset file could not be read.
+    Io(std::io::Error),
+
+    /// The asset file is not valid UTF-8.
+    Utf8(std::str::Utf8Error),
+
+    /// A line of the asset file could not be parsed, by line number.
+    Parse(usize),
+
+    /// The asset file contains a 1D LUT, only 3D LUTs are supported.
+    Unsupported1d,
+
+    /// The asset file does not specify a `LUT_3D_SIZE`.
+    MissingSize,
+
+    /// The asset file does not contain `LUT_3D_SIZE` cubed entries.
+    WrongLength {
+        /// Number of entries required by the size.
+        expected: usize,
+        /// Number of entries in the file.
+        found: usize,
+    },
+}
+
+impl Display for ColorLutLoaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read color lut: {error}"),
+            Self::Utf8(error) => write!(f, "color lut is not valid utf-8: {error}"),
+            Self::Parse(line) => write!(f, "could not parse color lut at line {line}"),
+            Self::Unsupported1d => write!(f, "1d color luts are not supported"),
+            Self::MissingSize => write!(f, "color lut does not specify LUT_3D_SIZE"),
+            Self::WrongLength { expected, found } => {
+                write!(f, "color lut has {found} entries, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColorLutLoaderError {}
+
+impl From<std::io::Error> for ColorLutLoaderError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<std::str::Utf8Error> for ColorLutLoaderError {
+    fn from(error: std::str::Utf8Error) -> Self {
+        Self::Utf8(error)
+    }
+}
+
+impl AssetLoader for ColorLutLoader {
+    type Asset = ColorLut;
+    type Settings = ();
+    type Error = ColorLutLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        ColorLut::from_cube(std::str::from_utf8(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["cube"]
+    }
+}
+
+/// Convert images used as LUTs by any strategy once they have loaded, and again whenever they are
+/// modified.
+fn update_image_color_luts_system(
+    mut image_messages: MessageReader<AssetEvent<Image>>,
+    images: Res<Assets<Image>>,
+    strategies: Query<&RatatuiCameraStrategy>,
+    mut image_color_luts: ResMut<ImageColorLuts>,
+) {
+    for message in image_messages.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = message {
+            image_color_luts.0.remove(id);
+        }
+    }
+
+    for strategy in &strategies {
+        let Some(ColorLutHandle::Image(handle)) =
+            strategy.colors().and_then(|colors| colors.lut.as_ref())
+        else {
+            continue;
+        };
+
+        if image_color_luts.0.contains_key(&handle.id()) {
+            continue;
+        }
+
+        let Some(image) = images.get(handle) else {
+            continue;
+        };
+
+        let color_lut = ColorLut::from_image(image);
+        if color_lut.is_none() {
+            log::warn!("image used as a color lut does not have a valid lut layout.");
+        }
+
+        image_color_luts.0.insert(handle.id(), color_lut);
+    }
+}
@@ -1,4 +1,8 @@
+use std::sync::LazyLock;
+
+use bevy::reflect::Reflect;
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
 const ANSI_COLORS_16: [[u8; 3]; 16] = [
     [0, 0, 0],
@@ -58,15 +62,63 @@ const fn generate_ansi_colors_256() -> [[u8; 3]; 256] {
 
 const ANSI_COLORS_256: [[u8; 3]; 256] = generate_ansi_colors_256();
 
+static ANSI_COLORS_16_OKLAB: LazyLock<[[f32; 3]; 16]> =
+    LazyLock::new(|| ANSI_COLORS_16.map(srgb_to_oklab));
+
+static ANSI_COLORS_256_OKLAB: LazyLock<[[f32; 3]; 256]> =
+    LazyLock::new(|| ANSI_COLORS_256.map(srgb_to_oklab));
+
+/// Number of high bits of each color channel used to index the nearest ANSI color caches.
+const CACHE_BITS: u32 = 5;
+
+/// Number of buckets along each color channel of the nearest ANSI color caches.
+const CACHE_SIDE: usize = 1 << CACHE_BITS;
+
+// nearest ANSI color index for each bucket of a 32x32x32 cube of rgb colors, computed once from
+// the center of each bucket, so that converting a cell's color is a single lookup rather than a
+// search through every ANSI color.
+static ANSI_16_RGB_CACHE: LazyLock<Box<[u8]>> =
+    LazyLock::new(|| ansi_index_cache(&ANSI_COLORS_16, &*ANSI_COLORS_16_OKLAB, ColorDistance::Rgb));
+
+static ANSI_16_OKLAB_CACHE: LazyLock<Box<[u8]>> = LazyLock::new(|| {
+    ansi_index_cache(
+        &ANSI_COLORS_16,
+        &*ANSI_COLORS_16_OKLAB,
+        ColorDistance::OkLab,
+    )
+});
+
+static ANSI_256_RGB_CACHE: LazyLock<Box<[u8]>> = LazyLock::new(|| {
+    ansi_index_cache(
+        &ANSI_COLORS_256,
+        &*ANSI_COLORS_256_OKLAB,
+        ColorDistance::Rgb,
+    )
+});
+
+static ANSI_256_OKLAB_CACHE: LazyLock<Box<[u8]>> = LazyLock::new(|| {
+    ansi_index_cache(
+        &ANSI_COLORS_256,
+        &*ANSI_COLORS_256_OKLAB,
+        ColorDistance::OkLab,
+    )
+});
+
 /// Options for restricting the terminal colors that rendered pixels are converted to.
 ///
 /// Many terminals support 24-bit RGB "true color", but some only support pre-defined sets of 16 or
 /// 256 ANSI colors. This enum represents each of those sets of possible colors when converting
 /// rendered pixels to terminal characters.
 ///
+/// Conversions to ANSI colors are looked up in tables computed on first use, which round each
+/// color channel to 32 levels, so that large terminal areas are cheap to convert every frame. A
+/// color may therefore map to an ANSI color slightly further from it than the nearest one, by at
+/// most twice the distance between the color and its rounded value (at most ~14 for
+/// [ColorDistance::Rgb], out of ~441 between black and white).
+///
 /// Reference for terminal color support:
 /// https://en.wikipedia.org/wiki/ANSI_escape_code#Colors
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Reflect)]
 pub enum ColorSupport {
     /// Any 24-bit color, represented by ratatui's `Color::Rgb` enum variant.
     #[default]
@@ -81,47 +133,121 @@ pub enum ColorSupport {
     ANSI16,
 }
 
-pub fn color_for_color_support(color: Option<Color>, support: ColorSupport) -> Option<Color> {
+/// How the closest ANSI color to a rendered color is measured, when converting to the 16 or 256
+/// ANSI color sets (see [ColorSupport]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub enum ColorDistance {
+    /// Euclidean distance between sRGB channel values. Cheap, but often picks visibly wrong
+    /// colors from the small 16 color set (e.g. dark blues becoming gray).
+    #[default]
+    Rgb,
+
+    /// Euclidean distance in the OKLab color space, which is designed so that distances match
+    /// perceived differences in color. Picks closer looking colors, at a small extra cost.
+    OkLab,
+}
+
+pub fn color_for_color_support(
+    color: Option<Color>,
+    support: ColorSupport,
+    distance: ColorDistance,
+) -> Option<Color> {
     color.map(|color| match support {
         ColorSupport::TrueColor => color,
-        ColorSupport::ANSI256 => color_to_ansi_256(color),
-        ColorSupport::ANSI16 => color_to_ansi_16(color),
+        ColorSupport::ANSI256 => color_to_ansi_256(color, distance),
+        ColorSupport::ANSI16 => color_to_ansi_16(color, distance),
     })
 }
 
-fn color_to_ansi_256(color: Color) -> Color {
+fn color_to_ansi_256(color: Color, distance: ColorDistance) -> Color {
     let Color::Rgb(r, g, b) = color else {
         return color;
     };
 
-    let index = color_rgb_to_ansi_index([r, g, b], &ANSI_COLORS_256);
+    let cache = match distance {
+        ColorDistance::Rgb => &ANSI_256_RGB_CACHE,
+        ColorDistance::OkLab => &ANSI_256_OKLAB_CACHE,
+    };
+
+    let index = cached_ansi_index([r, g, b], cache);
 
     Color::Indexed(index)
 }
 
-fn color_to_ansi_16(color: Color) -> Color {
-    let index = match color {
-        Color::Rgb(r, g, b) => color_rgb_to_ansi_index([r, g, b], &ANSI_COLORS_16),
-        Color::Indexed(index) => {
-            color_rgb_to_ansi_index(ANSI_COLORS_256[index as usize], &ANSI_COLORS_16)
-        }
+fn color_to_ansi_16(color: Color, distance: ColorDistance) -> Color {
+    let rgb = match color {
+        Color::Rgb(r, g, b) => [r, g, b],
+        Color::Indexed(index) => ANSI_COLORS_256[index as usize],
         _ => return color,
     };
 
+    let cache = match distance {
+        ColorDistance::Rgb => &ANSI_16_RGB_CACHE,
+        ColorDistance::OkLab => &ANSI_16_OKLAB_CACHE,
+    };
+
+    let index = cached_ansi_index(rgb, cache);
+
     ratatui_color_from_ansi_index(index)
 }
 
-fn color_rgb_to_ansi_index(color: [u8; 3], colors: &[[u8; 3]]) -> u8 {
+fn cached_ansi_index(color: [u8; 3], cache: &[u8]) -> u8 {
+    let [r, g, b] = color.map(cache_bucket);
+    cache[(r * CACHE_SIDE + g) * CACHE_SIDE + b]
+}
+
+/// Bucket of the nearest ANSI color caches that a color channel value falls in.
+fn cache_bucket(channel: u8) -> usize {
+    (channel >> (8 - CACHE_BITS)) as usize
+}
+
+/// Color channel value at the center of a bucket of the nearest ANSI color caches.
+fn bucket_center(bucket: usize) -> u8 {
+    ((bucket << (8 - CACHE_BITS)) + (1 << (7 - CACHE_BITS))) as u8
+}
+
+fn ansi_index_cache(
+    colors: &[[u8; 3]],
+    colors_oklab: &[[f32; 3]],
+    distance: ColorDistance,
+) -> Box<[u8]> {
+    (0..CACHE_SIDE.pow(3))
+        .map(|index| {
+            let color = [
+                index / CACHE_SIDE / CACHE_SIDE,
+                index / CACHE_SIDE % CACHE_SIDE,
+                index % CACHE_SIDE,
+            ]
+            .map(bucket_center);
+            color_rgb_to_ansi_index(color, colors, colors_oklab, distance)
+        })
+        .collect()
+}
+
+fn color_rgb_to_ansi_index(
+    color: [u8; 3],
+    colors: &[[u8; 3]],
+    colors_oklab: &[[f32; 3]],
+    distance: ColorDistance,
+) -> u8 {
+    let closest = match distance {
+        ColorDistance::Rgb => closest_index(colors, |&a| color_distance(a, color)),
+        ColorDistance::OkLab => {
+            let color = srgb_to_oklab(color);
+            closest_index(colors_oklab, |&a| oklab_distance(a, color))
+        }
+    };
+
+    closest.unwrap_or(0) as u8
+}
+
+fn closest_index<T>(colors: &[T], distance: impl Fn(&T) -> f64) -> Option<usize> {
     colors
         .iter()
+        .map(distance)
         .enumerate()
-        .min_by(|&(_, &a), &(_, &b)| {
-            color_distance(a, color)
-                .partial_cmp(&color_distance(b, color))
-                .unwrap()
-        })
-        .map(|(i, _)| i as u8)
-        .unwrap_or(0)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(i, _)| i)
 }
 
 fn color_distance(a: [u8; 3], b: [u8; 3]) -> f64 {
@@ -135,6 +261,35 @@ fn color_distance(a: [u8; 3], b: [u8; 3]) -> f64 {
     ((d_r + d_g + d_b) as f64).sqrt()
 }
 
+fn oklab_distance(a: [f32; 3], b: [f32; 3]) -> f64 {
+    let [a_l, a_a, a_b] = a;
+    let [b_l, b_a, b_b] = b;
+
+    ((a_l - b_l).powi(2) + (a_a - b_a).powi(2) + (a_b - b_b).powi(2)).sqrt() as f64
+}
+
+/// Convert an sRGB color to OKLab, reference: https://bottosson.github.io/posts/oklab/
+fn srgb_to_oklab(color: [u8; 3]) -> [f32; 3] {
+    let [r, g, b] = color.map(|channel| {
+        let channel = channel as f32 / 255.0;
+        if channel <= 0.04045 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    });
+
+    let l = (0.412_221_47 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
 const fn ratatui_color_from_ansi_index(index: u8) -> Color {
     match index {
         0 => Color::Black,
@@ -156,3 +311,123 @@ const fn ratatui_color_from_ansi_index(index: u8) -> Color {
         16..=255 => Color::Indexed(index),
     }
 }
+
+/// Approximate rgb value of a ratatui color, using the standard xterm palette for ANSI colors, or
+/// `None` for `Color::Reset` (whatever the terminal's default color is).
+pub(crate) fn rgb_for_color(color: Color) -> Option<[u8; 3]> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some([r, g, b]),
+        Color::Indexed(index) => return Some(ANSI_COLORS_256[index as usize]),
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+    };
+
+    Some(ANSI_COLORS_16[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Distance between a color and one of the ANSI colors, measured the same way as the search.
+    fn distance_to_ansi(
+        color: [u8; 3],
+        index: u8,
+        colors: &[[u8; 3]],
+        colors_oklab: &[[f32; 3]],
+        distance: ColorDistance,
+    ) -> f64 {
+        match distance {
+            ColorDistance::Rgb => color_distance(color, colors[index as usize]),
+            ColorDistance::OkLab => {
+                oklab_distance(srgb_to_oklab(color), colors_oklab[index as usize])
+            }
+        }
+    }
+
+    #[test]
+    fn cached_ansi_colors_are_within_the_rounding_tolerance_of_the_nearest() {
+        let caches: [(&[[u8; 3]], &[[f32; 3]], ColorDistance, &[u8]); 4] = [
+            (
+                &ANSI_COLORS_16,
+                &*ANSI_COLORS_16_OKLAB,
+                ColorDistance::Rgb,
+                &ANSI_16_RGB_CACHE,
+            ),
+            (
+                &ANSI_COLORS_16,
+                &*ANSI_COLORS_16_OKLAB,
+                ColorDistance::OkLab,
+                &ANSI_16_OKLAB_CACHE,
+            ),
+            (
+                &ANSI_COLORS_256,
+                &*ANSI_COLORS_256_OKLAB,
+                ColorDistance::Rgb,
+                &ANSI_256_RGB_CACHE,
+            ),
+            (
+                &ANSI_COLORS_256,
+                &*ANSI_COLORS_256_OKLAB,
+                ColorDistance::OkLab,
+                &ANSI_256_OKLAB_CACHE,
+            ),
+        ];
+
+        // every fifth level of each channel, from 0 to 255 inclusive.
+        let levels = || (0..=u8::MAX).step_by(5);
+
+        for (colors, colors_oklab, distance, cache) in caches {
+            for color in
+                levels().flat_map(|r| levels().flat_map(move |g| levels().map(move |b| [r, g, b])))
+            {
+                let cached = cached_ansi_index(color, cache);
+                let nearest = color_rgb_to_ansi_index(color, colors, colors_oklab, distance);
+
+                // the cache holds the nearest color to the rounded color, which is at most this
+                // much further from the color than its own nearest color (by the triangle
+                // inequality).
+                let rounded = color.map(|channel| bucket_center(cache_bucket(channel)));
+                let tolerance = 2.
+                    * match distance {
+                        ColorDistance::Rgb => color_distance(color, rounded),
+                        ColorDistance::OkLab => {
+                            oklab_distance(srgb_to_oklab(color), srgb_to_oklab(rounded))
+                        }
+                    };
+
+                let excess = distance_to_ansi(color, cached, colors, colors_oklab, distance)
+                    - distance_to_ansi(color, nearest, colors, colors_oklab, distance);
+                assert!(
+                    excess <= tolerance + 1e-6,
+                    "{color:?} ({distance:?}) mapped to {cached} rather than {nearest}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn the_rgb_rounding_tolerance_is_at_most_fourteen() {
+        let max_offset = (0..=u8::MAX)
+            .map(|channel| channel.abs_diff(bucket_center(cache_bucket(channel))))
+            .max()
+            .unwrap();
+
+        assert!(2. * color_distance([0; 3], [max_offset; 3]) <= 14.);
+    }
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+/// Resource describing the assumed pixel dimensions of the glyphs in the terminal's font. Used
+/// when calculating the aspect ratio of the area a camera widget is drawn within, and when
+/// choosing the dimensions of the render texture during autoresize.
+///
+/// By default, cells are assumed to be exactly twice as tall as they are wide. With the
+/// `crossterm` feature enabled, the cell size is instead measured from the pixel dimensions the
+/// terminal reports (see [RatatuiCameraFontMetrics::detect]), and measured again whenever they
+/// change. If your terminal does not report its pixel dimensions, or you are able to measure the
+/// cell size some other way (e.g. from kitty's text-sizing queries), insert this resource with the
+/// correct values to avoid stretched output. Inserted values are never replaced by detection.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiCameraFontMetrics;
+/// #
+/// # fn setup_system(mut commands: Commands) {
+/// // a terminal reporting 120x40 cells in a 1080x880 pixel window.
+/// if let Some(font_metrics) = RatatuiCameraFontMetrics::from_window_size(120, 40, 1080, 880) {
+///     commands.insert_resource(font_metrics);
+/// }
+/// # }
+/// ```
+///
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraFontMetrics {
+    /// Width of a terminal cell, in pixels.
+    pub cell_width: u16,
+
+    /// Height of a terminal cell, in pixels.
+    pub cell_height: u16,
+
+    /// Distance from the top of a terminal cell to the baseline of its glyph, in pixels.
+    pub baseline: u16,
+}
+
+impl Default for RatatuiCameraFontMetrics {
+    fn default() -> Self {
+        Self {
+            cell_width: 8,
+            cell_height: 16,
+            baseline: 13,
+        }
+    }
+}
+
+impl RatatuiCameraFontMetrics {
+    /// Create font metrics from the size of the terminal window in cells and in pixels, as
+    /// reported by the terminal. Returns `None` if any of the dimensions are zero (many terminals
+    /// report zero pixel dimensions when they do not support the query). The baseline is
+    /// estimated at four fifths of the cell height.
+    pub fn from_window_size(columns: u16, rows: u16, width: u16, height: u16) -> Option<Self> {
+        if columns == 0 || rows == 0 || width < columns || height < rows {
+            return None;
+        }
+
+        let cell_width = width / columns;
+        let cell_height = height / rows;
+
+        Some(Self {
+            cell_width,
+            cell_height,
+            baseline: cell_height * 4 / 5,
+        })
+    }
+
+    /// Measure the font metrics from the terminal's size in cells and in pixels, as reported by
+    /// `TIOCGWINSZ` on unix (through `crossterm::terminal::window_size()`). Returns `None` if the
+    /// size cannot be queried (e.g. on Windows, or when not attached to a terminal), or if the
+    /// terminal reports zero pixel dimensions.
+    #[cfg(feature = "crossterm")]
+    pub fn detect() -> Option<Self> {
+        let size = crossterm::terminal::window_size().ok()?;
+        Self::from_window_size(size.columns, size.rows, size.width, size.height)
+    }
+
+    /// The ratio of a terminal cell's height to its width.
+    pub fn cell_aspect_ratio(&self) -> f32 {
+        self.cell_height.max(1) as f32 / self.cell_width.max(1) as f32
+    }
+}
+
+/// Replaces the font metrics with newly measured ones whenever the terminal's reported size
+/// changes, unless the resource was set to something other than what was last measured.
+#[cfg(feature = "crossterm")]
+pub(crate) fn detect_font_metrics_system(
+    mut font_metrics: ResMut<RatatuiCameraFontMetrics>,
+    mut detected: Local<Option<RatatuiCameraFontMetrics>>,
+) {
+    if *font_metrics != detected.unwrap_or_default() {
+        return;
+    }
+
+    if let Some(measured) = RatatuiCameraFontMetrics::detect() {
+        font_metrics.set_if_neq(measured);
+        *detected = Some(measured);
+    }
+}
@@ -2,34 +2,170 @@
 
 //! Bevy rendered to the terminal!
 
+#[cfg(not(any(feature = "core_2d", feature = "core_3d")))]
+compile_error!("bevy_ratatui_camera requires at least one of the `core_2d` or `core_3d` features");
+
+mod buffer_ansi;
+mod buffer_html;
+mod buffer_image;
+mod buffer_texture;
 mod camera;
+mod camera_cell_diffing;
+#[cfg(feature = "core_3d")]
+mod camera_commands;
+mod camera_config;
+mod camera_crt_effect;
+mod camera_defaults;
+mod camera_depth_joints;
+mod camera_diagnostics;
 mod camera_edge_detection;
+mod camera_emissive_detection;
+mod camera_error;
+#[cfg(feature = "mouse")]
+mod camera_focus;
+#[cfg(feature = "gpu_cells")]
+mod camera_gpu_cells;
 mod camera_image_pipe;
+mod camera_layers;
+mod camera_mask;
+#[cfg(feature = "mouse")]
+mod camera_mouse;
 mod camera_node;
+#[cfg(feature = "depth")]
+mod camera_node_depth;
+#[cfg(feature = "core_3d")]
+mod camera_node_edge_color;
+#[cfg(feature = "core_3d")]
+mod camera_node_emissive;
+#[cfg(feature = "core_3d")]
+mod camera_node_normal;
+mod camera_node_post_process;
+#[cfg(feature = "sobel")]
 mod camera_node_sobel;
+mod camera_on_demand;
+#[cfg(feature = "orbit")]
+mod camera_orbit;
+mod camera_posterize;
 mod camera_readback;
+mod camera_recorder;
 mod camera_strategy;
+mod camera_strategy_compat;
+mod camera_subcamera;
+mod camera_tour;
+mod camera_ui;
+mod camera_validation;
+mod camera_viewport;
+mod character_ramp;
+mod character_support;
+mod color_lut;
 mod color_support;
+mod font_metrics;
 mod plugin;
+#[cfg(any(test, feature = "test_utils"))]
+pub mod test_utils;
 mod widget;
+mod widget_braille;
+mod widget_cell_cache;
+mod widget_compositor;
 mod widget_depth_buffer;
+#[cfg(feature = "draw")]
+mod widget_draw;
+mod widget_glyph_masks;
+mod widget_image;
+mod widget_layout;
 mod widget_math;
+mod widget_overlay;
+mod widget_overlay_hud;
+mod widget_query;
+mod widget_render_options;
+mod widget_screen_anchor;
+mod widget_snapshot;
+mod widget_statistics;
 mod widget_strategy_depth;
+mod widget_strategy_glyphs;
 mod widget_strategy_halfblocks;
 mod widget_strategy_luminance;
+mod widget_strategy_luminance_depth;
 mod widget_strategy_none;
+mod widget_strategy_normals;
+mod widget_strategy_threshold;
 mod widget_utilities;
+mod widget_wireframe;
 
+pub use buffer_texture::RatatuiBufferTexture;
 pub use camera::{
-    RatatuiCamera, RatatuiCameraDepthDetection, RatatuiCameraLastArea, RatatuiCameraSet,
-    RatatuiSubcamera, RatatuiSubcameras,
+    RatatuiCamera, RatatuiCameraDepthDetection, RatatuiCameraDepthDownsampling,
+    RatatuiCameraEdgeColorDetection, RatatuiCameraLastArea, RatatuiCameraLatency,
+    RatatuiCameraLowLatencySet, RatatuiCameraMaskDetection, RatatuiCameraNormalDetection,
+    RatatuiCameraOrder, RatatuiCameraSet, RatatuiCameraSmoothResize, RatatuiSubcamera,
+    RatatuiSubcameras,
+};
+pub use camera_cell_diffing::{RatatuiCameraCellChanges, RatatuiCameraCellDiffing};
+#[cfg(feature = "core_3d")]
+pub use camera_commands::{RatatuiCameraCommands, RatatuiCameraEntityCommands};
+pub use camera_config::{
+    RatatuiCameraConfig, RatatuiCameraConfigHandle, RatatuiCameraConfigLoader,
+    RatatuiCameraConfigLoaderError,
+};
+pub use camera_crt_effect::RatatuiCameraCrtEffect;
+pub use camera_defaults::RatatuiCameraDefaults;
+pub use camera_depth_joints::{DepthJointStyle, RatatuiCameraDepthJoints};
+pub use camera_diagnostics::RatatuiCameraDiagnosticsPlugin;
+pub use camera_edge_detection::{
+    EdgeCharacters, EdgeKernel, EdgeThicknessUnit, RatatuiCameraEdgeColor,
+    RatatuiCameraEdgeDetection, RatatuiCameraEdgeDownsampling,
 };
-pub use camera_edge_detection::{EdgeCharacters, RatatuiCameraEdgeDetection};
+pub use camera_emissive_detection::RatatuiCameraEmissiveDetection;
+pub use camera_error::RatatuiCameraError;
+#[cfg(feature = "mouse")]
+pub use camera_focus::{RatatuiCameraFocus, RatatuiCameraFocusChanged};
+#[cfg(feature = "gpu_cells")]
+pub use camera_gpu_cells::RatatuiCameraGpuCells;
+pub use camera_layers::{RatatuiCameraLayer, RatatuiCameraLayers};
+pub use camera_mask::RatatuiCameraMask;
+#[cfg(feature = "mouse")]
+pub use camera_mouse::{RatatuiCameraMouseClicked, RatatuiCameraMouseHit, RatatuiCameraMouseMoved};
+pub use camera_node::{RatatuiCameraInstance, RatatuiCameraNodeKind, RatatuiCameraNodeLabel};
+pub use camera_node_post_process::{RatatuiCameraPostProcess, RatatuiCameraPostProcessPlugin};
+pub use camera_on_demand::{RatatuiCameraOnDemand, RatatuiCameraPaused};
+#[cfg(feature = "orbit")]
+pub use camera_orbit::RatatuiOrbitController;
+pub use camera_posterize::RatatuiCameraPosterize;
+pub use camera_readback::RatatuiCameraReady;
+pub use camera_recorder::{RatatuiCameraRecorder, RatatuiCameraRecordingFormat};
 pub use camera_strategy::{
-    CharactersConfig, ColorChoice, ColorsConfig, CommonConfig, DepthConfig, HalfBlocksConfig,
-    LuminanceConfig, RatatuiCameraStrategy,
+    CharactersConfig, ColorChoice, ColorsConfig, CommonConfig, DepthConfig, DepthFog, DepthMapping,
+    GlyphsConfig, GrayscaleWeights, HalfBlocksConfig, HalfBlocksMode, LuminanceConfig,
+    LuminanceDepthConfig, ModifierMapping, ModifierSource, NormalsConfig, RatatuiCameraStrategy,
+    ThresholdConfig, ThresholdDithering, ThresholdOutput,
+};
+pub use camera_subcamera::{RatatuiSubcameraManualClear, RatatuiSubcameraTargetChanged};
+pub use camera_tour::{
+    RatatuiCameraTour, RatatuiCameraTourFinished, RatatuiCameraTourPath, RatatuiCameraTourPlugin,
+};
+pub use camera_ui::RatatuiCameraUi;
+pub use camera_viewport::ViewportConfig;
+pub use character_ramp::{CharacterRamp, CharacterRampLoader, CharacterRampLoaderError};
+pub use character_support::RatatuiCameraCharacterSupport;
+pub use color_lut::{ColorLut, ColorLutHandle, ColorLutLoader, ColorLutLoaderError};
+pub use color_support::{ColorDistance, ColorSupport};
+pub use font_metrics::RatatuiCameraFontMetrics;
+pub use plugin::{RatatuiCameraPlugin, RatatuiCameraPluginWithDefaults};
+pub use widget::{RatatuiCameraWidget, RatatuiCameraWidgetRef};
+pub use widget_braille::RatatuiBrailleCanvas;
+pub use widget_compositor::{CompositorLayer, RatatuiCameraCompositor};
+pub use widget_depth_buffer::{RatatuiCameraDepthBuffer, RatatuiCameraDepthBuffers};
+#[cfg(feature = "draw")]
+pub use widget_draw::RatatuiCameraDraw;
+pub use widget_image::RatatuiImageWidget;
+pub use widget_layout::{CameraLayoutArrangement, RatatuiCameraLayout};
+pub use widget_overlay::{
+    OverlayOcclusion, RatatuiOverlay, RatatuiOverlayAnchor, RatatuiOverlayLayer,
+    RatatuiOverlayOrder, RatatuiOverlayPlugin,
 };
-pub use color_support::ColorSupport;
-pub use plugin::RatatuiCameraPlugin;
-pub use widget::RatatuiCameraWidget;
-pub use widget_depth_buffer::RatatuiCameraDepthBuffer;
+pub use widget_overlay_hud::{RatatuiBadgeOverlay, RatatuiBarOverlay, RatatuiDistanceOverlay};
+pub use widget_query::RatatuiCameraWidgets;
+pub use widget_render_options::{FillMode, ImageAlignment, RatatuiCameraRenderOptions};
+pub use widget_screen_anchor::{RatatuiScreenAnchor, ScreenAnchorPosition};
+pub use widget_statistics::RatatuiCameraImageStatistics;
+pub use widget_wireframe::{RatatuiCameraWireframe, WireframeSource};
@@ -1,8 +1,36 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, render::RenderApp};
 
 use crate::{
-    camera_node::RatatuiCameraNodePlugin, camera_node_sobel::RatatuiCameraNodeSobelPlugin,
-    camera_readback::RatatuiCameraReadbackPlugin,
+    ColorSupport, RatatuiBadgeOverlay, RatatuiBarOverlay, RatatuiCamera, RatatuiCameraDefaults,
+    RatatuiCameraEdgeDetection, RatatuiCameraOrder, RatatuiCameraStrategy, RatatuiDistanceOverlay,
+    RatatuiOverlayPlugin, buffer_texture::RatatuiBufferTexturePlugin,
+    camera_config::RatatuiCameraConfigPlugin, camera_crt_effect::RatatuiCameraCrtEffectPlugin,
+    camera_defaults::RatatuiCameraDefaultsPlugin, camera_layers::RatatuiCameraLayersPlugin,
+    camera_node::RatatuiCameraNodePlugin, camera_on_demand::RatatuiCameraOnDemandPlugin,
+    camera_posterize::RatatuiCameraPosterizePlugin, camera_readback::RatatuiCameraReadbackPlugin,
+    camera_recorder::RatatuiCameraRecorderPlugin, camera_subcamera::RatatuiCameraSubcameraPlugin,
+    camera_ui::RatatuiCameraUiPlugin, camera_validation::RatatuiCameraValidationPlugin,
+    character_ramp::RatatuiCameraCharacterRampPlugin, color_lut::RatatuiCameraColorLutPlugin,
+    widget_wireframe::RatatuiCameraWireframePlugin,
+};
+
+#[cfg(feature = "gpu_cells")]
+use crate::camera_gpu_cells::RatatuiCameraGpuCellsPlugin;
+#[cfg(feature = "depth")]
+use crate::camera_node_depth::RatatuiCameraNodeDepthPlugin;
+#[cfg(feature = "sobel")]
+use crate::camera_node_sobel::RatatuiCameraNodeSobelPlugin;
+#[cfg(feature = "orbit")]
+use crate::camera_orbit::RatatuiOrbitControllerPlugin;
+#[cfg(feature = "draw")]
+use crate::widget_draw::RatatuiCameraDrawPlugin;
+#[cfg(feature = "mouse")]
+use crate::{camera_focus::RatatuiCameraFocusPlugin, camera_mouse::RatatuiCameraMousePlugin};
+#[cfg(feature = "core_3d")]
+use crate::{
+    camera_node_edge_color::RatatuiCameraNodeEdgeColorPlugin,
+    camera_node_emissive::RatatuiCameraNodeEmissivePlugin,
+    camera_node_normal::RatatuiCameraNodeNormalPlugin,
 };
 
 /// Add this plugin, add a RatatuiCamera component to your camera, and then a RatatuiCameraWidget
@@ -65,15 +93,116 @@ use crate::{
 /// }
 /// ```
 ///
+/// Adding this plugin more than once (e.g. from several of your own plugins that each depend on
+/// it) is safe, as any duplicates are ignored with a warning. The render graph nodes added by
+/// this plugin are labelled with [RatatuiCameraNodeLabel](crate::RatatuiCameraNodeLabel)s
+/// namespaced by the app's [RatatuiCameraInstance](crate::RatatuiCameraInstance), so they will not
+/// conflict with render graph nodes added by your application or by other instances.
+///
+/// Cameras that do not provide their own configuration use the [RatatuiCameraDefaults] resource,
+/// which is initialized if it has not been inserted and is read whenever a camera is spawned. Use
+/// [RatatuiCameraPlugin::with_defaults] to provide it along with the plugin.
+///
 #[derive(Debug)]
 pub struct RatatuiCameraPlugin;
 
+impl RatatuiCameraPlugin {
+    /// Add the plugin with the provided defaults, used by cameras that do not provide their own
+    /// configuration.
+    pub fn with_defaults(defaults: RatatuiCameraDefaults) -> RatatuiCameraPluginWithDefaults {
+        RatatuiCameraPluginWithDefaults { defaults }
+    }
+}
+
 impl Plugin for RatatuiCameraPlugin {
     fn build(&self, app: &mut App) {
+        if app.is_plugin_added::<RatatuiCameraReadbackPlugin>() {
+            log::warn!("RatatuiCameraPlugin was added more than once, ignoring the duplicate.");
+            return;
+        }
+
+        if app.get_sub_app(RenderApp).is_none() {
+            log::warn!(
+                "RatatuiCameraPlugin requires bevy's RenderPlugin to be added first, skipping."
+            );
+            return;
+        }
+
+        app.add_plugins(RatatuiCameraDefaultsPlugin);
+
+        app.register_type::<RatatuiCamera>()
+            .register_type::<RatatuiCameraOrder>()
+            .register_type::<RatatuiCameraStrategy>()
+            .register_type::<RatatuiCameraEdgeDetection>()
+            .register_type::<ColorSupport>();
+
         app.add_plugins((
             RatatuiCameraNodePlugin,
-            RatatuiCameraNodeSobelPlugin,
             RatatuiCameraReadbackPlugin,
+            RatatuiCameraCharacterRampPlugin,
+            RatatuiCameraColorLutPlugin,
+            RatatuiCameraConfigPlugin,
+            RatatuiCameraLayersPlugin,
+            RatatuiCameraUiPlugin,
+            RatatuiCameraOnDemandPlugin,
+            RatatuiCameraValidationPlugin,
+            RatatuiCameraRecorderPlugin,
+            RatatuiCameraWireframePlugin,
+            RatatuiCameraCrtEffectPlugin,
+            RatatuiCameraPosterizePlugin,
+            RatatuiCameraSubcameraPlugin,
+            RatatuiBufferTexturePlugin,
+        ));
+
+        app.add_plugins((
+            RatatuiOverlayPlugin::<RatatuiBarOverlay>::default(),
+            RatatuiOverlayPlugin::<RatatuiBadgeOverlay>::default(),
+            RatatuiOverlayPlugin::<RatatuiDistanceOverlay>::default(),
+        ));
+
+        #[cfg(feature = "core_3d")]
+        app.add_plugins((
+            RatatuiCameraNodeNormalPlugin,
+            RatatuiCameraNodeEmissivePlugin,
+            RatatuiCameraNodeEdgeColorPlugin,
         ));
+
+        #[cfg(feature = "sobel")]
+        app.add_plugins(RatatuiCameraNodeSobelPlugin);
+
+        #[cfg(feature = "depth")]
+        app.add_plugins(RatatuiCameraNodeDepthPlugin);
+
+        #[cfg(feature = "gpu_cells")]
+        app.add_plugins(RatatuiCameraGpuCellsPlugin);
+
+        #[cfg(feature = "draw")]
+        app.add_plugins(RatatuiCameraDrawPlugin);
+
+        #[cfg(feature = "mouse")]
+        app.add_plugins((RatatuiCameraMousePlugin, RatatuiCameraFocusPlugin));
+
+        #[cfg(feature = "orbit")]
+        app.add_plugins(RatatuiOrbitControllerPlugin);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
     }
 }
+
+/// [RatatuiCameraPlugin] along with the [RatatuiCameraDefaults] it provides to cameras, see
+/// [RatatuiCameraPlugin::with_defaults].
+#[derive(Debug)]
+pub struct RatatuiCameraPluginWithDefaults {
+    /// Configuration used by cameras that do not provide their own, see [RatatuiCameraDefaults].
+    pub defaults: RatatuiCameraDefaults,
+}
+
+impl Plugin for RatatuiCameraPluginWithDefaults {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.defaults.clone())
+            .add_plugins(RatatuiCameraPlugin);
+    }
+}
+
@@ -0,0 +1,34 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct Settings {
+    tint: vec4f,
+    scanline_intensity: f32,
+    scanline_count: f32,
+    curvature: f32,
+};
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+    // barrel distortion, sampling further from the center towards the edges so that the image
+    // bulges outwards, leaving the corners empty.
+    let centered = in.uv * 2.0 - 1.0;
+    let distorted = centered * (1.0 + settings.curvature * dot(centered, centered));
+    let uv = distorted * 0.5 + 0.5;
+
+    if any(uv < vec2f(0.0)) || any(uv > vec2f(1.0)) {
+        return vec4f(0.0);
+    }
+
+    let color = textureSampleLevel(screen_texture, texture_sampler, uv, 0.0);
+
+    // the lower half of each scanline is darkened, so that with one scanline per terminal row
+    // the scanlines land on the lower half of each cell.
+    let scanline_position = fract(uv.y * settings.scanline_count);
+    let scanline = select(1.0, 1.0 - settings.scanline_intensity, scanline_position >= 0.5);
+
+    return vec4f(color.rgb * settings.tint.rgb * scanline, color.a);
+}
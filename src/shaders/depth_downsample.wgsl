@@ -0,0 +1,33 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var depth_texture: texture_depth_2d;
+
+fn load_depth(coords: vec2u) -> f32 {
+    #ifdef DEPTH_TEXTURE_SAMPLING_SUPPORTED
+    return textureLoad(depth_texture, coords, 0);
+    #else
+    return 0.0;
+    #endif
+}
+
+// each output pixel covers a block of depth texture pixels, and keeps the nearest depth (highest,
+// as depth is reversed) within it. the depth is written as its raw bytes, so that it is read back
+// exactly the same as a full depth texture copy.
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+    let source_size = textureDimensions(depth_texture);
+    let output_size = in.position.xy / in.uv;
+    let scale = vec2f(source_size) / output_size;
+
+    let start = vec2u(floor((in.position.xy - 0.5) * scale));
+    let end = min(vec2u(ceil((in.position.xy + 0.5) * scale)), source_size);
+
+    var depth = 0.0;
+    for (var y = start.y; y < end.y; y++) {
+        for (var x = start.x; x < end.x; x++) {
+            depth = max(depth, load_depth(vec2u(x, y)));
+        }
+    }
+
+    return unpack4x8unorm(bitcast<u32>(depth));
+}
@@ -0,0 +1,58 @@
+#import bevy_pbr::{mesh_functions, view_transformations::position_world_to_clip}
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3f,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4f,
+};
+
+struct MeshValues {
+    edge_color: vec4f,
+    mask: u32,
+};
+
+// one output per enabled readback, in the same order as the pipeline's targets.
+struct FragmentOutput {
+#ifdef EDGE_COLOR
+    @location(0) edge_color: vec4f,
+#ifdef MASK
+    @location(1) mask: vec4f,
+#endif
+#else
+    @location(0) mask: vec4f,
+#endif
+};
+
+@group(1) @binding(0) var<uniform> mesh_values: MeshValues;
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    let world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    let world_position = mesh_functions::mesh_position_local_to_world(
+        world_from_local,
+        vec4f(vertex.position, 1.0),
+    );
+
+    var out: VertexOutput;
+    out.clip_position = position_world_to_clip(world_position.xyz);
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> FragmentOutput {
+    var out: FragmentOutput;
+
+#ifdef EDGE_COLOR
+    out.edge_color = mesh_values.edge_color;
+#endif
+
+    // the mask is stored as a raw byte in the red channel.
+#ifdef MASK
+    out.mask = vec4f(f32(mesh_values.mask) / 255.0, 0.0, 0.0, 1.0);
+#endif
+
+    return out;
+}
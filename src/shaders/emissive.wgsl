@@ -0,0 +1,15 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+// brightness is stored as a fraction of this value, so that values above 1.0 survive being
+// written to a non-HDR texture.
+const MAX_BRIGHTNESS: f32 = 4.0;
+
+@group(0) @binding(0) var main_texture: texture_2d<f32>;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+    let color = textureLoad(main_texture, vec2i(in.position.xy), 0).rgb;
+    let brightness = max(color.r, max(color.g, color.b));
+
+    return vec4f(vec3f(clamp(brightness / MAX_BRIGHTNESS, 0.0, 1.0)), 1.0);
+}
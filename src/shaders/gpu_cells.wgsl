@@ -0,0 +1,63 @@
+struct GpuCellsSettings {
+    scale: f32,
+    character_count: u32,
+}
+
+@group(0) @binding(0) var camera_texture: texture_2d<f32>;
+@group(0) @binding(1) var cells_texture: texture_storage_2d<rgba8unorm, write>;
+@group(0) @binding(2) var<uniform> settings: GpuCellsSettings;
+
+// alpha value marking a cell with no opaque pixels, as character indices only go up to 254.
+const TRANSPARENT_CELL: f32 = 1.0;
+
+// nearest pixel of the camera texture to a pixel of a grid of the provided size, matching the
+// nearest neighbor resize done before the cpu strategies convert the image.
+fn load_nearest(position: vec2u, grid_size: vec2u) -> vec4f {
+    let source_size = textureDimensions(camera_texture);
+    let coords = vec2u((vec2f(position) + 0.5) * vec2f(source_size) / vec2f(grid_size));
+    return textureLoad(camera_texture, min(coords, source_size - 1u), 0);
+}
+
+fn linear_to_srgb(color: vec3f) -> vec3f {
+    let low = color * 12.92;
+    let high = 1.055 * pow(color, vec3f(1.0 / 2.4)) - 0.055;
+    return select(high, low, color <= vec3f(0.0031308));
+}
+
+fn srgb_to_linear(color: vec3f) -> vec3f {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3f(2.4));
+    return select(high, low, color <= vec3f(0.04045));
+}
+
+// each invocation converts one terminal cell, covering two vertically stacked pixels, into the
+// index of its luminance character and its foreground color. the color is written to the rgb
+// channels and the character index to the alpha channel.
+@compute @workgroup_size(8, 8, 1)
+fn convert(@builtin(global_invocation_id) id: vec3u) {
+    let cells = textureDimensions(cells_texture);
+    if any(id.xy >= cells) {
+        return;
+    }
+
+    let pixels = cells * vec2u(1u, 2u);
+    let top = load_nearest(id.xy * vec2u(1u, 2u), pixels);
+    let bottom = load_nearest(id.xy * vec2u(1u, 2u) + vec2u(0u, 1u), pixels);
+
+    // averaged in srgb, the same as the cpu strategies.
+    let color = (vec4f(linear_to_srgb(top.rgb), top.a) + vec4f(linear_to_srgb(bottom.rgb), bottom.a)) * 0.5;
+
+    if color.a * 255.0 < 1.0 || settings.character_count == 0u {
+        textureStore(cells_texture, id.xy, vec4f(0.0, 0.0, 0.0, TRANSPARENT_CELL));
+        return;
+    }
+
+    let luminance = dot(srgb_to_linear(color.rgb), vec3f(0.2126, 0.7152, 0.0722));
+    let scaled_luminance = min(luminance * settings.scale, 1.0);
+    let index = min(
+        u32(scaled_luminance * f32(settings.character_count)),
+        settings.character_count - 1u,
+    );
+
+    textureStore(cells_texture, id.xy, vec4f(color.rgb, f32(index) / 255.0));
+}
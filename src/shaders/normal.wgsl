@@ -0,0 +1,13 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var normal_prepass_texture: texture_2d<f32>;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+    let normal = textureLoad(normal_prepass_texture, vec2i(in.position.xy), 0).xyz;
+
+    // the prepass is cleared to zero where nothing was drawn, mark those pixels as transparent.
+    let coverage = select(0.0, 1.0, any(normal != vec3f(0.0)));
+
+    return vec4f(normal, coverage);
+}
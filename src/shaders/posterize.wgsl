@@ -0,0 +1,36 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct Settings {
+    grid: vec2f,
+    levels: f32,
+    pixelate: u32,
+};
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var texture_sampler: sampler;
+@group(0) @binding(2) var<uniform> settings: Settings;
+
+fn linear_to_srgb(color: vec3f) -> vec3f {
+    return pow(max(color, vec3f(0.0)), vec3f(1.0 / 2.2));
+}
+
+fn srgb_to_linear(color: vec3f) -> vec3f {
+    return pow(color, vec3f(2.2));
+}
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+    // sample each block of the grid at its center, so every pixel of a block shares one color.
+    var uv = in.uv;
+    if settings.pixelate != 0u {
+        uv = (floor(uv * settings.grid) + 0.5) / settings.grid;
+    }
+
+    let color = textureSampleLevel(screen_texture, texture_sampler, uv, 0.0);
+
+    // quantized in (approximate) sRGB space, so that the levels are evenly spaced perceptually.
+    let steps = max(settings.levels - 1.0, 1.0);
+    let quantized = round(linear_to_srgb(color.rgb) * steps) / steps;
+
+    return vec4f(srgb_to_linear(quantized), color.a);
+}
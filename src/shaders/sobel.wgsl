@@ -13,10 +13,10 @@ struct Config {
     normal_enabled: u32,
     normal_threshold: f32,
 
-    #ifdef SIXTEEN_BYTE_ALIGNMENT
-    // pad to 32 bytes when structs must be 16 byte aligned
-    _padding: u32,
-    #endif
+    softness: f32,
+
+    // horizontal, vertical, forward diagonal, and backward diagonal weights for each neighbor.
+    kernel: array<vec4f, 9>,
 };
 
 @group(0) @binding(0) var screen_texture: texture_2d<f32>;
@@ -28,30 +28,6 @@ struct Config {
 
 var<private> thickness: f32 = 1.4;
 
-var<private> sobel_horizontal: array<f32, 9> = array<f32, 9>(
-    1.0, 0.0, -1.0,
-    2.0, 0.0, -2.0,
-    1.0, 0.0, -1.0,
-);
-
-var<private> sobel_vertical: array<f32, 9> = array<f32, 9>(
-     1.0,  2.0,  1.0,
-     0.0,  0.0,  0.0,
-    -1.0, -2.0, -1.0,
-);
-
-var<private> sobel_forward: array<f32, 9> = array<f32, 9>(
-     0.0,  1.0,  2.0,
-    -1.0,  0.0,  1.0,
-    -2.0, -1.0,  0.0,
-);
-
-var<private> sobel_backward: array<f32, 9> = array<f32, 9>(
-     2.0,  1.0,  0.0,
-     1.0,  0.0, -1.0,
-     0.0, -1.0, -2.0,
-);
-
 var<private> neighbors: array<vec2f, 9> = array<vec2f, 9>(
     vec2f(-1.0,  1.0), vec2f(0.0,  1.0), vec2f(1.0,  1.0),
     vec2f(-1.0,  0.0), vec2f(0.0,  0.0), vec2f(1.0,  0.0),
@@ -61,19 +37,19 @@ var<private> neighbors: array<vec2f, 9> = array<vec2f, 9>(
 fn detect_edge_f32(samples: ptr<function, array<f32, 9>>) -> vec4f {
     var horizontal = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        horizontal += (*samples)[i] * sobel_horizontal[i];
+        horizontal += (*samples)[i] * config.kernel[i].x;
     }
     var vertical = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        vertical += (*samples)[i] * sobel_vertical[i];
+        vertical += (*samples)[i] * config.kernel[i].y;
     }
     var forward = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        forward += (*samples)[i] * sobel_forward[i];
+        forward += (*samples)[i] * config.kernel[i].z;
     }
     var backward = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        backward += (*samples)[i] * sobel_backward[i];
+        backward += (*samples)[i] * config.kernel[i].w;
     }
 
     let edge = vec4f(
@@ -89,19 +65,19 @@ fn detect_edge_f32(samples: ptr<function, array<f32, 9>>) -> vec4f {
 fn detect_edge_vec3(samples: ptr<function, array<vec3f, 9>>) -> vec4f {
     var horizontal = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        horizontal += (*samples)[i].xyz * sobel_horizontal[i];
+        horizontal += (*samples)[i].xyz * config.kernel[i].x;
     }
     var vertical = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        vertical += (*samples)[i].xyz * sobel_vertical[i];
+        vertical += (*samples)[i].xyz * config.kernel[i].y;
     }
     var forward = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        forward += (*samples)[i].xyz * sobel_forward[i];
+        forward += (*samples)[i].xyz * config.kernel[i].z;
     }
     var backward = vec3f(0.0);
     for (var i = 0; i < 9; i++) {
-        backward += (*samples)[i].xyz * sobel_backward[i];
+        backward += (*samples)[i].xyz * config.kernel[i].w;
     }
 
     let edge = vec4f(
@@ -114,6 +90,16 @@ fn detect_edge_vec3(samples: ptr<function, array<vec3f, 9>>) -> vec4f {
     return edge;
 }
 
+// without softness, edges below the threshold are discarded and the rest keep their severity.
+// with softness, edges ramp smoothly from 0.0 at the threshold to 1.0 at threshold + softness.
+fn apply_threshold(edge: vec4f, threshold: f32) -> vec4f {
+    if config.softness > 0.0 {
+        return smoothstep(vec4f(threshold), vec4f(threshold + config.softness), edge);
+    }
+
+    return select(edge, vec4f(0.0), edge < vec4f(threshold));
+}
+
 fn prepass_depth(frag_coord: vec2f) -> f32 {
     #ifdef DEPTH_TEXTURE_SAMPLING_SUPPORTED
     return textureLoad(depth_prepass_texture, vec2i(frag_coord), 0);
@@ -136,21 +122,8 @@ fn detect_edge_depth(frag_coord: vec2f) -> vec4f {
         samples[i] =  prepass_depth(frag_coord + neighbors[i] * config.thickness);
     }
 
-    var edge = detect_edge_f32(&samples);
-    if edge.x < config.depth_threshold {
-        edge.x = 0.0;
-    }
-    if edge.y < config.depth_threshold {
-        edge.y = 0.0;
-    }
-    if edge.z < config.depth_threshold {
-        edge.z = 0.0;
-    }
-    if edge.w < config.depth_threshold {
-        edge.w = 0.0;
-    }
-
-    return edge;
+    let edge = detect_edge_f32(&samples);
+    return apply_threshold(edge, config.depth_threshold);
 }
 
 fn detect_edge_normal(frag_coord: vec2f) -> vec4f {
@@ -163,21 +136,8 @@ fn detect_edge_normal(frag_coord: vec2f) -> vec4f {
         samples[i] = prepass_normal(frag_coord + neighbors[i] * config.thickness);
     }
 
-    var edge = detect_edge_vec3(&samples);
-    if edge.x < config.normal_threshold {
-        edge.x = 0.0;
-    }
-    if edge.y < config.normal_threshold {
-        edge.y = 0.0;
-    }
-    if edge.z < config.normal_threshold {
-        edge.z = 0.0;
-    }
-    if edge.w < config.normal_threshold {
-        edge.w = 0.0;
-    }
-
-    return edge;
+    let edge = detect_edge_vec3(&samples);
+    return apply_threshold(edge, config.normal_threshold);
 }
 
 fn detect_edge_color(frag_coord: vec2f) -> vec4f {
@@ -194,21 +154,8 @@ fn detect_edge_color(frag_coord: vec2f) -> vec4f {
         ).rgb;
     }
 
-    var edge = detect_edge_vec3(&samples);
-    if edge.x < config.color_threshold {
-        edge.x = 0.0;
-    }
-    if edge.y < config.color_threshold {
-        edge.y = 0.0;
-    }
-    if edge.z < config.color_threshold {
-        edge.z = 0.0;
-    }
-    if edge.w < config.color_threshold {
-        edge.w = 0.0;
-    }
-
-    return edge;
+    let edge = detect_edge_vec3(&samples);
+    return apply_threshold(edge, config.color_threshold);
 }
 
 @fragment
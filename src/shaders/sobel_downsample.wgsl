@@ -0,0 +1,24 @@
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+@group(0) @binding(0) var sobel_texture: texture_2d<f32>;
+
+// each output pixel covers a block of sobel texture pixels, and keeps the strongest edge in each
+// direction within it, so that thin edges are not lost between sampled pixels.
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4f {
+    let source_size = textureDimensions(sobel_texture);
+    let output_size = in.position.xy / in.uv;
+    let scale = vec2f(source_size) / output_size;
+
+    let start = vec2u(floor((in.position.xy - 0.5) * scale));
+    let end = min(vec2u(ceil((in.position.xy + 0.5) * scale)), source_size);
+
+    var edge = vec4f(0.0);
+    for (var y = start.y; y < end.y; y++) {
+        for (var x = start.x; x < end.x; x++) {
+            edge = max(edge, textureLoad(sobel_texture, vec2u(x, y), 0));
+        }
+    }
+
+    return edge;
+}
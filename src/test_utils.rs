@@ -0,0 +1,281 @@
+//! Utilities for testing code that uses RatatuiCamera widgets without a GPU or a terminal, e.g.
+//! in CI. Build an app with [RatatuiCameraTestAppBuilder], spawn cameras with
+//! [RatatuiCameraTestImages] holding the images they should appear to render, and a
+//! RatatuiCameraWidget is created from those images each update just as it would be from images
+//! read back from the GPU. Draw it with e.g.
+//! [RatatuiCameraWidget::render_to_buffer](crate::RatatuiCameraWidget::render_to_buffer) to check
+//! the result. Requires the `test_utils` cargo feature.
+//!
+//! # Example:
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraStrategy, RatatuiCameraWidget};
+//! # use bevy_ratatui_camera::test_utils::{RatatuiCameraTestAppBuilder, RatatuiCameraTestImages};
+//! # use image::{DynamicImage, Rgba, RgbaImage};
+//! # use ratatui::{layout::Rect, style::Color};
+//! #
+//! let mut app = RatatuiCameraTestAppBuilder::new().build();
+//!
+//! let image = RgbaImage::from_pixel(8, 8, Rgba([255, 255, 255, 255]));
+//! let camera = app
+//!     .world_mut()
+//!     .spawn((
+//!         Camera::default(),
+//!         RatatuiCamera::new(8, 8),
+//!         RatatuiCameraStrategy::halfblocks(),
+//!         RatatuiCameraTestImages::new(DynamicImage::ImageRgba8(image)),
+//!     ))
+//!     .id();
+//!
+//! app.update();
+//!
+//! let widget = app.world().get::<RatatuiCameraWidget>(camera).unwrap();
+//! assert!(widget.is_ready());
+//!
+//! let buffer = widget.render_to_buffer(Rect::new(0, 0, 4, 2));
+//! assert_eq!(buffer[(0, 0)].bg, Color::Rgb(255, 255, 255));
+//! ```
+//!
+
+use bevy::prelude::*;
+use image::DynamicImage;
+
+use crate::{
+    ColorLut, RatatuiCameraCharacterSupport, RatatuiCameraFontMetrics, RatatuiCameraLatency,
+    RatatuiCameraReady, RatatuiCameraSet,
+    camera_defaults::RatatuiCameraDefaultsPlugin,
+    camera_image_pipe::ImageReceiver,
+    camera_readback::{
+        RatatuiCameraReceiver, RatatuiDepthReceiver, RatatuiEdgeColorReceiver,
+        RatatuiEmissiveReceiver, RatatuiMaskReceiver, RatatuiNormalReceiver, RatatuiSobelReceiver,
+        create_ratatui_camera_widgets_system, resize_ratatui_camera_observer,
+    },
+    color_lut::ImageColorLuts,
+};
+
+/// Builds an [App] with the main world half of [RatatuiCameraPlugin](crate::RatatuiCameraPlugin)
+/// and none of its rendering, so cameras' widgets are created from their
+/// [RatatuiCameraTestImages] instead of from images read back from the GPU. Add your own draw
+/// systems and plugins to the built app as usual.
+///
+/// Unlike the real plugin, the character support defaults to unicode rather than being detected
+/// from the environment, so that tests behave the same on every machine.
+///
+#[derive(Clone, Debug)]
+pub struct RatatuiCameraTestAppBuilder {
+    font_metrics: RatatuiCameraFontMetrics,
+    character_support: RatatuiCameraCharacterSupport,
+}
+
+impl Default for RatatuiCameraTestAppBuilder {
+    fn default() -> Self {
+        Self {
+            font_metrics: RatatuiCameraFontMetrics::default(),
+            character_support: RatatuiCameraCharacterSupport::Unicode,
+        }
+    }
+}
+
+impl RatatuiCameraTestAppBuilder {
+    /// Create a builder with default font metrics and unicode character support.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the provided font metrics, as if detected from the terminal.
+    pub fn font_metrics(mut self, font_metrics: RatatuiCameraFontMetrics) -> Self {
+        self.font_metrics = font_metrics;
+        self
+    }
+
+    /// Use the provided character support, as if detected from the environment.
+    pub fn character_support(mut self, character_support: RatatuiCameraCharacterSupport) -> Self {
+        self.character_support = character_support;
+        self
+    }
+
+    /// Build the app, with bevy's `MinimalPlugins` and [RatatuiCameraTestPlugin].
+    pub fn build(self) -> App {
+        let mut app = App::new();
+
+        app.add_plugins((MinimalPlugins, RatatuiCameraTestPlugin))
+            .insert_resource(self.font_metrics)
+            .insert_resource(self.character_support);
+
+        app
+    }
+}
+
+/// Creates a RatatuiCameraWidget each frame for every camera with [RatatuiCameraTestImages],
+/// without any rendering. Added by [RatatuiCameraTestAppBuilder], or add it to your own app in
+/// place of [RatatuiCameraPlugin](crate::RatatuiCameraPlugin). Uses unicode character support
+/// unless a RatatuiCameraCharacterSupport resource was already inserted.
+///
+#[derive(Debug)]
+pub struct RatatuiCameraTestPlugin;
+
+impl Plugin for RatatuiCameraTestPlugin {
+    fn build(&self, app: &mut App) {
+        if !app
+            .world()
+            .contains_resource::<RatatuiCameraCharacterSupport>()
+        {
+            app.insert_resource(RatatuiCameraCharacterSupport::Unicode);
+        }
+
+        app.init_resource::<RatatuiCameraFontMetrics>()
+            .init_resource::<RatatuiCameraLatency>()
+            .init_resource::<Assets<ColorLut>>()
+            .init_resource::<ImageColorLuts>()
+            .add_message::<RatatuiCameraReady>()
+            .add_plugins(RatatuiCameraDefaultsPlugin)
+            .add_observer(resize_ratatui_camera_observer)
+            .add_systems(
+                First,
+                (
+                    inject_test_images_system,
+                    create_ratatui_camera_widgets_system,
+                )
+                    .chain()
+                    .in_set(RatatuiCameraSet),
+            );
+    }
+}
+
+/// When within a camera entity alongside a RatatuiCamera in an app with
+/// [RatatuiCameraTestPlugin], these images are used in place of the images the camera would have
+/// rendered. Each image should have the format of the texture it stands in for (e.g. RGBA8 for the
+/// camera's image). Changing this component counts as a newly rendered frame.
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiCameraTestImages {
+    /// Stands in for the camera's rendered image.
+    pub camera: DynamicImage,
+
+    /// Stands in for the depth texture (see
+    /// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection)).
+    pub depth: Option<DynamicImage>,
+
+    /// Stands in for the edge detection texture (see
+    /// [RatatuiCameraEdgeDetection](crate::RatatuiCameraEdgeDetection)).
+    pub sobel: Option<DynamicImage>,
+
+    /// Stands in for the normal texture (see
+    /// [RatatuiCameraNormalDetection](crate::RatatuiCameraNormalDetection)).
+    pub normal: Option<DynamicImage>,
+
+    /// Stands in for the emissive texture (see
+    /// [RatatuiCameraEmissiveDetection](crate::RatatuiCameraEmissiveDetection)).
+    pub emissive: Option<DynamicImage>,
+
+    /// Stands in for the edge color texture (see
+    /// [RatatuiCameraEdgeColorDetection](crate::RatatuiCameraEdgeColorDetection)).
+    pub edge_color: Option<DynamicImage>,
+
+    /// Stands in for the mask texture (see
+    /// [RatatuiCameraMaskDetection](crate::RatatuiCameraMaskDetection)).
+    pub mask: Option<DynamicImage>,
+}
+
+impl RatatuiCameraTestImages {
+    /// Use the provided image as the camera's rendered image, without any other textures.
+    pub fn new(camera: DynamicImage) -> Self {
+        Self {
+            camera,
+            depth: None,
+            sobel: None,
+            normal: None,
+            emissive: None,
+            edge_color: None,
+            mask: None,
+        }
+    }
+
+    /// Also use the provided image as the depth texture.
+    pub fn with_depth(mut self, depth: DynamicImage) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Also use the provided image as the edge detection texture.
+    pub fn with_sobel(mut self, sobel: DynamicImage) -> Self {
+        self.sobel = Some(sobel);
+        self
+    }
+
+    /// Also use the provided image as the normal texture.
+    pub fn with_normal(mut self, normal: DynamicImage) -> Self {
+        self.normal = Some(normal);
+        self
+    }
+
+    /// Also use the provided image as the emissive texture.
+    pub fn with_emissive(mut self, emissive: DynamicImage) -> Self {
+        self.emissive = Some(emissive);
+        self
+    }
+
+    /// Also use the provided image as the edge color texture.
+    pub fn with_edge_color(mut self, edge_color: DynamicImage) -> Self {
+        self.edge_color = Some(edge_color);
+        self
+    }
+
+    /// Also use the provided image as the mask texture.
+    pub fn with_mask(mut self, mask: DynamicImage) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+}
+
+fn inject_test_images_system(
+    mut commands: Commands,
+    test_images: Query<
+        (
+            Entity,
+            &RatatuiCameraTestImages,
+            Option<&RatatuiCameraReceiver>,
+        ),
+        Changed<RatatuiCameraTestImages>,
+    >,
+) {
+    for (entity_id, test_images, previous_receiver) in &test_images {
+        let frame_number = previous_receiver.map_or(0, |receiver| receiver.frame_number) + 1;
+        let receiver =
+            |image: &DynamicImage| ImageReceiver::from_image(image.clone(), frame_number);
+
+        let mut entity = commands.entity(entity_id);
+        entity.insert(RatatuiCameraReceiver(receiver(&test_images.camera)));
+
+        match &test_images.depth {
+            Some(image) => entity.insert(RatatuiDepthReceiver(receiver(image))),
+            None => entity.remove::<RatatuiDepthReceiver>(),
+        };
+
+        match &test_images.sobel {
+            Some(image) => entity.insert(RatatuiSobelReceiver(receiver(image))),
+            None => entity.remove::<RatatuiSobelReceiver>(),
+        };
+
+        match &test_images.normal {
+            Some(image) => entity.insert(RatatuiNormalReceiver(receiver(image))),
+            None => entity.remove::<RatatuiNormalReceiver>(),
+        };
+
+        match &test_images.emissive {
+            Some(image) => entity.insert(RatatuiEmissiveReceiver(receiver(image))),
+            None => entity.remove::<RatatuiEmissiveReceiver>(),
+        };
+
+        match &test_images.edge_color {
+            Some(image) => entity.insert(RatatuiEdgeColorReceiver(receiver(image))),
+            None => entity.remove::<RatatuiEdgeColorReceiver>(),
+        };
+
+        match &test_images.mask {
+            Some(image) => entity.insert(RatatuiMaskReceiver(receiver(image))),
+            None => entity.remove::<RatatuiMaskReceiver>(),
+        };
+    }
+}
@@ -1,35 +1,91 @@
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
 
-use bevy::prelude::{Component, Entity};
+use bevy::log::info_span;
+use bevy::platform::time::Instant;
+use bevy::prelude::{Component, Entity, Mat4};
 use image::DynamicImage;
+use image::imageops::FilterType;
 use ratatui::widgets::{StatefulWidgetRef, Widget};
 use ratatui::{prelude::*, widgets::WidgetRef};
 
+use crate::camera_cell_diffing::DrawnCells;
+use crate::camera_recorder::RatatuiCameraRecorder;
+use crate::character_support::RatatuiCameraCharacterSupport;
+use crate::font_metrics::RatatuiCameraFontMetrics;
+use crate::widget_cell_cache::ConvertedCellCache;
 use crate::widget_depth_buffer::RatatuiCameraDepthBuffer;
+use crate::widget_glyph_masks::{GLYPH_MASK_HEIGHT, GLYPH_MASK_WIDTH};
+use crate::widget_math::resize_image;
+use crate::widget_overlay::{ProjectedOverlay, RatatuiOverlayOrder};
+use crate::widget_render_options::{FULL_IMAGE_REGION, RatatuiCameraRenderOptions};
+use crate::widget_statistics::RatatuiCameraImageStatistics;
 use crate::widget_strategy_depth::RatatuiCameraWidgetDepth;
+use crate::widget_strategy_glyphs::RatatuiCameraWidgetGlyphs;
 use crate::widget_strategy_halfblocks::RatatuiCameraWidgetHalf;
 use crate::widget_strategy_luminance::RatatuiCameraWidgetLuminance;
+use crate::widget_strategy_luminance_depth::RatatuiCameraWidgetLuminanceDepth;
 use crate::widget_strategy_none::RatatuiCameraWidgetNone;
-use crate::{RatatuiCameraEdgeDetection, RatatuiCameraStrategy};
+use crate::widget_strategy_normals::RatatuiCameraWidgetNormals;
+use crate::widget_strategy_threshold::RatatuiCameraWidgetThreshold;
+#[cfg(feature = "gpu_cells")]
+use crate::widget_utilities::adjusts_image_colors;
+use crate::widget_utilities::{
+    adjust_image_colors, apply_depth_joints, apply_edge_colors, apply_emissive_detection,
+    apply_modifier_mappings,
+};
+use crate::widget_wireframe::ProjectedEdge;
+use crate::{
+    ColorLut, HalfBlocksConfig, HalfBlocksMode, RatatuiCameraCellChanges, RatatuiCameraCellDiffing,
+    RatatuiCameraDepthJoints, RatatuiCameraEdgeDetection, RatatuiCameraEmissiveDetection,
+    RatatuiCameraStrategy, ViewportConfig,
+};
 
 /// Ratatui widget that will be inserted into each RatatuiCamera containing entity and updated each
 /// frame with the last image rendered by the camera. When drawn in a ratatui buffer, it will use
 /// the RatatuiCamera's specified RatatuiCameraStrategy to convert the rendered image to unicode
 /// characters, and will draw them in the buffer.
 ///
-#[derive(Component, Debug)]
+#[derive(Component, Clone, Debug)]
 pub struct RatatuiCameraWidget {
     /// Associated entity.
     pub entity: Entity,
 
-    /// RatatuiCamera camera's rendered image copied back from the GPU.
-    pub camera_image: DynamicImage,
+    /// RatatuiCamera camera's rendered image copied back from the GPU. Images are shared with the
+    /// camera's readback rather than copied each frame, and only replaced when a new frame arrives.
+    pub camera_image: Arc<DynamicImage>,
 
     /// RatatuiCamera camera's depth texture copied back from the GPU.
-    pub depth_image: Option<DynamicImage>,
+    pub depth_image: Option<Arc<DynamicImage>>,
 
     /// RatatuiCamera camera's sobel texture generated by the GPU, if any.
-    pub sobel_image: Option<DynamicImage>,
+    pub sobel_image: Option<Arc<DynamicImage>>,
+
+    /// RatatuiCamera camera's normal prepass texture copied back from the GPU, if any.
+    pub normal_image: Option<Arc<DynamicImage>>,
+
+    /// RatatuiCamera camera's brightness texture generated by the GPU, if any. Brightness values
+    /// are stored as a fraction of
+    /// [RatatuiCameraEmissiveDetection::MAX_BRIGHTNESS](crate::RatatuiCameraEmissiveDetection::MAX_BRIGHTNESS).
+    pub emissive_image: Option<Arc<DynamicImage>>,
+
+    /// RatatuiCamera camera's edge color texture generated by the GPU, if any. Pixels covered by
+    /// meshes with a [RatatuiCameraEdgeColor](crate::RatatuiCameraEdgeColor) hold that color,
+    /// and other pixels are transparent.
+    pub edge_color_image: Option<Arc<DynamicImage>>,
+
+    /// RatatuiCamera camera's mask texture generated by the GPU, if any. The red channel of pixels
+    /// covered by meshes with a [RatatuiCameraMask](crate::RatatuiCameraMask) holds that mask
+    /// value, and is `0` elsewhere.
+    pub mask_image: Option<Arc<DynamicImage>>,
+
+    /// RatatuiCamera camera's cells texture generated by the GPU, if the camera has
+    /// [RatatuiCameraGpuCells](crate::RatatuiCameraGpuCells). One pixel per terminal cell, with
+    /// the cell's foreground color in the rgb channels and its character index in the alpha
+    /// channel (`u8::MAX` for transparent cells).
+    #[cfg(feature = "gpu_cells")]
+    pub gpu_cells_image: Option<Arc<DynamicImage>>,
 
     /// Strategy used to convert the rendered image to unicode.
     pub strategy: RatatuiCameraStrategy,
@@ -37,17 +93,140 @@ pub struct RatatuiCameraWidget {
     /// RatatuiCamera's edge detection settings, if any.
     pub edge_detection: Option<RatatuiCameraEdgeDetection>,
 
+    /// RatatuiCamera's emissive detection settings, if any.
+    pub emissive_detection: Option<RatatuiCameraEmissiveDetection>,
+
+    /// RatatuiCamera's depth joint settings, if any.
+    pub depth_joints: Option<RatatuiCameraDepthJoints>,
+
+    /// RatatuiCamera's cell diffing settings, if any.
+    pub cell_diffing: Option<RatatuiCameraCellDiffing>,
+
+    /// The part of the camera image drawn by the widget, copied from the RatatuiCamera's
+    /// [ViewportConfig](crate::ViewportConfig) if it has one. Can be changed before rendering in
+    /// order to pan and zoom within the image.
+    pub viewport: ViewportConfig,
+
+    /// The loaded color grading LUT of the strategy's colors config, if any.
+    pub color_lut: Option<ColorLut>,
+
+    /// Inverse of the RatatuiCamera camera's projection matrix, for converting depth values back
+    /// into view-space positions.
+    pub view_from_clip: Mat4,
+
+    /// Assumed dimensions of the terminal's font, used for aspect ratio calculations.
+    pub font_metrics: RatatuiCameraFontMetrics,
+
+    /// Characters the terminal is able to display, unsupported characters are replaced when drawn.
+    pub character_support: RatatuiCameraCharacterSupport,
+
+    /// Widgets of the layer cameras related to this camera (see
+    /// [RatatuiCameraLayer](crate::RatatuiCameraLayer)), each drawn over this camera's image using
+    /// its own strategy.
+    pub layers: Vec<RatatuiCameraWidget>,
+
+    /// Sequence number of the camera image, counting the frames read back since the camera was
+    /// created or last resized. `0` while the image is still blank (see `is_ready()`).
+    pub frame_number: u64,
+
+    /// When the camera image was rendered, or `None` while the image is still blank.
+    pub rendered_at: Option<Instant>,
+
+    /// How long ago the camera image was rendered when this widget was created, or `None` while
+    /// the image is still blank. Useful for showing when the view was last updated, or for
+    /// skipping frames that are too old when readback falls behind.
+    pub staleness: Option<Duration>,
+
     /// The area this widget was rendered within last frame.
     pub last_area: Rect,
 
-    /// The area this widget was most recently rendered within, which will replace `last_area`
+    /// The options this widget was rendered with last frame (see
+    /// [RatatuiCameraWidget::render_with_options]), which the coordinate conversion methods (e.g.
+    /// `cell_to_ndc()`) use to map cells onto the image the same way it was drawn.
+    pub last_render_options: RatatuiCameraRenderOptions,
+
+    /// What was recorded the last time this widget was rendered, kept behind a lock so that
+    /// rendering through a shared reference records it as well.
+    pub(crate) draw_state: SharedDrawState,
+
+    /// Cells converted by earlier draws of this frame's widget (or its clones), so that drawing it
+    /// into several areas does not repeat the conversion.
+    pub(crate) cell_cache: Arc<ConvertedCellCache>,
+
+    /// Records each frame drawn (other than through `detached()`), if a [RatatuiCameraRecorder]
+    /// resource was present when the widget was created.
+    pub(crate) recorder: Option<RatatuiCameraRecorder>,
+
+    /// Whether to keep drawing when the area changes, see
+    /// [RatatuiCameraSmoothResize](crate::RatatuiCameraSmoothResize).
+    pub(crate) smooth_resize: bool,
+
+    /// Overlay components projected into this camera by each
+    /// [RatatuiOverlayPlugin](crate::RatatuiOverlayPlugin), drawn over the camera's image.
+    pub(crate) overlays: Vec<ProjectedOverlay>,
+
+    /// Edges of each [RatatuiCameraWireframe](crate::RatatuiCameraWireframe) projected into this
+    /// camera, drawn over the camera's image.
+    pub(crate) wireframe_edges: Vec<ProjectedEdge>,
+}
+
+/// State recorded each time a [RatatuiCameraWidget] is rendered, read after rendering or by the
+/// next frame's widget.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DrawState {
+    /// Statistics about the image drawn the last time the widget was rendered.
+    pub(crate) statistics: Option<RatatuiCameraImageStatistics>,
+
+    /// Cells that changed since the previous frame the last time the widget was rendered.
+    pub(crate) changes: Option<RatatuiCameraCellChanges>,
+
+    /// The area the widget was most recently rendered within, which will replace `last_area`
     /// before the camera widget is available to render next frame.
     pub(crate) next_last_area: Rect,
+
+    /// The options the widget was most recently rendered with, which will replace
+    /// `last_render_options` before the camera widget is available to render next frame.
+    pub(crate) next_render_options: RatatuiCameraRenderOptions,
+
+    /// The cells drawn the last time a widget for this camera was rendered, carried over to the
+    /// next frame's widget for cell diffing.
+    pub(crate) drawn_cells: Option<Arc<DrawnCells>>,
+
+    /// Time taken to convert the images into cells the last time the widget was rendered, for
+    /// [RatatuiCameraDiagnosticsPlugin](crate::RatatuiCameraDiagnosticsPlugin).
+    pub(crate) conversion_time: Option<Duration>,
+}
+
+/// [DrawState] behind a lock. Clones of a widget copy the state rather than sharing it.
+#[derive(Debug, Default)]
+pub(crate) struct SharedDrawState(Mutex<DrawState>);
+
+impl SharedDrawState {
+    pub(crate) fn new(draw_state: DrawState) -> Self {
+        Self(Mutex::new(draw_state))
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, DrawState> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for SharedDrawState {
+    fn clone(&self) -> Self {
+        Self::new(self.lock().clone())
+    }
+}
+
+/// Statistics about the images drawn by a widget and by each of its layers, in the same order.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DrawnStatistics {
+    pub(crate) camera: Option<RatatuiCameraImageStatistics>,
+    pub(crate) layers: Vec<Option<RatatuiCameraImageStatistics>>,
 }
 
 impl Widget for &mut RatatuiCameraWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_common(area, buf, None);
+        self.render_common(area, buf, None, &RatatuiCameraRenderOptions::default());
     }
 }
 
@@ -55,35 +234,370 @@ impl StatefulWidget for &mut RatatuiCameraWidget {
     type State = RatatuiCameraDepthBuffer;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        self.render_common(area, buf, Some(state));
+        self.render_common(
+            area,
+            buf,
+            Some(state),
+            &RatatuiCameraRenderOptions::default(),
+        );
+    }
+}
+
+impl WidgetRef for RatatuiCameraWidget {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.render_common(area, buf, None, &RatatuiCameraRenderOptions::default());
+    }
+}
+
+impl StatefulWidgetRef for RatatuiCameraWidget {
+    type State = RatatuiCameraDepthBuffer;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_common(
+            area,
+            buf,
+            Some(state),
+            &RatatuiCameraRenderOptions::default(),
+        );
+    }
+}
+
+/// View of a [RatatuiCameraWidget] that draws it without recording anything, returned by
+/// [RatatuiCameraWidget::detached]. Drawing the widget itself (through `&mut` or a shared
+/// reference) records the area it was drawn within, so that the camera is resized to fit it, along
+/// with statistics, cell changes, and recordings. Drawing this view leaves them untouched instead,
+/// letterboxing the image if the area differs. Useful for drawing one camera into several areas,
+/// e.g. a main view plus a minimap.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::RatatuiContext;
+/// # use bevy_ratatui_camera::RatatuiCameraWidget;
+/// # use ratatui::{
+/// #     layout::{Constraint, Layout},
+/// #     widgets::Widget,
+/// # };
+/// #
+/// fn draw_scene_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     mut camera_widget: Single<&mut RatatuiCameraWidget>,
+/// ) -> Result {
+///     ratatui.draw(|frame| {
+///         let [main_area, minimap_area] =
+///             Layout::horizontal([Constraint::Fill(3), Constraint::Fill(1)]).areas(frame.area());
+///
+///         camera_widget.render(main_area, frame.buffer_mut());
+///         camera_widget.detached().render(minimap_area, frame.buffer_mut());
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Copy, Debug)]
+pub struct RatatuiCameraWidgetRef<'a>(&'a RatatuiCameraWidget);
+
+impl WidgetRef for RatatuiCameraWidgetRef<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.0.render_detached(area, buf, None);
+    }
+}
+
+impl StatefulWidgetRef for RatatuiCameraWidgetRef<'_> {
+    type State = RatatuiCameraDepthBuffer;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.0.render_detached(area, buf, Some(state));
+    }
+}
+
+impl Widget for RatatuiCameraWidgetRef<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl StatefulWidget for RatatuiCameraWidgetRef<'_> {
+    type State = RatatuiCameraDepthBuffer;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
     }
 }
 
 impl RatatuiCameraWidget {
-    /// Check for a change in area since last frame, updating the `next_last_area` attribute to
+    /// Create a widget holding the provided image, with default settings, as though it had been
+    /// rendered by a camera. Used for converting images without a camera.
+    pub(crate) fn from_image(camera_image: Arc<DynamicImage>) -> Self {
+        Self {
+            entity: Entity::PLACEHOLDER,
+            camera_image,
+            depth_image: None,
+            sobel_image: None,
+            normal_image: None,
+            emissive_image: None,
+            edge_color_image: None,
+            mask_image: None,
+            #[cfg(feature = "gpu_cells")]
+            gpu_cells_image: None,
+            strategy: RatatuiCameraStrategy::default(),
+            edge_detection: None,
+            emissive_detection: None,
+            depth_joints: None,
+            cell_diffing: None,
+            viewport: ViewportConfig::default(),
+            color_lut: None,
+            view_from_clip: Mat4::IDENTITY,
+            font_metrics: RatatuiCameraFontMetrics::default(),
+            character_support: RatatuiCameraCharacterSupport::default(),
+            layers: Vec::new(),
+            frame_number: 1,
+            rendered_at: None,
+            staleness: None,
+            last_area: Rect::default(),
+            last_render_options: RatatuiCameraRenderOptions::default(),
+            draw_state: SharedDrawState::default(),
+            cell_cache: Arc::default(),
+            recorder: None,
+            smooth_resize: false,
+            overlays: Vec::new(),
+            wireframe_edges: Vec::new(),
+        }
+    }
+
+    /// Draw this widget without recording anything, see [RatatuiCameraWidgetRef].
+    pub fn detached(&self) -> RatatuiCameraWidgetRef<'_> {
+        RatatuiCameraWidgetRef(self)
+    }
+
+    /// Statistics about the image drawn the last time this widget was rendered, or `None` if it
+    /// has not been rendered yet. As the widget is replaced each frame, read these after rendering
+    /// (e.g. later in the same draw system) and carry them over yourself if needed.
+    pub fn statistics(&self) -> Option<RatatuiCameraImageStatistics> {
+        self.draw_state.lock().statistics.clone()
+    }
+
+    /// Cells that changed since the previous frame the last time this widget was rendered, or
+    /// `None` if it has not been rendered yet or does not have
+    /// [RatatuiCameraCellDiffing](crate::RatatuiCameraCellDiffing). Like `statistics()`, read these
+    /// after rendering.
+    pub fn changes(&self) -> Option<RatatuiCameraCellChanges> {
+        self.draw_state.lock().changes.clone()
+    }
+
+    /// Check for a change in area since last frame, updating the recorded `next_last_area` to
     /// trigger a resize if necessary. Returns `true` if the area changed, otherwise `false`.
-    fn area_check(&mut self, area: Rect) -> bool {
+    fn area_check(&self, area: Rect) -> bool {
         if self.last_area != area {
-            self.next_last_area = area;
+            self.draw_state.lock().next_last_area = area;
             return true;
         }
 
         false
     }
 
-    /// Common render method shared by the Widget and StatefulWidget `render()` implementations.
-    fn render_common(
-        &mut self,
+    /// Common render method shared by the Widget, WidgetRef, and stateful `render()`
+    /// implementations, and `render_with_options()`.
+    pub(crate) fn render_common(
+        &self,
         area: Rect,
         buf: &mut Buffer,
         depth_buffer: Option<&mut RatatuiCameraDepthBuffer>,
+        options: &RatatuiCameraRenderOptions,
     ) {
+        self.draw_state.lock().next_render_options = *options;
+
         if self.area_check(area) {
-            return;
+            self.draw_state.lock().drawn_cells = None;
+
+            if !self.smooth_resize {
+                return;
+            }
         }
 
-        let render_area = self.calculate_render_area(area);
-        let (camera_image, depth_image, sobel_image) = self.resize_images_to_area(render_area);
+        let render_area = self.calculate_render_area_with_options(area, options);
+        let source_region = self.calculate_source_region(area, options);
+
+        let _span = info_span!("ratatui_camera_render", camera = %self.entity).entered();
+        let conversion_start = Instant::now();
+        let statistics = match depth_buffer {
+            Some(depth_buffer) => {
+                depth_buffer.within_area(render_area, &self.view_from_clip, |depth_buffer| {
+                    let statistics =
+                        self.render_region(render_area, source_region, buf, Some(depth_buffer));
+                    self.render_wireframe_edges(render_area, buf, Some(depth_buffer));
+                    self.render_projected_overlays(render_area, buf, Some(depth_buffer));
+                    statistics
+                })
+            }
+            None => {
+                let statistics = self.render_region_cached(render_area, source_region, buf);
+                self.render_wireframe_edges(render_area, buf, None);
+                self.render_projected_overlays(render_area, buf, None);
+                statistics
+            }
+        };
+        let conversion_time = conversion_start.elapsed();
+
+        self.character_support.downgrade_buffer(render_area, buf);
+
+        for (layer, layer_statistics) in self.layers.iter().zip(statistics.layers) {
+            layer.draw_state.lock().statistics = layer_statistics;
+        }
+
+        let mut draw_state = self.draw_state.lock();
+        draw_state.statistics = statistics.camera;
+        draw_state.conversion_time = Some(conversion_time);
+
+        if let Some(cell_diffing) = self.cell_diffing {
+            let (drawn_cells, changes) =
+                cell_diffing.diff(render_area, buf, draw_state.drawn_cells.as_deref());
+            draw_state.drawn_cells = Some(drawn_cells);
+            draw_state.changes = Some(changes);
+        }
+
+        if let Some(recorder) = &self.recorder {
+            recorder.record(render_area, buf);
+        }
+    }
+
+    /// Render method shared by the [RatatuiCameraWidgetRef] implementations, which draws the
+    /// widget regardless of whether the area has changed, without recording anything.
+    pub(crate) fn render_detached(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        depth_buffer: Option<&mut RatatuiCameraDepthBuffer>,
+    ) {
+        let options = RatatuiCameraRenderOptions::default();
+        let render_area = self.calculate_render_area_with_options(area, &options);
+        let source_region = self.calculate_source_region(area, &options);
+
+        match depth_buffer {
+            Some(depth_buffer) => {
+                depth_buffer.within_area(render_area, &self.view_from_clip, |depth_buffer| {
+                    self.render_region(render_area, source_region, buf, Some(depth_buffer));
+                    self.render_wireframe_edges(render_area, buf, Some(depth_buffer));
+                    self.render_projected_overlays(render_area, buf, Some(depth_buffer));
+                });
+            }
+            None => {
+                self.render_region_cached(render_area, source_region, buf);
+                self.render_wireframe_edges(render_area, buf, None);
+                self.render_projected_overlays(render_area, buf, None);
+            }
+        }
+
+        self.character_support.downgrade_buffer(render_area, buf);
+    }
+
+    /// Draw the provided region of this widget's images (in normalized image coordinates) within
+    /// the render area, cropping them first if the region does not cover the entire image.
+    pub(crate) fn render_region(
+        &self,
+        render_area: Rect,
+        source_region: bevy::math::Rect,
+        buf: &mut Buffer,
+        depth_buffer: Option<&mut RatatuiCameraDepthBuffer>,
+    ) -> DrawnStatistics {
+        if source_region == FULL_IMAGE_REGION {
+            return self.render_with_layers(render_area, buf, depth_buffer);
+        }
+
+        let mut cropped = self.clone();
+        cropped.crop_images(source_region);
+        cropped.render_with_layers(render_area, buf, depth_buffer)
+    }
+
+    /// Draw this widget's images and then those of its layers within the render area.
+    pub(crate) fn render_with_layers(
+        &self,
+        render_area: Rect,
+        buf: &mut Buffer,
+        mut depth_buffer: Option<&mut RatatuiCameraDepthBuffer>,
+    ) -> DrawnStatistics {
+        let statistics = self.render_strategy(render_area, buf, depth_buffer.as_deref_mut());
+        self.render_edge_colors(render_area, buf);
+        self.render_depth_joints(render_area, buf);
+        self.render_emissive(render_area, buf);
+        self.render_modifiers(render_area, buf);
+
+        let layer_statistics = self
+            .layers
+            .iter()
+            .map(|layer| {
+                if let Some(depth_buffer) = depth_buffer.as_deref_mut() {
+                    depth_buffer.set_view_from_clip(&layer.view_from_clip);
+                }
+                let statistics =
+                    layer.render_strategy(render_area, buf, depth_buffer.as_deref_mut());
+                layer.render_edge_colors(render_area, buf);
+                layer.render_depth_joints(render_area, buf);
+                layer.render_emissive(render_area, buf);
+                layer.render_modifiers(render_area, buf);
+                statistics
+            })
+            .collect();
+
+        if let Some(depth_buffer) = depth_buffer {
+            depth_buffer.set_view_from_clip(&self.view_from_clip);
+        }
+
+        DrawnStatistics {
+            camera: statistics,
+            layers: layer_statistics,
+        }
+    }
+
+    /// Convert this widget's images using its strategy, and draw them within the render area.
+    /// Returns statistics about the converted image, or `None` if its cells were chosen on the
+    /// GPU.
+    fn render_strategy(
+        &self,
+        render_area: Rect,
+        buf: &mut Buffer,
+        depth_buffer: Option<&mut RatatuiCameraDepthBuffer>,
+    ) -> Option<RatatuiCameraImageStatistics> {
+        let (width, height) = (render_area.width as u32, render_area.height as u32 * 2);
+
+        #[cfg(feature = "gpu_cells")]
+        let depth_buffer = self.render_gpu_cells(render_area, buf, depth_buffer)?;
+
+        // strategies that sample the full region covered by each cell need a full glyph mask's
+        // worth of pixels per cell, rather than two.
+        let mut camera_image = match self.strategy {
+            RatatuiCameraStrategy::HalfBlocks(HalfBlocksConfig {
+                mode: HalfBlocksMode::TwoColor,
+                ..
+            })
+            | RatatuiCameraStrategy::Glyphs(_) => resize_image(
+                &self.camera_image,
+                render_area.width as u32 * GLYPH_MASK_WIDTH,
+                render_area.height as u32 * GLYPH_MASK_HEIGHT,
+                FilterType::Triangle,
+            ),
+            _ => resize_image(&self.camera_image, width, height, FilterType::Nearest),
+        };
+
+        let [depth_image, sobel_image] = [&self.depth_image, &self.sobel_image].map(|image| {
+            image
+                .as_ref()
+                .map(|image| resize_image(image, width, height, FilterType::Nearest))
+        });
+
+        if let Some(colors_config) = self.strategy.colors() {
+            adjust_image_colors(
+                &mut camera_image,
+                depth_image.as_ref(),
+                &self.view_from_clip,
+                colors_config,
+                self.color_lut.as_ref(),
+            );
+        }
+
+        let statistics = RatatuiCameraImageStatistics::from_image(&camera_image);
 
         match self.strategy {
             RatatuiCameraStrategy::HalfBlocks(ref strategy_config) => {
@@ -105,6 +619,7 @@ impl RatatuiCameraWidget {
                     depth_buffer,
                     strategy_config,
                     &self.edge_detection,
+                    self.view_from_clip,
                 )
                 .render(render_area, buf);
             }
@@ -119,11 +634,248 @@ impl RatatuiCameraWidget {
                 )
                 .render(render_area, buf);
             }
+            RatatuiCameraStrategy::LuminanceDepth(ref strategy_config) => {
+                RatatuiCameraWidgetLuminanceDepth::new(
+                    camera_image,
+                    depth_image,
+                    sobel_image,
+                    depth_buffer,
+                    strategy_config,
+                    &self.edge_detection,
+                )
+                .render(render_area, buf);
+            }
+            RatatuiCameraStrategy::Glyphs(ref strategy_config) => {
+                RatatuiCameraWidgetGlyphs::new(
+                    camera_image,
+                    depth_image,
+                    sobel_image,
+                    depth_buffer,
+                    strategy_config,
+                    &self.edge_detection,
+                )
+                .render(render_area, buf);
+            }
+            RatatuiCameraStrategy::Threshold(ref strategy_config) => {
+                RatatuiCameraWidgetThreshold::new(
+                    camera_image,
+                    depth_image,
+                    sobel_image,
+                    depth_buffer,
+                    strategy_config,
+                    &self.edge_detection,
+                )
+                .render(render_area, buf);
+            }
+            RatatuiCameraStrategy::Normals(ref strategy_config) => {
+                let normal_image = self.normal_image.as_ref().map(|normal_image| {
+                    resize_image(
+                        normal_image,
+                        render_area.width as u32,
+                        render_area.height as u32 * 2,
+                        FilterType::Nearest,
+                    )
+                });
+
+                RatatuiCameraWidgetNormals::new(
+                    camera_image,
+                    normal_image,
+                    depth_image,
+                    sobel_image,
+                    depth_buffer,
+                    strategy_config,
+                    &self.edge_detection,
+                )
+                .render(render_area, buf);
+            }
             RatatuiCameraStrategy::None => {
                 RatatuiCameraWidgetNone::new(camera_image, sobel_image, &self.edge_detection)
                     .render_ref(render_area, buf);
             }
         }
+
+        Some(statistics)
+    }
+
+    /// Draw the characters and colors chosen on the GPU, if the camera has
+    /// [RatatuiCameraGpuCells](crate::RatatuiCameraGpuCells) and they match what the CPU would
+    /// choose for this frame. Returns the depth buffer back if the strategy still needs to be
+    /// converted on the CPU instead.
+    #[cfg(feature = "gpu_cells")]
+    fn render_gpu_cells<'a>(
+        &self,
+        render_area: Rect,
+        buf: &mut Buffer,
+        depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+    ) -> Option<Option<&'a mut RatatuiCameraDepthBuffer>> {
+        let RatatuiCameraStrategy::Luminance(ref strategy_config) = self.strategy else {
+            return Some(depth_buffer);
+        };
+
+        let Some(cells_image) = self.gpu_cells_image.as_ref().filter(|cells_image| {
+            cells_image.width() == render_area.width as u32
+                && cells_image.height() == render_area.height as u32
+        }) else {
+            return Some(depth_buffer);
+        };
+
+        if adjusts_image_colors(
+            self.depth_image.as_deref(),
+            &strategy_config.colors,
+            self.color_lut.as_ref(),
+        ) {
+            return Some(depth_buffer);
+        }
+
+        let (width, height) = (render_area.width as u32, render_area.height as u32 * 2);
+        let [depth_image, sobel_image] = [&self.depth_image, &self.sobel_image].map(|image| {
+            image
+                .as_ref()
+                .map(|image| resize_image(image, width, height, FilterType::Nearest))
+        });
+
+        RatatuiCameraWidgetLuminance::from_gpu_cells(
+            cells_image,
+            depth_image,
+            sobel_image,
+            depth_buffer,
+            strategy_config,
+            &self.edge_detection,
+        )
+        .render(render_area, buf);
+
+        None
+    }
+
+    /// Recolor the detected edges around meshes with an edge color, if edge color detection is
+    /// enabled.
+    fn render_edge_colors(&self, render_area: Rect, buf: &mut Buffer) {
+        let (Some(edge_color_image), Some(sobel_image), Some(edge_detection)) = (
+            &self.edge_color_image,
+            &self.sobel_image,
+            &self.edge_detection,
+        ) else {
+            return;
+        };
+
+        let [edge_color_image, sobel_image] = [edge_color_image, sobel_image].map(|image| {
+            resize_image(
+                image,
+                render_area.width as u32,
+                render_area.height as u32 * 2,
+                FilterType::Nearest,
+            )
+        });
+
+        let (color_support, color_distance) = self
+            .strategy
+            .colors()
+            .map(|colors| (colors.support, colors.distance))
+            .unwrap_or_default();
+
+        apply_edge_colors(
+            &edge_color_image,
+            &sobel_image,
+            edge_detection,
+            color_support,
+            color_distance,
+            render_area,
+            buf,
+        );
+    }
+
+    /// Draw joints along large jumps in depth between cells, if depth joints are enabled.
+    fn render_depth_joints(&self, render_area: Rect, buf: &mut Buffer) {
+        let (Some(depth_image), Some(depth_joints)) = (&self.depth_image, &self.depth_joints)
+        else {
+            return;
+        };
+
+        let depth_image = resize_image(
+            depth_image,
+            render_area.width as u32,
+            render_area.height as u32 * 2,
+            FilterType::Nearest,
+        );
+
+        let (color_support, color_distance) = self
+            .strategy
+            .colors()
+            .map(|colors| (colors.support, colors.distance))
+            .unwrap_or_default();
+
+        apply_depth_joints(
+            &depth_image,
+            &self.view_from_clip,
+            depth_joints,
+            color_support,
+            color_distance,
+            render_area,
+            buf,
+        );
+    }
+
+    /// Emphasize the cells covering emissive pixels, if emissive detection is enabled.
+    fn render_emissive(&self, render_area: Rect, buf: &mut Buffer) {
+        let (Some(emissive_image), Some(emissive_detection)) =
+            (&self.emissive_image, &self.emissive_detection)
+        else {
+            return;
+        };
+
+        let emissive_image = resize_image(
+            emissive_image,
+            render_area.width as u32,
+            render_area.height as u32 * 2,
+            FilterType::Nearest,
+        );
+
+        apply_emissive_detection(
+            &emissive_image,
+            emissive_detection,
+            self.strategy
+                .characters()
+                .map(|characters| &characters.list[..]),
+            render_area,
+            buf,
+        );
+    }
+
+    /// Add style modifiers to cells according to the luminance or depth ranges of the strategy's
+    /// modifier mappings, if any.
+    fn render_modifiers(&self, render_area: Rect, buf: &mut Buffer) {
+        let Some(mappings) = self
+            .strategy
+            .common()
+            .map(|common| &common.modifiers)
+            .filter(|mappings| !mappings.is_empty())
+        else {
+            return;
+        };
+
+        let (width, height) = (render_area.width as u32, render_area.height as u32 * 2);
+        let camera_image = resize_image(&self.camera_image, width, height, FilterType::Nearest);
+        let depth_image = self
+            .depth_image
+            .as_ref()
+            .map(|image| resize_image(image, width, height, FilterType::Nearest));
+
+        apply_modifier_mappings(
+            &camera_image,
+            depth_image.as_ref(),
+            &self.view_from_clip,
+            mappings,
+            render_area,
+            buf,
+        );
+    }
+
+    /// Whether this widget holds an image actually rendered by the camera. Until the first frame
+    /// is read back from the GPU (and again briefly after the camera is resized), the widget
+    /// holds a blank image, so draw systems can show a loading placeholder instead while this is
+    /// `false`. See also [RatatuiCameraReady](crate::RatatuiCameraReady).
+    pub fn is_ready(&self) -> bool {
+        self.frame_number > 0
     }
 
     /// Create a depth buffer that can be used for occlusion effects. Pass the resulting buffer
@@ -132,6 +884,9 @@ impl RatatuiCameraWidget {
     /// render methods and into `render_overlay_with_depth()` in order to record their depths as
     /// well, skipping terminal cells when they would be occluded.
     ///
+    /// To share a depth buffer between multiple camera widgets drawn into the same area, whose
+    /// render areas may differ, create it with `RatatuiCameraDepthBuffer::new(area)` instead.
+    ///
     /// Note that objects will only occlude if they show up in Bevy's render prepass, so please
     /// consult Bevy's documentation on what is excluded.
     pub fn new_depth_buffer(&self, area: Rect) -> RatatuiCameraDepthBuffer {
@@ -194,6 +949,98 @@ impl RatatuiCameraWidget {
 
         let render_area = self.calculate_render_area(area);
 
-        widget.render_ref(render_area, buf, depth_buffer);
+        depth_buffer.within_area(render_area, &self.view_from_clip, |depth_buffer| {
+            widget.render_ref(render_area, buf, depth_buffer);
+        });
+    }
+
+    /// See [RatatuiCameraWidget::render_overlay_with_depth]. This variant draws many overlay
+    /// widgets in one pass, sorted by the provided [RatatuiOverlayOrder] (lower layers first, then
+    /// further from the camera first), so that overlays without their own occlusion logic are
+    /// still layered correctly.
+    pub fn render_overlays_with_depth<'a, W>(
+        &mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        overlays: impl IntoIterator<Item = (RatatuiOverlayOrder, &'a W)>,
+        depth_buffer: &mut RatatuiCameraDepthBuffer,
+    ) where
+        W: StatefulWidgetRef<State = RatatuiCameraDepthBuffer> + ?Sized + 'a,
+    {
+        if self.last_area != area {
+            return;
+        }
+
+        let render_area = self.calculate_render_area(area);
+
+        let mut overlays = overlays.into_iter().collect::<Vec<_>>();
+        overlays.sort_by_key(|(order, _)| *order);
+
+        depth_buffer.within_area(render_area, &self.view_from_clip, |depth_buffer| {
+            for (_, widget) in overlays {
+                widget.render_ref(render_area, buf, depth_buffer);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use image::{DynamicImage, Rgba, RgbaImage};
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Color,
+        widgets::{Widget, WidgetRef},
+    };
+
+    use super::RatatuiCameraWidget;
+
+    fn white_widget() -> RatatuiCameraWidget {
+        let image = RgbaImage::from_pixel(8, 8, Rgba([255, 255, 255, 255]));
+        RatatuiCameraWidget::from_image(Arc::new(DynamicImage::ImageRgba8(image)))
+    }
+
+    #[test]
+    fn rendering_through_mut_records_the_area() {
+        let mut widget = white_widget();
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+
+        (&mut widget).render(area, &mut buf);
+
+        assert_eq!(widget.draw_state.lock().next_last_area, area);
+    }
+
+    #[test]
+    fn rendering_through_a_shared_reference_records_the_area() {
+        let widget = white_widget();
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+
+        let boxed: Box<dyn WidgetRef> = Box::new(widget.clone());
+        boxed.render_ref(area, &mut buf);
+        widget.render_ref(area, &mut buf);
+
+        assert_eq!(widget.draw_state.lock().next_last_area, area);
+    }
+
+    #[test]
+    fn rendering_detached_leaves_the_widget_untouched() {
+        let widget = white_widget();
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(area);
+
+        widget.detached().render(area, &mut buf);
+
+        assert_eq!(widget.draw_state.lock().next_last_area, Rect::default());
+        assert_eq!(widget.statistics(), None);
+        assert!(
+            buf.content
+                .iter()
+                .any(|cell| cell.bg == Color::Rgb(255, 255, 255))
+        );
     }
 }
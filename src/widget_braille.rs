@@ -0,0 +1,351 @@
+use bevy::prelude::*;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+
+use crate::{RatatuiCameraDepthBuffer, RatatuiCameraWidget, widget_utilities::blend_colors};
+
+/// Number of braille dots across and down each terminal cell.
+const DOTS_PER_CELL: UVec2 = UVec2::new(2, 4);
+
+/// Dots with at least this much coverage are drawn.
+const DOT_COVERAGE_THRESHOLD: f32 = 0.5;
+
+/// How far (as a fraction of the recorded depth) dots can be behind the recorded depth before
+/// they are occluded, so that lines along the surfaces they outline are not hidden by them.
+const DEPTH_TOLERANCE: f32 = 0.02;
+
+/// A canvas for drawing lines, polylines, and circles into a ratatui buffer using braille
+/// characters, which divide each terminal cell into two columns of four dots. Shapes are
+/// rasterized with anti-aliasing: dots covered by at least half of a shape are drawn, and each
+/// cell's color is blended with the background already in the buffer according to how well its
+/// dots are covered.
+///
+/// Coordinates are in dots from the top left of the canvas' area, with the center of each dot at
+/// whole coordinates (so a canvas `w` cells wide and `h` cells high has dots from `(0, 0)` to
+/// `(2w - 1, 4h - 1)`). To draw shapes in the bevy world over a
+/// camera widget, create the canvas with
+/// [RatatuiCameraWidget::new_braille_canvas] and use the `world_` methods, which project
+/// world-space points through the camera.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::RatatuiContext;
+/// # use bevy_ratatui_camera::RatatuiCameraWidget;
+/// # use ratatui::{style::Color, widgets::Widget};
+/// #
+/// fn draw_scene_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     mut camera: Single<(&Camera, &GlobalTransform, &mut RatatuiCameraWidget)>,
+/// ) -> Result {
+///     let (camera, camera_transform, ref mut widget) = *camera;
+///
+///     ratatui.draw(|frame| {
+///         let area = frame.area();
+///         let widget: &mut RatatuiCameraWidget = widget;
+///         widget.render(area, frame.buffer_mut());
+///
+///         let mut canvas = widget.new_braille_canvas(area);
+///         let path = [Vec3::ZERO, Vec3::X, Vec3::new(1., 1., 0.)];
+///         canvas.world_polyline(widget, camera, camera_transform, &path, Color::Yellow);
+///         canvas.render(frame.buffer_mut());
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct RatatuiBrailleCanvas {
+    area: Rect,
+    cells: Vec<BrailleCell>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct BrailleCell {
+    dots: u8,
+    coverage: f32,
+    color: Option<Color>,
+}
+
+impl RatatuiBrailleCanvas {
+    /// Create an empty canvas covering the provided area of the buffer.
+    pub fn new(area: Rect) -> Self {
+        Self {
+            area,
+            cells: vec![BrailleCell::default(); area.area() as usize],
+        }
+    }
+
+    /// The area of the buffer the canvas covers.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Dimensions of the canvas in dots.
+    pub fn dot_dimensions(&self) -> UVec2 {
+        UVec2::new(self.area.width as u32, self.area.height as u32) * DOTS_PER_CELL
+    }
+
+    /// Remove everything drawn on the canvas.
+    pub fn clear(&mut self) {
+        self.cells.fill(BrailleCell::default());
+    }
+
+    /// Draw a line one dot thick between two points (in dots).
+    pub fn line(&mut self, from: Vec2, to: Vec2, color: Color) {
+        self.rasterize_line(from.extend(0.), to.extend(0.), color, None);
+    }
+
+    /// See [RatatuiBrailleCanvas::line]. The z coordinate of each end is its depth (following
+    /// bevy's convention, see [RatatuiCameraDepthBuffer]), interpolated along the line, and dots
+    /// further from the camera than the depth recorded beneath them are skipped, hiding the line
+    /// behind the scene. The depth buffer should cover the same area as the canvas, and is left
+    /// unchanged.
+    pub fn line_with_depth(
+        &mut self,
+        from: Vec3,
+        to: Vec3,
+        color: Color,
+        depth_buffer: &RatatuiCameraDepthBuffer,
+    ) {
+        self.rasterize_line(from, to, color, Some(depth_buffer));
+    }
+
+    /// Draw connected lines through each of the points (in dots) in order.
+    pub fn polyline(&mut self, points: &[Vec2], color: Color) {
+        for segment in points.windows(2) {
+            self.line(segment[0], segment[1], color);
+        }
+    }
+
+    /// Draw the outline of a circle one dot thick, with the provided center and radius (in dots).
+    /// As terminal cells are usually twice as tall as they are wide, braille dots are roughly
+    /// square, so circles appear round.
+    pub fn circle(&mut self, center: Vec2, radius: f32, color: Color) {
+        let min = (center - radius - 1.).floor().as_ivec2();
+        let max = (center + radius + 1.).ceil().as_ivec2();
+
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                let distance = (Vec2::new(x as f32, y as f32).distance(center) - radius).abs();
+                self.plot(x, y, 1. - distance, color);
+            }
+        }
+    }
+
+    /// Draw the canvas into the buffer. Cells with nothing drawn are left untouched, and cells
+    /// that already hold braille characters (e.g. from another canvas) keep their dots.
+    pub fn render(&self, buf: &mut Buffer) {
+        let area = self.area.intersection(buf.area);
+
+        for position in area.positions() {
+            let index = self.cell_index(position.x, position.y);
+            let Some(braille) = self.cells.get(index).filter(|cell| cell.dots != 0) else {
+                continue;
+            };
+
+            let cell = &mut buf[position];
+            let existing_dots = cell
+                .symbol()
+                .chars()
+                .next()
+                .and_then(|character| (character as u32).checked_sub(0x2800))
+                .filter(|dots| *dots <= 0xFF)
+                .unwrap_or(0) as u8;
+
+            let character =
+                char::from_u32(0x2800 + (existing_dots | braille.dots) as u32).unwrap_or_default();
+            let color = blend_colors(Some(cell.bg), braille.color, braille.coverage);
+
+            cell.set_char(character);
+            if let Some(color) = color {
+                cell.set_fg(color);
+            }
+        }
+    }
+
+    /// Draw a line between two points in the bevy world, projected through the widget's camera.
+    /// Lines with an end behind the camera are skipped.
+    pub fn world_line(
+        &mut self,
+        widget: &RatatuiCameraWidget,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        from: Vec3,
+        to: Vec3,
+        color: Color,
+    ) {
+        self.world_polyline(widget, camera, camera_transform, &[from, to], color);
+    }
+
+    /// Draw connected lines through each of the points in the bevy world in order, projected
+    /// through the widget's camera. Segments with an end behind the camera are skipped.
+    pub fn world_polyline(
+        &mut self,
+        widget: &RatatuiCameraWidget,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        points: &[Vec3],
+        color: Color,
+    ) {
+        let dots = points
+            .iter()
+            .map(|point| self.world_to_dot(widget, camera, camera_transform, *point))
+            .collect::<Vec<_>>();
+
+        for segment in dots.windows(2) {
+            if let [Some(from), Some(to)] = segment {
+                self.line(*from, *to, color);
+            }
+        }
+    }
+
+    /// Draw a circle around a point in the bevy world, projected through the widget's camera,
+    /// with a radius in dots (rather than world units, so it stays the same size on screen).
+    pub fn world_circle(
+        &mut self,
+        widget: &RatatuiCameraWidget,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        center: Vec3,
+        radius: f32,
+        color: Color,
+    ) {
+        if let Some(center) = self.world_to_dot(widget, camera, camera_transform, center) {
+            self.circle(center, radius, color);
+        }
+    }
+
+    /// Convert a point in the bevy world into canvas dot coordinates, assuming the canvas covers
+    /// the widget's render area. Returns `None` for points behind the camera.
+    pub fn world_to_dot(
+        &self,
+        widget: &RatatuiCameraWidget,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        point: Vec3,
+    ) -> Option<Vec2> {
+        let ndc = camera
+            .world_to_ndc(camera_transform, point)
+            .filter(|ndc| (0.0..=1.0).contains(&ndc.z))?;
+        let render_area = widget.calculate_render_area(self.area);
+        let dimensions = UVec2::new(render_area.width as u32, render_area.height as u32);
+        let offset = UVec2::new(
+            (render_area.x - self.area.x) as u32,
+            (render_area.y - self.area.y) as u32,
+        );
+
+        let relative = Vec2::new(ndc.x / 2. + 0.5, -ndc.y / 2. + 0.5);
+        let position =
+            (relative * dimensions.as_vec2() + offset.as_vec2()) * DOTS_PER_CELL.as_vec2();
+
+        // shift from the edges of dots to their centers.
+        Some(position - 0.5)
+    }
+
+    fn rasterize_line(
+        &mut self,
+        from: Vec3,
+        to: Vec3,
+        color: Color,
+        depth_buffer: Option<&RatatuiCameraDepthBuffer>,
+    ) {
+        let steep = (to.y - from.y).abs() > (to.x - from.x).abs();
+        let (mut from, mut to) = if steep {
+            (from.yxz(), to.yxz())
+        } else {
+            (from, to)
+        };
+
+        if from.x > to.x {
+            std::mem::swap(&mut from, &mut to);
+        }
+
+        let delta = to - from;
+        let (gradient, depth_gradient) = if delta.x == 0. {
+            (0., 0.)
+        } else {
+            (delta.y / delta.x, delta.z / delta.x)
+        };
+
+        // Xiaolin Wu's algorithm, drawing the nearer of the two dots the line passes between, with
+        // the share of the line covering it. Depth (1/z) interpolates linearly across the screen.
+        for major in from.x.round() as i32..=to.x.round() as i32 {
+            let minor = from.y + gradient * (major as f32 - from.x);
+            let depth = from.z + depth_gradient * (major as f32 - from.x);
+            let coverage = 1. - (minor - minor.round()).abs();
+
+            let (x, y) = if steep {
+                (minor.round() as i32, major)
+            } else {
+                (major, minor.round() as i32)
+            };
+
+            if depth_buffer.is_some_and(|depth_buffer| is_occluded(depth_buffer, x, y, depth)) {
+                continue;
+            }
+
+            self.plot(x, y, coverage, color);
+        }
+    }
+
+    /// Cover the dot at the provided coordinates by the provided amount, drawing it if the
+    /// coverage reaches the threshold.
+    fn plot(&mut self, x: i32, y: i32, coverage: f32, color: Color) {
+        if coverage < DOT_COVERAGE_THRESHOLD || x < 0 || y < 0 {
+            return;
+        }
+
+        let (x, y) = (x as u32, y as u32);
+        let dimensions = self.dot_dimensions();
+        if x >= dimensions.x || y >= dimensions.y {
+            return;
+        }
+
+        let index = self.cell_index(
+            self.area.x + (x / DOTS_PER_CELL.x) as u16,
+            self.area.y + (y / DOTS_PER_CELL.y) as u16,
+        );
+        let cell = &mut self.cells[index];
+
+        cell.dots |= braille_dot_bit(x % DOTS_PER_CELL.x, y % DOTS_PER_CELL.y);
+        cell.coverage = cell.coverage.max(coverage.min(1.));
+        cell.color = Some(color);
+    }
+
+    fn cell_index(&self, x: u16, y: u16) -> usize {
+        (y - self.area.y) as usize * self.area.width as usize + (x - self.area.x) as usize
+    }
+}
+
+/// Whether the dot at the provided coordinates is further from the camera than the depth recorded
+/// beneath it (two depths per cell, one for each half).
+fn is_occluded(depth_buffer: &RatatuiCameraDepthBuffer, x: i32, y: i32, depth: f32) -> bool {
+    if x < 0 || y < 0 {
+        return false;
+    }
+
+    depth_buffer
+        .get(x as usize / 2, y as usize / 2)
+        .is_some_and(|recorded| depth < recorded * (1. - DEPTH_TOLERANCE))
+}
+
+/// Bit of a braille character's codepoint (offset from U+2800) that raises the dot at the
+/// provided column (0 to 1) and row (0 to 3).
+fn braille_dot_bit(column: u32, row: u32) -> u8 {
+    match (column, row) {
+        (0, 3) => 1 << 6,
+        (1, 3) => 1 << 7,
+        (column, row) => 1 << (column * 3 + row),
+    }
+}
+
+impl RatatuiCameraWidget {
+    /// Create a braille canvas covering the area the camera's image is drawn within (see
+    /// [RatatuiCameraWidget::calculate_render_area]), for drawing lines and circles over the
+    /// widget with [RatatuiBrailleCanvas].
+    pub fn new_braille_canvas(&self, area: Rect) -> RatatuiBrailleCanvas {
+        RatatuiBrailleCanvas::new(self.calculate_render_area(area))
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt::{self, Write};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use bevy::math::Rect as ImageRect;
+use ratatui::buffer::Cell;
+use ratatui::prelude::*;
+
+use crate::widget::DrawnStatistics;
+use crate::{ColorChoice, ColorLut, RatatuiCameraWidget};
+
+/// Cells converted from a widget's images, keyed by the size of the area they were drawn within,
+/// the region of the images they cover, and the widget's settings. As the widget is replaced each
+/// frame (and clones of it share the cache), drawing the same widget into several areas of the
+/// same size in one frame converts the images once rather than once per area. The first draw of
+/// each key is drawn directly, so widgets drawn once per frame do not pay for caching.
+#[derive(Debug, Default)]
+pub(crate) struct ConvertedCellCache {
+    entries: Mutex<Vec<(CellCacheKey, CachedCells)>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct CellCacheKey {
+    size: Size,
+    source_region: ImageRect,
+    settings: u64,
+}
+
+#[derive(Clone, Debug)]
+enum CachedCells {
+    /// Drawn once, directly into the buffer it was drawn in.
+    Drawn,
+
+    /// Drawn more than once, so converted into patches that later draws reuse.
+    Converted(Arc<ConvertedCells>),
+}
+
+#[derive(Debug)]
+struct ConvertedCells {
+    patches: Vec<CellPatch>,
+    statistics: DrawnStatistics,
+}
+
+/// The parts of a cell that the conversion wrote, applied over whatever the buffer already holds,
+/// so that parts the conversion left alone (e.g. the background of a cell only given a foreground
+/// color, or every part of a transparent cell) keep showing what was underneath.
+#[derive(Debug)]
+struct CellPatch {
+    symbol: Option<String>,
+    style: Style,
+}
+
+impl ConvertedCellCache {
+    fn get(&self, key: CellCacheKey) -> Option<CachedCells> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, cached)| cached.clone())
+    }
+
+    fn insert(&self, key: CellCacheKey, cached: CachedCells) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|(entry_key, _)| *entry_key != key);
+        entries.push((key, cached));
+    }
+}
+
+impl ConvertedCells {
+    fn draw(&self, area: Rect, buf: &mut Buffer) {
+        for (patch, position) in self.patches.iter().zip(area.positions()) {
+            let Some(cell) = buf.cell_mut(position) else {
+                continue;
+            };
+
+            if let Some(symbol) = &patch.symbol {
+                cell.set_symbol(symbol);
+            }
+            cell.set_style(patch.style);
+        }
+    }
+}
+
+/// Fills of the two scratch buffers that cells are converted into, which differ in every part of
+/// the cell. A part was written by the conversion if it differs from the fill in either buffer,
+/// even if the conversion happened to write the value one of the fills already held. The symbols
+/// are unicode noncharacters, which are never drawn by a strategy.
+fn scratch_fills() -> [Cell; 2] {
+    [
+        ("\u{FFFF}", Color::Reset, Modifier::empty()),
+        ("\u{FFFE}", Color::Black, Modifier::all()),
+    ]
+    .map(|(symbol, color, modifier)| {
+        let mut cell = Cell::new(symbol);
+        cell.set_fg(color).set_bg(color);
+        cell.modifier = modifier;
+        cell
+    })
+}
+
+impl CellPatch {
+    fn new([first, second]: [&Cell; 2], [first_fill, second_fill]: &[Cell; 2]) -> Self {
+        let symbol = (first.symbol() != first_fill.symbol()
+            || second.symbol() != second_fill.symbol())
+        .then(|| second.symbol().to_string());
+        let fg = (first.fg != first_fill.fg || second.fg != second_fill.fg).then_some(second.fg);
+        let bg = (first.bg != first_fill.bg || second.bg != second_fill.bg).then_some(second.bg);
+
+        Self {
+            symbol,
+            style: Style {
+                fg,
+                bg,
+                ..Style::new()
+                    .add_modifier(first.modifier - first_fill.modifier)
+                    .remove_modifier(second_fill.modifier - second.modifier)
+            },
+        }
+    }
+}
+
+/// Writes formatted text into a hasher, for hashing types that implement `Debug` but not `Hash`.
+struct HashWriter<'a>(&'a mut DefaultHasher);
+
+impl Write for HashWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl RatatuiCameraWidget {
+    /// Discard the cells converted by earlier draws of this widget, e.g. to free their memory if
+    /// the widget is kept across frames (as for a
+    /// [RatatuiCameraPaused](crate::RatatuiCameraPaused) camera). Changing the widget's settings or
+    /// images does not require this, as cells are only reused by draws with the same settings.
+    pub fn clear_cell_cache(&mut self) {
+        self.cell_cache = Arc::default();
+    }
+
+    /// Draw the provided region of this widget's images within the render area, reusing the cells
+    /// converted by an earlier draw with the same size, region, and settings if there is one.
+    pub(crate) fn render_region_cached(
+        &self,
+        render_area: Rect,
+        source_region: ImageRect,
+        buf: &mut Buffer,
+    ) -> DrawnStatistics {
+        let key = CellCacheKey {
+            size: render_area.as_size(),
+            source_region,
+            settings: self.settings_hash(),
+        };
+
+        let converted = match self.cell_cache.get(key) {
+            None => {
+                let statistics = self.render_region(render_area, source_region, buf, None);
+                self.cell_cache.insert(key, CachedCells::Drawn);
+                return statistics;
+            }
+            Some(CachedCells::Drawn) => {
+                let converted = Arc::new(self.convert_cells(key));
+                self.cell_cache
+                    .insert(key, CachedCells::Converted(converted.clone()));
+
+                converted
+            }
+            Some(CachedCells::Converted(converted)) => converted,
+        };
+
+        converted.draw(render_area, buf);
+        converted.statistics.clone()
+    }
+
+    /// Convert the region of this widget's images into patches for an area of the key's size.
+    fn convert_cells(&self, key: CellCacheKey) -> ConvertedCells {
+        let area = Rect::from((Position::ORIGIN, key.size));
+        let fills = scratch_fills();
+        let mut statistics = DrawnStatistics::default();
+        let [first, second] = fills.clone().map(|fill| {
+            let mut cells = Buffer::filled(area, fill);
+            statistics = self.render_region(area, key.source_region, &mut cells, None);
+            cells
+        });
+
+        ConvertedCells {
+            patches: first
+                .content
+                .iter()
+                .zip(&second.content)
+                .map(|(first, second)| CellPatch::new([first, second], &fills))
+                .collect(),
+            statistics,
+        }
+    }
+
+    /// Hash of everything besides the area and image region that converted cells depend on.
+    fn settings_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash_settings(&mut hasher);
+        hasher.finish()
+    }
+
+    fn hash_settings(&self, hasher: &mut DefaultHasher) {
+        // the settings do not implement `Hash`, but their debug output covers every field.
+        let _ = write!(
+            HashWriter(hasher),
+            "{:?}",
+            (
+                &self.strategy,
+                &self.edge_detection,
+                &self.emissive_detection,
+                &self.depth_joints,
+                &self.viewport,
+                &self.view_from_clip,
+                &self.font_metrics,
+                &self.character_support,
+            )
+        );
+
+        // images are replaced rather than modified, so they are identified by their allocation.
+        [
+            Some(&self.camera_image),
+            self.depth_image.as_ref(),
+            self.sobel_image.as_ref(),
+            self.normal_image.as_ref(),
+            self.emissive_image.as_ref(),
+            self.edge_color_image.as_ref(),
+            self.mask_image.as_ref(),
+            #[cfg(feature = "gpu_cells")]
+            self.gpu_cells_image.as_ref(),
+        ]
+        .map(|image| image.map(Arc::as_ptr))
+        .hash(hasher);
+
+        // color callbacks all have the same debug output, so they are identified by their
+        // allocation as well.
+        if let Some(colors) = self.strategy.colors() {
+            [&colors.foreground, &colors.background]
+                .map(|color_choice| match color_choice {
+                    Some(ColorChoice::Callback(callback)) => {
+                        Some(Arc::as_ptr(callback).cast::<()>())
+                    }
+                    _ => None,
+                })
+                .hash(hasher);
+        }
+        self.color_lut.as_ref().map(ColorLut::table_id).hash(hasher);
+
+        for layer in &self.layers {
+            layer.hash_settings(hasher);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use image::{DynamicImage, Rgba, RgbaImage};
+    use ratatui::buffer::{Buffer, Cell};
+    use ratatui::layout::Rect;
+    use ratatui::style::Color;
+
+    use crate::widget_render_options::FULL_IMAGE_REGION;
+    use crate::{ColorChoice, RatatuiCameraStrategy, RatatuiCameraWidget};
+
+    fn white_widget(strategy: RatatuiCameraStrategy) -> RatatuiCameraWidget {
+        let image = RgbaImage::from_pixel(8, 8, Rgba([255, 255, 255, 255]));
+        let mut widget = RatatuiCameraWidget::from_image(Arc::new(DynamicImage::ImageRgba8(image)));
+        widget.strategy = strategy;
+        widget
+    }
+
+    fn draw(widget: &mut RatatuiCameraWidget, area: Rect) -> Buffer {
+        let mut underneath = Cell::new("x");
+        underneath.set_bg(Color::Blue);
+
+        let mut buf = Buffer::filled(area, underneath);
+        widget.render_region_cached(area, FULL_IMAGE_REGION, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn cached_draws_match_direct_draws() {
+        let mut widget = white_widget(RatatuiCameraStrategy::luminance_braille());
+        let area = Rect::new(0, 0, 4, 2);
+
+        let direct = draw(&mut widget, area);
+        let converted = draw(&mut widget, area);
+        let reused = draw(&mut widget, area);
+
+        assert_eq!(direct, converted);
+        assert_eq!(direct, reused);
+    }
+
+    #[test]
+    fn cached_draws_keep_the_background_of_foreground_only_cells() {
+        let mut widget = white_widget(RatatuiCameraStrategy::luminance_braille());
+        let area = Rect::new(0, 0, 4, 2);
+
+        draw(&mut widget, area);
+        draw(&mut widget, area);
+        let reused = draw(&mut widget, area);
+
+        assert!(reused.content.iter().all(|cell| cell.bg == Color::Blue));
+        assert!(reused.content.iter().all(|cell| cell.symbol() != "x"));
+    }
+
+    #[test]
+    fn changing_the_strategy_converts_again() {
+        let mut widget = white_widget(RatatuiCameraStrategy::halfblocks());
+        let area = Rect::new(0, 0, 4, 2);
+
+        draw(&mut widget, area);
+        draw(&mut widget, area);
+        widget.strategy = RatatuiCameraStrategy::luminance_braille();
+        let changed = draw(&mut widget, area);
+
+        let mut fresh = white_widget(RatatuiCameraStrategy::luminance_braille());
+        assert_eq!(changed, draw(&mut fresh, area));
+    }
+
+    #[test]
+    fn changing_the_color_callback_converts_again() {
+        let mut widget = white_widget(RatatuiCameraStrategy::luminance_braille());
+        let area = Rect::new(0, 0, 4, 2);
+        let set_foreground = |widget: &mut RatatuiCameraWidget, color: Color| {
+            widget.strategy.colors_mut().unwrap().foreground =
+                Some(ColorChoice::from_callback(move |_, _| Some(color)));
+        };
+
+        set_foreground(&mut widget, Color::Red);
+        draw(&mut widget, area);
+        draw(&mut widget, area);
+        set_foreground(&mut widget, Color::Green);
+        let changed = draw(&mut widget, area);
+
+        assert!(changed.content.iter().all(|cell| cell.fg == Color::Green));
+    }
+}
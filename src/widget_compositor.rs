@@ -0,0 +1,195 @@
+use bevy::ecs::{entity::Entity, query::QueryFilter, system::Query};
+use ratatui::{buffer::Cell, prelude::*};
+
+use crate::{
+    RatatuiCameraDepthBuffer, RatatuiCameraRenderOptions, RatatuiCameraWidget,
+    widget_utilities::blend_colors,
+};
+
+/// Draws the widgets of several cameras into one area, composited according to per-camera blend
+/// rules, instead of rendering each widget in the right order by hand. Each camera is drawn in
+/// ascending [CompositorLayer::z_order] (ties keep the order they were added in), skipping fully
+/// transparent pixels as usual (see the strategy's `common.transparent`), and blended over the
+/// cameras beneath it by its [CompositorLayer::opacity].
+///
+/// With [RatatuiCameraCompositor::depth_merge] enabled, one depth buffer is shared by every
+/// camera, so that cameras with
+/// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection) occlude each other by depth
+/// rather than purely by z-order. Depths are compared as distances from each camera (see
+/// [RatatuiCameraDepthBuffer::with_linear_depth]), so intersecting geometry from cameras with
+/// differing projections occludes correctly, as long as the cameras share a viewpoint.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::RatatuiContext;
+/// # use bevy_ratatui_camera::{CompositorLayer, RatatuiCameraCompositor, RatatuiCameraWidget};
+/// #
+/// # #[derive(Component)]
+/// # struct SkyCamera;
+/// # #[derive(Component)]
+/// # struct HudCamera;
+/// #
+/// fn draw_scene_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     mut widgets: Query<&mut RatatuiCameraWidget>,
+///     sky_camera: Single<Entity, With<SkyCamera>>,
+///     hud_camera: Single<Entity, With<HudCamera>>,
+/// ) -> Result {
+///     let compositor = RatatuiCameraCompositor::new()
+///         .with_camera(*sky_camera)
+///         .with_camera(CompositorLayer::new(*hud_camera).with_z_order(1).with_opacity(0.75));
+///
+///     ratatui.draw(|frame| {
+///         compositor.render(frame.area(), frame.buffer_mut(), &mut widgets);
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RatatuiCameraCompositor {
+    /// The cameras composited, and how each is blended with the cameras beneath it.
+    pub layers: Vec<CompositorLayer>,
+
+    /// Share one depth buffer between every camera, so that cameras with depth detection occlude
+    /// each other by depth.
+    pub depth_merge: bool,
+}
+
+/// A camera drawn by a [RatatuiCameraCompositor], along with how it is blended with the cameras
+/// drawn before it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CompositorLayer {
+    /// The camera entity, whose RatatuiCameraWidget is drawn.
+    pub entity: Entity,
+
+    /// Cameras with a higher z-order are drawn later, over cameras with a lower z-order.
+    pub z_order: i32,
+
+    /// How opaque the camera's cells are, from `0.0` (not drawn) to `1.0` (replacing the cells
+    /// beneath). In between, colors are blended with the cells beneath, and the characters of
+    /// whichever is more opaque are kept.
+    pub opacity: f32,
+}
+
+impl CompositorLayer {
+    /// Draw the provided camera with a z-order of `0` and full opacity.
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            z_order: 0,
+            opacity: 1.,
+        }
+    }
+
+    /// Cameras with a higher z-order are drawn later, over cameras with a lower z-order.
+    pub fn with_z_order(mut self, z_order: i32) -> Self {
+        self.z_order = z_order;
+        self
+    }
+
+    /// How opaque the camera's cells are, from `0.0` (not drawn) to `1.0`.
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl From<Entity> for CompositorLayer {
+    fn from(entity: Entity) -> Self {
+        Self::new(entity)
+    }
+}
+
+impl RatatuiCameraCompositor {
+    /// Create a compositor without any cameras.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a camera to composite, either an entity (drawn at z-order `0` with full opacity) or a
+    /// [CompositorLayer].
+    pub fn with_camera(mut self, layer: impl Into<CompositorLayer>) -> Self {
+        self.layers.push(layer.into());
+        self
+    }
+
+    /// Share one depth buffer between every camera, see
+    /// [RatatuiCameraCompositor::depth_merge].
+    pub fn with_depth_merge(mut self, depth_merge: bool) -> Self {
+        self.depth_merge = depth_merge;
+        self
+    }
+
+    /// Draw every camera's widget into the area, looking each widget up from the provided query.
+    /// Cameras without a widget (e.g. despawned cameras) are skipped. Each widget is drawn
+    /// through `&mut`, so cameras are resized to fit the area as with their own `render()`.
+    pub fn render<F: QueryFilter>(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        widgets: &mut Query<&mut RatatuiCameraWidget, F>,
+    ) {
+        let mut layers = self.layers.clone();
+        layers.sort_by_key(|layer| layer.z_order);
+
+        let mut depth_buffer = self
+            .depth_merge
+            .then(|| RatatuiCameraDepthBuffer::new(area).with_linear_depth(true));
+        let options = RatatuiCameraRenderOptions::default();
+
+        for layer in layers {
+            let opacity = layer.opacity.clamp(0., 1.);
+            if opacity <= 0. {
+                continue;
+            }
+
+            let Ok(widget) = widgets.get(layer.entity) else {
+                continue;
+            };
+
+            if opacity >= 1. {
+                widget.render_common(area, buf, depth_buffer.as_mut(), &options);
+                continue;
+            }
+
+            // draw over a copy of the cells beneath, and blend only the cells that changed.
+            let blend_area = area.intersection(buf.area);
+            let mut layer_buf = Buffer::empty(blend_area);
+            for position in blend_area.positions() {
+                layer_buf[position] = buf[position].clone();
+            }
+
+            widget.render_common(blend_area, &mut layer_buf, depth_buffer.as_mut(), &options);
+
+            for position in blend_area.positions() {
+                let above = &layer_buf[position];
+                if *above != buf[position] {
+                    buf[position] = blend_cells(&buf[position], above, opacity);
+                }
+            }
+        }
+    }
+}
+
+/// Blend the colors of a cell drawn over another by the provided opacity, keeping the symbol and
+/// modifiers of whichever cell is more opaque.
+fn blend_cells(below: &Cell, above: &Cell, opacity: f32) -> Cell {
+    let mut cell = if opacity >= 0.5 {
+        above.clone()
+    } else {
+        below.clone()
+    };
+
+    if let Some(fg) = blend_colors(Some(below.fg), Some(above.fg), opacity) {
+        cell.fg = fg;
+    }
+    if let Some(bg) = blend_colors(Some(below.bg), Some(above.bg), opacity) {
+        cell.bg = bg;
+    }
+
+    cell
+}
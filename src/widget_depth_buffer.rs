@@ -1,4 +1,12 @@
+use bevy::{
+    math::{Mat4, Vec4},
+    platform::collections::HashMap,
+    prelude::{Entity, Resource},
+};
 use image::{DynamicImage, GenericImageView};
+use ratatui::layout::Rect;
+
+use crate::RatatuiCameraWidget;
 
 /// A depth buffer for keeping track of the bevy world-space depth of each character drawn to the
 /// terminal buffer, for occluding characters "behind" others with respect to a bevy camera.
@@ -11,24 +19,122 @@ use image::{DynamicImage, GenericImageView};
 /// Depth values follow Bevy's convention, which is 1/Z with the near plane being 1.0, and the far
 /// plane being 0.0. This means that this buffer will record the highest value seen for a given
 /// coordinate pair.
+///
+/// A single depth buffer can be shared between multiple camera widgets (and overlays) drawn into
+/// the same area, for correct occlusion between them. Create it with the full area they are drawn
+/// within, and pass it to each render call in the order they should be drawn. Each widget tests
+/// against and updates the part of the buffer its own render area covers, even when their render
+/// areas differ (e.g. due to differing aspect ratios).
+///
+/// Raw depths are only comparable between cameras with the same projection (e.g. the same near
+/// plane). To correctly occlude intersecting geometry from separate cameras with differing
+/// projections, enable [RatatuiCameraDepthBuffer::with_linear_depth], which compares view-space
+/// distances instead (assuming the cameras share a viewpoint).
 #[derive(Clone, Debug, Default)]
 pub struct RatatuiCameraDepthBuffer {
+    area: Rect,
     width: usize,
     height: usize,
     pub(crate) buffer: Vec<f32>,
+    linear_depth: bool,
+
+    /// Inverse projection of the camera currently drawing into this buffer, for converting its
+    /// depths to linear depths.
+    view_from_clip: Option<Mat4>,
 }
 
 impl RatatuiCameraDepthBuffer {
     /// Create a new depth buffer matching the provided area. Height is doubled because there are
     /// two pixels vertically per terminal cell, therefore two depths.
-    pub fn new(area: ratatui::layout::Rect) -> Self {
+    pub fn new(area: Rect) -> Self {
         Self {
+            area,
             width: area.width as usize,
             height: area.height as usize * 2,
             buffer: vec![0.0; area.width as usize * area.height as usize * 2],
+            linear_depth: false,
+            view_from_clip: None,
+        }
+    }
+
+    /// Compare depths by their distance from each camera (recording `1.0 / distance` rather than
+    /// the raw depth), so that depths drawn by cameras with differing projections (e.g. differing
+    /// near planes, or a perspective and an orthographic camera) can be compared with each other.
+    /// Depths passed to [RatatuiCameraDepthBuffer::compare_and_update] are converted using the
+    /// projection of the camera widget currently drawing into the buffer.
+    pub fn with_linear_depth(mut self, linear_depth: bool) -> Self {
+        self.linear_depth = linear_depth;
+        self
+    }
+
+    /// Reset every depth in this buffer and resize it to match the provided area, reusing the
+    /// buffer's existing allocation where possible. Use this to reuse one depth buffer from frame
+    /// to frame, rather than creating a new one each frame.
+    pub fn clear_and_resize(&mut self, area: Rect) {
+        self.area = area;
+        self.view_from_clip = None;
+        self.width = area.width as usize;
+        self.height = area.height as usize * 2;
+        self.buffer.clear();
+        self.buffer.resize(self.width * self.height, 0.0);
+    }
+
+    /// The area (in terminal cells) that this depth buffer covers.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Merge the depths recorded in another depth buffer into this one, keeping the closer depth
+    /// wherever the two overlap. The buffers' areas are used to line up their coordinates, so
+    /// buffers covering different parts of the terminal can be merged.
+    pub fn merge(&mut self, other: &RatatuiCameraDepthBuffer) {
+        for y in 0..other.height {
+            for x in 0..other.width {
+                let terminal_x = (other.area.x as usize + x).checked_sub(self.area.x as usize);
+                let terminal_y =
+                    (other.area.y as usize * 2 + y).checked_sub(self.area.y as usize * 2);
+
+                let (Some(self_x), Some(self_y)) = (terminal_x, terminal_y) else {
+                    continue;
+                };
+
+                let Some(index) = self.index(self_x, self_y) else {
+                    continue;
+                };
+
+                let depth = other.buffer[x + y * other.width];
+                self.buffer[index] = self.buffer[index].max(depth);
+            }
         }
     }
 
+    /// Run the provided function with a depth buffer covering the provided area. If this buffer
+    /// covers a different area, the overlapping depths are copied into a temporary buffer for the
+    /// provided area, which is merged back into this one afterwards.
+    /// The provided inverse projection of the camera drawing into the buffer is used to convert
+    /// its depths when linear depth is enabled.
+    pub(crate) fn within_area<R>(
+        &mut self,
+        area: Rect,
+        view_from_clip: &Mat4,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        self.view_from_clip = Some(*view_from_clip);
+
+        if self.area == area {
+            return f(self);
+        }
+
+        let mut region = Self::new(area).with_linear_depth(self.linear_depth);
+        region.view_from_clip = self.view_from_clip;
+        region.merge(self);
+
+        let result = f(&mut region);
+        self.merge(&region);
+
+        result
+    }
+
     /// Retrieve the depth value for the provided coordinates.
     pub fn get(&self, x: usize, y: usize) -> Option<f32> {
         let index = self.index(x, y)?;
@@ -56,7 +162,11 @@ impl RatatuiCameraDepthBuffer {
     ///   left as is, and the returned option will contain false (meaning a character at the new
     ///   depth is occluded and should not be drawn).
     /// - If the provided coordinates are outside of the depth buffer, `None` is returned.
+    ///
+    /// With linear depth enabled, the provided depth is first converted to `1.0 / distance` (see
+    /// [RatatuiCameraDepthBuffer::with_linear_depth]).
     pub fn compare_and_update(&mut self, x: usize, y: usize, depth: f32) -> Option<bool> {
+        let depth = self.comparable_depth(depth);
         let previous_depth = self.get(x, y)?;
 
         if depth >= previous_depth {
@@ -85,6 +195,30 @@ impl RatatuiCameraDepthBuffer {
         self.compare_and_update(x as usize, y as usize, depth)
     }
 
+    /// Set the inverse projection of the camera drawing into the buffer, e.g. for each of a
+    /// widget's layers.
+    pub(crate) fn set_view_from_clip(&mut self, view_from_clip: &Mat4) {
+        self.view_from_clip = Some(*view_from_clip);
+    }
+
+    /// Convert a raw depth from the camera drawing into the buffer to the depth recorded, which
+    /// is `1.0 / distance` from the camera with linear depth enabled, and otherwise unchanged.
+    fn comparable_depth(&self, depth: f32) -> f32 {
+        let (true, Some(view_from_clip)) = (self.linear_depth, self.view_from_clip) else {
+            return depth;
+        };
+
+        let view_position = view_from_clip * Vec4::new(0.0, 0.0, depth, 1.0);
+        let distance = -view_position.z / view_position.w;
+
+        // the far plane of an infinite perspective projection lies at an infinite distance.
+        if distance.is_finite() && distance > 0.0 {
+            1.0 / distance
+        } else {
+            0.0
+        }
+    }
+
     /// Convert the provided 2D coordinates to an index in our flat buffer, returning None if the
     /// coordinates lie outside the bounds.
     fn index(&self, x: usize, y: usize) -> Option<usize> {
@@ -100,3 +234,62 @@ impl RatatuiCameraDepthBuffer {
         x < self.width && y < self.height
     }
 }
+
+/// A pool of depth buffers keyed by camera entity, so that depth buffers can be reused from frame
+/// to frame rather than allocated anew each frame. A camera's depth buffer is dropped when its
+/// `RatatuiCamera` is removed.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::RatatuiContext;
+/// # use bevy_ratatui_camera::{RatatuiCameraDepthBuffers, RatatuiCameraWidget};
+/// # use ratatui::widgets::StatefulWidget;
+/// #
+/// fn draw_scene_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     mut camera_widget: Single<&mut RatatuiCameraWidget>,
+///     mut depth_buffers: ResMut<RatatuiCameraDepthBuffers>,
+/// ) -> Result {
+///     ratatui.draw(|frame| {
+///         let area = frame.area();
+///         let widget: &mut RatatuiCameraWidget = &mut camera_widget;
+///         let depth_buffer = depth_buffers.for_widget(widget, area);
+///         widget.render(area, frame.buffer_mut(), depth_buffer);
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Resource, Clone, Debug, Default)]
+pub struct RatatuiCameraDepthBuffers {
+    buffers: HashMap<Entity, RatatuiCameraDepthBuffer>,
+}
+
+impl RatatuiCameraDepthBuffers {
+    /// Retrieve the pooled depth buffer for the provided camera widget, cleared and resized to
+    /// match the widget's render area within the provided area (see
+    /// [RatatuiCameraWidget::new_depth_buffer]).
+    pub fn for_widget(
+        &mut self,
+        widget: &RatatuiCameraWidget,
+        area: Rect,
+    ) -> &mut RatatuiCameraDepthBuffer {
+        self.for_entity(widget.entity, widget.calculate_render_area(area))
+    }
+
+    /// Retrieve the pooled depth buffer for the provided entity, cleared and resized to match the
+    /// provided area. Use this for a depth buffer shared by multiple camera widgets, keyed by
+    /// whichever camera entity is drawn first.
+    pub fn for_entity(&mut self, entity: Entity, area: Rect) -> &mut RatatuiCameraDepthBuffer {
+        let depth_buffer = self.buffers.entry(entity).or_default();
+        depth_buffer.clear_and_resize(area);
+        depth_buffer
+    }
+
+    /// Drop the pooled depth buffer for the provided entity, if any.
+    pub fn remove(&mut self, entity: Entity) -> Option<RatatuiCameraDepthBuffer> {
+        self.buffers.remove(&entity)
+    }
+}
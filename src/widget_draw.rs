@@ -0,0 +1,81 @@
+use std::fmt::Debug;
+
+use bevy::prelude::*;
+use bevy_ratatui::RatatuiContext;
+use ratatui::Frame;
+
+use crate::{RatatuiCameraError, RatatuiCameraLowLatencySet, RatatuiCameraWidgets};
+
+/// Resource holding a closure that draws the camera widgets, for apps that would otherwise write
+/// a draw system that only calls `RatatuiContext::draw` and renders the widgets. While the
+/// resource exists, the plugin calls the closure once per frame inside `RatatuiContext::draw`,
+/// ordered after the camera widgets are updated for both [RatatuiCameraLatency] settings, and
+/// reports any terminal error as a [RatatuiCameraError::Draw] instead of returning it.
+///
+/// Requires the `draw` feature, and bevy_ratatui's `RatatuiContext` resource. Apps that draw
+/// themselves should not also insert this resource, as only the last draw each frame is shown.
+///
+/// [RatatuiCameraLatency]: crate::RatatuiCameraLatency
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiCameraDraw, RatatuiCameraLayout};
+/// #
+/// # fn build_app(app: &mut App) {
+/// app.insert_resource(RatatuiCameraDraw::new(|frame, widgets| {
+///     let widgets = widgets.iter_ordered().map(|(_, widget)| widget);
+///     RatatuiCameraLayout::grid().render(frame.area(), frame.buffer_mut(), widgets);
+/// }));
+/// # };
+/// ```
+///
+#[derive(Resource)]
+pub struct RatatuiCameraDraw(
+    Box<dyn Fn(&mut Frame, &mut RatatuiCameraWidgets) + Send + Sync + 'static>,
+);
+
+impl Debug for RatatuiCameraDraw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RatatuiCameraDraw").finish_non_exhaustive()
+    }
+}
+
+impl RatatuiCameraDraw {
+    /// Draw each frame with the provided closure, which receives the terminal frame and the
+    /// camera widgets (see [RatatuiCameraWidgets]).
+    pub fn new(
+        draw: impl Fn(&mut Frame, &mut RatatuiCameraWidgets) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Box::new(draw))
+    }
+}
+
+pub struct RatatuiCameraDrawPlugin;
+
+impl Plugin for RatatuiCameraDrawPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            draw_camera_widgets_system
+                .after(RatatuiCameraLowLatencySet)
+                .run_if(resource_exists::<RatatuiCameraDraw>)
+                .run_if(resource_exists::<RatatuiContext>),
+        );
+    }
+}
+
+fn draw_camera_widgets_system(
+    draw: Res<RatatuiCameraDraw>,
+    mut ratatui: ResMut<RatatuiContext>,
+    mut widgets: RatatuiCameraWidgets,
+    mut errors: MessageWriter<RatatuiCameraError>,
+) {
+    if let Err(error) = ratatui.draw(|frame| (draw.0)(frame, &mut widgets)) {
+        RatatuiCameraError::Draw {
+            reason: error.to_string(),
+        }
+        .report(&mut errors);
+    }
+}
@@ -0,0 +1,103 @@
+/// Width in pixels of each glyph coverage mask.
+pub const GLYPH_MASK_WIDTH: u32 = 4;
+
+/// Height in pixels of each glyph coverage mask. Twice the width, as terminal cells are twice as
+/// high as they are wide.
+pub const GLYPH_MASK_HEIGHT: u32 = 8;
+
+/// A rasterized glyph, as one bit per pixel starting from the top left, row by row.
+pub type GlyphMask = u32;
+
+/// Build a glyph mask from rows of '#' (covered) and '.' (uncovered) pixels.
+const fn mask(rows: [&str; GLYPH_MASK_HEIGHT as usize]) -> GlyphMask {
+    let mut mask = 0;
+
+    let mut y = 0;
+    while y < GLYPH_MASK_HEIGHT as usize {
+        let row = rows[y].as_bytes();
+        let mut x = 0;
+        while x < GLYPH_MASK_WIDTH as usize {
+            if row[x] == b'#' {
+                mask |= 1 << (y * GLYPH_MASK_WIDTH as usize + x);
+            }
+            x += 1;
+        }
+        y += 1;
+    }
+
+    mask
+}
+
+/// Returns whether the pixel at the provided coordinates is covered by the glyph mask.
+pub const fn mask_covers(mask: GlyphMask, x: u32, y: u32) -> bool {
+    mask & (1 << (y * GLYPH_MASK_WIDTH + x)) != 0
+}
+
+/// Look up the coverage mask for a character, if one exists in the embedded table.
+pub fn glyph_mask(character: char) -> Option<GlyphMask> {
+    GLYPH_MASKS
+        .iter()
+        .find(|(glyph, _)| *glyph == character)
+        .map(|(_, mask)| *mask)
+}
+
+/// Embedded bitmap font, approximating how each character covers a terminal cell.
+#[rustfmt::skip]
+pub const GLYPH_MASKS: &[(char, GlyphMask)] = &[
+    (' ', mask(["....", "....", "....", "....", "....", "....", "....", "...."])),
+    ('.', mask(["....", "....", "....", "....", "....", "....", ".##.", "...."])),
+    (',', mask(["....", "....", "....", "....", "....", "....", ".##.", ".#.."])),
+    ('\'', mask([".#..", ".#..", "....", "....", "....", "....", "....", "...."])),
+    ('`', mask([".#..", "..#.", "....", "....", "....", "....", "....", "...."])),
+    ('"', mask(["#.#.", "#.#.", "....", "....", "....", "....", "....", "...."])),
+    ('^', mask([".##.", "#..#", "....", "....", "....", "....", "....", "...."])),
+    (':', mask(["....", "....", ".##.", "....", "....", ".##.", "....", "...."])),
+    (';', mask(["....", "....", ".##.", "....", "....", ".##.", ".#..", "...."])),
+    ('-', mask(["....", "....", "....", "....", "####", "....", "....", "...."])),
+    ('_', mask(["....", "....", "....", "....", "....", "....", "....", "####"])),
+    ('=', mask(["....", "....", "....", "####", "....", "####", "....", "...."])),
+    ('~', mask(["....", "....", "....", ".#.#", "#.#.", "....", "....", "...."])),
+    ('+', mask(["....", "....", ".#..", ".#..", "####", ".#..", ".#..", "...."])),
+    ('*', mask(["....", "....", "#.#.", ".#..", "###.", ".#..", "#.#.", "...."])),
+    ('|', mask([".#..", ".#..", ".#..", ".#..", ".#..", ".#..", ".#..", ".#.."])),
+    ('/', mask(["...#", "...#", "..#.", "..#.", ".#..", ".#..", "#...", "#..."])),
+    ('\\', mask(["#...", "#...", ".#..", ".#..", "..#.", "..#.", "...#", "...#"])),
+    ('(', mask(["..#.", ".#..", ".#..", ".#..", ".#..", ".#..", ".#..", "..#."])),
+    (')', mask([".#..", "..#.", "..#.", "..#.", "..#.", "..#.", "..#.", ".#.."])),
+    ('[', mask([".##.", ".#..", ".#..", ".#..", ".#..", ".#..", ".#..", ".##."])),
+    (']', mask([".##.", "..#.", "..#.", "..#.", "..#.", "..#.", "..#.", ".##."])),
+    ('<', mask(["....", "..##", ".#..", "#...", "#...", ".#..", "..##", "...."])),
+    ('>', mask(["....", "##..", "..#.", "...#", "...#", "..#.", "##..", "...."])),
+    ('v', mask(["....", "....", "....", "#..#", "#..#", ".##.", ".##.", "...."])),
+    ('o', mask(["....", "....", "....", ".##.", "#..#", "#..#", ".##.", "...."])),
+    ('x', mask(["....", "....", "....", "#..#", ".##.", ".##.", "#..#", "...."])),
+    ('L', mask(["#...", "#...", "#...", "#...", "#...", "#...", "####", "...."])),
+    ('T', mask(["####", ".#..", ".#..", ".#..", ".#..", ".#..", ".#..", "...."])),
+    ('V', mask(["#..#", "#..#", "#..#", "#..#", ".##.", ".##.", ".##.", "...."])),
+    ('X', mask(["#..#", "#..#", ".##.", ".##.", ".##.", "#..#", "#..#", "...."])),
+    ('Y', mask(["#..#", "#..#", ".##.", ".#..", ".#..", ".#..", ".#..", "...."])),
+    ('O', mask([".##.", "#..#", "#..#", "#..#", "#..#", "#..#", ".##.", "...."])),
+    ('H', mask(["#..#", "#..#", "#..#", "####", "#..#", "#..#", "#..#", "...."])),
+    ('A', mask([".##.", "#..#", "#..#", "####", "#..#", "#..#", "#..#", "...."])),
+    ('M', mask(["#..#", "####", "####", "#..#", "#..#", "#..#", "#..#", "...."])),
+    ('W', mask(["#..#", "#..#", "#..#", "#..#", "####", "####", "#..#", "...."])),
+    ('#', mask(["....", ".#.#", "####", ".#.#", ".#.#", "####", ".#.#", "...."])),
+    ('%', mask(["##..", "##.#", "..#.", ".#..", ".#..", "#.##", "..##", "...."])),
+    ('&', mask([".#..", "#.#.", ".#..", "##.#", "#.#.", "#.#.", ".#.#", "...."])),
+    ('@', mask([".##.", "#..#", "#.##", "#.##", "#.##", "#...", ".###", "...."])),
+    ('▀', mask(["####", "####", "####", "####", "....", "....", "....", "...."])),
+    ('▄', mask(["....", "....", "....", "....", "####", "####", "####", "####"])),
+    ('▌', mask(["##..", "##..", "##..", "##..", "##..", "##..", "##..", "##.."])),
+    ('▐', mask(["..##", "..##", "..##", "..##", "..##", "..##", "..##", "..##"])),
+    ('▘', mask(["##..", "##..", "##..", "##..", "....", "....", "....", "...."])),
+    ('▝', mask(["..##", "..##", "..##", "..##", "....", "....", "....", "...."])),
+    ('▖', mask(["....", "....", "....", "....", "##..", "##..", "##..", "##.."])),
+    ('▗', mask(["....", "....", "....", "....", "..##", "..##", "..##", "..##"])),
+    ('▚', mask(["##..", "##..", "##..", "##..", "..##", "..##", "..##", "..##"])),
+    ('▞', mask(["..##", "..##", "..##", "..##", "##..", "##..", "##..", "##.."])),
+    ('▙', mask(["##..", "##..", "##..", "##..", "####", "####", "####", "####"])),
+    ('▛', mask(["####", "####", "####", "####", "##..", "##..", "##..", "##.."])),
+    ('▜', mask(["####", "####", "####", "####", "..##", "..##", "..##", "..##"])),
+    ('▟', mask(["..##", "..##", "..##", "..##", "####", "####", "####", "####"])),
+    ('█', mask(["####", "####", "####", "####", "####", "####", "####", "####"])),
+];
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use bevy::{
+    asset::{Assets, Handle},
+    image::Image,
+};
+use image::DynamicImage;
+use ratatui::{prelude::*, widgets::WidgetRef};
+
+use crate::{
+    RatatuiCameraCharacterSupport, RatatuiCameraFontMetrics, RatatuiCameraStrategy,
+    RatatuiCameraWidget,
+};
+
+/// Ratatui widget that converts any image (logos, splash art, pre-rendered frames, etcetera) into
+/// unicode characters using the same strategies as [RatatuiCameraWidget], without a camera. The
+/// image is letterboxed to preserve its aspect ratio, and the converted cells are cached, so
+/// drawing the same widget every frame only converts the image again when the area changes.
+/// Draw it by reference (`&RatatuiImageWidget` implements ratatui's `Widget`).
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::RatatuiContext;
+/// # use bevy_ratatui_camera::{RatatuiCameraStrategy, RatatuiImageWidget};
+/// #
+/// # #[derive(Resource)]
+/// # struct Logo(Handle<Image>);
+/// #
+/// fn draw_logo_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     logo: Res<Logo>,
+///     images: Res<Assets<Image>>,
+/// ) -> Result {
+///     let Some(widget) = RatatuiImageWidget::from_handle(&logo.0, &images) else {
+///         return Ok(());
+///     };
+///     let widget = widget.with_strategy(RatatuiCameraStrategy::luminance_braille());
+///
+///     ratatui.draw(|frame| {
+///         frame.render_widget(&widget, frame.area());
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct RatatuiImageWidget {
+    widget: RatatuiCameraWidget,
+    strategy: RatatuiCameraStrategy,
+}
+
+impl RatatuiImageWidget {
+    /// Convert the provided image, using the default strategy.
+    pub fn new(image: DynamicImage) -> Self {
+        Self::from_widget(RatatuiCameraWidget::from_image(Arc::new(image)))
+    }
+
+    /// Convert the provided bevy image, if it is loaded and its texture format can be converted
+    /// to an `image::DynamicImage`.
+    pub fn from_handle(handle: &Handle<Image>, images: &Assets<Image>) -> Option<Self> {
+        let image = images.get(handle)?.clone().try_into_dynamic().ok()?;
+
+        Some(Self::new(image))
+    }
+
+    fn from_widget(widget: RatatuiCameraWidget) -> Self {
+        let strategy = widget.strategy.clone();
+
+        let mut image_widget = Self { widget, strategy };
+        image_widget.update_strategy();
+        image_widget
+    }
+
+    /// Strategy used to convert the image into unicode characters.
+    pub fn with_strategy(mut self, strategy: RatatuiCameraStrategy) -> Self {
+        self.strategy = strategy;
+        self.update_strategy();
+        self
+    }
+
+    /// Font metrics used to preserve the image's aspect ratio, see [RatatuiCameraFontMetrics].
+    pub fn with_font_metrics(mut self, font_metrics: RatatuiCameraFontMetrics) -> Self {
+        self.widget.font_metrics = font_metrics;
+        self
+    }
+
+    /// Characters the terminal can display, which the strategy is downgraded to. Detected from
+    /// the environment by default, see [RatatuiCameraCharacterSupport].
+    pub fn with_character_support(
+        mut self,
+        character_support: RatatuiCameraCharacterSupport,
+    ) -> Self {
+        self.widget.character_support = character_support;
+        self.update_strategy();
+        self
+    }
+
+    /// The area within the provided area that the image is drawn within, excluding the gutters
+    /// needed to preserve its aspect ratio.
+    pub fn calculate_render_area(&self, area: Rect) -> Rect {
+        self.widget.calculate_render_area(area)
+    }
+
+    /// Apply the character support to a fresh copy of the strategy, so that it can be upgraded
+    /// again by a later change of character support.
+    fn update_strategy(&mut self) {
+        let mut strategy = self.strategy.clone();
+        self.widget
+            .character_support
+            .downgrade_strategy(&mut strategy);
+
+        self.widget.strategy = strategy;
+        self.widget.cell_cache = Arc::default();
+    }
+}
+
+impl WidgetRef for RatatuiImageWidget {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.widget.render_detached(area, buf, None);
+    }
+}
@@ -0,0 +1,213 @@
+use std::ops::DerefMut;
+
+use ratatui::prelude::*;
+
+use crate::{RatatuiCameraFontMetrics, RatatuiCameraWidget};
+
+/// Splits an area into one pane per camera and draws each camera's widget into its pane, in place
+/// of building a ratatui `Layout` by hand for however many cameras there are.
+///
+/// The grid arrangement chooses its number of columns based on the shape of the area, the shape of
+/// a terminal cell (see [RatatuiCameraFontMetrics]), and the aspect ratio the cameras are expected
+/// to have, so that as much of each camera's image as possible is shown after letterboxing.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::RatatuiContext;
+/// # use bevy_ratatui_camera::{RatatuiCameraLayout, RatatuiCameraWidgets};
+/// #
+/// fn draw_scene_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     mut widgets: RatatuiCameraWidgets,
+/// ) -> Result {
+///     let layout = RatatuiCameraLayout::grid().with_spacing(1);
+///
+///     ratatui.draw(|frame| {
+///         let widgets = widgets.iter_ordered().map(|(_, widget)| widget);
+///         layout.render(frame.area(), frame.buffer_mut(), widgets);
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiCameraLayout {
+    /// How the panes are arranged within the area.
+    pub arrangement: CameraLayoutArrangement,
+
+    /// Number of empty cells between neighboring panes.
+    pub spacing: u16,
+
+    /// The aspect ratio (width divided by height, in pixels) each pane should ideally have,
+    /// used by the grid arrangement to choose its number of columns. For cameras with autoresize
+    /// enabled, which fill whatever pane they are drawn in, this is the shape the cameras' images
+    /// are closest to.
+    pub aspect_ratio: f32,
+}
+
+/// The ways a [RatatuiCameraLayout] can arrange its panes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CameraLayoutArrangement {
+    /// One row of panes, side by side.
+    Horizontal,
+
+    /// One column of panes, stacked on top of each other.
+    Vertical,
+
+    /// Rows of equally sized panes, filled left to right and then top to bottom.
+    #[default]
+    Grid,
+}
+
+impl Default for RatatuiCameraLayout {
+    fn default() -> Self {
+        Self {
+            arrangement: CameraLayoutArrangement::default(),
+            spacing: 0,
+            aspect_ratio: Self::ASPECT_RATIO_DEFAULT,
+        }
+    }
+}
+
+impl RatatuiCameraLayout {
+    /// Default value of `aspect_ratio`.
+    pub const ASPECT_RATIO_DEFAULT: f32 = 16. / 9.;
+
+    /// Arrange the cameras in one row, side by side.
+    pub fn horizontal() -> Self {
+        Self {
+            arrangement: CameraLayoutArrangement::Horizontal,
+            ..Self::default()
+        }
+    }
+
+    /// Arrange the cameras in one column, stacked on top of each other.
+    pub fn vertical() -> Self {
+        Self {
+            arrangement: CameraLayoutArrangement::Vertical,
+            ..Self::default()
+        }
+    }
+
+    /// Arrange the cameras in a grid, with the number of columns chosen to fit the area.
+    pub fn grid() -> Self {
+        Self {
+            arrangement: CameraLayoutArrangement::Grid,
+            ..Self::default()
+        }
+    }
+
+    /// Leave the provided number of empty cells between neighboring panes.
+    pub fn with_spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// The aspect ratio each pane should ideally have, see [RatatuiCameraLayout::aspect_ratio].
+    pub fn with_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = aspect_ratio;
+        self
+    }
+
+    /// Split the area into the provided number of panes, in the order cameras are drawn into them.
+    pub fn areas(
+        &self,
+        area: Rect,
+        count: usize,
+        font_metrics: &RatatuiCameraFontMetrics,
+    ) -> Vec<Rect> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        match self.arrangement {
+            CameraLayoutArrangement::Horizontal => self.split(area, Direction::Horizontal, count),
+            CameraLayoutArrangement::Vertical => self.split(area, Direction::Vertical, count),
+            CameraLayoutArrangement::Grid => {
+                let columns = self.grid_columns(area, count, font_metrics);
+                let rows = count.div_ceil(columns);
+
+                self.split(area, Direction::Vertical, rows)
+                    .into_iter()
+                    .flat_map(|row| self.split(row, Direction::Horizontal, columns))
+                    .take(count)
+                    .collect()
+            }
+        }
+    }
+
+    /// Draw each widget into its own pane of the area, in the order provided (e.g. from
+    /// [RatatuiCameraWidgets::iter_ordered](crate::RatatuiCameraWidgets::iter_ordered)). Each
+    /// widget is drawn through `&mut`, so cameras are resized to fit their panes as with their own
+    /// `render()`.
+    pub fn render<W: DerefMut<Target = RatatuiCameraWidget>>(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        widgets: impl IntoIterator<Item = W>,
+    ) {
+        let mut widgets = widgets.into_iter().collect::<Vec<_>>();
+        let Some(font_metrics) = widgets.first().map(|widget| widget.font_metrics) else {
+            return;
+        };
+
+        let areas = self.areas(area, widgets.len(), &font_metrics);
+        for (widget, area) in widgets.iter_mut().zip(areas) {
+            Widget::render(widget.deref_mut(), area, buf);
+        }
+    }
+
+    fn split(&self, area: Rect, direction: Direction, count: usize) -> Vec<Rect> {
+        Layout::new(direction, vec![Constraint::Fill(1); count])
+            .spacing(self.spacing)
+            .split(area)
+            .to_vec()
+    }
+
+    /// The number of columns that shows the most of each camera's image, assuming each image has
+    /// `aspect_ratio` and is letterboxed within its pane.
+    fn grid_columns(
+        &self,
+        area: Rect,
+        count: usize,
+        font_metrics: &RatatuiCameraFontMetrics,
+    ) -> usize {
+        let aspect_ratio = if self.aspect_ratio.is_finite() && self.aspect_ratio > 0. {
+            self.aspect_ratio
+        } else {
+            Self::ASPECT_RATIO_DEFAULT
+        };
+        let cell_aspect_ratio = font_metrics.cell_aspect_ratio();
+
+        let shown_area = |columns: usize| {
+            let rows = count.div_ceil(columns);
+            let spacing = self.spacing as f32;
+
+            // pane dimensions in units of cell widths.
+            let width = (area.width as f32 - spacing * (columns - 1) as f32) / columns as f32;
+            let height = (area.height as f32 - spacing * (rows - 1) as f32) / rows as f32
+                * cell_aspect_ratio;
+
+            if width <= 0. || height <= 0. {
+                return 0.;
+            }
+
+            let shown_width = width.min(height * aspect_ratio);
+            shown_width * shown_width / aspect_ratio
+        };
+
+        (1..=count)
+            .map(|columns| (columns, shown_area(columns)))
+            .fold((1, 0.), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .0
+    }
+}
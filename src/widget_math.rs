@@ -1,13 +1,33 @@
-use bevy::math::{IVec2, Vec3};
-use image::{DynamicImage, imageops::FilterType};
+use bevy::math::{Dir3, IVec2, Ray3d, Rect as ImageRect, Vec2, Vec3};
+use bevy::prelude::{Camera, GlobalTransform};
+use image::{DynamicImage, GenericImageView, imageops::FilterType};
+#[cfg(feature = "mouse")]
+use ratatui::layout::Position;
 use ratatui::layout::Rect;
 
 use crate::RatatuiCameraWidget;
 
+/// Resize an image to exact dimensions, copying it as-is if it is already that size (e.g. when the
+/// camera is autoresized to the area it is drawn in).
+pub(crate) fn resize_image(
+    image: &DynamicImage,
+    width: u32,
+    height: u32,
+    filter: FilterType,
+) -> DynamicImage {
+    if image.dimensions() == (width, height) {
+        return image.clone();
+    }
+
+    image.resize_exact(width, height, filter)
+}
+
 impl RatatuiCameraWidget {
-    /// Calculate the aspect ratio of the widget's render image.
+    /// Calculate the aspect ratio of the widget's render image, in terminal cells (the ratio of
+    /// columns to rows needed to display the image without distortion).
     pub fn aspect_ratio(&self) -> f32 {
-        (self.camera_image.width() * 2) as f32 / self.camera_image.height() as f32
+        self.camera_image.width() as f32 * self.font_metrics.cell_aspect_ratio()
+            / self.camera_image.height() as f32
     }
 
     /// Calculate the area that the image will actually be drawn (excluding the vertical or
@@ -40,64 +60,305 @@ impl RatatuiCameraWidget {
         let width = area.width as u32;
         let height = area.height as u32 * 2;
 
-        let camera_image = self.camera_image.resize(width, height, FilterType::Nearest);
+        let camera_image = resize_image(&self.camera_image, width, height, FilterType::Nearest);
 
         let depth_image = self
             .depth_image
             .as_ref()
-            .map(|i| i.resize(width, height, FilterType::Nearest));
+            .map(|i| resize_image(i, width, height, FilterType::Nearest));
 
         let sobel_image = self
             .sobel_image
             .as_ref()
-            .map(|i| i.resize(width, height, FilterType::Nearest));
+            .map(|i| resize_image(i, width, height, FilterType::Nearest));
 
         (camera_image, depth_image, sobel_image)
     }
 
+    /// The area the image was drawn within inside the provided area, and the region of the image
+    /// drawn there (in normalized image coordinates), following the fill mode, alignment, and
+    /// viewport the widget was last rendered with.
+    fn drawn_region(&self, area: Rect) -> (Rect, ImageRect) {
+        let options = &self.last_render_options;
+
+        (
+            self.calculate_render_area_with_options(area, options),
+            self.calculate_source_region(area, options),
+        )
+    }
+
+    /// Whether a terminal buffer cell lies within the part of the area the image was drawn in,
+    /// excluding any gutters, following the options the widget was last rendered with. Used for
+    /// hit-testing the mouse against the area the widget was last rendered within.
+    #[cfg(feature = "mouse")]
+    pub(crate) fn drawn_area_contains(&self, area: Rect, position: Position) -> bool {
+        let (render_area, _) = self.drawn_region(area);
+
+        render_area.contains(position)
+    }
+
+    /// Convert a position in terminal buffer cells (where e.g. `0.5` is the center of the first
+    /// cell) into normalized image coordinates, from `0.0` to `1.0` across each axis of the image.
+    fn cell_to_image_position(&self, area: Rect, cell_position: Vec2) -> Vec2 {
+        let (render_area, region) = self.drawn_region(area);
+        let origin = Vec2::new(render_area.x as f32, render_area.y as f32);
+        let size = Vec2::new(render_area.width as f32, render_area.height as f32);
+
+        region.min + (cell_position - origin) / size * region.size()
+    }
+
+    /// Convert normalized image coordinates into a position in terminal buffer cells, the inverse
+    /// of [RatatuiCameraWidget::cell_to_image_position].
+    fn image_position_to_cell(&self, area: Rect, image_position: Vec2) -> Vec2 {
+        let (render_area, region) = self.drawn_region(area);
+        let origin = Vec2::new(render_area.x as f32, render_area.y as f32);
+        let size = Vec2::new(render_area.width as f32, render_area.height as f32);
+
+        origin + (image_position - region.min) / region.size() * size
+    }
+
     /// Convert a pair of terminal buffer cell coordinates (number of characters from the left edge
     /// and top edge of the buffer, respectively) into an NDC (Normalized Device Coordinates) value
-    /// that represents a position in the camera viewport.
+    /// that represents a position in the camera viewport. Follows the fill mode, alignment, and
+    /// viewport the widget was last rendered with (see [RatatuiCameraWidget::last_render_options]).
     pub fn cell_to_ndc(&self, area: Rect, cell_coords: IVec2) -> Vec3 {
-        let render_area = self.calculate_render_area(area);
-        let cell_coords = IVec2 {
-            x: cell_coords.x - render_area.x as i32,
-            y: cell_coords.y - render_area.y as i32,
-        };
+        let image_position = self.cell_to_image_position(area, cell_coords.as_vec2());
 
-        self.relative_cell_to_ndc(render_area, cell_coords)
+        image_position_to_ndc(image_position, 0.5)
     }
 
     /// See [RatatuiCameraWidget::cell_to_ndc]. Rather than the global cell coordinates, this
-    /// variant takes the cell coordinates relative to the provided area.
+    /// variant takes the cell coordinates relative to the area the image was drawn within.
     pub fn relative_cell_to_ndc(&self, area: Rect, cell_coords: IVec2) -> Vec3 {
-        let render_area = self.calculate_render_area(area);
-        let x = (cell_coords.x as f32 / render_area.width as f32 - 0.5) * 2.;
-        let y = (cell_coords.y as f32 / render_area.height as f32 - 0.5) * -2.;
+        let (render_area, _) = self.drawn_region(area);
+        let origin = IVec2::new(render_area.x as i32, render_area.y as i32);
 
-        Vec3::new(x, y, 0.5)
+        self.cell_to_ndc(area, origin + cell_coords)
     }
 
     /// Convert an NDC (Normalized Device Coordinates) value that represents a position in the
     /// camera viewport into a pair of terminal buffer cell coordinates (number of characters from
-    /// the left edge and top edge of the buffer, respectively).
+    /// the left edge and top edge of the buffer, respectively). Like
+    /// [RatatuiCameraWidget::cell_to_ndc], follows the options the widget was last rendered with.
     pub fn ndc_to_cell(&self, area: Rect, ndc_coords: Vec3) -> IVec2 {
-        let render_area = self.calculate_render_area(area);
-        let cell = self.ndc_to_relative_cell(render_area, ndc_coords);
+        let image_position = Vec2::new(ndc_coords.x / 2. + 0.5, -ndc_coords.y / 2. + 0.5);
 
-        IVec2 {
-            x: cell.x + render_area.x as i32,
-            y: cell.y + render_area.y as i32,
-        }
+        self.image_position_to_cell(area, image_position)
+            .floor()
+            .as_ivec2()
     }
 
     /// See [RatatuiCameraWidget::ndc_to_cell]. Rather than the global cell coordinates, this
-    /// variant gives the cell coordinates relative to the provided area.
+    /// variant gives the cell coordinates relative to the area the image was drawn within.
     pub fn ndc_to_relative_cell(&self, area: Rect, ndc_coords: Vec3) -> IVec2 {
-        let render_area = self.calculate_render_area(area);
-        let x = ((ndc_coords.x / 2. + 0.5) * render_area.width as f32) as i32;
-        let y = ((-ndc_coords.y / 2. + 0.5) * render_area.height as f32) as i32;
+        let (render_area, _) = self.drawn_region(area);
+        let origin = IVec2::new(render_area.x as i32, render_area.y as i32);
+
+        self.ndc_to_cell(area, ndc_coords) - origin
+    }
+
+    /// The pixel of an image under the center of a pair of terminal buffer cell coordinates, or
+    /// `None` if the cell lies outside of the area the image was drawn within.
+    fn pixel_at_cell(
+        &self,
+        area: Rect,
+        cell_coords: IVec2,
+        image: &DynamicImage,
+    ) -> Option<(u32, u32)> {
+        let (render_area, _) = self.drawn_region(area);
+        let position = (
+            u16::try_from(cell_coords.x).ok()?,
+            u16::try_from(cell_coords.y).ok()?,
+        );
+        if !render_area.contains(position.into()) {
+            return None;
+        }
+
+        let image_position = self.cell_to_image_position(area, cell_coords.as_vec2() + 0.5);
+        let pixel = image_position * Vec2::new(image.width() as f32, image.height() as f32);
+        let (pixel_x, pixel_y) = (pixel.x as u32, pixel.y as u32);
+
+        image
+            .in_bounds(pixel_x, pixel_y)
+            .then_some((pixel_x, pixel_y))
+    }
+
+    /// Retrieve the depth under a pair of terminal buffer cell coordinates (number of characters
+    /// from the left edge and top edge of the buffer, respectively), sampled at the center of the
+    /// cell. Depth values follow Bevy's convention, with the near plane being 1.0 and the far plane
+    /// being 0.0 (meaning nothing was rendered there).
+    ///
+    /// Like [RatatuiCameraWidget::cell_to_ndc], follows the options the widget was last rendered
+    /// with. Returns `None` if the cell lies outside of the render area, or if the camera has no
+    /// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection).
+    pub fn depth_at_cell(&self, area: Rect, cell_coords: IVec2) -> Option<f32> {
+        let depth_image = self.depth_image.as_ref()?;
+        let (pixel_x, pixel_y) = self.pixel_at_cell(area, cell_coords, depth_image)?;
+
+        Some(f32::from_le_bytes(
+            depth_image.get_pixel(pixel_x, pixel_y).0,
+        ))
+    }
+
+    /// Get the [RatatuiCameraMask](crate::RatatuiCameraMask) value of the mesh visible under a
+    /// pair of terminal buffer cell coordinates, sampled from the center of the cell. Useful for
+    /// finding what kind of object is under the cursor, or for styling cells by object type.
+    ///
+    /// Returns `None` if the camera has no
+    /// [RatatuiCameraMaskDetection](crate::RatatuiCameraMaskDetection) or the coordinates are
+    /// outside of the rendered area, and `Some(0)` where no masked mesh is visible.
+    pub fn mask_at_cell(&self, area: Rect, cell_coords: IVec2) -> Option<u8> {
+        let mask_image = self.mask_image.as_ref()?;
+        let (pixel_x, pixel_y) = self.pixel_at_cell(area, cell_coords, mask_image)?;
+
+        Some(mask_image.get_pixel(pixel_x, pixel_y).0[0])
+    }
+
+    /// Reconstruct the world-space position of the rendered surface under a pair of terminal
+    /// buffer cell coordinates, using the depth image (see [RatatuiCameraWidget::depth_at_cell]).
+    /// Useful for point-and-click interactions with the scene's actual geometry. The camera and
+    /// global transform should belong to this widget's camera entity.
+    ///
+    /// Returns `None` if no depth is available for the cell, or if nothing was rendered there.
+    pub fn cell_to_world(
+        &self,
+        area: Rect,
+        cell_coords: IVec2,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+    ) -> Option<Vec3> {
+        let depth = self.depth_at_cell(area, cell_coords)?;
+        if depth <= 0.0 {
+            return None;
+        }
+
+        let image_position = self.cell_to_image_position(area, cell_coords.as_vec2() + 0.5);
+        let ndc = image_position_to_ndc(image_position, depth);
+
+        camera.ndc_to_world(camera_transform, ndc)
+    }
+
+    /// Cast a ray from the camera through the center of a pair of terminal buffer cell coordinates
+    /// (number of characters from the left edge and top edge of the buffer, respectively), for
+    /// picking or intersecting with the scene (e.g. with `Ray3d::intersect_plane`). Unlike
+    /// [RatatuiCameraWidget::cell_to_world], this does not need depth detection. The camera and
+    /// global transform should belong to this widget's camera entity.
+    ///
+    /// Returns `None` if the camera's projection cannot be inverted.
+    pub fn cell_to_world_ray(
+        &self,
+        area: Rect,
+        cell_coords: IVec2,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+    ) -> Option<Ray3d> {
+        let image_position = self.cell_to_image_position(area, cell_coords.as_vec2() + 0.5);
+        let ndc = image_position_to_ndc(image_position, 1.);
+
+        // the near plane is at a depth of 1.0, and a depth of 0.0 (the far plane of an infinite
+        // perspective projection) would produce NaNs.
+        let near = camera.ndc_to_world(camera_transform, ndc)?;
+        let far = camera.ndc_to_world(camera_transform, ndc.with_z(f32::EPSILON))?;
+
+        Dir3::new(far - near).ok().map(|direction| Ray3d {
+            origin: near,
+            direction,
+        })
+    }
+
+    /// Convert a world-space position into a pair of terminal buffer cell coordinates (number of
+    /// characters from the left edge and top edge of the buffer, respectively), for positioning
+    /// labels or other overlays over objects in the scene. The camera and global transform should
+    /// belong to this widget's camera entity.
+    ///
+    /// Returns `None` if the position is behind the camera or beyond its far plane. The returned
+    /// cell may still lie outside of the render area, if the position is outside of the camera's
+    /// view to the sides.
+    pub fn world_to_cell(
+        &self,
+        area: Rect,
+        world_position: Vec3,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+    ) -> Option<IVec2> {
+        let ndc = camera.world_to_ndc(camera_transform, world_position)?;
+        if !(0.0..=1.0).contains(&ndc.z) {
+            return None;
+        }
+
+        Some(self.ndc_to_cell(area, ndc))
+    }
+}
 
-        IVec2 { x, y }
+/// Convert normalized image coordinates (with `y` pointing down) into NDC, at the provided depth.
+fn image_position_to_ndc(image_position: Vec2, depth: f32) -> Vec3 {
+    Vec3::new(
+        image_position.x * 2. - 1.,
+        1. - image_position.y * 2.,
+        depth,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bevy::math::{IVec2, Vec3};
+    use image::{DynamicImage, Rgba, RgbaImage};
+    use ratatui::layout::Rect;
+
+    use crate::{
+        FillMode, ImageAlignment, RatatuiCameraRenderOptions, RatatuiCameraWidget, ViewportConfig,
+    };
+
+    fn widget_rendered_with(fill_mode: FillMode, alignment: ImageAlignment) -> RatatuiCameraWidget {
+        let image = RgbaImage::from_pixel(8, 8, Rgba([255, 255, 255, 255]));
+        let mut widget = RatatuiCameraWidget::from_image(Arc::new(DynamicImage::ImageRgba8(image)));
+        widget.last_render_options = RatatuiCameraRenderOptions {
+            fill_mode,
+            alignment,
+        };
+        widget
+    }
+
+    #[test]
+    fn cells_map_through_the_alignment_of_the_last_render() {
+        let widget = widget_rendered_with(FillMode::Fit, ImageAlignment::TopLeft);
+        let area = Rect::new(0, 0, 40, 4);
+
+        assert_eq!(
+            widget.cell_to_ndc(area, IVec2::ZERO),
+            Vec3::new(-1., 1., 0.5)
+        );
+    }
+
+    #[test]
+    fn cells_map_through_the_viewport() {
+        let mut widget = widget_rendered_with(FillMode::Stretch, ImageAlignment::Center);
+        widget.viewport = ViewportConfig {
+            zoom: 2.,
+            ..ViewportConfig::default()
+        };
+        let area = Rect::new(0, 0, 8, 4);
+
+        assert_eq!(
+            widget.cell_to_ndc(area, IVec2::ZERO),
+            Vec3::new(-0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            widget.cell_to_ndc(area, IVec2::new(8, 4)),
+            Vec3::new(0.5, -0.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn cropped_cells_round_trip_through_ndc() {
+        let widget = widget_rendered_with(FillMode::Crop, ImageAlignment::Top);
+        let area = Rect::new(2, 1, 40, 4);
+
+        for cell in [IVec2::new(2, 1), IVec2::new(20, 3), IVec2::new(41, 4)] {
+            let ndc = widget.cell_to_ndc(area, cell) + Vec3::new(0.01, -0.01, 0.);
+            assert_eq!(widget.ndc_to_cell(area, ndc), cell);
+        }
     }
 }
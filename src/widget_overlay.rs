@@ -0,0 +1,346 @@
+use std::{any::TypeId, cmp::Ordering, fmt, marker::PhantomData, sync::Arc};
+
+use bevy::prelude::*;
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+};
+
+use crate::{
+    RatatuiCameraDepthBuffer, RatatuiCameraLatency, RatatuiCameraLayer, RatatuiCameraLowLatencySet,
+    RatatuiCameraSet, RatatuiCameraWidget, RatatuiScreenAnchor,
+};
+
+/// Add this plugin (once for each overlay component) to draw every entity with the component over
+/// each RatatuiCamera's widget, see [RatatuiOverlay].
+pub struct RatatuiOverlayPlugin<T>(PhantomData<T>);
+
+impl<T> fmt::Debug for RatatuiOverlayPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RatatuiOverlayPlugin").finish()
+    }
+}
+
+impl<T> Default for RatatuiOverlayPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: RatatuiOverlay + Component + Clone> Plugin for RatatuiOverlayPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            First,
+            project_ratatui_overlays_system::<T>.after(RatatuiCameraSet),
+        )
+        .add_systems(
+            PostUpdate,
+            project_ratatui_overlays_system::<T>
+                .after(RatatuiCameraLowLatencySet)
+                .run_if(|latency: Res<RatatuiCameraLatency>| {
+                    *latency != RatatuiCameraLatency::Standard
+                }),
+        );
+    }
+}
+
+/// Implement this trait for a component to draw something over each RatatuiCamera's widget at the
+/// terminal cell its entity appears at (e.g. a name label or a health bar), occluded by anything
+/// in the scene closer to the camera than the entity. This formalizes the pattern of projecting
+/// entities yourself and drawing them with
+/// [RatatuiCameraWidget::render_overlay_with_depth].
+///
+/// Register the component with a [RatatuiOverlayPlugin]. Each frame, every entity with the
+/// component and a `GlobalTransform` is projected through each RatatuiCamera, and drawn whenever
+/// that camera's widget is rendered (after the camera's image, within the same render area).
+/// Entities with a [RatatuiScreenAnchor] are instead anchored to a point of the render area, and
+/// are never occluded, for HUD elements that do not follow anything in the world. Overlays are
+/// drawn in the order of their [RatatuiOverlayOrder], so insert a [RatatuiOverlayLayer] to draw
+/// an entity's overlays over (or under) the others.
+/// Overlays are only occluded when the widget is rendered with a depth buffer (see
+/// [RatatuiCameraWidget::new_depth_buffer]), as chosen by [RatatuiOverlay::occlusion]. Entities
+/// behind the camera or hidden through bevy's `Visibility` are skipped. See
+/// [RatatuiBarOverlay](crate::RatatuiBarOverlay), [RatatuiBadgeOverlay](crate::RatatuiBadgeOverlay)
+/// and [RatatuiDistanceOverlay](crate::RatatuiDistanceOverlay) for ready-made overlays.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiOverlay, RatatuiOverlayAnchor, RatatuiOverlayPlugin};
+/// # use ratatui::{buffer::Buffer, layout::{Position, Rect}, style::Style};
+/// #
+/// #[derive(Component, Clone)]
+/// struct NameLabel(String);
+///
+/// impl RatatuiOverlay for NameLabel {
+///     fn render_overlay(&self, anchor: RatatuiOverlayAnchor, area: Rect, buf: &mut Buffer) {
+///         let IVec2 { x, y } = anchor.cell;
+///         let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) else {
+///             return;
+///         };
+///
+///         if area.contains(Position::new(x, y)) {
+///             let width = (area.right() - x) as usize;
+///             buf.set_stringn(x, y, &self.0, width, Style::default());
+///         }
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(RatatuiOverlayPlugin::<NameLabel>::default());
+/// ```
+///
+pub trait RatatuiOverlay: Send + Sync + 'static {
+    /// Draw the overlay into the buffer, anchored where its entity appears (which may lie outside
+    /// of the area). The area is the camera widget's render area, and the buffer only covers that
+    /// area. Cells left untouched are not drawn over the camera's image.
+    fn render_overlay(&self, anchor: RatatuiOverlayAnchor, area: Rect, buf: &mut Buffer);
+
+    /// How the overlay is hidden behind the scene, when the widget is rendered with a depth
+    /// buffer. Defaults to [OverlayOcclusion::PerCell].
+    fn occlusion(&self) -> OverlayOcclusion {
+        OverlayOcclusion::PerCell
+    }
+}
+
+/// Where an overlay's entity appears in a camera's view, see [RatatuiOverlay::render_overlay].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RatatuiOverlayAnchor {
+    /// The terminal cell the entity appears at.
+    pub cell: IVec2,
+
+    /// Distance from the camera to the entity, in world units.
+    pub distance: f32,
+}
+
+/// How a [RatatuiOverlay] is hidden behind the scene, when the widget is rendered with a depth
+/// buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverlayOcclusion {
+    /// Each cell of the overlay is hidden where the scene is closer to the camera than the
+    /// overlay's entity, and the overlay hides anything drawn later that is further away.
+    #[default]
+    PerCell,
+
+    /// The whole overlay is hidden when the scene is closer to the camera than the overlay's
+    /// entity at the cell the entity appears at, so that e.g. a health bar is never cut in half.
+    WholeOverlay,
+
+    /// The overlay is always drawn on top of the scene.
+    AlwaysVisible,
+}
+
+/// When inserted on an entity with [RatatuiOverlay] components, its overlays are drawn after
+/// (over) those of entities in lower layers, regardless of their distance from the camera.
+/// Entities without one are in layer `0`.
+///
+/// Note that overlays with [OverlayOcclusion::PerCell] still record their depth in the depth
+/// buffer, so an overlay in a higher layer with the same occlusion is hidden behind it where it is
+/// further from the camera. Use [OverlayOcclusion::WholeOverlay] or
+/// [OverlayOcclusion::AlwaysVisible] for overlays that should always be drawn on top.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RatatuiOverlayLayer(pub i32);
+
+/// The order overlays are drawn in: by layer (lower layers first), then by depth (further from the
+/// camera first), so that nearer overlays in the same layer are drawn over further ones. See
+/// [RatatuiCameraWidget::render_overlays_with_depth] to draw your own overlay widgets in this
+/// order.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RatatuiOverlayOrder {
+    /// Explicit layer, see [RatatuiOverlayLayer].
+    pub layer: i32,
+
+    /// Depth in normalized device coordinates, as returned by `Camera::world_to_ndc` (where
+    /// larger values are closer to the camera).
+    pub depth: f32,
+}
+
+impl RatatuiOverlayOrder {
+    /// Order an overlay by the provided layer and depth.
+    pub fn new(layer: i32, depth: f32) -> Self {
+        Self { layer, depth }
+    }
+}
+
+impl Eq for RatatuiOverlayOrder {}
+
+impl PartialOrd for RatatuiOverlayOrder {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RatatuiOverlayOrder {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.layer
+            .cmp(&other.layer)
+            .then(self.depth.total_cmp(&other.depth))
+    }
+}
+
+/// An overlay component copied from an entity, along with where that entity appears in a
+/// camera's view.
+#[derive(Clone)]
+pub(crate) struct ProjectedOverlay {
+    type_id: TypeId,
+    order: RatatuiOverlayOrder,
+    ndc: Vec3,
+    distance: f32,
+    screen_anchor: Option<RatatuiScreenAnchor>,
+    overlay: Arc<dyn RatatuiOverlay>,
+}
+
+impl fmt::Debug for ProjectedOverlay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProjectedOverlay")
+            .field("type_id", &self.type_id)
+            .field("order", &self.order)
+            .field("ndc", &self.ndc)
+            .field("distance", &self.distance)
+            .field("screen_anchor", &self.screen_anchor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RatatuiCameraWidget {
+    /// Draw the overlays projected into this widget's camera within the render area (already
+    /// sorted by [RatatuiOverlayOrder]), skipping cells occluded according to the depth buffer if
+    /// one is provided.
+    pub(crate) fn render_projected_overlays(
+        &self,
+        render_area: Rect,
+        buf: &mut Buffer,
+        mut depth_buffer: Option<&mut RatatuiCameraDepthBuffer>,
+    ) {
+        for projected in &self.overlays {
+            let origin = IVec2::new(render_area.x as i32, render_area.y as i32);
+            let relative_cell = match projected.screen_anchor {
+                Some(screen_anchor) => screen_anchor.cell(render_area) - origin,
+                None => IVec2::new(
+                    ((projected.ndc.x / 2. + 0.5) * render_area.width as f32) as i32,
+                    ((-projected.ndc.y / 2. + 0.5) * render_area.height as f32) as i32,
+                ),
+            };
+            let anchor = RatatuiOverlayAnchor {
+                cell: relative_cell + origin,
+                distance: projected.distance,
+            };
+
+            let occlusion = projected.overlay.occlusion();
+            let anchor_occluded = occlusion == OverlayOcclusion::WholeOverlay
+                && depth_buffer.as_deref().is_some_and(|depth_buffer| {
+                    is_occluded(depth_buffer, relative_cell, projected.ndc.z)
+                });
+            if anchor_occluded {
+                continue;
+            }
+
+            let mut overlay_buf = Buffer::empty(render_area);
+            projected
+                .overlay
+                .render_overlay(anchor, render_area, &mut overlay_buf);
+
+            for position in render_area.positions() {
+                let cell = &overlay_buf[position];
+                if *cell == Cell::EMPTY {
+                    continue;
+                }
+
+                // depth buffers hold two depths per cell, for the upper and lower half.
+                let x = (position.x - render_area.x) as usize;
+                let y = (position.y - render_area.y) as usize * 2;
+                let (draw_bg, draw_fg) = match depth_buffer.as_deref_mut() {
+                    Some(depth_buffer) if occlusion == OverlayOcclusion::PerCell => (
+                        depth_buffer.compare_and_update(x, y, projected.ndc.z) != Some(false),
+                        depth_buffer.compare_and_update(x, y + 1, projected.ndc.z) != Some(false),
+                    ),
+                    _ => (true, true),
+                };
+
+                let target = &mut buf[position];
+                if draw_bg {
+                    target.set_bg(cell.bg);
+                }
+                if draw_fg {
+                    target.set_fg(cell.fg).set_symbol(cell.symbol());
+                    target.modifier = cell.modifier;
+                }
+            }
+        }
+    }
+}
+
+/// Whether both halves of the cell (relative to the depth buffer's area) hold depths closer to
+/// the camera than the provided depth.
+fn is_occluded(depth_buffer: &RatatuiCameraDepthBuffer, cell: IVec2, depth: f32) -> bool {
+    let (Ok(x), Ok(y)) = (usize::try_from(cell.x), usize::try_from(cell.y)) else {
+        return false;
+    };
+
+    [y * 2, y * 2 + 1].into_iter().all(|y| {
+        depth_buffer
+            .get(x, y)
+            .is_some_and(|recorded| recorded > depth)
+    })
+}
+
+fn project_ratatui_overlays_system<T: RatatuiOverlay + Component + Clone>(
+    mut ratatui_cameras: Query<
+        (&Camera, &GlobalTransform, &mut RatatuiCameraWidget),
+        Without<RatatuiCameraLayer>,
+    >,
+    overlays: Query<(
+        &T,
+        Option<&GlobalTransform>,
+        Option<&RatatuiScreenAnchor>,
+        Option<&RatatuiOverlayLayer>,
+        Option<&InheritedVisibility>,
+    )>,
+) {
+    let type_id = TypeId::of::<T>();
+
+    for (camera, camera_transform, mut widget) in &mut ratatui_cameras {
+        widget
+            .overlays
+            .retain(|projected| projected.type_id != type_id);
+
+        for (overlay, transform, screen_anchor, layer, visibility) in &overlays {
+            if visibility.is_some_and(|visibility| !visibility.get()) {
+                continue;
+            }
+
+            // screen anchored overlays are drawn at the nearest depth, so nothing occludes them.
+            let (ndc, distance) = match (screen_anchor, transform) {
+                (Some(_), _) => (Vec3::Z, 0.),
+                (None, Some(transform)) => {
+                    let Some(ndc) = camera
+                        .world_to_ndc(camera_transform, transform.translation())
+                        .filter(|ndc| (0.0..=1.0).contains(&ndc.z))
+                    else {
+                        continue;
+                    };
+
+                    let distance = camera_transform
+                        .translation()
+                        .distance(transform.translation());
+
+                    (ndc, distance)
+                }
+                (None, None) => continue,
+            };
+
+            widget.overlays.push(ProjectedOverlay {
+                type_id,
+                order: RatatuiOverlayOrder::new(layer.copied().unwrap_or_default().0, ndc.z),
+                ndc,
+                distance,
+                screen_anchor: screen_anchor.copied(),
+                overlay: Arc::new(overlay.clone()),
+            });
+        }
+
+        // kept sorted so that rendering draws them in a single pass, without allocating.
+        widget.overlays.sort_by_key(|projected| projected.order);
+    }
+}
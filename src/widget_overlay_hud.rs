@@ -0,0 +1,319 @@
+use bevy::prelude::*;
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use crate::{OverlayOcclusion, RatatuiOverlay, RatatuiOverlayAnchor};
+
+/// Characters for the partially filled cell at the end of a bar, in eighths.
+const BAR_EIGHTHS: [&str; 9] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+
+/// A progress or health bar drawn over each RatatuiCamera's widget, centered on the cell its
+/// entity appears at (see [RatatuiOverlay]). Partially filled cells are drawn with eighth block
+/// characters. Place the entity where the bar should appear (e.g. as a child entity above a
+/// character's head), or shift it with `offset`.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiBarOverlay;
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     Transform::from_xyz(0., 2., 0.),
+///     RatatuiBarOverlay::new(0.75).with_width(8),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiBarOverlay {
+    /// How full the bar is, from `0.0` to `1.0`.
+    pub value: f32,
+
+    /// Width of the bar, in terminal cells.
+    pub width: u16,
+
+    /// Color of the filled part of the bar.
+    pub filled_color: Color,
+
+    /// Color of the empty part of the bar.
+    pub empty_color: Color,
+
+    /// Offset (in terminal cells) of the bar's center from the cell its entity appears at.
+    pub offset: IVec2,
+
+    /// How the bar is hidden behind the scene.
+    pub occlusion: OverlayOcclusion,
+}
+
+impl Default for RatatuiBarOverlay {
+    fn default() -> Self {
+        Self {
+            value: 1.,
+            width: 10,
+            filled_color: Color::Green,
+            empty_color: Color::DarkGray,
+            offset: IVec2::ZERO,
+            occlusion: OverlayOcclusion::WholeOverlay,
+        }
+    }
+}
+
+impl RatatuiBarOverlay {
+    /// Create a bar filled to the provided value, from `0.0` to `1.0`.
+    pub fn new(value: f32) -> Self {
+        Self { value, ..default() }
+    }
+
+    /// Use the provided width, in terminal cells.
+    pub fn with_width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Use the provided colors for the filled and empty parts of the bar.
+    pub fn with_colors(mut self, filled_color: Color, empty_color: Color) -> Self {
+        self.filled_color = filled_color;
+        self.empty_color = empty_color;
+        self
+    }
+
+    /// Offset the bar from the cell its entity appears at, in terminal cells.
+    pub fn with_offset(mut self, offset: IVec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Hide the bar behind the scene as provided.
+    pub fn with_occlusion(mut self, occlusion: OverlayOcclusion) -> Self {
+        self.occlusion = occlusion;
+        self
+    }
+}
+
+impl RatatuiOverlay for RatatuiBarOverlay {
+    fn render_overlay(&self, anchor: RatatuiOverlayAnchor, area: Rect, buf: &mut Buffer) {
+        let center = anchor.cell + self.offset;
+        let left = center.x - self.width as i32 / 2;
+        let filled = self.value.clamp(0., 1.) * self.width as f32;
+
+        for index in 0..self.width {
+            let Some(cell) = overlay_cell(area, buf, IVec2::new(left + index as i32, center.y))
+            else {
+                continue;
+            };
+
+            let eighths = ((filled - index as f32).clamp(0., 1.) * 8.).round() as usize;
+            cell.set_symbol(BAR_EIGHTHS[eighths])
+                .set_fg(self.filled_color)
+                .set_bg(self.empty_color);
+        }
+    }
+
+    fn occlusion(&self) -> OverlayOcclusion {
+        self.occlusion
+    }
+}
+
+/// A short piece of text (e.g. an icon or a marker like `!`) drawn over each RatatuiCamera's
+/// widget, centered on the cell its entity appears at (see [RatatuiOverlay]).
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiBadgeOverlay;
+/// # use ratatui::style::{Color, Style};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     Transform::from_xyz(0., 2., 0.),
+///     RatatuiBadgeOverlay::new("!").with_style(Style::new().fg(Color::Yellow)),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiBadgeOverlay {
+    /// The text drawn.
+    pub text: String,
+
+    /// Style of the text.
+    pub style: Style,
+
+    /// Offset (in terminal cells) of the text's center from the cell its entity appears at.
+    pub offset: IVec2,
+
+    /// How the badge is hidden behind the scene.
+    pub occlusion: OverlayOcclusion,
+}
+
+impl Default for RatatuiBadgeOverlay {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            style: Style::default(),
+            offset: IVec2::ZERO,
+            occlusion: OverlayOcclusion::WholeOverlay,
+        }
+    }
+}
+
+impl RatatuiBadgeOverlay {
+    /// Create a badge drawing the provided text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..default()
+        }
+    }
+
+    /// Use the provided style for the text.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Offset the badge from the cell its entity appears at, in terminal cells.
+    pub fn with_offset(mut self, offset: IVec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Hide the badge behind the scene as provided.
+    pub fn with_occlusion(mut self, occlusion: OverlayOcclusion) -> Self {
+        self.occlusion = occlusion;
+        self
+    }
+}
+
+impl RatatuiOverlay for RatatuiBadgeOverlay {
+    fn render_overlay(&self, anchor: RatatuiOverlayAnchor, area: Rect, buf: &mut Buffer) {
+        draw_centered_text(area, buf, anchor.cell + self.offset, &self.text, self.style);
+    }
+
+    fn occlusion(&self) -> OverlayOcclusion {
+        self.occlusion
+    }
+}
+
+/// The distance from the camera to the entity (e.g. `12.5m`), drawn over each RatatuiCamera's
+/// widget centered on the cell its entity appears at (see [RatatuiOverlay]). Useful for
+/// waypoints and objective markers.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiDistanceOverlay;
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// commands.spawn((
+///     Transform::from_xyz(10., 0., -20.),
+///     RatatuiDistanceOverlay::default().with_offset(IVec2::new(0, 1)),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Debug)]
+pub struct RatatuiDistanceOverlay {
+    /// Number of digits shown after the decimal point.
+    pub precision: usize,
+
+    /// Text shown after the distance, e.g. a unit.
+    pub suffix: String,
+
+    /// Style of the text.
+    pub style: Style,
+
+    /// Offset (in terminal cells) of the text's center from the cell its entity appears at.
+    pub offset: IVec2,
+
+    /// How the text is hidden behind the scene.
+    pub occlusion: OverlayOcclusion,
+}
+
+impl Default for RatatuiDistanceOverlay {
+    fn default() -> Self {
+        Self {
+            precision: 1,
+            suffix: "m".into(),
+            style: Style::default(),
+            offset: IVec2::ZERO,
+            occlusion: OverlayOcclusion::AlwaysVisible,
+        }
+    }
+}
+
+impl RatatuiDistanceOverlay {
+    /// Show the provided number of digits after the decimal point.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Show the provided text after the distance.
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Use the provided style for the text.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Offset the text from the cell its entity appears at, in terminal cells.
+    pub fn with_offset(mut self, offset: IVec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Hide the text behind the scene as provided.
+    pub fn with_occlusion(mut self, occlusion: OverlayOcclusion) -> Self {
+        self.occlusion = occlusion;
+        self
+    }
+}
+
+impl RatatuiOverlay for RatatuiDistanceOverlay {
+    fn render_overlay(&self, anchor: RatatuiOverlayAnchor, area: Rect, buf: &mut Buffer) {
+        let text = format!("{:.*}{}", self.precision, anchor.distance, self.suffix);
+        draw_centered_text(area, buf, anchor.cell + self.offset, &text, self.style);
+    }
+
+    fn occlusion(&self) -> OverlayOcclusion {
+        self.occlusion
+    }
+}
+
+/// The cell at the provided position, if it lies within the area.
+fn overlay_cell(area: Rect, buf: &mut Buffer, position: IVec2) -> Option<&mut Cell> {
+    let (Ok(x), Ok(y)) = (u16::try_from(position.x), u16::try_from(position.y)) else {
+        return None;
+    };
+
+    area.contains((x, y).into())
+        .then(|| buf.cell_mut((x, y)))
+        .flatten()
+}
+
+/// Draw a line of text centered on the provided cell, clipped to the area.
+fn draw_centered_text(area: Rect, buf: &mut Buffer, center: IVec2, text: &str, style: Style) {
+    let line = Line::from(text);
+    let mut x = center.x - line.width() as i32 / 2;
+
+    for grapheme in line.styled_graphemes(style) {
+        let width = Span::raw(grapheme.symbol).width() as i32;
+        if let Some(cell) = overlay_cell(area, buf, IVec2::new(x, center.y)) {
+            cell.set_symbol(grapheme.symbol).set_style(grapheme.style);
+        }
+        x += width;
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Debug;
+
+use bevy::{
+    ecs::{
+        archetype::Archetypes,
+        component::{ComponentId, Components},
+        entity::Entities,
+        system::SystemParam,
+    },
+    prelude::*,
+};
+
+use crate::{RatatuiCameraOrder, RatatuiCameraWidget};
+
+/// System param for finding camera widgets in draw systems with several cameras, without a
+/// separate `Single` or `Query` (with `With`/`Without` filters to keep them disjoint) for each
+/// camera. Cameras are looked up by a marker component in the same entity, and can be iterated in
+/// a consistent order.
+///
+/// Only reads which components each camera entity has, so it can be used alongside any other
+/// queries in the same system.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui::RatatuiContext;
+/// # use bevy_ratatui_camera::RatatuiCameraWidgets;
+/// # use ratatui::layout::{Constraint, Layout};
+/// # use ratatui::widgets::Widget;
+/// #
+/// #[derive(Component)]
+/// struct Minimap;
+///
+/// fn draw_scene_system(
+///     mut ratatui: ResMut<RatatuiContext>,
+///     mut widgets: RatatuiCameraWidgets,
+/// ) -> Result {
+///     ratatui.draw(|frame| {
+///         let [main_area, minimap_area] =
+///             Layout::horizontal([Constraint::Fill(3), Constraint::Fill(1)]).areas(frame.area());
+///
+///         if let Some(mut minimap) = widgets.get::<Minimap>() {
+///             minimap.render(minimap_area, frame.buffer_mut());
+///         }
+///
+///         if let Some((_, mut main)) = widgets.iter_without::<Minimap>().next() {
+///             main.render(main_area, frame.buffer_mut());
+///         }
+///     })?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(SystemParam)]
+pub struct RatatuiCameraWidgets<'w, 's> {
+    widgets: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static mut RatatuiCameraWidget,
+            Option<&'static RatatuiCameraOrder>,
+        ),
+    >,
+    entities: &'w Entities,
+    archetypes: &'w Archetypes,
+    components: &'w Components,
+}
+
+impl Debug for RatatuiCameraWidgets<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RatatuiCameraWidgets")
+            .field("widgets", &self.widgets)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RatatuiCameraWidgets<'_, '_> {
+    /// The widget of the camera with the marker component `M`. If more than one camera has the
+    /// marker, the first in the order of [RatatuiCameraWidgets::iter_ordered] is returned.
+    pub fn get<M: Component>(&mut self) -> Option<Mut<'_, RatatuiCameraWidget>> {
+        self.iter_with::<M>().next().map(|(_, widget)| widget)
+    }
+
+    /// The widget of the provided camera entity, if it has one.
+    pub fn get_entity(&mut self, entity: Entity) -> Option<Mut<'_, RatatuiCameraWidget>> {
+        self.widgets
+            .get_mut(entity)
+            .ok()
+            .map(|(_, widget, _)| widget)
+    }
+
+    /// Whether any camera widget belongs to a camera with the marker component `M`.
+    pub fn contains<M: Component>(&self) -> bool {
+        let Some(component_id) = self.components.component_id::<M>() else {
+            return false;
+        };
+
+        self.widgets
+            .iter()
+            .any(|(entity, _, _)| self.has_component(entity, component_id))
+    }
+
+    /// Every camera widget with its camera entity, ordered by each camera's [RatatuiCameraOrder]
+    /// (lowest first, with cameras without one ordered as `0`) and then by entity, so that draw
+    /// order is the same every frame.
+    pub fn iter_ordered(&mut self) -> impl Iterator<Item = (Entity, Mut<'_, RatatuiCameraWidget>)> {
+        let mut widgets = self.widgets.iter_mut().collect::<Vec<_>>();
+        widgets.sort_by_key(|(entity, _, order)| (order.copied().unwrap_or_default(), *entity));
+
+        widgets
+            .into_iter()
+            .map(|(entity, widget, _)| (entity, widget))
+    }
+
+    /// The widgets of every camera with the marker component `M`, in the order of
+    /// [RatatuiCameraWidgets::iter_ordered].
+    pub fn iter_with<M: Component>(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, Mut<'_, RatatuiCameraWidget>)> {
+        self.iter_filtered::<M>(true)
+    }
+
+    /// The widgets of every camera without the marker component `M`, in the order of
+    /// [RatatuiCameraWidgets::iter_ordered].
+    pub fn iter_without<M: Component>(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, Mut<'_, RatatuiCameraWidget>)> {
+        self.iter_filtered::<M>(false)
+    }
+
+    fn iter_filtered<M: Component>(
+        &mut self,
+        with: bool,
+    ) -> impl Iterator<Item = (Entity, Mut<'_, RatatuiCameraWidget>)> {
+        let component_id = self.components.component_id::<M>();
+        let (entities, archetypes) = (self.entities, self.archetypes);
+
+        self.iter_ordered().filter(move |(entity, _)| {
+            let has_marker = component_id.is_some_and(|component_id| {
+                has_component(entities, archetypes, *entity, component_id)
+            });
+
+            has_marker == with
+        })
+    }
+
+    fn has_component(&self, entity: Entity, component_id: ComponentId) -> bool {
+        has_component(self.entities, self.archetypes, entity, component_id)
+    }
+}
+
+fn has_component(
+    entities: &Entities,
+    archetypes: &Archetypes,
+    entity: Entity,
+    component_id: ComponentId,
+) -> bool {
+    entities
+        .get(entity)
+        .is_some_and(|location| archetypes[location.archetype_id].contains(component_id))
+}
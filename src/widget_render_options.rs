@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use bevy::math::{Rect as ImageRect, UVec2, Vec2};
+use image::{DynamicImage, GenericImageView};
+use ratatui::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{RatatuiCameraDepthBuffer, RatatuiCameraWidget};
+
+/// Region covering the entire image, in normalized image coordinates.
+pub(crate) const FULL_IMAGE_REGION: ImageRect = ImageRect {
+    min: Vec2::ZERO,
+    max: Vec2::ONE,
+};
+
+/// How the camera image is mapped onto the area a [RatatuiCameraWidget] is rendered within.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FillMode {
+    /// Scale the image to fit within the area while preserving its aspect ratio, leaving empty
+    /// gutters on the sides it does not reach (letterboxing).
+    #[default]
+    Fit,
+
+    /// Scale the image to cover the entire area while preserving its aspect ratio, cropping the
+    /// parts of the image that overflow the area.
+    Crop,
+
+    /// Stretch the image to cover exactly the entire area, ignoring its aspect ratio.
+    Stretch,
+}
+
+/// Where the image is placed within the area a [RatatuiCameraWidget] is rendered within, when it
+/// does not cover the entire area (see [FillMode::Fit]). For [FillMode::Crop], this instead
+/// chooses which part of the image is kept, e.g. `Top` keeps the top of the image when its bottom
+/// is cut off.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImageAlignment {
+    /// Pin the image to the top left corner.
+    TopLeft,
+
+    /// Pin the image to the top edge, centered horizontally.
+    Top,
+
+    /// Pin the image to the top right corner.
+    TopRight,
+
+    /// Pin the image to the left edge, centered vertically.
+    Left,
+
+    /// Center the image (default).
+    #[default]
+    Center,
+
+    /// Pin the image to the right edge, centered vertically.
+    Right,
+
+    /// Pin the image to the bottom left corner.
+    BottomLeft,
+
+    /// Pin the image to the bottom edge, centered horizontally.
+    Bottom,
+
+    /// Pin the image to the bottom right corner.
+    BottomRight,
+}
+
+impl ImageAlignment {
+    /// Fraction of the leftover space on each axis that comes before the image.
+    fn fraction(self) -> Vec2 {
+        let x = match self {
+            Self::TopLeft | Self::Left | Self::BottomLeft => 0.,
+            Self::Top | Self::Center | Self::Bottom => 0.5,
+            Self::TopRight | Self::Right | Self::BottomRight => 1.,
+        };
+        let y = match self {
+            Self::TopLeft | Self::Top | Self::TopRight => 0.,
+            Self::Left | Self::Center | Self::Right => 0.5,
+            Self::BottomLeft | Self::Bottom | Self::BottomRight => 1.,
+        };
+
+        Vec2::new(x, y)
+    }
+}
+
+/// Options controlling how a [RatatuiCameraWidget] is drawn, used with
+/// [RatatuiCameraWidget::render_with_options].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RatatuiCameraRenderOptions {
+    /// How the camera image is mapped onto the area.
+    pub fill_mode: FillMode,
+
+    /// Where the image is placed within the area, or which part of it is kept when cropped.
+    pub alignment: ImageAlignment,
+}
+
+impl RatatuiCameraWidget {
+    /// Draw the widget the same way as its `Widget` implementation (or its `StatefulWidget`
+    /// implementation, if a depth buffer is provided), using the provided options to choose how
+    /// the camera image maps onto the area.
+    ///
+    /// The options are kept for the next frame's widget (see
+    /// [RatatuiCameraWidget::last_render_options]), so that its coordinate conversion methods
+    /// (e.g. `cell_to_ndc()`) map cells the same way. Note that `render_overlay()` assumes the
+    /// default [FillMode::Fit]. Use `calculate_render_area_with_options()` to find the area the
+    /// image is drawn within for other modes.
+    pub fn render_with_options(
+        &mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        depth_buffer: Option<&mut RatatuiCameraDepthBuffer>,
+        options: &RatatuiCameraRenderOptions,
+    ) {
+        self.render_common(area, buf, depth_buffer, options);
+    }
+
+    /// Draw only a region of the camera image within the area, letterboxed to preserve the region's
+    /// aspect ratio. The source region is in normalized image coordinates (from `0.0` to `1.0`
+    /// across each axis, with `y` pointing down), and replaces the widget's viewport. Useful for
+    /// split-screen or magnifier widgets showing several parts of one camera's image.
+    ///
+    /// Unlike the regular render methods, this does not resize the camera to fit the area, and
+    /// does not record statistics or cell changes, so the same widget can be drawn cropped any
+    /// number of times alongside its regular render.
+    ///
+    /// # Example:
+    ///
+    /// ```no_run
+    /// # use bevy::math::Rect as ImageRect;
+    /// # use bevy_ratatui_camera::RatatuiCameraWidget;
+    /// # use ratatui::prelude::*;
+    /// # fn draw(camera_widget: &RatatuiCameraWidget, area: Rect, buf: &mut Buffer) {
+    /// let [left, right] = Layout::horizontal([Constraint::Fill(1); 2]).areas(area);
+    /// camera_widget.render_cropped(left, buf, ImageRect::new(0., 0., 0.5, 1.));
+    /// camera_widget.render_cropped(right, buf, ImageRect::new(0.5, 0., 1., 1.));
+    /// # }
+    /// ```
+    pub fn render_cropped(&self, area: Rect, buf: &mut Buffer, source: ImageRect) {
+        let source = source.intersect(FULL_IMAGE_REGION);
+        if source.is_empty() {
+            return;
+        }
+
+        let mut cropped = self.clone();
+        cropped.crop_images(source);
+
+        let render_area = cropped.calculate_render_area(area);
+        cropped.render_with_layers(render_area, buf, None);
+        cropped.character_support.downgrade_buffer(render_area, buf);
+    }
+
+    /// See [RatatuiCameraWidget::calculate_render_area]. This variant takes the fill mode of the
+    /// provided options into account, as the image covers the entire area unless it is letterboxed,
+    /// in which case it is placed according to the alignment.
+    pub fn calculate_render_area_with_options(
+        &self,
+        area: Rect,
+        options: &RatatuiCameraRenderOptions,
+    ) -> Rect {
+        match options.fill_mode {
+            FillMode::Fit => {
+                let render_area = self.calculate_render_area(area);
+                let gutters = Vec2::new(
+                    (area.width - render_area.width) as f32,
+                    (area.height - render_area.height) as f32,
+                );
+                let offset = (gutters * options.alignment.fraction()).floor();
+
+                Rect {
+                    x: area.x + offset.x as u16,
+                    y: area.y + offset.y as u16,
+                    ..render_area
+                }
+            }
+            FillMode::Crop | FillMode::Stretch => area,
+        }
+    }
+
+    /// Calculate the region of the camera image that will be drawn, in normalized image
+    /// coordinates (from `0.0` to `1.0` across each axis), covering the widget's viewport and then
+    /// cropped according to the fill mode.
+    pub(crate) fn calculate_source_region(
+        &self,
+        area: Rect,
+        options: &RatatuiCameraRenderOptions,
+    ) -> ImageRect {
+        let viewport = self.viewport.region();
+
+        if options.fill_mode != FillMode::Crop || area.is_empty() {
+            return viewport;
+        }
+
+        let scale = area.width as f32 / area.height as f32 / self.aspect_ratio();
+        let size = if scale < 1. {
+            Vec2::new(scale, 1.)
+        } else {
+            Vec2::new(1., 1. / scale)
+        };
+
+        let min = (Vec2::ONE - size) * options.alignment.fraction();
+        let viewport_size = viewport.size();
+
+        ImageRect::from_corners(
+            viewport.min + min * viewport_size,
+            viewport.min + (min + size) * viewport_size,
+        )
+    }
+
+    /// Replace each of the widget's images (and those of its layers) with the provided region of
+    /// itself, in normalized image coordinates.
+    pub(crate) fn crop_images(&mut self, region: ImageRect) {
+        let crop = |image: &Arc<DynamicImage>| Arc::new(crop_image(image, region));
+
+        self.camera_image = crop(&self.camera_image);
+
+        for image in [
+            &mut self.depth_image,
+            &mut self.sobel_image,
+            &mut self.normal_image,
+            &mut self.emissive_image,
+            &mut self.edge_color_image,
+            &mut self.mask_image,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *image = crop(image);
+        }
+
+        // cells converted on the GPU cover the entire image, so the CPU converts cropped images.
+        #[cfg(feature = "gpu_cells")]
+        {
+            self.gpu_cells_image = None;
+        }
+
+        for layer in &mut self.layers {
+            layer.crop_images(region);
+        }
+    }
+}
+
+/// Crop an image to a region in normalized image coordinates, keeping at least one pixel.
+fn crop_image(image: &DynamicImage, region: ImageRect) -> DynamicImage {
+    let dimensions = UVec2::from(image.dimensions());
+    let size = dimensions.as_vec2();
+
+    let min = (region.min * size)
+        .floor()
+        .as_uvec2()
+        .min(dimensions.saturating_sub(UVec2::ONE));
+    let max = (region.max * size)
+        .ceil()
+        .as_uvec2()
+        .max(min + 1)
+        .min(dimensions);
+
+    image.crop_imm(min.x, min.y, max.x - min.x, max.y - min.y)
+}
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+use ratatui::layout::Rect;
+
+/// A point of a camera widget's render area (the area the camera's image is actually drawn
+/// within, see
+/// [RatatuiCameraWidget::calculate_render_area](crate::RatatuiCameraWidget::calculate_render_area)),
+/// for positioning HUD elements relative to the camera's image rather than the full frame, as the
+/// image shifts within the frame when it is letterboxed.
+///
+/// Insert it alongside a [RatatuiOverlay](crate::RatatuiOverlay) component to anchor the overlay
+/// to the render area instead of projecting it from the entity's position in the world, or use
+/// [RatatuiScreenAnchor::area] to position ratatui widgets you draw yourself.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::{RatatuiBarOverlay, RatatuiScreenAnchor, ScreenAnchorPosition};
+/// #
+/// # fn setup_scene_system(mut commands: Commands) {
+/// // a health bar in the bottom left corner of the camera's image.
+/// commands.spawn((
+///     RatatuiBarOverlay::new(1.).with_width(12),
+///     RatatuiScreenAnchor::new(ScreenAnchorPosition::BottomLeft).with_offset(IVec2::new(7, -1)),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RatatuiScreenAnchor {
+    /// The point of the render area anchored to.
+    pub position: ScreenAnchorPosition,
+
+    /// Offset from that point, in terminal cells.
+    pub offset: IVec2,
+}
+
+/// Points of a render area that a [RatatuiScreenAnchor] can anchor to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScreenAnchorPosition {
+    /// The top left cell.
+    TopLeft,
+
+    /// The middle cell of the top row.
+    Top,
+
+    /// The top right cell.
+    TopRight,
+
+    /// The middle cell of the leftmost column.
+    Left,
+
+    /// The middle cell.
+    #[default]
+    Center,
+
+    /// The middle cell of the rightmost column.
+    Right,
+
+    /// The bottom left cell.
+    BottomLeft,
+
+    /// The middle cell of the bottom row.
+    Bottom,
+
+    /// The bottom right cell.
+    BottomRight,
+}
+
+impl ScreenAnchorPosition {
+    /// Fraction of the way across and down an area the position lies.
+    fn fraction(&self) -> Vec2 {
+        match self {
+            Self::TopLeft => Vec2::new(0., 0.),
+            Self::Top => Vec2::new(0.5, 0.),
+            Self::TopRight => Vec2::new(1., 0.),
+            Self::Left => Vec2::new(0., 0.5),
+            Self::Center => Vec2::new(0.5, 0.5),
+            Self::Right => Vec2::new(1., 0.5),
+            Self::BottomLeft => Vec2::new(0., 1.),
+            Self::Bottom => Vec2::new(0.5, 1.),
+            Self::BottomRight => Vec2::new(1., 1.),
+        }
+    }
+}
+
+impl RatatuiScreenAnchor {
+    /// Anchor to the provided point of the render area, without an offset.
+    pub fn new(position: ScreenAnchorPosition) -> Self {
+        Self {
+            position,
+            offset: IVec2::ZERO,
+        }
+    }
+
+    /// Offset from the anchored point, in terminal cells.
+    pub fn with_offset(mut self, offset: IVec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// The cell anchored to within the render area, including the offset (which may place it
+    /// outside of the render area).
+    pub fn cell(&self, render_area: Rect) -> IVec2 {
+        let last_cell = IVec2::new(
+            render_area.width.saturating_sub(1) as i32,
+            render_area.height.saturating_sub(1) as i32,
+        );
+        let relative = (last_cell.as_vec2() * self.position.fraction())
+            .round()
+            .as_ivec2();
+
+        IVec2::new(render_area.x as i32, render_area.y as i32) + relative + self.offset
+    }
+
+    /// An area of the provided size aligned to the anchored point, e.g. with its bottom right
+    /// corner at the anchored cell for [ScreenAnchorPosition::BottomRight], or centered on it for
+    /// [ScreenAnchorPosition::Center], clipped to the render area. Draw your own ratatui widgets
+    /// within it.
+    pub fn area(&self, render_area: Rect, width: u16, height: u16) -> Rect {
+        let size = IVec2::new(width as i32, height as i32);
+        let alignment = ((size - IVec2::ONE).max(IVec2::ZERO).as_vec2() * self.position.fraction())
+            .round()
+            .as_ivec2();
+        let top_left = self.cell(render_area) - alignment;
+
+        let left = top_left.x.max(render_area.left() as i32);
+        let top = top_left.y.max(render_area.top() as i32);
+        let right = (top_left.x + size.x).min(render_area.right() as i32);
+        let bottom = (top_left.y + size.y).min(render_area.bottom() as i32);
+
+        if right <= left || bottom <= top {
+            return Rect::new(render_area.x, render_area.y, 0, 0);
+        }
+
+        Rect::new(
+            left as u16,
+            top as u16,
+            (right - left) as u16,
+            (bottom - top) as u16,
+        )
+    }
+}
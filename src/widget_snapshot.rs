@@ -0,0 +1,46 @@
+use ratatui::{buffer::Buffer, layout::Rect};
+
+use crate::{RatatuiCameraWidget, buffer_ansi::buffer_to_ansi};
+
+/// Convert the characters of a ratatui buffer into plain text, one line per buffer row.
+fn buffer_to_text(buffer: &Buffer) -> String {
+    let mut text = String::new();
+
+    for y in buffer.area.top()..buffer.area.bottom() {
+        for x in buffer.area.left()..buffer.area.right() {
+            if let Some(cell) = buffer.cell((x, y)) {
+                text.push_str(cell.symbol());
+            }
+        }
+
+        text.push('\n');
+    }
+
+    text
+}
+
+impl RatatuiCameraWidget {
+    /// Draw the widget into a standalone ratatui buffer covering the provided area, without needing
+    /// a terminal or `Frame`. The widget is drawn even if the area differs from the one it was last
+    /// rendered within (the image is letterboxed rather than the camera being resized), and the
+    /// widget itself is left unchanged.
+    pub fn render_to_buffer(&self, area: Rect) -> Buffer {
+        let mut buf = Buffer::empty(area);
+        self.render_detached(area, &mut buf, None);
+
+        buf
+    }
+
+    /// Draw the widget within the provided area (see [RatatuiCameraWidget::render_to_buffer]) and
+    /// return only the characters, one line per row. Intended for golden-file tests of strategies
+    /// and configuration, where colors would make snapshots brittle.
+    pub fn snapshot(&self, area: Rect) -> String {
+        buffer_to_text(&self.render_to_buffer(area))
+    }
+
+    /// See [RatatuiCameraWidget::snapshot]. This variant includes the colors of each cell as ANSI
+    /// escape sequences.
+    pub fn snapshot_with_colors(&self, area: Rect) -> String {
+        buffer_to_ansi(&self.render_to_buffer(area))
+    }
+}
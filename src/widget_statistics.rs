@@ -0,0 +1,97 @@
+use bevy::color::Luminance;
+use image::DynamicImage;
+
+/// Statistics about the image drawn by a camera widget, recorded each time the widget is rendered
+/// (at the resolution of the terminal cells it was drawn to, after any color adjustments). Useful
+/// for auto-tuning exposure, fog, or the contrast of UI drawn over the camera, without scanning
+/// the image again.
+///
+/// Luminance statistics only consider pixels that are not fully transparent. If every pixel is
+/// transparent, the luminance values are zero.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RatatuiCameraImageStatistics {
+    /// Lowest luminance value, between 0.0 and 1.0.
+    pub min_luminance: f32,
+
+    /// Highest luminance value, between 0.0 and 1.0.
+    pub max_luminance: f32,
+
+    /// Mean luminance value, between 0.0 and 1.0.
+    pub mean_luminance: f32,
+
+    /// Count of pixels within each of [RatatuiCameraImageStatistics::HISTOGRAM_BINS] equally
+    /// sized luminance ranges, from darkest to brightest.
+    pub histogram: [u32; RatatuiCameraImageStatistics::HISTOGRAM_BINS],
+
+    /// Fraction of the image's pixels that are fully transparent, between 0.0 and 1.0.
+    pub transparent_fraction: f32,
+}
+
+impl RatatuiCameraImageStatistics {
+    /// Number of bins in the luminance histogram.
+    pub const HISTOGRAM_BINS: usize = 16;
+
+    pub(crate) fn from_image(image: &DynamicImage) -> Self {
+        let mut statistics = Self {
+            min_luminance: f32::MAX,
+            max_luminance: 0.0,
+            ..Self::default()
+        };
+
+        let mut opaque_count = 0;
+        let mut transparent_count = 0;
+        let mut luminance_sum = 0.0;
+
+        for pixel in image.to_rgba8().pixels() {
+            if pixel[3] == 0 {
+                transparent_count += 1;
+                continue;
+            }
+
+            let luminance = bevy::color::Color::srgb_u8(pixel[0], pixel[1], pixel[2]).luminance();
+            let bin =
+                ((luminance * Self::HISTOGRAM_BINS as f32) as usize).min(Self::HISTOGRAM_BINS - 1);
+
+            statistics.min_luminance = statistics.min_luminance.min(luminance);
+            statistics.max_luminance = statistics.max_luminance.max(luminance);
+            statistics.histogram[bin] += 1;
+            luminance_sum += luminance;
+            opaque_count += 1;
+        }
+
+        if opaque_count == 0 {
+            statistics.min_luminance = 0.0;
+        } else {
+            statistics.mean_luminance = luminance_sum / opaque_count as f32;
+        }
+
+        let pixel_count = opaque_count + transparent_count;
+        if pixel_count > 0 {
+            statistics.transparent_fraction = transparent_count as f32 / pixel_count as f32;
+        }
+
+        statistics
+    }
+
+    /// Approximate luminance below which the provided fraction (between 0.0 and 1.0) of
+    /// non-transparent pixels fall, estimated from the histogram. For example, a fraction of 0.95
+    /// gives a brightness level that is more robust to small highlights than `max_luminance`.
+    pub fn luminance_percentile(&self, fraction: f32) -> f32 {
+        let total = self.histogram.iter().sum::<u32>();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let target = fraction.clamp(0.0, 1.0) * total as f32;
+        let mut cumulative = 0;
+
+        for (index, count) in self.histogram.iter().enumerate() {
+            cumulative += count;
+            if cumulative as f32 >= target {
+                return (index + 1) as f32 / Self::HISTOGRAM_BINS as f32;
+            }
+        }
+
+        1.0
+    }
+}
@@ -1,3 +1,5 @@
+use bevy::log::info_span;
+use bevy::math::Mat4;
 use image::{DynamicImage, GenericImageView};
 use ratatui::prelude::*;
 
@@ -16,6 +18,7 @@ pub struct RatatuiCameraWidgetDepth<'a> {
     depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
     strategy_config: &'a DepthConfig,
     edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+    view_from_clip: Mat4,
 }
 
 impl<'a> RatatuiCameraWidgetDepth<'a> {
@@ -26,6 +29,7 @@ impl<'a> RatatuiCameraWidgetDepth<'a> {
         depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
         strategy_config: &'a DepthConfig,
         edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+        view_from_clip: Mat4,
     ) -> Self {
         Self {
             camera_image,
@@ -34,12 +38,15 @@ impl<'a> RatatuiCameraWidgetDepth<'a> {
             depth_buffer,
             strategy_config,
             edge_detection,
+            view_from_clip,
         }
     }
 }
 
 impl Widget for &mut RatatuiCameraWidgetDepth<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let _span = info_span!("ratatui_camera_strategy_depth").entered();
+
         let Some(ref depth_image) = self.depth_image else {
             return;
         };
@@ -47,8 +54,8 @@ impl Widget for &mut RatatuiCameraWidgetDepth<'_> {
         let cell_candidates = convert_image_to_cell_candidates(
             &self.camera_image,
             depth_image,
-            &self.strategy_config.characters.list,
-            self.strategy_config.characters.scale,
+            self.strategy_config,
+            &self.view_from_clip,
         );
 
         for (index, (mut character, mut fg)) in cell_candidates.enumerate() {
@@ -100,12 +107,25 @@ impl Widget for &mut RatatuiCameraWidgetDepth<'_> {
                 &self.strategy_config.colors.background,
             );
 
+            if self.strategy_config.common.passthrough && fg.is_none() {
+                cell.reset();
+                continue;
+            }
+
             if self.strategy_config.common.transparent && fg.is_none() {
                 continue;
             }
 
-            fg = color_for_color_support(fg, self.strategy_config.colors.support);
-            bg = color_for_color_support(bg, self.strategy_config.colors.support);
+            fg = color_for_color_support(
+                fg,
+                self.strategy_config.colors.support,
+                self.strategy_config.colors.distance,
+            );
+            bg = color_for_color_support(
+                bg,
+                self.strategy_config.colors.support,
+                self.strategy_config.colors.distance,
+            );
 
             fg.map(|fg| cell.set_fg(fg).set_char(character));
             bg.map(|bg| cell.set_bg(bg));
@@ -116,13 +136,14 @@ impl Widget for &mut RatatuiCameraWidgetDepth<'_> {
 fn convert_image_to_cell_candidates(
     camera_image: &DynamicImage,
     depth_image: &DynamicImage,
-    depth_characters: &[char],
-    depth_scale: f32,
+    strategy_config: &DepthConfig,
+    view_from_clip: &Mat4,
 ) -> impl Iterator<Item = (char, Option<Color>)> {
     let rgba_quads = convert_image_to_rgba_quads(camera_image, depth_image);
 
     rgba_quads.into_iter().map(move |(rgba, depth)| {
-        let character = convert_depth_to_character(depth, depth_characters, depth_scale);
+        let scaled_depth = strategy_config.scaled_depth(depth, view_from_clip);
+        let character = convert_depth_to_character(scaled_depth, &strategy_config.characters.list);
         let color = if rgba[3] == 0 || depth == 0.0 {
             None
         } else {
@@ -159,10 +180,9 @@ fn convert_image_to_rgba_quads(
     rgba_quads
 }
 
-fn convert_depth_to_character(depth: f32, depth_characters: &[char], depth_scale: f32) -> char {
-    let scaled_depth = (depth * depth_scale).min(1.0);
-    let character_index =
-        ((scaled_depth * depth_characters.len() as f32) as usize).min(depth_characters.len() - 1);
+fn convert_depth_to_character(scaled_depth: f32, depth_characters: &[char]) -> char {
+    let character_index = ((scaled_depth * depth_characters.len() as f32) as usize)
+        .min(depth_characters.len().saturating_sub(1));
 
     let Some(character) = depth_characters.get(character_index) else {
         return ' ';
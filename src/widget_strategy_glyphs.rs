@@ -0,0 +1,232 @@
+use bevy::color::Luminance;
+use bevy::log::info_span;
+use image::{DynamicImage, GenericImageView, RgbaImage};
+use ratatui::prelude::*;
+
+use crate::color_support::color_for_color_support;
+use crate::widget_glyph_masks::{
+    GLYPH_MASK_HEIGHT, GLYPH_MASK_WIDTH, GlyphMask, glyph_mask, mask_covers,
+};
+use crate::widget_utilities::{colors_for_color_choices, map_cells, replace_detected_edges};
+use crate::{GlyphsConfig, RatatuiCameraDepthBuffer, RatatuiCameraEdgeDetection};
+
+#[derive(Debug)]
+pub struct RatatuiCameraWidgetGlyphs<'a> {
+    camera_image: DynamicImage,
+    depth_image: Option<DynamicImage>,
+    sobel_image: Option<DynamicImage>,
+    depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+    strategy_config: &'a GlyphsConfig,
+    edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+}
+
+impl<'a> RatatuiCameraWidgetGlyphs<'a> {
+    /// Unlike the other strategies, the camera image is expected to contain a full glyph mask's
+    /// worth of pixels for each cell (`GLYPH_MASK_WIDTH` by `GLYPH_MASK_HEIGHT`), while the depth
+    /// and sobel images are expected to contain two pixels per cell as usual.
+    pub fn new(
+        camera_image: DynamicImage,
+        depth_image: Option<DynamicImage>,
+        sobel_image: Option<DynamicImage>,
+        depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+        strategy_config: &'a GlyphsConfig,
+        edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+    ) -> Self {
+        Self {
+            camera_image,
+            depth_image,
+            sobel_image,
+            depth_buffer,
+            strategy_config,
+            edge_detection,
+        }
+    }
+}
+
+impl Widget for &mut RatatuiCameraWidgetGlyphs<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let _span = info_span!("ratatui_camera_strategy_glyphs").entered();
+
+        let glyphs = self
+            .strategy_config
+            .characters
+            .list
+            .iter()
+            .filter_map(|character| Some((*character, glyph_mask(*character)?)))
+            .collect::<Vec<_>>();
+
+        if glyphs.is_empty() {
+            return;
+        }
+
+        let camera_image = self.camera_image.to_rgba8();
+        let width = camera_image.width() / GLYPH_MASK_WIDTH;
+        let height = camera_image.height() / GLYPH_MASK_HEIGHT;
+
+        let (width, height) = (width.min(area.width as u32), height.min(area.height as u32));
+
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .collect::<Vec<_>>();
+
+        let cell_candidates = map_cells(&cells, self.strategy_config.common.parallel, |&(x, y)| {
+            let (coverage, rgba) =
+                cell_coverage_and_color(&camera_image, x, y, self.strategy_config.characters.scale);
+
+            let character = closest_glyph(&coverage, &glyphs);
+            let fg = if rgba[3] == 0 {
+                None
+            } else {
+                Some(Color::Rgb(rgba[0], rgba[1], rgba[2]))
+            };
+
+            (character, fg)
+        });
+
+        for y in 0..height {
+            for x in 0..width {
+                let (mut character, mut fg) = cell_candidates[(y * width + x) as usize];
+                let mut bg = None;
+
+                let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) else {
+                    continue;
+                };
+
+                if let (Some(depth_image), Some(depth_buffer)) =
+                    (&self.depth_image, &mut self.depth_buffer)
+                {
+                    if depth_buffer
+                        .compare_and_update_from_image(x, y * 2, depth_image)
+                        .is_none_or(|draw| !draw)
+                    {
+                        continue;
+                    }
+                    if depth_buffer
+                        .compare_and_update_from_image(x, y * 2 + 1, depth_image)
+                        .is_none_or(|draw| !draw)
+                    {
+                        continue;
+                    }
+                }
+
+                if let (Some(sobel_image), Some(edge_detection)) =
+                    (&self.sobel_image, self.edge_detection)
+                {
+                    if !sobel_image.in_bounds(x, y * 2) {
+                        continue;
+                    }
+
+                    let sobel_value = sobel_image.get_pixel(x, y * 2);
+
+                    (character, fg) =
+                        replace_detected_edges(character, fg, &sobel_value, edge_detection);
+                };
+
+                (fg, bg) = colors_for_color_choices(
+                    fg,
+                    bg,
+                    &self.strategy_config.colors.foreground,
+                    &self.strategy_config.colors.background,
+                );
+
+                if self.strategy_config.common.passthrough && fg.is_none() {
+                    cell.reset();
+                    continue;
+                }
+
+                if self.strategy_config.common.transparent && fg.is_none() {
+                    continue;
+                }
+
+                fg = color_for_color_support(
+                    fg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
+                bg = color_for_color_support(
+                    bg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
+
+                fg.map(|fg| cell.set_fg(fg).set_char(character));
+                bg.map(|bg| cell.set_bg(bg));
+            }
+        }
+    }
+}
+
+/// Collect the scaled luminance of each pixel in the cell's block (one value per glyph mask
+/// pixel), along with the average color of the non-transparent pixels in the block.
+fn cell_coverage_and_color(
+    camera_image: &RgbaImage,
+    cell_x: u32,
+    cell_y: u32,
+    scale: f32,
+) -> (
+    [f32; (GLYPH_MASK_WIDTH * GLYPH_MASK_HEIGHT) as usize],
+    [u8; 4],
+) {
+    let mut coverage = [0.0; (GLYPH_MASK_WIDTH * GLYPH_MASK_HEIGHT) as usize];
+    let mut color_sum = [0u32; 3];
+    let mut opaque_count = 0;
+
+    for y in 0..GLYPH_MASK_HEIGHT {
+        for x in 0..GLYPH_MASK_WIDTH {
+            let pixel = camera_image.get_pixel(
+                cell_x * GLYPH_MASK_WIDTH + x,
+                cell_y * GLYPH_MASK_HEIGHT + y,
+            );
+
+            if pixel[3] == 0 {
+                continue;
+            }
+
+            let luminance =
+                bevy::color::Color::srgba_u8(pixel[0], pixel[1], pixel[2], pixel[3]).luminance();
+            coverage[(y * GLYPH_MASK_WIDTH + x) as usize] = (luminance * scale).min(1.0);
+
+            color_sum[0] += pixel[0] as u32;
+            color_sum[1] += pixel[1] as u32;
+            color_sum[2] += pixel[2] as u32;
+            opaque_count += 1;
+        }
+    }
+
+    if opaque_count == 0 {
+        return (coverage, [0; 4]);
+    }
+
+    let rgba = [
+        (color_sum[0] / opaque_count) as u8,
+        (color_sum[1] / opaque_count) as u8,
+        (color_sum[2] / opaque_count) as u8,
+        u8::MAX,
+    ];
+
+    (coverage, rgba)
+}
+
+/// Find the glyph whose coverage mask has the smallest squared distance from the provided pixel
+/// coverage values, so that both the shape and the density of the glyph are taken into account.
+fn closest_glyph(
+    coverage: &[f32; (GLYPH_MASK_WIDTH * GLYPH_MASK_HEIGHT) as usize],
+    glyphs: &[(char, GlyphMask)],
+) -> char {
+    glyphs
+        .iter()
+        .map(|(character, mask)| {
+            let mut distance = 0.0;
+            for y in 0..GLYPH_MASK_HEIGHT {
+                for x in 0..GLYPH_MASK_WIDTH {
+                    let target = if mask_covers(*mask, x, y) { 1.0 } else { 0.0 };
+                    let difference = coverage[(y * GLYPH_MASK_WIDTH + x) as usize] - target;
+                    distance += difference * difference;
+                }
+            }
+            (*character, distance)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(character, _)| character)
+        .unwrap_or(' ')
+}
@@ -1,13 +1,13 @@
+use bevy::log::info_span;
 use image::{DynamicImage, GenericImageView};
 use ratatui::prelude::*;
 
-use crate::RatatuiCameraEdgeDetection;
-use crate::camera_strategy::HalfBlocksConfig;
+use crate::camera_strategy::{HalfBlocksConfig, HalfBlocksMode};
 use crate::color_support::color_for_color_support;
 use crate::widget_depth_buffer::RatatuiCameraDepthBuffer;
-use crate::widget_utilities::{
-    colors_for_color_choices, coords_from_index, replace_detected_edges,
-};
+use crate::widget_glyph_masks::{GLYPH_MASK_HEIGHT, GLYPH_MASK_WIDTH, glyph_mask, mask_covers};
+use crate::widget_utilities::{colors_for_color_choices, map_cells, replace_detected_edges};
+use crate::{RatatuiCameraEdgeDetection, RatatuiCameraStrategy};
 
 #[derive(Debug)]
 pub struct RatatuiCameraWidgetHalf<'a> {
@@ -20,6 +20,9 @@ pub struct RatatuiCameraWidgetHalf<'a> {
 }
 
 impl<'a> RatatuiCameraWidgetHalf<'a> {
+    /// When using `HalfBlocksMode::TwoColor`, the camera image is expected to contain a full
+    /// glyph mask's worth of pixels for each cell (`GLYPH_MASK_WIDTH` by `GLYPH_MASK_HEIGHT`),
+    /// while the depth and sobel images are expected to contain two pixels per cell as usual.
     pub fn new(
         camera_image: DynamicImage,
         depth_image: Option<DynamicImage>,
@@ -41,12 +44,29 @@ impl<'a> RatatuiCameraWidgetHalf<'a> {
 
 impl Widget for &mut RatatuiCameraWidgetHalf<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let cell_candidates =
-            convert_image_to_cell_candidates(&self.camera_image, self.strategy_config);
+        let _span = info_span!("ratatui_camera_strategy_half_blocks").entered();
 
-        for (index, (mut bg, mut fg)) in cell_candidates.enumerate() {
-            let mut character = '▄';
-            let (x, y) = coords_from_index(index, &self.camera_image);
+        let (cell_candidates, cells_width) = match self.strategy_config.mode {
+            HalfBlocksMode::Pixels => (
+                convert_image_to_cell_candidates(&self.camera_image, self.strategy_config),
+                self.camera_image.width(),
+            ),
+            HalfBlocksMode::TwoColor => (
+                convert_image_to_two_color_cell_candidates(
+                    &self.camera_image,
+                    self.strategy_config,
+                ),
+                self.camera_image.width() / GLYPH_MASK_WIDTH,
+            ),
+        };
+
+        if cells_width == 0 {
+            return;
+        }
+
+        for (index, (mut character, mut bg, mut fg)) in cell_candidates.into_iter().enumerate() {
+            let x = (index as u32 % cells_width) as u16;
+            let y = (index as u32 / cells_width) as u16;
 
             if x >= area.width || y >= area.height {
                 continue;
@@ -91,13 +111,38 @@ impl Widget for &mut RatatuiCameraWidgetHalf<'_> {
                 &self.strategy_config.colors.background,
             );
 
+            if self.strategy_config.common.passthrough && draw_bg && draw_fg {
+                match (fg, bg) {
+                    (None, None) => {
+                        cell.reset();
+                        continue;
+                    }
+                    (Some(_), None) => bg = Some(Color::Reset),
+                    // flip the half block, so the transparent half shows the terminal background.
+                    (None, Some(color)) if character == '▄' => {
+                        character = '▀';
+                        fg = Some(color);
+                        bg = Some(Color::Reset);
+                    }
+                    _ => {}
+                }
+            }
+
             if draw_bg {
-                bg = color_for_color_support(bg, self.strategy_config.colors.support);
+                bg = color_for_color_support(
+                    bg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
                 bg.map(|bg| cell.set_bg(bg));
             };
 
             if draw_fg {
-                fg = color_for_color_support(fg, self.strategy_config.colors.support);
+                fg = color_for_color_support(
+                    fg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
                 fg.map(|fg| cell.set_fg(fg).set_char(character));
             };
         }
@@ -107,23 +152,149 @@ impl Widget for &mut RatatuiCameraWidgetHalf<'_> {
 fn convert_image_to_cell_candidates(
     camera_image: &DynamicImage,
     strategy_config: &HalfBlocksConfig,
-) -> impl Iterator<Item = (Option<Color>, Option<Color>)> {
+) -> Vec<(char, Option<Color>, Option<Color>)> {
     let rgba_quads = convert_image_to_rgba_quads(camera_image);
 
-    rgba_quads.into_iter().map(move |rgbas| {
-        let bg = if strategy_config.common.transparent && rgbas[0][3] == 0 {
-            None
-        } else {
-            Some(Color::Rgb(rgbas[0][0], rgbas[0][1], rgbas[0][2]))
-        };
-        let fg = if strategy_config.common.transparent && rgbas[1][3] == 0 {
-            None
-        } else {
-            Some(Color::Rgb(rgbas[1][0], rgbas[1][1], rgbas[1][2]))
-        };
+    rgba_quads
+        .into_iter()
+        .map(|rgbas| {
+            let bg = if (strategy_config.common.transparent || strategy_config.common.passthrough)
+                && rgbas[0][3] == 0
+            {
+                None
+            } else {
+                Some(Color::Rgb(rgbas[0][0], rgbas[0][1], rgbas[0][2]))
+            };
+            let fg = if (strategy_config.common.transparent || strategy_config.common.passthrough)
+                && rgbas[1][3] == 0
+            {
+                None
+            } else {
+                Some(Color::Rgb(rgbas[1][0], rgbas[1][1], rgbas[1][2]))
+            };
+
+            ('▄', bg, fg)
+        })
+        .collect()
+}
+
+/// For each cell's block of pixels, find the block character and the pair of colors that best
+/// represent it. Each candidate block character splits the block's pixels into two clusters
+/// (covered and uncovered by the character), the colors are taken from the average color of each
+/// cluster, and the character whose clusters have the least total color variance is chosen.
+fn convert_image_to_two_color_cell_candidates(
+    camera_image: &DynamicImage,
+    strategy_config: &HalfBlocksConfig,
+) -> Vec<(char, Option<Color>, Option<Color>)> {
+    let camera_image = camera_image.to_rgba8();
+    let cells_width = camera_image.width() / GLYPH_MASK_WIDTH;
+    let cells_height = camera_image.height() / GLYPH_MASK_HEIGHT;
+
+    let glyphs = RatatuiCameraStrategy::CHARACTERS_QUADRANTS
+        .iter()
+        .filter_map(|character| Some((*character, glyph_mask(*character)?)))
+        .collect::<Vec<_>>();
+
+    let cells = (0..cells_height)
+        .flat_map(|cell_y| (0..cells_width).map(move |cell_x| (cell_x, cell_y)))
+        .collect::<Vec<_>>();
+
+    map_cells(
+        &cells,
+        strategy_config.common.parallel,
+        |&(cell_x, cell_y)| {
+            let mut pixels = [[0u8; 4]; (GLYPH_MASK_WIDTH * GLYPH_MASK_HEIGHT) as usize];
+            for y in 0..GLYPH_MASK_HEIGHT {
+                for x in 0..GLYPH_MASK_WIDTH {
+                    pixels[(y * GLYPH_MASK_WIDTH + x) as usize] = camera_image
+                        .get_pixel(
+                            cell_x * GLYPH_MASK_WIDTH + x,
+                            cell_y * GLYPH_MASK_HEIGHT + y,
+                        )
+                        .0;
+                }
+            }
+
+            let (character, bg, fg) = glyphs
+                .iter()
+                .map(|(character, mask)| {
+                    let covered = |index: usize| {
+                        mask_covers(
+                            *mask,
+                            index as u32 % GLYPH_MASK_WIDTH,
+                            index as u32 / GLYPH_MASK_WIDTH,
+                        )
+                    };
+                    let fg = cluster_average(&pixels, covered);
+                    let bg = cluster_average(&pixels, |index| !covered(index));
+                    let (fg, bg) = match (fg, bg) {
+                        (Some(fg), Some(bg)) => (fg, bg),
+                        (Some(fg), None) => (fg, fg),
+                        (None, Some(bg)) => (bg, bg),
+                        (None, None) => ([0; 4], [0; 4]),
+                    };
+                    let variance = pixels
+                        .iter()
+                        .enumerate()
+                        .map(|(index, pixel)| {
+                            let center = if covered(index) { fg } else { bg };
+                            color_distance_squared(pixel, &center)
+                        })
+                        .sum::<u32>();
+
+                    (variance, *character, bg, fg)
+                })
+                .min_by_key(|(variance, ..)| *variance)
+                .map(|(_, character, bg, fg)| (character, bg, fg))
+                .unwrap_or(('▄', [0; 4], [0; 4]));
+
+            let bg = if (strategy_config.common.transparent || strategy_config.common.passthrough)
+                && bg[3] == 0
+            {
+                None
+            } else {
+                Some(Color::Rgb(bg[0], bg[1], bg[2]))
+            };
+            let fg = if (strategy_config.common.transparent || strategy_config.common.passthrough)
+                && fg[3] == 0
+            {
+                None
+            } else {
+                Some(Color::Rgb(fg[0], fg[1], fg[2]))
+            };
+
+            (character, bg, fg)
+        },
+    )
+}
+
+fn cluster_average(pixels: &[[u8; 4]], in_cluster: impl Fn(usize) -> bool) -> Option<[u8; 4]> {
+    let mut sum = [0u32; 4];
+    let mut count = 0;
+
+    for (index, pixel) in pixels.iter().enumerate() {
+        if !in_cluster(index) {
+            continue;
+        }
+
+        for channel in 0..4 {
+            sum[channel] += pixel[channel] as u32;
+        }
+        count += 1;
+    }
+
+    if count == 0 {
+        return None;
+    }
+
+    Some(sum.map(|channel| (channel / count) as u8))
+}
 
-        (bg, fg)
-    })
+fn color_distance_squared(a: &[u8; 4], b: &[u8; 4]) -> u32 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (*a as i32 - *b as i32).pow(2) as u32)
+        .sum()
 }
 
 fn convert_image_to_rgba_quads(camera_image: &DynamicImage) -> Vec<[[u8; 4]; 2]> {
@@ -1,16 +1,18 @@
 use bevy::color::Luminance;
+use bevy::log::info_span;
 use image::{DynamicImage, GenericImageView};
 use ratatui::prelude::*;
 
 use crate::color_support::color_for_color_support;
 use crate::widget_utilities::{
-    average_in_rgba, colors_for_color_choices, coords_from_index, replace_detected_edges,
+    average_in_rgba, colors_for_color_choices, map_cells, replace_detected_edges,
 };
 use crate::{LuminanceConfig, RatatuiCameraDepthBuffer, RatatuiCameraEdgeDetection};
 
 #[derive(Debug)]
 pub struct RatatuiCameraWidgetLuminance<'a> {
-    camera_image: DynamicImage,
+    cell_candidates: Vec<(char, Option<Color>)>,
+    width: u32,
     depth_image: Option<DynamicImage>,
     sobel_image: Option<DynamicImage>,
     depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
@@ -27,8 +29,42 @@ impl<'a> RatatuiCameraWidgetLuminance<'a> {
         strategy_config: &'a LuminanceConfig,
         edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
     ) -> Self {
+        let cell_candidates = convert_image_to_cell_candidates(
+            &camera_image,
+            &strategy_config.characters.list,
+            strategy_config.characters.scale,
+            strategy_config.common.parallel,
+        );
+
         Self {
-            camera_image,
+            cell_candidates,
+            width: camera_image.width(),
+            depth_image,
+            sobel_image,
+            depth_buffer,
+            strategy_config,
+            edge_detection,
+        }
+    }
+
+    /// Like [RatatuiCameraWidgetLuminance::new], but with each cell's character index and color
+    /// already chosen on the GPU (see [RatatuiCameraGpuCells](crate::RatatuiCameraGpuCells)), one
+    /// pixel per cell rather than two.
+    #[cfg(feature = "gpu_cells")]
+    pub fn from_gpu_cells(
+        cells_image: &DynamicImage,
+        depth_image: Option<DynamicImage>,
+        sobel_image: Option<DynamicImage>,
+        depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+        strategy_config: &'a LuminanceConfig,
+        edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+    ) -> Self {
+        let cell_candidates =
+            convert_gpu_cells_to_cell_candidates(cells_image, &strategy_config.characters.list);
+
+        Self {
+            cell_candidates,
+            width: cells_image.width(),
             depth_image,
             sobel_image,
             depth_buffer,
@@ -40,15 +76,14 @@ impl<'a> RatatuiCameraWidgetLuminance<'a> {
 
 impl Widget for &mut RatatuiCameraWidgetLuminance<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let cell_candidates = convert_image_to_cell_candidates(
-            &self.camera_image,
-            &self.strategy_config.characters.list,
-            self.strategy_config.characters.scale,
-        );
+        let _span = info_span!("ratatui_camera_strategy_luminance").entered();
 
-        for (index, (mut character, mut fg)) in cell_candidates.enumerate() {
+        let cell_candidates = std::mem::take(&mut self.cell_candidates);
+
+        for (index, (mut character, mut fg)) in cell_candidates.into_iter().enumerate() {
             let mut bg = None;
-            let (x, y) = coords_from_index(index, &self.camera_image);
+            let x = (index as u32 % self.width) as u16;
+            let y = (index as u32 / self.width) as u16;
 
             if x >= area.width || y >= area.height {
                 continue;
@@ -95,12 +130,25 @@ impl Widget for &mut RatatuiCameraWidgetLuminance<'_> {
                 &self.strategy_config.colors.background,
             );
 
+            if self.strategy_config.common.passthrough && fg.is_none() {
+                cell.reset();
+                continue;
+            }
+
             if self.strategy_config.common.transparent && fg.is_none() {
                 continue;
             }
 
-            fg = color_for_color_support(fg, self.strategy_config.colors.support);
-            bg = color_for_color_support(bg, self.strategy_config.colors.support);
+            fg = color_for_color_support(
+                fg,
+                self.strategy_config.colors.support,
+                self.strategy_config.colors.distance,
+            );
+            bg = color_for_color_support(
+                bg,
+                self.strategy_config.colors.support,
+                self.strategy_config.colors.distance,
+            );
 
             fg.map(|fg| cell.set_fg(fg).set_char(character));
             bg.map(|bg| cell.set_bg(bg));
@@ -112,12 +160,13 @@ fn convert_image_to_cell_candidates(
     camera_image: &DynamicImage,
     luminance_characters: &[char],
     luminance_scale: f32,
-) -> impl Iterator<Item = (char, Option<Color>)> {
+    parallel: bool,
+) -> Vec<(char, Option<Color>)> {
     let rgba_quads = convert_image_to_rgba_quads(camera_image);
 
-    rgba_quads.into_iter().map(move |rgba| {
+    map_cells(&rgba_quads, parallel, |rgba| {
         let character =
-            convert_rgba_quads_to_character(&rgba, luminance_characters, luminance_scale);
+            convert_rgba_quads_to_character(rgba, luminance_characters, luminance_scale);
         let color = if rgba[3] == 0 {
             None
         } else {
@@ -127,6 +176,31 @@ fn convert_image_to_cell_candidates(
     })
 }
 
+/// Read the characters and colors chosen on the GPU, where the alpha channel of each pixel holds
+/// the cell's character index, or `u8::MAX` for cells without any opaque pixels.
+#[cfg(feature = "gpu_cells")]
+fn convert_gpu_cells_to_cell_candidates(
+    cells_image: &DynamicImage,
+    luminance_characters: &[char],
+) -> Vec<(char, Option<Color>)> {
+    cells_image
+        .to_rgba8()
+        .pixels()
+        .map(|pixel| {
+            if pixel[3] == u8::MAX {
+                return (' ', None);
+            }
+
+            let character = luminance_characters
+                .get(pixel[3] as usize)
+                .copied()
+                .unwrap_or(' ');
+
+            (character, Some(Color::Rgb(pixel[0], pixel[1], pixel[2])))
+        })
+        .collect()
+}
+
 fn convert_image_to_rgba_quads(camera_image: &DynamicImage) -> Vec<[u8; 4]> {
     let mut rgba_quads =
         vec![[0; 4]; (camera_image.width() * camera_image.height().div_ceil(2)) as usize];
@@ -155,7 +229,7 @@ fn convert_rgba_quads_to_character(
             .luminance();
     let scaled_luminance = (luminance * luminance_scale).min(1.0);
     let character_index = ((scaled_luminance * luminance_characters.len() as f32) as usize)
-        .min(luminance_characters.len() - 1);
+        .min(luminance_characters.len().saturating_sub(1));
 
     let Some(character) = luminance_characters.get(character_index) else {
         return ' ';
@@ -1,3 +1,4 @@
+use bevy::log::info_span;
 use image::{DynamicImage, GenericImageView};
 use ratatui::prelude::*;
 use ratatui::widgets::WidgetRef;
@@ -27,6 +28,8 @@ impl<'a> RatatuiCameraWidgetNone<'a> {
 
 impl WidgetRef for RatatuiCameraWidgetNone<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let _span = info_span!("ratatui_camera_strategy_none").entered();
+
         let Self {
             camera_image,
             sobel_image,
@@ -0,0 +1,199 @@
+use bevy::log::info_span;
+use bevy::math::Vec3;
+use image::{DynamicImage, GenericImageView, Rgba};
+use ratatui::prelude::*;
+
+use crate::color_support::color_for_color_support;
+use crate::widget_utilities::{average_in_rgba, colors_for_color_choices, replace_detected_edges};
+use crate::{NormalsConfig, RatatuiCameraDepthBuffer, RatatuiCameraEdgeDetection};
+
+#[derive(Debug)]
+pub struct RatatuiCameraWidgetNormals<'a> {
+    camera_image: DynamicImage,
+    normal_image: Option<DynamicImage>,
+    depth_image: Option<DynamicImage>,
+    sobel_image: Option<DynamicImage>,
+    depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+    strategy_config: &'a NormalsConfig,
+    edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+}
+
+impl<'a> RatatuiCameraWidgetNormals<'a> {
+    pub fn new(
+        camera_image: DynamicImage,
+        normal_image: Option<DynamicImage>,
+        depth_image: Option<DynamicImage>,
+        sobel_image: Option<DynamicImage>,
+        depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+        strategy_config: &'a NormalsConfig,
+        edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+    ) -> Self {
+        Self {
+            camera_image,
+            normal_image,
+            depth_image,
+            sobel_image,
+            depth_buffer,
+            strategy_config,
+            edge_detection,
+        }
+    }
+}
+
+impl Widget for &mut RatatuiCameraWidgetNormals<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let _span = info_span!("ratatui_camera_strategy_normals").entered();
+
+        let Some(normal_image) = &self.normal_image else {
+            return;
+        };
+
+        let light_direction = self
+            .strategy_config
+            .light_direction
+            .try_normalize()
+            .unwrap_or(Vec3::Y);
+        let characters = &self.strategy_config.characters.list;
+
+        for y in 0..(self.camera_image.height() / 2).min(area.height as u32) {
+            for x in 0..self.camera_image.width().min(area.width as u32) {
+                let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) else {
+                    continue;
+                };
+
+                if let (Some(depth_image), Some(depth_buffer)) =
+                    (&self.depth_image, &mut self.depth_buffer)
+                {
+                    if depth_buffer
+                        .compare_and_update_from_image(x, y * 2, depth_image)
+                        .is_none_or(|draw| !draw)
+                    {
+                        continue;
+                    }
+                    if depth_buffer
+                        .compare_and_update_from_image(x, y * 2 + 1, depth_image)
+                        .is_none_or(|draw| !draw)
+                    {
+                        continue;
+                    }
+                }
+
+                let intensity = [y * 2, y * 2 + 1]
+                    .into_iter()
+                    .filter(|&py| normal_image.in_bounds(x, py))
+                    .filter_map(|py| decode_normal(&normal_image.get_pixel(x, py)))
+                    .map(|normal| {
+                        let ambient = self.strategy_config.ambient.clamp(0.0, 1.0);
+                        ambient + (1.0 - ambient) * normal.dot(light_direction).max(0.0)
+                    })
+                    .reduce(|a, b| (a + b) / 2.0);
+
+                let rgba = average_in_rgba(
+                    &self.camera_image.get_pixel(x, y * 2).0,
+                    &self.camera_image.get_pixel(x, y * 2 + 1),
+                );
+
+                let (mut character, mut fg) = match intensity {
+                    Some(intensity) => {
+                        let (character, band) = character_for_intensity(
+                            intensity,
+                            characters,
+                            self.strategy_config.characters.scale,
+                        );
+                        let rgba = if self.strategy_config.shade_colors {
+                            shade_rgba(rgba, band)
+                        } else {
+                            rgba
+                        };
+
+                        (character, Some(Color::Rgb(rgba[0], rgba[1], rgba[2])))
+                    }
+                    None => (' ', None),
+                };
+                let mut bg = None;
+
+                if let (Some(sobel_image), Some(edge_detection)) =
+                    (&self.sobel_image, self.edge_detection)
+                {
+                    if !sobel_image.in_bounds(x, y * 2) {
+                        continue;
+                    }
+
+                    let sobel_value = sobel_image.get_pixel(x, y * 2);
+
+                    (character, fg) =
+                        replace_detected_edges(character, fg, &sobel_value, edge_detection);
+                };
+
+                (fg, bg) = colors_for_color_choices(
+                    fg,
+                    bg,
+                    &self.strategy_config.colors.foreground,
+                    &self.strategy_config.colors.background,
+                );
+
+                if self.strategy_config.common.passthrough && fg.is_none() {
+                    cell.reset();
+                    continue;
+                }
+
+                if self.strategy_config.common.transparent && fg.is_none() {
+                    continue;
+                }
+
+                fg = color_for_color_support(
+                    fg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
+                bg = color_for_color_support(
+                    bg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
+
+                fg.map(|fg| cell.set_fg(fg).set_char(character));
+                bg.map(|bg| cell.set_bg(bg));
+            }
+        }
+    }
+}
+
+/// Decode a world space normal from a pixel of the normal image. The prepass stores each normal
+/// as `normal * 0.5 + 0.5`, and the readback image is sRGB encoded on top of that. Pixels not
+/// covered by any geometry have zero alpha, and return `None`.
+fn decode_normal(pixel: &Rgba<u8>) -> Option<Vec3> {
+    if pixel[3] == 0 {
+        return None;
+    }
+
+    let linear = bevy::color::Color::srgb_u8(pixel[0], pixel[1], pixel[2]).to_linear();
+    let normal = Vec3::new(linear.red, linear.green, linear.blue) * 2.0 - 1.0;
+
+    normal.try_normalize()
+}
+
+/// Select a character for a light intensity, returning it alongside the intensity of its band
+/// (the fraction of the way through the character list), for quantized color shading.
+fn character_for_intensity(intensity: f32, characters: &[char], scale: f32) -> (char, f32) {
+    let scaled_intensity = (intensity * scale).clamp(0.0, 1.0);
+    let character_index = ((scaled_intensity * characters.len() as f32) as usize)
+        .min(characters.len().saturating_sub(1));
+
+    let Some(character) = characters.get(character_index) else {
+        return (' ', scaled_intensity);
+    };
+
+    let band = (character_index + 1) as f32 / characters.len() as f32;
+
+    (*character, band)
+}
+
+fn shade_rgba(rgba: [u8; 4], band: f32) -> [u8; 4] {
+    [
+        (rgba[0] as f32 * band) as u8,
+        (rgba[1] as f32 * band) as u8,
+        (rgba[2] as f32 * band) as u8,
+        rgba[3],
+    ]
+}
@@ -0,0 +1,246 @@
+use bevy::color::Luminance;
+use bevy::log::info_span;
+use image::{DynamicImage, GenericImageView};
+use ratatui::prelude::*;
+
+use crate::color_support::color_for_color_support;
+use crate::widget_utilities::{colors_for_color_choices, replace_detected_edges};
+use crate::{
+    RatatuiCameraDepthBuffer, RatatuiCameraEdgeDetection, ThresholdConfig, ThresholdDithering,
+    ThresholdOutput,
+};
+
+/// 4x4 Bayer matrix used for ordered dithering.
+const BAYER_MATRIX: [[f32; 4]; 4] = [
+    [0., 8., 2., 10.],
+    [12., 4., 14., 6.],
+    [3., 11., 1., 9.],
+    [15., 7., 13., 5.],
+];
+
+#[derive(Debug)]
+pub struct RatatuiCameraWidgetThreshold<'a> {
+    camera_image: DynamicImage,
+    depth_image: Option<DynamicImage>,
+    sobel_image: Option<DynamicImage>,
+    depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+    strategy_config: &'a ThresholdConfig,
+    edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+}
+
+impl<'a> RatatuiCameraWidgetThreshold<'a> {
+    pub fn new(
+        camera_image: DynamicImage,
+        depth_image: Option<DynamicImage>,
+        sobel_image: Option<DynamicImage>,
+        depth_buffer: Option<&'a mut RatatuiCameraDepthBuffer>,
+        strategy_config: &'a ThresholdConfig,
+        edge_detection: &'a Option<RatatuiCameraEdgeDetection>,
+    ) -> Self {
+        Self {
+            camera_image,
+            depth_image,
+            sobel_image,
+            depth_buffer,
+            strategy_config,
+            edge_detection,
+        }
+    }
+}
+
+impl Widget for &mut RatatuiCameraWidgetThreshold<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let _span = info_span!("ratatui_camera_strategy_threshold").entered();
+
+        let width = self.camera_image.width();
+        let height = self.camera_image.height() / 2;
+
+        let subpixels = match self.strategy_config.output {
+            ThresholdOutput::Colors { .. } => threshold_image(
+                &self.camera_image,
+                1,
+                self.strategy_config.common.transparent || self.strategy_config.common.passthrough,
+                self.strategy_config.threshold,
+                self.strategy_config.dithering,
+            ),
+            ThresholdOutput::Characters { .. } => threshold_image(
+                &self.camera_image,
+                2,
+                self.strategy_config.common.transparent || self.strategy_config.common.passthrough,
+                self.strategy_config.threshold,
+                self.strategy_config.dithering,
+            ),
+        };
+
+        for y in 0..height.min(area.height as u32) {
+            for x in 0..width.min(area.width as u32) {
+                let (mut character, mut fg, mut bg) = match self.strategy_config.output {
+                    ThresholdOutput::Colors { on, off } => {
+                        let color_for = |subpixel: Option<bool>| {
+                            subpixel.map(|subpixel| if subpixel { on } else { off })
+                        };
+                        let top = subpixels[(y * 2 * width + x) as usize];
+                        let bottom = subpixels[((y * 2 + 1) * width + x) as usize];
+
+                        ('▄', color_for(bottom), color_for(top))
+                    }
+                    ThresholdOutput::Characters { on, off } => {
+                        match subpixels[(y * width + x) as usize] {
+                            Some(true) => (on, Some(Color::White), Some(Color::Black)),
+                            Some(false) => (off, Some(Color::White), Some(Color::Black)),
+                            None => (' ', None, None),
+                        }
+                    }
+                };
+
+                let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) else {
+                    continue;
+                };
+
+                if let (Some(depth_image), Some(depth_buffer)) =
+                    (&self.depth_image, &mut self.depth_buffer)
+                {
+                    if depth_buffer
+                        .compare_and_update_from_image(x, y * 2, depth_image)
+                        .is_none_or(|draw| !draw)
+                    {
+                        continue;
+                    }
+                    if depth_buffer
+                        .compare_and_update_from_image(x, y * 2 + 1, depth_image)
+                        .is_none_or(|draw| !draw)
+                    {
+                        continue;
+                    }
+                }
+
+                if let (Some(sobel_image), Some(edge_detection)) =
+                    (&self.sobel_image, self.edge_detection)
+                {
+                    if !sobel_image.in_bounds(x, y * 2) {
+                        continue;
+                    }
+
+                    let sobel_value = sobel_image.get_pixel(x, y * 2);
+
+                    (character, fg) =
+                        replace_detected_edges(character, fg, &sobel_value, edge_detection);
+                };
+
+                (fg, bg) = colors_for_color_choices(
+                    fg,
+                    bg,
+                    &self.strategy_config.colors.foreground,
+                    &self.strategy_config.colors.background,
+                );
+
+                if self.strategy_config.common.passthrough && fg.is_none() && bg.is_none() {
+                    cell.reset();
+                    continue;
+                }
+
+                if self.strategy_config.common.transparent && fg.is_none() && bg.is_none() {
+                    continue;
+                }
+
+                fg = color_for_color_support(
+                    fg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
+                bg = color_for_color_support(
+                    bg,
+                    self.strategy_config.colors.support,
+                    self.strategy_config.colors.distance,
+                );
+
+                fg.map(|fg| cell.set_fg(fg).set_char(character));
+                bg.map(|bg| cell.set_bg(bg));
+            }
+        }
+    }
+}
+
+/// Convert the image to a grid of on/off values, one per `rows_per_value` rows of pixels (the
+/// luminance of the combined pixels is averaged). If `transparent` is set, fully transparent
+/// regions produce `None`.
+fn threshold_image(
+    camera_image: &DynamicImage,
+    rows_per_value: u32,
+    transparent: bool,
+    threshold: f32,
+    dithering: Option<ThresholdDithering>,
+) -> Vec<Option<bool>> {
+    let camera_image = camera_image.to_rgba8();
+    let width = camera_image.width();
+    let height = camera_image.height() / rows_per_value;
+
+    let mut luminances = Vec::with_capacity((width * height) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = 0.;
+            let mut count = 0;
+
+            for row in 0..rows_per_value {
+                let pixel = camera_image.get_pixel(x, y * rows_per_value + row);
+
+                if transparent && pixel[3] == 0 {
+                    continue;
+                }
+
+                sum += bevy::color::Color::srgba_u8(pixel[0], pixel[1], pixel[2], pixel[3])
+                    .luminance();
+                count += 1;
+            }
+
+            luminances.push((count > 0).then_some(sum / count as f32));
+        }
+    }
+
+    match dithering {
+        None => luminances
+            .into_iter()
+            .map(|luminance| luminance.map(|luminance| luminance >= threshold))
+            .collect(),
+        Some(ThresholdDithering::Ordered) => luminances
+            .into_iter()
+            .enumerate()
+            .map(|(index, luminance)| {
+                let x = index % width as usize;
+                let y = index / width as usize;
+                let offset = (BAYER_MATRIX[y % 4][x % 4] + 0.5) / 16. - 0.5;
+
+                luminance.map(|luminance| luminance + offset >= threshold)
+            })
+            .collect(),
+        Some(ThresholdDithering::ErrorDiffusion) => {
+            let mut result = Vec::with_capacity(luminances.len());
+            let width = width as usize;
+
+            for index in 0..luminances.len() {
+                let Some(luminance) = luminances[index] else {
+                    result.push(None);
+                    continue;
+                };
+
+                let on = luminance >= threshold;
+                let error = luminance - if on { 1. } else { 0. };
+                result.push(Some(on));
+
+                // floyd-steinberg weights, skipping neighbors outside the image or transparent.
+                let x = index % width;
+                let mut diffuse = |neighbor: Option<usize>, weight: f32| {
+                    if let Some(Some(value)) = neighbor.and_then(|n| luminances.get_mut(n)) {
+                        *value += error * weight;
+                    }
+                };
+                diffuse((x + 1 < width).then_some(index + 1), 7. / 16.);
+                diffuse((x > 0).then_some(index + width - 1), 3. / 16.);
+                diffuse(Some(index + width), 5. / 16.);
+                diffuse((x + 1 < width).then_some(index + width + 1), 1. / 16.);
+            }
+
+            result
+        }
+    }
+}
@@ -1,7 +1,39 @@
-use image::{DynamicImage, Rgb, Rgba};
-use ratatui::style::Color;
+use bevy::color::Luminance;
+use bevy::math::{Mat4, Vec4};
+use bevy::tasks::{ComputeTaskPool, ParallelSlice};
+use image::{DynamicImage, GenericImageView, Rgb, Rgba};
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
 
-use crate::{ColorChoice, RatatuiCameraEdgeDetection};
+use crate::{
+    ColorChoice, ColorDistance, ColorLut, ColorSupport, ColorsConfig, EdgeCharacters,
+    GrayscaleWeights, ModifierMapping, ModifierSource, RatatuiCameraDepthJoints,
+    RatatuiCameraEdgeDetection, RatatuiCameraEmissiveDetection,
+    color_support::color_for_color_support,
+};
+
+/// Convert each item (e.g. a cell's pixels) into a cell candidate, keeping the items' order. If
+/// `parallel` is set and bevy's compute task pool is available, the items are split into chunks
+/// that are converted across the pool's threads, otherwise they are converted serially.
+pub fn map_cells<T, U>(
+    items: &[T],
+    parallel: bool,
+    convert: impl Fn(&T) -> U + Send + Sync,
+) -> Vec<U>
+where
+    T: Sync,
+    U: Send + 'static,
+{
+    match ComputeTaskPool::try_get().filter(|_| parallel) {
+        Some(task_pool) => items
+            .par_splat_map(task_pool, None, |_, chunk| {
+                chunk.iter().map(&convert).collect::<Vec<_>>()
+            })
+            .into_iter()
+            .flatten()
+            .collect(),
+        None => items.iter().map(convert).collect(),
+    }
+}
 
 pub fn coords_from_index(index: usize, image: &DynamicImage) -> (u16, u16) {
     (
@@ -16,7 +48,27 @@ pub fn replace_detected_edges(
     sobel_value: &Rgba<u8>,
     edge_detection: &RatatuiCameraEdgeDetection,
 ) -> (char, Option<Color>) {
-    let edge_color = edge_detection.edge_color.or(fg);
+    let mut edge_color = edge_detection.edge_color.or(fg);
+
+    if edge_detection.softness > 0.0 {
+        // the sobel texture is sRGB, so intensities need to be converted back to linear values.
+        let intensity = sobel_value
+            .0
+            .iter()
+            .map(|&value| {
+                bevy::color::Color::srgb_u8(value, value, value)
+                    .to_linear()
+                    .red
+            })
+            .fold(0., f32::max);
+
+        if intensity < 0.5 {
+            return (character, blend_colors(fg, edge_color, intensity));
+        }
+
+        edge_color = blend_colors(fg, edge_color, intensity);
+    }
+
     match edge_detection.edge_characters {
         crate::EdgeCharacters::Directional {
             vertical,
@@ -53,6 +105,19 @@ pub fn replace_detected_edges(
     }
 }
 
+/// Linearly interpolate between two colors. Colors that are not RGB cannot be blended, so the
+/// closer of the two is used instead.
+pub(crate) fn blend_colors(from: Option<Color>, to: Option<Color>, amount: f32) -> Option<Color> {
+    match (from, to) {
+        (Some(Color::Rgb(r1, g1, b1)), Some(Color::Rgb(r2, g2, b2))) => {
+            let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * amount).round() as u8;
+            Some(Color::Rgb(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2)))
+        }
+        _ if amount < 0.5 => from,
+        _ => to,
+    }
+}
+
 pub fn average_in_rgb(rgb_triplet: &[u8; 3], pixel: &Rgb<u8>) -> [u8; 3] {
     [
         ((rgb_triplet[0] as u16 + pixel[0] as u16) / 2) as u8,
@@ -109,3 +174,377 @@ fn color_for_color_choice(
         ColorChoice::Callback(callback) => callback(fg, bg),
     }
 }
+
+/// Apply the color adjustments in the provided colors config to the camera image, before any
+/// character or color selection happens.
+/// Whether [adjust_image_colors] would change the image with the provided depth image and LUT.
+pub fn adjusts_image_colors(
+    depth_image: Option<&DynamicImage>,
+    colors_config: &ColorsConfig,
+    color_lut: Option<&ColorLut>,
+) -> bool {
+    colors_config.has_tone_adjustments()
+        || colors_config.grayscale.is_some()
+        || (colors_config.fog.is_some() && depth_image.is_some())
+        || color_lut.is_some()
+}
+
+pub fn adjust_image_colors(
+    camera_image: &mut DynamicImage,
+    depth_image: Option<&DynamicImage>,
+    view_from_clip: &Mat4,
+    colors_config: &ColorsConfig,
+    color_lut: Option<&ColorLut>,
+) {
+    if !adjusts_image_colors(depth_image, colors_config, color_lut) {
+        return;
+    }
+
+    let fog = colors_config.fog.zip(depth_image);
+
+    if camera_image.as_rgba8().is_none() {
+        *camera_image = DynamicImage::ImageRgba8(camera_image.to_rgba8());
+    }
+
+    let Some(pixels) = camera_image.as_mut_rgba8() else {
+        return;
+    };
+
+    if colors_config.has_tone_adjustments() {
+        let [weight_r, weight_g, weight_b] = GrayscaleWeights::Rec709.weights();
+        let inverse_gamma = 1.0 / colors_config.gamma.max(f32::EPSILON);
+
+        for pixel in pixels.pixels_mut() {
+            let channels = [pixel[0], pixel[1], pixel[2]].map(|channel| {
+                let channel = channel as f32 / u8::MAX as f32 * colors_config.brightness;
+                (channel - 0.5) * colors_config.contrast + 0.5
+            });
+
+            let luminance =
+                channels[0] * weight_r + channels[1] * weight_g + channels[2] * weight_b;
+
+            for (index, channel) in channels.into_iter().enumerate() {
+                let channel = luminance + (channel - luminance) * colors_config.saturation;
+                pixel[index] =
+                    (channel.clamp(0.0, 1.0).powf(inverse_gamma) * u8::MAX as f32).round() as u8;
+            }
+        }
+    }
+
+    if let Some(grayscale) = colors_config.grayscale {
+        let [weight_r, weight_g, weight_b] = grayscale.weights();
+
+        for pixel in pixels.pixels_mut() {
+            let gray = (pixel[0] as f32 * weight_r
+                + pixel[1] as f32 * weight_g
+                + pixel[2] as f32 * weight_b)
+                .round()
+                .clamp(0., u8::MAX as f32) as u8;
+
+            pixel[0] = gray;
+            pixel[1] = gray;
+            pixel[2] = gray;
+        }
+    }
+
+    if let Some((fog, depth_image)) = fog {
+        let (width, height) = pixels.dimensions();
+        let (depth_width, depth_height) = depth_image.dimensions();
+
+        for (x, y, pixel) in pixels.enumerate_pixels_mut() {
+            // the camera image may have been resized to a different resolution than the depth.
+            let depth_x = (x * depth_width / width).min(depth_width.saturating_sub(1));
+            let depth_y = (y * depth_height / height).min(depth_height.saturating_sub(1));
+            if !depth_image.in_bounds(depth_x, depth_y) {
+                continue;
+            }
+
+            let depth = f32::from_le_bytes(depth_image.get_pixel(depth_x, depth_y).0);
+            let amount =
+                (1.0 - fog.mapping.map(depth, view_from_clip)) * fog.max_amount.clamp(0.0, 1.0);
+
+            for channel in 0..3 {
+                pixel[channel] = (pixel[channel] as f32
+                    + (fog.color[channel] as f32 - pixel[channel] as f32) * amount)
+                    .round() as u8;
+            }
+        }
+    }
+
+    if let Some(color_lut) = color_lut {
+        for pixel in pixels.pixels_mut() {
+            let [r, g, b] = color_lut.apply([pixel[0], pixel[1], pixel[2]]);
+            pixel[0] = r;
+            pixel[1] = g;
+            pixel[2] = b;
+        }
+    }
+}
+
+/// Add the emissive detection's modifiers to cells covering pixels brighter than its threshold,
+/// and shift their characters further along the strategy's character list (if it has one).
+pub fn apply_emissive_detection(
+    emissive_image: &DynamicImage,
+    emissive_detection: &RatatuiCameraEmissiveDetection,
+    characters: Option<&[char]>,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    for y in 0..(emissive_image.height() / 2).min(area.height as u32) {
+        for x in 0..emissive_image.width().min(area.width as u32) {
+            let brightness = [y * 2, y * 2 + 1]
+                .into_iter()
+                .map(|pixel_y| {
+                    // stored as a fraction of the max brightness, in an sRGB texture.
+                    let value = emissive_image.get_pixel(x, pixel_y)[0];
+                    bevy::color::Color::srgb_u8(value, value, value)
+                        .to_linear()
+                        .red
+                        * RatatuiCameraEmissiveDetection::MAX_BRIGHTNESS
+                })
+                .fold(0., f32::max);
+
+            if brightness <= emissive_detection.threshold {
+                continue;
+            }
+
+            let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) else {
+                continue;
+            };
+
+            cell.modifier.insert(emissive_detection.modifier);
+
+            let Some(characters) = characters else {
+                continue;
+            };
+
+            let boosted_character = cell
+                .symbol()
+                .chars()
+                .next()
+                .and_then(|character| characters.iter().position(|&c| c == character))
+                .map(|index| {
+                    characters
+                        [(index + emissive_detection.character_boost).min(characters.len() - 1)]
+                });
+
+            if let Some(boosted_character) = boosted_character {
+                cell.set_char(boosted_character);
+            }
+        }
+    }
+}
+
+/// Recolor the cells drawn as detected edges using the nearest edge color recorded around them,
+/// so that the outline just outside of a mesh picks up the mesh's edge color as well.
+pub fn apply_edge_colors(
+    edge_color_image: &DynamicImage,
+    sobel_image: &DynamicImage,
+    edge_detection: &RatatuiCameraEdgeDetection,
+    color_support: ColorSupport,
+    color_distance: ColorDistance,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let edge_characters = match edge_detection.edge_characters {
+        EdgeCharacters::Single(character) => vec![character],
+        EdgeCharacters::Directional {
+            vertical,
+            horizontal,
+            forward_diagonal,
+            backward_diagonal,
+        } => vec![vertical, horizontal, forward_diagonal, backward_diagonal],
+    };
+
+    for y in 0..(sobel_image.height() / 2).min(area.height as u32) {
+        for x in 0..sobel_image.width().min(area.width as u32) {
+            if sobel_image
+                .get_pixel(x, y * 2)
+                .0
+                .iter()
+                .all(|value| *value == 0)
+            {
+                continue;
+            }
+
+            let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) else {
+                continue;
+            };
+
+            if !cell
+                .symbol()
+                .chars()
+                .next()
+                .is_some_and(|character| edge_characters.contains(&character))
+            {
+                continue;
+            }
+
+            let neighbors = (-1..=1).flat_map(|dx| (-1..=2).map(move |dy| (dx, dy)));
+            let edge_color = neighbors
+                .filter_map(|(dx, dy)| {
+                    let pixel_x = x.checked_add_signed(dx)?;
+                    let pixel_y = (y * 2).checked_add_signed(dy)?;
+                    edge_color_image
+                        .in_bounds(pixel_x, pixel_y)
+                        .then(|| edge_color_image.get_pixel(pixel_x, pixel_y))
+                })
+                .find(|pixel| pixel[3] > 0);
+
+            if let Some(edge_color) = edge_color
+                && let Some(fg) = color_for_color_support(
+                    Some(Color::Rgb(edge_color[0], edge_color[1], edge_color[2])),
+                    color_support,
+                    color_distance,
+                )
+            {
+                cell.fg = fg;
+            }
+        }
+    }
+}
+
+/// Draw box-drawing characters over the nearer cells along large jumps in depth between
+/// neighboring cells, joined up with each other wherever joints neighbor each other.
+pub fn apply_depth_joints(
+    depth_image: &DynamicImage,
+    view_from_clip: &Mat4,
+    depth_joints: &RatatuiCameraDepthJoints,
+    color_support: ColorSupport,
+    color_distance: ColorDistance,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let width = depth_image.width().min(area.width as u32) as usize;
+    let height = (depth_image.height() / 2).min(area.height as u32) as usize;
+
+    // distance from the camera of the nearest of each cell's two pixels, infinite where nothing
+    // was rendered.
+    let distances = (0..width * height)
+        .map(|index| {
+            let (x, y) = ((index % width) as u32, (index / width) as u32);
+            let depth = [y * 2, y * 2 + 1]
+                .into_iter()
+                .map(|pixel_y| f32::from_le_bytes(depth_image.get_pixel(x, pixel_y).0))
+                .fold(0., f32::max);
+
+            let view_position = *view_from_clip * Vec4::new(0.0, 0.0, depth, 1.0);
+            let distance = -view_position.z / view_position.w;
+            if depth > 0.0 && distance.is_finite() {
+                distance
+            } else {
+                f32::INFINITY
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let distance_at = |x: usize, y: usize, dx: isize, dy: isize| {
+        let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        (x < width && y < height).then(|| distances[x + y * width])
+    };
+
+    let is_joint = |x: usize, y: usize| {
+        let distance = distances[x + y * width];
+        if !distance.is_finite() {
+            return false;
+        }
+
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .any(|(dx, dy)| {
+                distance_at(x, y, dx, dy).is_some_and(|neighbor| {
+                    neighbor > distance && (neighbor - distance) / distance > depth_joints.threshold
+                })
+            })
+    };
+
+    let joints = (0..width * height)
+        .map(|index| is_joint(index % width, index / width))
+        .collect::<Vec<_>>();
+
+    let joint_at = |x: usize, y: usize, dx: isize, dy: isize| {
+        let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+            return false;
+        };
+        x < width && y < height && joints[x + y * width]
+    };
+
+    let color = color_for_color_support(depth_joints.color, color_support, color_distance);
+
+    for y in 0..height {
+        for x in 0..width {
+            if !joints[x + y * width] {
+                continue;
+            }
+
+            let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) else {
+                continue;
+            };
+
+            cell.set_symbol(depth_joints.style.symbol(
+                joint_at(x, y, 0, -1),
+                joint_at(x, y, 1, 0),
+                joint_at(x, y, 0, 1),
+                joint_at(x, y, -1, 0),
+            ));
+
+            if let Some(color) = color {
+                cell.set_fg(color);
+            }
+        }
+    }
+}
+
+/// Add the style modifiers of each mapping to the cells whose luminance or depth falls within the
+/// mapping's range. Cells with no opaque pixels are skipped.
+pub fn apply_modifier_mappings(
+    camera_image: &DynamicImage,
+    depth_image: Option<&DynamicImage>,
+    view_from_clip: &Mat4,
+    mappings: &[ModifierMapping],
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let width = camera_image.width().min(area.width as u32);
+    let height = (camera_image.height() / 2).min(area.height as u32);
+
+    for y in 0..height {
+        for x in 0..width {
+            let pixels = [y * 2, y * 2 + 1].map(|pixel_y| camera_image.get_pixel(x, pixel_y));
+            if pixels.iter().all(|pixel| pixel[3] == 0) {
+                continue;
+            }
+
+            let luminance = pixels
+                .iter()
+                .map(|pixel| bevy::color::Color::srgb_u8(pixel[0], pixel[1], pixel[2]).luminance())
+                .sum::<f32>()
+                / 2.;
+
+            let depth = depth_image.map(|depth_image| {
+                [y * 2, y * 2 + 1]
+                    .into_iter()
+                    .map(|pixel_y| f32::from_le_bytes(depth_image.get_pixel(x, pixel_y).0))
+                    .fold(0., f32::max)
+            });
+
+            let Some(cell) = buf.cell_mut((area.x + x as u16, area.y + y as u16)) else {
+                continue;
+            };
+
+            for mapping in mappings {
+                let value = match mapping.source {
+                    ModifierSource::Luminance => luminance,
+                    ModifierSource::Depth(depth_mapping) => match depth {
+                        Some(depth) if depth > 0.0 => depth_mapping.map(depth, view_from_clip),
+                        _ => continue,
+                    },
+                };
+
+                if (mapping.min..=mapping.max).contains(&value) {
+                    cell.modifier.insert(mapping.modifier);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use bevy::{
+    camera::primitives::Aabb,
+    mesh::PrimitiveTopology,
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+
+use crate::{
+    RatatuiBrailleCanvas, RatatuiCameraDepthBuffer, RatatuiCameraLatency, RatatuiCameraLayer,
+    RatatuiCameraLowLatencySet, RatatuiCameraSet, RatatuiCameraWidget,
+};
+
+/// Triangles whose normals are at least this similar are considered coplanar, and the edge
+/// between them is not drawn.
+const COPLANAR_NORMAL_DOT: f32 = 0.999;
+
+pub struct RatatuiCameraWireframePlugin;
+
+impl Plugin for RatatuiCameraWireframePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            First,
+            project_ratatui_wireframes_system.after(RatatuiCameraSet),
+        )
+        .add_systems(
+            PostUpdate,
+            project_ratatui_wireframes_system
+                .after(RatatuiCameraLowLatencySet)
+                .run_if(|latency: Res<RatatuiCameraLatency>| {
+                    *latency != RatatuiCameraLatency::Standard
+                }),
+        );
+    }
+}
+
+/// When within an entity, the entity's edges are drawn as braille lines (see
+/// [RatatuiBrailleCanvas]) over every RatatuiCamera's widget, projected from the bevy world
+/// directly into the terminal buffer. These vector wireframes stay crisp at resolutions where
+/// edge detection (see [RatatuiCameraEdgeDetection](crate::RatatuiCameraEdgeDetection)) breaks
+/// up, as lines are drawn at the resolution of braille dots rather than of the render texture.
+///
+/// When a widget is rendered with a depth buffer from a camera with
+/// [RatatuiCameraDepthDetection](crate::RatatuiCameraDepthDetection), edges hidden behind the
+/// scene are skipped. Edges crossing behind the camera are skipped entirely.
+///
+/// # Example:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ratatui_camera::RatatuiCameraWireframe;
+/// # use ratatui::style::Color;
+/// #
+/// # fn setup_scene_system(
+/// #     mut commands: Commands,
+/// #     mut meshes: ResMut<Assets<Mesh>>,
+/// # ) {
+/// commands.spawn((
+///     Mesh3d(meshes.add(Cuboid::default())),
+///     RatatuiCameraWireframe::mesh(Color::Cyan),
+/// ));
+/// # };
+/// ```
+///
+#[derive(Component, Clone, Debug, Default)]
+pub struct RatatuiCameraWireframe {
+    /// Which edges to draw.
+    pub source: WireframeSource,
+
+    /// Color of the lines.
+    pub color: Color,
+}
+
+/// Where the edges of a [RatatuiCameraWireframe] come from.
+#[derive(Clone, Debug, Default)]
+pub enum WireframeSource {
+    /// The edges of the entity's `Mesh3d` (a triangle or line list). Edges between coplanar
+    /// triangles (e.g. the diagonals of a quad) are skipped. Meshes only kept in the render world
+    /// (see `RenderAssetUsages`) cannot be drawn.
+    #[default]
+    Mesh,
+
+    /// The twelve edges of the entity's bounding box (its `Aabb`).
+    Aabb,
+
+    /// Edges provided directly, as pairs of points relative to the entity's transform.
+    Edges(Vec<[Vec3; 2]>),
+}
+
+impl RatatuiCameraWireframe {
+    /// Draw the edges of the entity's mesh.
+    pub fn mesh(color: Color) -> Self {
+        Self {
+            source: WireframeSource::Mesh,
+            color,
+        }
+    }
+
+    /// Draw the edges of the entity's bounding box.
+    pub fn aabb(color: Color) -> Self {
+        Self {
+            source: WireframeSource::Aabb,
+            color,
+        }
+    }
+
+    /// Draw the provided edges, relative to the entity's transform.
+    pub fn edges(edges: Vec<[Vec3; 2]>, color: Color) -> Self {
+        Self {
+            source: WireframeSource::Edges(edges),
+            color,
+        }
+    }
+}
+
+/// A wireframe edge, with its ends in normalized device coordinates of a camera.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ProjectedEdge {
+    from: Vec3,
+    to: Vec3,
+    color: Color,
+}
+
+impl RatatuiCameraWidget {
+    /// Draw the wireframe edges projected into this widget's camera within the render area,
+    /// skipping dots occluded according to the depth buffer if one is provided.
+    pub(crate) fn render_wireframe_edges(
+        &self,
+        render_area: Rect,
+        buf: &mut Buffer,
+        depth_buffer: Option<&RatatuiCameraDepthBuffer>,
+    ) {
+        if self.wireframe_edges.is_empty() {
+            return;
+        }
+
+        let mut canvas = RatatuiBrailleCanvas::new(render_area);
+        let dimensions = canvas.dot_dimensions().as_vec2();
+
+        // ndc to dot coordinates, with the center of each dot at whole coordinates.
+        let to_dot = |ndc: Vec3| {
+            let relative = Vec2::new(ndc.x / 2. + 0.5, -ndc.y / 2. + 0.5);
+            (relative * dimensions - 0.5).extend(ndc.z)
+        };
+
+        for edge in &self.wireframe_edges {
+            let (from, to) = (to_dot(edge.from), to_dot(edge.to));
+            match depth_buffer {
+                Some(depth_buffer) => canvas.line_with_depth(from, to, edge.color, depth_buffer),
+                None => canvas.line(from.truncate(), to.truncate(), edge.color),
+            }
+        }
+
+        canvas.render(buf);
+    }
+}
+
+fn project_ratatui_wireframes_system(
+    mut ratatui_cameras: Query<
+        (&Camera, &GlobalTransform, &mut RatatuiCameraWidget),
+        Without<RatatuiCameraLayer>,
+    >,
+    wireframes: Query<(
+        &RatatuiCameraWireframe,
+        &GlobalTransform,
+        Option<&Mesh3d>,
+        Option<&Aabb>,
+        Option<&InheritedVisibility>,
+    )>,
+    meshes: Res<Assets<Mesh>>,
+    mut mesh_messages: MessageReader<AssetEvent<Mesh>>,
+    mut mesh_edges: Local<HashMap<AssetId<Mesh>, Vec<[Vec3; 2]>>>,
+) {
+    for message in mesh_messages.read() {
+        if let AssetEvent::Modified { id } | AssetEvent::Removed { id } = message {
+            mesh_edges.remove(id);
+        }
+    }
+
+    let mut world_edges = Vec::new();
+
+    for (wireframe, transform, mesh, aabb, visibility) in &wireframes {
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            continue;
+        }
+
+        let aabb_edges;
+        let edges: &[[Vec3; 2]] = match &wireframe.source {
+            WireframeSource::Mesh => {
+                let Some((mesh_id, mesh)) =
+                    mesh.and_then(|mesh| Some((mesh.id(), meshes.get(&mesh.0)?)))
+                else {
+                    continue;
+                };
+
+                mesh_edges
+                    .entry(mesh_id)
+                    .or_insert_with(|| mesh_wireframe_edges(mesh))
+            }
+            WireframeSource::Aabb => {
+                let Some(aabb) = aabb else {
+                    continue;
+                };
+
+                aabb_edges = aabb_wireframe_edges(aabb);
+                &aabb_edges
+            }
+            WireframeSource::Edges(edges) => edges,
+        };
+
+        world_edges.extend(edges.iter().map(|[from, to]| {
+            (
+                transform.transform_point(*from),
+                transform.transform_point(*to),
+                wireframe.color,
+            )
+        }));
+    }
+
+    for (camera, camera_transform, mut widget) in &mut ratatui_cameras {
+        widget.wireframe_edges.clear();
+
+        let project = |point: Vec3| {
+            camera
+                .world_to_ndc(camera_transform, point)
+                .filter(|ndc| (0.0..=1.0).contains(&ndc.z))
+        };
+
+        for (from, to, color) in &world_edges {
+            if let (Some(from), Some(to)) = (project(*from), project(*to)) {
+                widget.wireframe_edges.push(ProjectedEdge {
+                    from,
+                    to,
+                    color: *color,
+                });
+            }
+        }
+    }
+}
+
+/// Unique edges of a triangle list or line list mesh, skipping edges shared by coplanar
+/// triangles.
+fn mesh_wireframe_edges(mesh: &Mesh) -> Vec<[Vec3; 2]> {
+    let Some(positions) = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|positions| positions.as_float3())
+    else {
+        return Vec::new();
+    };
+
+    let indices: Vec<usize> = match mesh.indices() {
+        Some(indices) => indices.iter().collect(),
+        None => (0..positions.len()).collect(),
+    };
+    let position = |index: usize| positions.get(index).copied().map(Vec3::from);
+
+    // vertices are often duplicated (e.g. with different normals), so edges are keyed by position.
+    let key = |a: Vec3, b: Vec3| {
+        let (a, b) = (
+            a.to_array().map(f32::to_bits),
+            b.to_array().map(f32::to_bits),
+        );
+        if a <= b { (a, b) } else { (b, a) }
+    };
+
+    match mesh.primitive_topology() {
+        PrimitiveTopology::LineList => {
+            let mut seen = HashSet::new();
+            indices
+                .chunks_exact(2)
+                .filter_map(|line| Some([position(line[0])?, position(line[1])?]))
+                .filter(|[a, b]| seen.insert(key(*a, *b)))
+                .collect()
+        }
+        PrimitiveTopology::TriangleList => {
+            let mut normals = HashMap::<_, (Vec3, Vec3, Vec<Vec3>)>::new();
+            let mut order = Vec::new();
+
+            for triangle in indices.chunks_exact(3) {
+                let (Some(a), Some(b), Some(c)) = (
+                    position(triangle[0]),
+                    position(triangle[1]),
+                    position(triangle[2]),
+                ) else {
+                    continue;
+                };
+
+                let normal = (b - a).cross(c - a).normalize_or_zero();
+                for (from, to) in [(a, b), (b, c), (c, a)] {
+                    let edge_key = key(from, to);
+                    let (_, _, edge_normals) = normals.entry(edge_key).or_insert_with(|| {
+                        order.push(edge_key);
+                        (from, to, Vec::new())
+                    });
+                    edge_normals.push(normal);
+                }
+            }
+
+            order
+                .iter()
+                .filter_map(|edge_key| normals.get(edge_key))
+                .filter(|(_, _, edge_normals)| {
+                    !matches!(edge_normals.as_slice(), [first, second] if first.dot(*second) >= COPLANAR_NORMAL_DOT)
+                })
+                .map(|(from, to, _)| [*from, *to])
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// The twelve edges of a bounding box.
+fn aabb_wireframe_edges(aabb: &Aabb) -> [[Vec3; 2]; 12] {
+    let (min, max) = (Vec3::from(aabb.min()), Vec3::from(aabb.max()));
+    let corner = |x: bool, y: bool, z: bool| {
+        Vec3::new(
+            if x { max.x } else { min.x },
+            if y { max.y } else { min.y },
+            if z { max.z } else { min.z },
+        )
+    };
+
+    let mut edges = [[Vec3::ZERO; 2]; 12];
+    for (index, (a, b)) in [(false, false), (false, true), (true, false), (true, true)]
+        .into_iter()
+        .enumerate()
+    {
+        edges[index * 3] = [corner(false, a, b), corner(true, a, b)];
+        edges[index * 3 + 1] = [corner(a, false, b), corner(a, true, b)];
+        edges[index * 3 + 2] = [corner(a, b, false), corner(a, b, true)];
+    }
+
+    edges
+}